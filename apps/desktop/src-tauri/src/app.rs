@@ -66,6 +66,9 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             app.manage(crate::state::OptionKeyDatabase::new(pool.clone()));
             app.manage(crate::state::GoogleOAuthState::from_env());
             app.manage(crate::state::OverlayState::new());
+            app.manage(crate::state::PostProcessingState::new());
+            app.manage(crate::state::SelectionRewriteState::new());
+            app.manage(crate::state::PasteHistoryState::new());
 
             #[cfg(desktop)]
             {
@@ -100,11 +103,20 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
                 let pool_for_bg = pool.clone();
                 let app_handle_for_bg = app_handle.clone();
                 tauri::async_runtime::spawn(async move {
-                    let transcription_mode =
-                        crate::db::preferences_queries::fetch_transcription_mode(pool_for_bg)
-                            .await
-                            .ok()
-                            .flatten();
+                    let transcription_mode = crate::db::preferences_queries::fetch_transcription_mode(
+                        pool_for_bg.clone(),
+                    )
+                    .await
+                    .ok()
+                    .flatten();
+                    let engine = crate::db::preferences_queries::fetch_local_transcription_engine(
+                        pool_for_bg,
+                    )
+                    .await
+                    .ok()
+                    .flatten()
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or_default();
 
                     let should_init_whisper = match transcription_mode.as_deref() {
                         None | Some("local") => true,
@@ -114,7 +126,7 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
                     if should_init_whisper {
                         eprintln!("[app] Transcription mode is local or unset, initializing Whisper in background...");
                         if let Err(err) =
-                            initialize_transcriber_background(&app_handle_for_bg).await
+                            initialize_transcriber_background(&app_handle_for_bg, engine).await
                         {
                             eprintln!("[app] Background Whisper initialization failed: {err}");
                         }
@@ -188,21 +200,47 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             crate::commands::storage_upload_data,
             crate::commands::storage_get_download_url,
             crate::commands::transcribe_audio,
+            crate::commands::transcribe_audio_with_fallback,
+            crate::commands::post_process_text,
+            crate::commands::cancel_post_processing,
+            crate::commands::ollama_probe,
+            crate::commands::ollama_list_models,
+            crate::commands::ollama_pull_model,
+            crate::commands::ollama_warm_up,
             crate::commands::surface_main_window,
             crate::commands::set_toast_overlay_click_through,
             crate::commands::set_agent_overlay_click_through,
             crate::commands::restore_overlay_focus,
             crate::commands::paste,
+            crate::commands::undo_last_paste,
             crate::commands::transcription_create,
             crate::commands::transcription_list,
             crate::commands::transcription_delete,
             crate::commands::transcription_update,
+            crate::commands::transcription_edit_transcript,
             crate::commands::transcription_audio_load,
             crate::commands::purge_stale_transcription_audio,
             crate::commands::term_create,
             crate::commands::term_update,
             crate::commands::term_list,
             crate::commands::term_delete,
+            crate::commands::term_suggestions,
+            crate::commands::term_suggestion_accept,
+            crate::commands::term_suggestion_reject,
+            crate::commands::voice_command_list,
+            crate::commands::voice_command_upsert,
+            crate::commands::voice_command_delete,
+            crate::commands::snippet_create,
+            crate::commands::snippet_update,
+            crate::commands::snippet_list,
+            crate::commands::snippet_delete,
+            crate::commands::snippet_expand,
+            crate::commands::apply_voice_commands,
+            crate::commands::inverse_normalize_transcript,
+            crate::commands::clean_transcript,
+            crate::commands::correct_transcript_spacing,
+            crate::commands::format_code_transcript,
+            crate::commands::redact_transcript,
             crate::commands::hotkey_list,
             crate::commands::hotkey_save,
             crate::commands::hotkey_delete,
@@ -215,44 +253,49 @@ pub fn build() -> tauri::Builder<tauri::Wry> {
             crate::commands::tone_list,
             crate::commands::tone_get,
             crate::commands::tone_delete,
+            crate::commands::tone_preview,
+            crate::commands::tone_render,
             crate::commands::clear_local_data,
             crate::commands::set_phase,
             crate::commands::set_pill_hover_enabled,
             crate::commands::start_key_listener,
             crate::commands::stop_key_listener,
+            crate::commands::get_key_listener_source,
             crate::commands::play_audio,
             crate::commands::get_text_field_info,
             crate::commands::get_screen_context,
             crate::commands::get_selected_text,
+            crate::commands::selection_rewrite_begin,
+            crate::commands::selection_rewrite_apply,
+            crate::commands::selection_rewrite_cancel,
             crate::commands::initialize_local_transcriber,
         ])
 }
 
-async fn initialize_transcriber_background(app: &tauri::AppHandle) -> Result<(), String> {
-    use std::sync::Arc;
+async fn initialize_transcriber_background(
+    app: &tauri::AppHandle,
+    engine: crate::system::models::LocalTranscriptionEngine,
+) -> Result<(), String> {
     use tauri::Manager;
 
     let transcriber_state = app.state::<crate::state::TranscriberState>();
-    if transcriber_state.is_initialized() {
+    if transcriber_state.is_initialized(engine) {
         return Ok(());
     }
 
     let default_model_size = crate::system::models::WhisperModelSize::default();
     let app_clone = app.clone();
-    let model_path = tauri::async_runtime::spawn_blocking(move || {
-        crate::system::models::ensure_whisper_model(&app_clone, default_model_size)
-            .map_err(|err| err.to_string())
+    let new_transcriber = tauri::async_runtime::spawn_blocking(move || {
+        let model_path =
+            crate::system::models::ensure_local_model(&app_clone, engine, default_model_size)
+                .map_err(|err| err.to_string())?;
+        crate::platform::load_local_transcriber(engine, &model_path)
     })
     .await
     .map_err(|err| err.to_string())??;
 
-    let new_transcriber: Arc<dyn crate::platform::Transcriber> = Arc::new(
-        crate::platform::whisper::WhisperTranscriber::new(&model_path)
-            .map_err(|err| format!("Failed to initialize Whisper transcriber: {err}"))?,
-    );
-
-    let _ = transcriber_state.initialize(new_transcriber);
-    eprintln!("[app] Background Whisper initialization completed successfully");
+    let _ = transcriber_state.initialize(engine, new_transcriber);
+    eprintln!("[app] Background {engine} initialization completed successfully");
 
     Ok(())
 }
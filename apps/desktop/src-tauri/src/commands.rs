@@ -5,20 +5,20 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, EventTarget, Manager, State};
 
 use crate::domain::{
-    ApiKey, ApiKeyCreateRequest, ApiKeyView, AudioChunkPayload, OverlayPhase, OverlayPhasePayload,
-    RecordingLevelPayload, TranscriptionAudioSnapshot, EVT_AUDIO_CHUNK, EVT_OVERLAY_PHASE,
+    ApiKey, ApiKeyCreateRequest, ApiKeyView, AudioChunkPayload, OllamaPullProgressPayload,
+    OverlayPhase, OverlayPhasePayload, PostProcessDeltaPayload, RecordingLevelPayload,
+    TranscriptionAttempt, TranscriptionAudioSnapshot, TranscriptionFallbackStep, TranslationMode,
+    EVT_AUDIO_CHUNK, EVT_OLLAMA_PULL_PROGRESS, EVT_OVERLAY_PHASE, EVT_POST_PROCESS_DELTA,
     EVT_REC_LEVEL,
 };
 use crate::platform::{
     ChunkCallback, GpuDescriptor, LevelCallback, TranscriptionDevice, TranscriptionRequest,
 };
 use crate::system::crypto::{protect_api_key, reveal_api_key};
-use crate::system::models::WhisperModelSize;
+use crate::system::models::{LocalTranscriptionEngine, WhisperModelSize};
 use crate::system::StorageRepo;
 use sqlx::Row;
 
-use crate::platform::input::paste_text_into_focused_field as platform_paste_text;
-
 #[derive(serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StopRecordingResponse {
@@ -64,6 +64,16 @@ pub struct AppTargetUpsertArgs {
     pub icon_path: Option<String>,
     #[serde(default)]
     pub paste_keybind: Option<String>,
+    #[serde(default)]
+    pub inverse_text_normalization: Option<bool>,
+    #[serde(default)]
+    pub code_mode_enabled: bool,
+    #[serde(default)]
+    pub injection_strategy: crate::domain::InjectionStrategy,
+    #[serde(default)]
+    pub typing_delay_ms: Option<u32>,
+    #[serde(default)]
+    pub rich_text_paste: bool,
 }
 
 #[derive(serde::Deserialize)]
@@ -123,9 +133,45 @@ pub struct TranscriptionOptionsDto {
     #[serde(default)]
     pub device: Option<TranscriptionDeviceSelectionDto>,
     pub model_size: Option<String>,
+    /// Leading text for the Whisper prompt (e.g. a script hint); the
+    /// vocabulary glossary is appended automatically.
     pub initial_prompt: Option<String>,
     #[serde(default)]
     pub language: Option<String>,
+    #[serde(default)]
+    pub candidate_languages: Vec<String>,
+    #[serde(default)]
+    pub translation_mode: Option<TranslationMode>,
+    #[serde(default)]
+    pub hotkey_action: Option<String>,
+    #[serde(default)]
+    pub tone_id: Option<String>,
+    /// Local engine override; defaults to the stored preference.
+    #[serde(default)]
+    pub engine: Option<String>,
+    /// Owner of the dictionary terms applied to the transcript.
+    #[serde(default)]
+    pub user_id: Option<String>,
+    /// `AppTarget` being dictated into, used to rank vocabulary terms.
+    #[serde(default)]
+    pub app_target_id: Option<String>,
+    /// Text already in the focused field, used as prompt context.
+    #[serde(default)]
+    pub app_context: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscribeAudioResponse {
+    pub text: String,
+    pub detected_language: Option<String>,
+    pub language_probabilities: Vec<crate::domain::LanguageProbability>,
+    pub translation_mode: TranslationMode,
+    pub original_text: Option<String>,
+    pub translated_text: Option<String>,
+    pub applied_replacements: Vec<crate::domain::AppliedReplacement>,
+    /// The exact prompt the transcriber was conditioned on.
+    pub transcription_prompt: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -288,16 +334,23 @@ pub async fn app_target_upsert(
     args: AppTargetUpsertArgs,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::AppTarget, String> {
-    crate::db::app_target_queries::upsert_app_target(
-        database.pool(),
-        &args.id,
-        &args.name,
-        args.tone_id,
-        args.icon_path,
-        args.paste_keybind,
-    )
-    .await
-    .map_err(|err| err.to_string())
+    let target = crate::domain::AppTarget {
+        id: args.id,
+        name: args.name,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        tone_id: args.tone_id,
+        icon_path: args.icon_path,
+        paste_keybind: args.paste_keybind,
+        inverse_text_normalization: args.inverse_text_normalization,
+        code_mode_enabled: args.code_mode_enabled,
+        injection_strategy: args.injection_strategy,
+        typing_delay_ms: args.typing_delay_ms,
+        rich_text_paste: args.rich_text_paste,
+    };
+
+    crate::db::app_target_queries::upsert_app_target(database.pool(), &target)
+        .await
+        .map_err(|err| err.to_string())
 }
 
 #[tauri::command]
@@ -311,15 +364,38 @@ pub async fn app_target_list(
 
 #[tauri::command]
 pub async fn transcription_create(
-    transcription: crate::domain::Transcription,
+    mut transcription: crate::domain::Transcription,
     user_id: String,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Transcription, String> {
+    redact_for_storage(database.pool(), &user_id, &mut transcription).await?;
     crate::db::transcription_queries::insert_transcription(database.pool(), &transcription, &user_id)
         .await
         .map_err(|err| err.to_string())
 }
 
+/// Masks personal data in a transcription about to be saved when the user
+/// turned on redaction before storage. Fails rather than saving it unmasked.
+async fn redact_for_storage(
+    pool: sqlx::SqlitePool,
+    user_id: &str,
+    transcription: &mut crate::domain::Transcription,
+) -> Result<(), String> {
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(pool, user_id)
+        .await
+        .map_err(|err| format!("Unable to load redaction preferences: {err}"))?;
+    let Some(preferences) = preferences.filter(|prefs| prefs.redact_before_storage) else {
+        return Ok(());
+    };
+
+    let detectors = preferences
+        .redaction_detectors
+        .unwrap_or_else(|| crate::domain::DEFAULT_PII_KINDS.to_vec());
+    let patterns = preferences.redaction_patterns.unwrap_or_default();
+    crate::text::redact_transcription(transcription, &detectors, &patterns);
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn transcription_list(
     limit: Option<u32>,
@@ -364,14 +440,84 @@ pub async fn transcription_delete(
 
 #[tauri::command]
 pub async fn transcription_update(
-    transcription: crate::domain::Transcription,
+    mut transcription: crate::domain::Transcription,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Transcription, String> {
+    redact_for_storage(
+        database.pool(),
+        crate::db::preferences_queries::LOCAL_USER_ID,
+        &mut transcription,
+    )
+    .await?;
     crate::db::transcription_queries::update_transcription(database.pool(), &transcription)
         .await
         .map_err(|err| err.to_string())
 }
 
+/// Saves a transcript the user corrected by hand and learns term suggestions
+/// from the edit. Programmatic updates such as retranscription go through
+/// `transcription_update` instead so they never count as corrections.
+#[tauri::command]
+pub async fn transcription_edit_transcript(
+    id: String,
+    transcript: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::Transcription, String> {
+    let pool = database.pool();
+    let previous = crate::db::transcription_queries::fetch_transcription_by_id(pool.clone(), &id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("Transcription {id} not found"))?;
+
+    let mut edited = crate::domain::Transcription {
+        transcript,
+        ..previous.clone()
+    };
+    redact_for_storage(
+        pool.clone(),
+        crate::db::preferences_queries::LOCAL_USER_ID,
+        &mut edited,
+    )
+    .await?;
+    let updated = crate::db::transcription_queries::update_transcription(pool.clone(), &edited)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    if previous.transcript != updated.transcript {
+        if let Err(err) = record_transcript_corrections(pool, &previous, &updated).await {
+            eprintln!("[term-suggestions] Failed to record corrections: {err}");
+        }
+    }
+
+    Ok(updated)
+}
+
+/// Corrections seen in fewer transcriptions than this are not suggested.
+const TERM_SUGGESTION_MIN_OCCURRENCES: i64 = 2;
+
+/// Stores the substitutions between the raw model output and a transcript the
+/// user just edited, replacing any from earlier edits of the same transcript.
+async fn record_transcript_corrections(
+    pool: sqlx::SqlitePool,
+    previous: &crate::domain::Transcription,
+    updated: &crate::domain::Transcription,
+) -> Result<(), String> {
+    let Some(raw) = previous.raw_transcript.as_deref() else {
+        return Ok(());
+    };
+
+    let substitutions =
+        crate::text::correction_substitutions(raw, &previous.transcript, &updated.transcript);
+    crate::db::term_suggestion_queries::replace_transcription_corrections(
+        pool,
+        &updated.id,
+        &substitutions,
+        current_timestamp_millis()?,
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
+
 #[tauri::command]
 pub async fn transcription_audio_load(
     app: AppHandle,
@@ -453,6 +599,443 @@ pub async fn term_delete(
         .map_err(|err| err.to_string())
 }
 
+#[tauri::command]
+pub async fn term_suggestions(
+    user_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::TermSuggestion>, String> {
+    let pool = database.pool();
+    let suggestions = crate::db::term_suggestion_queries::fetch_term_suggestions(
+        pool.clone(),
+        &user_id,
+        TERM_SUGGESTION_MIN_OCCURRENCES,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+    let terms = crate::db::term_queries::fetch_terms(pool, &user_id)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let source_key = crate::db::term_suggestion_queries::suggestion_source_key;
+    Ok(suggestions
+        .into_iter()
+        .filter(|suggestion| {
+            !terms.iter().any(|term| {
+                term.is_replacement
+                    && source_key(&term.source_value) == source_key(&suggestion.source_value)
+            })
+        })
+        .collect())
+}
+
+/// Saves `term`, usually built from `suggestion` and possibly edited by the
+/// user, and stops offering the suggestion.
+#[tauri::command]
+pub async fn term_suggestion_accept(
+    suggestion: crate::domain::TermSuggestion,
+    term: crate::domain::Term,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::Term, String> {
+    let pool = database.pool();
+    let created = crate::db::term_queries::insert_term(pool.clone(), &term)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    crate::db::term_suggestion_queries::record_term_suggestion_decision(
+        pool,
+        &term.created_by_user_id,
+        &suggestion.source_value,
+        &suggestion.destination_value,
+        "accepted",
+        current_timestamp_millis()?,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+    Ok(created)
+}
+
+#[tauri::command]
+pub async fn term_suggestion_reject(
+    user_id: String,
+    suggestion: crate::domain::TermSuggestion,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::term_suggestion_queries::record_term_suggestion_decision(
+        database.pool(),
+        &user_id,
+        &suggestion.source_value,
+        &suggestion.destination_value,
+        "rejected",
+        current_timestamp_millis()?,
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn voice_command_list(
+    user_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::VoiceCommand>, String> {
+    let stored = crate::db::voice_command_queries::fetch_voice_commands(database.pool(), &user_id)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(crate::domain::merge_voice_commands(stored))
+}
+
+/// Saves a custom command, or the user's override of a built-in one.
+#[tauri::command]
+pub async fn voice_command_upsert(
+    command: crate::domain::VoiceCommand,
+    user_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::VoiceCommand, String> {
+    let phrase = command
+        .phrase
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let language = crate::domain::language_subtag(&command.language);
+    if phrase.is_empty() {
+        return Err("Voice command phrase cannot be empty".to_string());
+    }
+    if language.is_empty() {
+        return Err("Voice command language cannot be empty".to_string());
+    }
+    if command.action == crate::domain::VoiceCommandAction::Insert
+        && command.value.as_deref().is_none_or(str::is_empty)
+    {
+        return Err("Voice commands that insert text need a value".to_string());
+    }
+
+    let command = crate::domain::VoiceCommand {
+        phrase,
+        language,
+        ..command
+    };
+    crate::db::voice_command_queries::upsert_voice_command(
+        database.pool(),
+        &command,
+        &user_id,
+        current_timestamp_millis()?,
+    )
+    .await
+    .map_err(|err| err.to_string())
+}
+
+/// Removes a custom command; deleting an override restores the built-in one.
+#[tauri::command]
+pub async fn voice_command_delete(
+    id: String,
+    user_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::voice_command_queries::delete_voice_command(database.pool(), &id, &user_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+fn validate_snippet(snippet: crate::domain::Snippet) -> Result<crate::domain::Snippet, String> {
+    let trigger = snippet
+        .trigger
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if trigger.is_empty() {
+        return Err("Snippet trigger cannot be empty".to_string());
+    }
+    if snippet.expansion.trim().is_empty() {
+        return Err("Snippet expansion cannot be empty".to_string());
+    }
+    Ok(crate::domain::Snippet { trigger, ..snippet })
+}
+
+#[tauri::command]
+pub async fn snippet_create(
+    snippet: crate::domain::Snippet,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::Snippet, String> {
+    let snippet = validate_snippet(snippet)?;
+    crate::db::snippet_queries::insert_snippet(database.pool(), &snippet)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn snippet_update(
+    snippet: crate::domain::Snippet,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::domain::Snippet, String> {
+    let snippet = validate_snippet(snippet)?;
+    crate::db::snippet_queries::update_snippet(database.pool(), &snippet)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn snippet_list(
+    user_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::domain::Snippet>, String> {
+    crate::db::snippet_queries::fetch_snippets(database.pool(), &user_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub async fn snippet_delete(
+    id: String,
+    user_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    crate::db::snippet_queries::delete_snippet(database.pool(), &id, &user_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Reads only the values the snippet refers to, since reading the selection
+/// sends a copy keystroke to the focused app.
+fn snippet_context(snippet: &crate::domain::Snippet) -> crate::domain::SnippetContext {
+    let now = chrono::Local::now();
+    let clipboard = snippet
+        .uses_variable("clipboard")
+        .then(|| arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text()))
+        .and_then(|result| {
+            result
+                .map_err(|err| eprintln!("[snippets] Failed to read clipboard: {err}"))
+                .ok()
+        });
+    let selected_text = snippet
+        .uses_variable("selected_text")
+        .then(crate::platform::accessibility::get_selected_text)
+        .flatten();
+
+    crate::domain::SnippetContext {
+        date: Some(now.format("%Y-%m-%d").to_string()),
+        time: Some(now.format("%H:%M").to_string()),
+        clipboard,
+        selected_text,
+    }
+}
+
+/// Pastes the user's snippet when the whole transcript is its trigger, e.g.
+/// "insert snippet signature", the same way `paste` inserts dictation into
+/// the app target. Returns `None` without pasting otherwise.
+#[tauri::command]
+pub async fn snippet_expand(
+    text: String,
+    user_id: String,
+    keybind: Option<String>,
+    app_target_id: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    paste_history: State<'_, crate::state::PasteHistoryState>,
+) -> Result<Option<crate::domain::SnippetExpansion>, String> {
+    let snippets = crate::db::snippet_queries::fetch_snippets(database.pool(), &user_id)
+        .await
+        .map_err(|err| err.to_string())?;
+    let Some(snippet) = crate::text::match_snippet(&text, &snippets).cloned() else {
+        return Ok(None);
+    };
+
+    let target = fetch_injection_target(database.pool(), app_target_id.as_deref()).await;
+    let options = injection_options(target.as_ref(), keybind, None);
+    let rendered = {
+        let snippet = snippet.clone();
+        tauri::async_runtime::spawn_blocking(move || snippet.render(&snippet_context(&snippet)))
+            .await
+            .map_err(|err| format!("Snippet task join error: {err}"))?
+    };
+    let report = inject_and_record(rendered.clone(), options, &paste_history)
+        .await
+        .map_err(|err| {
+            eprintln!("[snippets] Paste failed: {err}");
+            err
+        })?;
+
+    Ok(Some(crate::domain::SnippetExpansion {
+        snippet_id: snippet.id,
+        trigger: snippet.trigger,
+        text: rendered,
+        report,
+    }))
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyVoiceCommandsResponse {
+    pub text: String,
+    pub executed: Vec<crate::domain::ExecutedVoiceCommand>,
+}
+
+/// Runs spoken commands such as "new line" or "delete that" in a transcript
+/// using the grammar for `language`, or every language when it is unknown.
+#[tauri::command]
+pub async fn apply_voice_commands(
+    text: String,
+    language: Option<String>,
+    user_id: String,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<ApplyVoiceCommandsResponse, String> {
+    let stored = crate::db::voice_command_queries::fetch_voice_commands(database.pool(), &user_id)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let language = language
+        .map(|value| crate::domain::language_subtag(&value))
+        .filter(|value| !value.is_empty());
+    let grammar: Vec<crate::domain::VoiceCommand> = crate::domain::merge_voice_commands(stored)
+        .into_iter()
+        .filter(|command| {
+            language.as_deref().is_none_or(|language| {
+                crate::domain::language_subtag(&command.language) == language
+            })
+        })
+        .collect();
+
+    let (text, executed) = crate::text::apply_voice_commands(&text, &grammar);
+    Ok(ApplyVoiceCommandsResponse { text, executed })
+}
+
+/// The app target's setting wins, then the tone's. Normalization is on unless
+/// one of them turns it off; lookup failures keep the default.
+async fn resolve_inverse_text_normalization(
+    pool: sqlx::SqlitePool,
+    tone_id: Option<&str>,
+    app_target_id: Option<&str>,
+) -> bool {
+    if let Some(app_target_id) = app_target_id {
+        match crate::db::app_target_queries::fetch_app_target_by_id(pool.clone(), app_target_id)
+            .await
+        {
+            Ok(Some(target)) => {
+                if let Some(enabled) = target.inverse_text_normalization {
+                    return enabled;
+                }
+            }
+            Ok(None) => {}
+            Err(err) => eprintln!("[itn] Failed to load app target {app_target_id}: {err}"),
+        }
+    }
+
+    if let Some(tone_id) = tone_id {
+        match crate::db::tone_queries::fetch_tone_by_id(pool, tone_id).await {
+            Ok(Some(tone)) => return tone.inverse_text_normalization.unwrap_or(true),
+            Ok(None) => {}
+            Err(err) => eprintln!("[itn] Failed to load tone {tone_id}: {err}"),
+        }
+    }
+
+    true
+}
+
+/// Rewrites spoken numbers, dates, times and units ("twenty five percent",
+/// "이십오 퍼센트") as written forms unless the tone or app turns it off.
+#[tauri::command]
+pub async fn inverse_normalize_transcript(
+    text: String,
+    language: Option<String>,
+    tone_id: Option<String>,
+    app_target_id: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<String, String> {
+    let enabled = resolve_inverse_text_normalization(
+        database.pool(),
+        tone_id.as_deref(),
+        app_target_id.as_deref(),
+    )
+    .await;
+    if !enabled {
+        return Ok(text);
+    }
+
+    Ok(crate::text::inverse_normalize(&text, language.as_deref()))
+}
+
+/// Removes filler words, stutters and repeated words without a network
+/// round trip. `filler_words` replaces the built-in list when given.
+#[tauri::command]
+pub fn clean_transcript(text: String, filler_words: Option<Vec<String>>) -> String {
+    let filler_words = filler_words.unwrap_or_else(|| {
+        crate::text::DEFAULT_FILLER_WORDS
+            .iter()
+            .map(|word| word.to_string())
+            .collect()
+    });
+    crate::text::clean_disfluencies(&text, &filler_words)
+}
+
+/// Fixes word spacing and sentence-final punctuation for languages with a
+/// local corrector (currently Korean) when `enabled_languages` includes them.
+/// Without a detected language, text containing Hangul is treated as Korean.
+#[tauri::command]
+pub fn correct_transcript_spacing(
+    text: String,
+    language: Option<String>,
+    enabled_languages: Option<Vec<String>>,
+) -> String {
+    let language = language
+        .map(|value| crate::domain::language_subtag(&value))
+        .filter(|value| !value.is_empty())
+        .or_else(|| {
+            text.chars()
+                .any(|ch| ('가'..='힣').contains(&ch))
+                .then(|| "ko".to_string())
+        });
+    let Some(language) = language else {
+        return text;
+    };
+
+    let enabled = match &enabled_languages {
+        Some(languages) => languages
+            .iter()
+            .any(|value| crate::domain::language_subtag(value) == language),
+        None => crate::domain::DEFAULT_SPACING_CORRECTION_LANGUAGES.contains(&language.as_str()),
+    };
+
+    match language.as_str() {
+        "ko" if enabled => crate::text::correct_korean_spacing(&text),
+        _ => text,
+    }
+}
+
+/// Formats a transcript for a code editor: "camel case user id" becomes
+/// `userId` and spoken symbols become characters.
+#[tauri::command]
+pub fn format_code_transcript(text: String) -> String {
+    crate::text::format_code_dictation(&text)
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactTranscriptResponse {
+    pub text: String,
+    pub redactions: Vec<crate::domain::Redaction>,
+    /// Summary for the transcription's `warnings`; `None` when nothing matched.
+    pub warning: Option<String>,
+}
+
+/// Masks personal data in `text` with placeholders before it leaves the
+/// device or is saved. `detectors` defaults to every built-in detector.
+#[tauri::command]
+pub fn redact_transcript(
+    text: String,
+    detectors: Option<Vec<crate::domain::PiiKind>>,
+    patterns: Option<Vec<String>>,
+    stage: crate::domain::RedactionStage,
+) -> RedactTranscriptResponse {
+    let detectors = detectors.unwrap_or_else(|| crate::domain::DEFAULT_PII_KINDS.to_vec());
+    let patterns = patterns.unwrap_or_default();
+    let (text, redactions) = crate::text::redact_pii(&text, &detectors, &patterns);
+    let warning = crate::domain::redaction_warning(stage, &redactions);
+
+    RedactTranscriptResponse {
+        text,
+        redactions,
+        warning,
+    }
+}
+
 #[tauri::command]
 pub async fn hotkey_list(
     database: State<'_, crate::state::OptionKeyDatabase>,
@@ -581,6 +1164,10 @@ pub async fn tone_upsert(
     user_id: String,
     database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<crate::domain::Tone, String> {
+    if let Err(err) = crate::domain::validate_tone_template(&tone.prompt_template) {
+        eprintln!("[tone] Tone {} template warning: {err}", tone.id);
+    }
+
     let pool = database.pool();
 
     if let Some(existing) = crate::db::tone_queries::fetch_tone_by_id(pool.clone(), &tone.id)
@@ -642,17 +1229,153 @@ pub async fn tone_delete(
         .map_err(|err| err.to_string())
 }
 
-#[tauri::command]
-pub async fn clear_local_data(
-    database: State<'_, crate::state::OptionKeyDatabase>,
-) -> Result<(), String> {
-    let pool = database.pool();
-    let mut transaction = pool.begin().await.map_err(|err| err.to_string())?;
-
-    const TABLES_TO_CLEAR: [&str; 8] = [
-        "user_profiles",
-        "transcriptions",
-        "terms",
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TonePreviewArgs {
+    pub transcription_id: String,
+    #[serde(default)]
+    pub tone_id: Option<String>,
+    /// Unsaved template text from the editor; wins over `tone_id`.
+    #[serde(default)]
+    pub prompt_template: Option<String>,
+    #[serde(default)]
+    pub app_name: Option<String>,
+    #[serde(default)]
+    pub selected_text: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TonePreviewResponse {
+    pub rendered: String,
+    pub variables: Vec<String>,
+    pub warning: Option<String>,
+}
+
+/// Renders a tone against a stored transcription so the editor can show what
+/// the post-processing model will receive.
+#[tauri::command]
+pub async fn tone_preview(
+    args: TonePreviewArgs,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<TonePreviewResponse, String> {
+    let pool = database.pool();
+
+    let template = match (args.prompt_template, args.tone_id) {
+        (Some(template), _) => template,
+        (None, Some(tone_id)) => {
+            crate::db::tone_queries::fetch_tone_by_id(pool.clone(), &tone_id)
+                .await
+                .map_err(|err| err.to_string())?
+                .ok_or_else(|| format!("Tone {tone_id} not found"))?
+                .prompt_template
+        }
+        (None, None) => return Err("A tone id or prompt template is required".to_string()),
+    };
+
+    let transcription = crate::db::transcription_queries::fetch_transcription_by_id(
+        pool.clone(),
+        &args.transcription_id,
+    )
+    .await
+    .map_err(|err| err.to_string())?
+    .ok_or_else(|| format!("Transcription {} not found", args.transcription_id))?;
+
+    let context = tone_template_context(
+        pool,
+        transcription
+            .raw_transcript
+            .unwrap_or(transcription.transcript),
+        args.app_name,
+        args.selected_text,
+        transcription.detected_language,
+    )
+    .await;
+
+    Ok(TonePreviewResponse {
+        rendered: crate::domain::render_tone_template(&template, &context),
+        variables: crate::domain::tone_template_variables(&template),
+        warning: crate::domain::validate_tone_template(&template)
+            .err()
+            .map(|err| err.to_string()),
+    })
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToneRenderArgs {
+    pub prompt_template: String,
+    pub transcript: String,
+    #[serde(default)]
+    pub app_name: Option<String>,
+    #[serde(default)]
+    pub selected_text: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// Renders a tone's template for a post-processing request.
+#[tauri::command]
+pub async fn tone_render(
+    args: ToneRenderArgs,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<String, String> {
+    let context = tone_template_context(
+        database.pool(),
+        args.transcript,
+        args.app_name,
+        args.selected_text,
+        args.language,
+    )
+    .await;
+
+    Ok(crate::domain::render_tone_template(
+        &args.prompt_template,
+        &context,
+    ))
+}
+
+/// Template values for a transcript, completed with the user's name and
+/// title and today's date. `language` falls back to the user's preference.
+async fn tone_template_context(
+    pool: sqlx::SqlitePool,
+    transcript: String,
+    app_name: Option<String>,
+    selected_text: Option<String>,
+    language: Option<String>,
+) -> crate::domain::ToneTemplateContext {
+    let user = crate::db::user_queries::fetch_user(pool)
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!("[tone] Failed to load user for tone template: {err}");
+            None
+        });
+
+    crate::domain::ToneTemplateContext {
+        transcript,
+        app_name,
+        selected_text,
+        user_name: user
+            .as_ref()
+            .map(|user| user.name.clone())
+            .filter(|name| !name.is_empty()),
+        user_title: user.as_ref().and_then(|user| user.title.clone()),
+        language: language.or_else(|| user.and_then(|user| user.preferred_language)),
+        date: Some(chrono::Local::now().format("%Y-%m-%d").to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn clear_local_data(
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    let pool = database.pool();
+    let mut transaction = pool.begin().await.map_err(|err| err.to_string())?;
+
+    const TABLES_TO_CLEAR: [&str; 8] = [
+        "user_profiles",
+        "transcriptions",
+        "terms",
         "hotkeys",
         "api_keys",
         "user_preferences",
@@ -855,35 +1578,122 @@ pub fn storage_get_download_url(app: AppHandle, path: String) -> Result<String,
     repo.get_download_url(&path).map_err(|err| err.to_string())
 }
 
-#[tauri::command]
-pub async fn transcribe_audio(
-    app: AppHandle,
-    samples: Vec<f64>,
-    sample_rate: u32,
+/// An explicit mode wins, then a translate hotkey action, then the tone's own
+/// setting. Lookup failures fall back to plain transcription.
+async fn resolve_translation_mode(
+    pool: sqlx::SqlitePool,
+    explicit: Option<TranslationMode>,
+    hotkey_action: Option<&str>,
+    tone_id: Option<&str>,
+) -> TranslationMode {
+    if let Some(mode) = explicit {
+        return mode;
+    }
+
+    if let Some(mode) = hotkey_action.and_then(TranslationMode::for_hotkey_action) {
+        return mode;
+    }
+
+    let Some(tone_id) = tone_id else {
+        return TranslationMode::Off;
+    };
+
+    match crate::db::tone_queries::fetch_tone_by_id(pool, tone_id).await {
+        Ok(tone) => tone
+            .and_then(|tone| tone.translation_mode)
+            .unwrap_or_default(),
+        Err(err) => {
+            eprintln!("[translation] Failed to load tone {tone_id} for translation mode: {err}");
+            TranslationMode::Off
+        }
+    }
+}
+
+async fn resolve_local_transcription_engine(
+    pool: sqlx::SqlitePool,
+    explicit: Option<&str>,
+) -> LocalTranscriptionEngine {
+    let stored = match explicit {
+        Some(value) => Some(value.to_string()),
+        None => crate::db::preferences_queries::fetch_local_transcription_engine(pool)
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("[transcription] Failed to load local engine preference: {err}");
+                None
+            }),
+    };
+
+    let parsed = stored
+        .as_deref()
+        .map(str::parse::<LocalTranscriptionEngine>);
+    match parsed {
+        Some(Ok(engine)) => engine,
+        Some(Err(_)) => {
+            eprintln!(
+                "[transcription] Unrecognised local transcription engine '{}'; falling back to default.",
+                stored.unwrap_or_default()
+            );
+            LocalTranscriptionEngine::default()
+        }
+        None => LocalTranscriptionEngine::default(),
+    }
+}
+
+struct PreparedTranscription {
+    /// Its `initial_prompt` never includes the focused field's text, since it
+    /// is sent to remote providers and stored with the transcription.
+    request: TranscriptionRequest,
+    /// Prompt for on-device engines, which may also see the focused field.
+    local_prompt: Option<String>,
+    model_size: WhisperModelSize,
+    engine_override: Option<String>,
+    user_id: String,
+}
+
+async fn prepare_transcription_request(
+    pool: sqlx::SqlitePool,
     options: Option<TranscriptionOptionsDto>,
-    transcriber_state: State<'_, crate::state::TranscriberState>,
-) -> Result<String, String> {
+) -> PreparedTranscription {
     let mut request = TranscriptionRequest::default();
+    let mut local_prompt = None;
     let mut model_size = WhisperModelSize::default();
+    let mut engine_override = None;
+    let mut user_id = crate::db::preferences_queries::LOCAL_USER_ID.to_string();
 
     if let Some(TranscriptionOptionsDto {
         device,
         model_size: maybe_model_size,
         initial_prompt,
         language: maybe_language,
+        candidate_languages,
+        translation_mode,
+        hotkey_action,
+        tone_id,
+        engine,
+        user_id: maybe_user_id,
+        app_target_id,
+        app_context,
     }) = options
     {
+        engine_override = engine.filter(|value| !value.trim().is_empty());
+        if let Some(value) = maybe_user_id.filter(|value| !value.trim().is_empty()) {
+            user_id = value;
+        }
+
         if let Some(device_dto) = device {
             request = device_dto.into_request();
         }
 
-        if let Some(prompt_value) = initial_prompt {
-            let sanitized: String = prompt_value.chars().filter(|ch| *ch != '\0').collect();
-            let trimmed = sanitized.trim();
-            if !trimmed.is_empty() {
-                request.initial_prompt = Some(trimmed.to_string());
-            }
-        }
+        let preamble =
+            initial_prompt.map(|value| value.chars().filter(|ch| *ch != '\0').collect::<String>());
+        (request.initial_prompt, local_prompt) = build_transcription_prompts(
+            pool.clone(),
+            &user_id,
+            preamble.as_deref(),
+            app_target_id.as_deref(),
+            app_context.as_deref(),
+        )
+        .await;
 
         if let Some(language_value) = maybe_language {
             let sanitized: String = language_value.chars().filter(|ch| *ch != '\0').collect();
@@ -893,6 +1703,20 @@ pub async fn transcribe_audio(
             }
         }
 
+        request.candidate_languages = candidate_languages
+            .into_iter()
+            .map(|value| value.chars().filter(|ch| *ch != '\0').collect::<String>())
+            .filter(|value| !value.trim().is_empty())
+            .collect();
+
+        request.translation_mode = resolve_translation_mode(
+            pool.clone(),
+            translation_mode,
+            hotkey_action.as_deref(),
+            tone_id.as_deref(),
+        )
+        .await;
+
         if let Some(size_value) = maybe_model_size {
             match size_value.parse::<WhisperModelSize>() {
                 Ok(parsed) => {
@@ -908,37 +1732,109 @@ pub async fn transcribe_audio(
         }
     }
 
-    let initial_path = crate::system::paths::whisper_model_path(&app, model_size)
-        .map_err(|err| err.to_string())?;
+    PreparedTranscription {
+        request,
+        local_prompt,
+        model_size,
+        engine_override,
+        user_id,
+    }
+}
 
-    let model_path = if initial_path.exists() {
-        initial_path
-    } else {
-        let handle = app.clone();
-        tauri::async_runtime::spawn_blocking(move || {
-            crate::system::models::ensure_whisper_model(&handle, model_size)
-                .map_err(|err| err.to_string())
-        })
+const VOCABULARY_HISTORY_LIMIT: u32 = 100;
+
+/// Whisper prompts from the caller's preamble and the user's vocabulary terms
+/// ranked by recent use, within the token budget: one without the focused
+/// field's text for remote providers and storage, and one with it for
+/// on-device engines.
+async fn build_transcription_prompts(
+    pool: sqlx::SqlitePool,
+    user_id: &str,
+    preamble: Option<&str>,
+    app_target_id: Option<&str>,
+    app_context: Option<&str>,
+) -> (Option<String>, Option<String>) {
+    let terms = crate::db::term_queries::fetch_terms(pool.clone(), user_id)
         .await
-        .map_err(|err| err.to_string())??
-    };
+        .unwrap_or_else(|err| {
+            eprintln!("[vocabulary] Failed to load terms: {err}");
+            Vec::new()
+        });
+    let recent = crate::db::transcription_queries::fetch_recent_dictations(
+        pool,
+        user_id,
+        VOCABULARY_HISTORY_LIMIT,
+    )
+    .await
+    .unwrap_or_else(|err| {
+        eprintln!("[vocabulary] Failed to load recent dictations: {err}");
+        Vec::new()
+    });
 
-    let transcriber = if let Some(existing) = transcriber_state.get() {
-        existing.clone()
-    } else {
-        eprintln!("[transcribe_audio] Transcriber not initialized, performing lazy initialization...");
-        let init_model_path = model_path.clone();
-        let new_transcriber: Arc<dyn crate::platform::Transcriber> = Arc::new(
-            crate::platform::whisper::WhisperTranscriber::new(&init_model_path)
-                .map_err(|err| format!("Failed to initialize Whisper transcriber: {err}"))?,
-        );
-        let _ = transcriber_state.initialize(new_transcriber.clone());
-        new_transcriber
+    let build = |app_context: Option<&str>| {
+        crate::text::build_vocabulary_prompt(&crate::text::VocabularyPromptInput {
+            preamble,
+            terms: &terms,
+            recent: &recent,
+            app_target_id,
+            app_context,
+            token_budget: crate::text::WHISPER_PROMPT_TOKEN_BUDGET,
+        })
+    };
+    let shared = build(None);
+    let local = match app_context {
+        Some(_) => build(app_context),
+        None => shared.clone(),
+    };
+    (shared, local)
+}
+
+/// Applies the user's replacement terms to a finished transcript. The
+/// translated text is left alone since terms are written in the spoken
+/// language.
+async fn apply_dictionary_terms(
+    pool: sqlx::SqlitePool,
+    user_id: &str,
+    mut response: TranscribeAudioResponse,
+) -> TranscribeAudioResponse {
+    let terms = match crate::db::term_queries::fetch_terms(pool, user_id).await {
+        Ok(terms) => terms,
+        Err(err) => {
+            eprintln!("[dictionary] Failed to load terms: {err}");
+            return response;
+        }
     };
 
-    let model_path_string = model_path.to_string_lossy().into_owned();
-    request.model_path = Some(model_path_string);
+    let source = response.original_text.as_deref().unwrap_or(&response.text);
+    let (replaced, applied) = crate::text::apply_dictionary(source, &terms);
+    if applied.is_empty() {
+        return response;
+    }
+
+    eprintln!("[dictionary] Applied {} replacement(s)", applied.len());
+    match response.original_text.take() {
+        Some(_) => {
+            response.text = response
+                .translation_mode
+                .compose(&replaced, response.translated_text.as_deref());
+            response.original_text = Some(replaced);
+        }
+        None => response.text = replaced,
+    }
+    response.applied_replacements = applied;
+    response
+}
 
+/// Runs `transcriber` off the async runtime and shapes its output for the
+/// frontend, composing translated text according to the request.
+/// `transcription_prompt` is the prompt reported back for storage.
+async fn run_transcriber(
+    transcriber: Arc<dyn crate::platform::Transcriber>,
+    samples: Vec<f64>,
+    sample_rate: u32,
+    request: TranscriptionRequest,
+    transcription_prompt: Option<String>,
+) -> Result<TranscribeAudioResponse, String> {
     let request = Some(request);
     let join_result = tauri::async_runtime::spawn_blocking(move || {
         let original_len = samples.len();
@@ -963,7 +1859,24 @@ pub async fn transcribe_audio(
         let request_ref = request.as_ref();
         transcriber
             .transcribe(filtered.as_slice(), sample_rate, request_ref)
-            .map(|text| text.trim().to_string())
+            .map(|output| {
+                let translation_mode = request_ref
+                    .map(|req| req.translation_mode)
+                    .unwrap_or_default();
+                let original = output.text.trim().to_string();
+                let translated = output.translation.map(|text| text.trim().to_string());
+
+                TranscribeAudioResponse {
+                    text: translation_mode.compose(&original, translated.as_deref()),
+                    detected_language: output.detected_language,
+                    language_probabilities: output.language_probabilities,
+                    translation_mode,
+                    original_text: translated.as_ref().map(|_| original.clone()),
+                    translated_text: translated,
+                    applied_replacements: Vec::new(),
+                    transcription_prompt,
+                }
+            })
     })
     .await;
 
@@ -983,6 +1896,564 @@ pub async fn transcribe_audio(
     }
 }
 
+#[tauri::command]
+pub async fn transcribe_audio(
+    app: AppHandle,
+    samples: Vec<f64>,
+    sample_rate: u32,
+    options: Option<TranscriptionOptionsDto>,
+    transcriber_state: State<'_, crate::state::TranscriberState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<TranscribeAudioResponse, String> {
+    let PreparedTranscription {
+        mut request,
+        local_prompt,
+        model_size,
+        engine_override,
+        user_id,
+    } = prepare_transcription_request(database.pool(), options).await;
+
+    let engine =
+        resolve_local_transcription_engine(database.pool(), engine_override.as_deref()).await;
+    let (transcriber, model_path) =
+        load_local_engine(&app, &transcriber_state, engine, model_size).await?;
+
+    let model_path_string = model_path.to_string_lossy().into_owned();
+    request.model_path = Some(model_path_string);
+    let transcription_prompt = std::mem::replace(&mut request.initial_prompt, local_prompt);
+
+    let response = run_transcriber(
+        transcriber,
+        samples,
+        sample_rate,
+        request,
+        transcription_prompt,
+    )
+    .await?;
+    Ok(apply_dictionary_terms(database.pool(), &user_id, response).await)
+}
+
+/// Downloads the model if needed and returns the engine's shared transcriber,
+/// loading it on first use.
+async fn load_local_engine(
+    app: &AppHandle,
+    transcriber_state: &crate::state::TranscriberState,
+    engine: LocalTranscriptionEngine,
+    model_size: WhisperModelSize,
+) -> Result<(Arc<dyn crate::platform::Transcriber>, PathBuf), String> {
+    let existing_path = crate::system::models::local_model_exists(app, engine, model_size)
+        .map_err(|err| err.to_string())?;
+
+    let model_path = if let Some(path) = existing_path {
+        path
+    } else {
+        let handle = app.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            crate::system::models::ensure_local_model(&handle, engine, model_size)
+                .map_err(|err| err.to_string())
+        })
+        .await
+        .map_err(|err| err.to_string())??
+    };
+
+    let transcriber = if let Some(existing) = transcriber_state.get(engine) {
+        existing.clone()
+    } else {
+        eprintln!(
+            "[transcribe_audio] {engine} transcriber not initialized, performing lazy initialization..."
+        );
+        let init_model_path = model_path.clone();
+        let new_transcriber = tauri::async_runtime::spawn_blocking(move || {
+            crate::platform::load_local_transcriber(engine, &init_model_path)
+        })
+        .await
+        .map_err(|err| err.to_string())??;
+        let _ = transcriber_state.initialize(engine, new_transcriber.clone());
+        new_transcriber
+    };
+
+    Ok((transcriber, model_path))
+}
+
+/// Builds the transcriber for a stored API key (OpenAI, Groq, self-hosted
+/// OpenAI-compatible servers, Deepgram or Azure Speech) and returns it
+/// together with the key's provider name.
+async fn load_remote_transcriber(
+    pool: sqlx::SqlitePool,
+    api_key_id: &str,
+    policy: crate::platform::remote::RetryPolicy,
+) -> Result<(Arc<dyn crate::platform::Transcriber>, String), String> {
+    let api_key = crate::db::api_key_queries::fetch_api_key_by_id(pool, api_key_id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("API key {api_key_id} not found"))?;
+    let secret =
+        reveal_api_key(&api_key.salt, &api_key.key_ciphertext).map_err(|err| err.to_string())?;
+
+    let transcriber = crate::platform::remote::transcriber_for_api_key(&api_key, secret, policy)
+        .map_err(|err| err.to_string())?;
+
+    Ok((transcriber, api_key.provider))
+}
+
+/// The user's fallback chain, or a single step built from the primary
+/// transcription preference when no chain is configured.
+async fn resolve_fallback_chain(pool: sqlx::SqlitePool) -> Vec<TranscriptionFallbackStep> {
+    let chain = crate::db::preferences_queries::fetch_transcription_fallback_chain(pool.clone())
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!("[transcription-fallback] Failed to load fallback chain: {err}");
+            Vec::new()
+        });
+    if !chain.is_empty() {
+        return chain;
+    }
+
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        pool,
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .unwrap_or_else(|err| {
+        eprintln!("[transcription] Failed to load transcription preferences: {err}");
+        None
+    });
+
+    let primary = preferences.and_then(|prefs| {
+        match (
+            prefs.transcription_mode.as_deref(),
+            prefs.transcription_api_key_id,
+        ) {
+            (Some("api"), Some(api_key_id)) => Some(TranscriptionFallbackStep::Api { api_key_id }),
+            _ => None,
+        }
+    });
+
+    vec![primary.unwrap_or(TranscriptionFallbackStep::Local {
+        model_size: None,
+        engine: None,
+    })]
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscribeWithFallbackResponse {
+    #[serde(flatten)]
+    pub transcription: TranscribeAudioResponse,
+    /// Mode and key of the provider that served the dictation, for
+    /// `Transcription.transcription_mode` / `transcription_api_key_id`.
+    pub transcription_mode: String,
+    pub transcription_api_key_id: Option<String>,
+    pub model_size: Option<String>,
+    pub attempts: Vec<TranscriptionAttempt>,
+    pub warnings: Vec<String>,
+}
+
+/// Tries each provider of the fallback chain in order until one succeeds, so
+/// a rate-limited key or a dropped network falls through to the next
+/// provider instead of failing the dictation.
+#[tauri::command]
+pub async fn transcribe_audio_with_fallback(
+    app: AppHandle,
+    samples: Vec<f64>,
+    sample_rate: u32,
+    options: Option<TranscriptionOptionsDto>,
+    transcriber_state: State<'_, crate::state::TranscriberState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<TranscribeWithFallbackResponse, String> {
+    let PreparedTranscription {
+        request,
+        local_prompt,
+        model_size: default_model_size,
+        engine_override,
+        user_id,
+    } = prepare_transcription_request(database.pool(), options).await;
+    let chain = resolve_fallback_chain(database.pool()).await;
+
+    let mut attempts: Vec<TranscriptionAttempt> = Vec::with_capacity(chain.len());
+    let mut served = None;
+
+    for (index, step) in chain.iter().enumerate() {
+        let started = std::time::Instant::now();
+        let mut step_request = request.clone();
+        let mut step_model_size = None;
+
+        let loaded = match step {
+            TranscriptionFallbackStep::Api { api_key_id } => {
+                // Waiting out a rate limit only makes sense for the last
+                // provider; earlier ones hand over to the next step instead.
+                let policy = crate::platform::remote::RetryPolicy {
+                    retry_rate_limits: index + 1 == chain.len(),
+                    ..Default::default()
+                };
+                load_remote_transcriber(database.pool(), api_key_id, policy).await
+            }
+            TranscriptionFallbackStep::Local { model_size, engine } => {
+                let model_size = model_size
+                    .as_deref()
+                    .and_then(|value| value.parse::<WhisperModelSize>().ok())
+                    .unwrap_or(default_model_size);
+                let engine = resolve_local_transcription_engine(
+                    database.pool(),
+                    engine.as_deref().or(engine_override.as_deref()),
+                )
+                .await;
+                step_model_size = Some(model_size.as_str().to_string());
+
+                load_local_engine(&app, &transcriber_state, engine, model_size)
+                    .await
+                    .map(|(transcriber, model_path)| {
+                        step_request.model_path = Some(model_path.to_string_lossy().into_owned());
+                        step_request.initial_prompt = local_prompt.clone();
+                        (transcriber, format!("{engine} {}", model_size.as_str()))
+                    })
+            }
+        };
+
+        let (provider, result) = match loaded {
+            Ok((transcriber, provider)) => {
+                let result = run_transcriber(
+                    transcriber,
+                    samples.clone(),
+                    sample_rate,
+                    step_request,
+                    request.initial_prompt.clone(),
+                )
+                .await;
+                (provider, result)
+            }
+            Err(err) => (step.mode().to_string(), Err(err)),
+        };
+
+        attempts.push(TranscriptionAttempt {
+            mode: step.mode().to_string(),
+            api_key_id: step.api_key_id().map(str::to_string),
+            provider,
+            latency_ms: started.elapsed().as_millis() as u64,
+            error: result.as_ref().err().cloned(),
+        });
+
+        match result {
+            Ok(response) => {
+                served = Some((step, step_model_size, response));
+                break;
+            }
+            Err(err) => {
+                eprintln!(
+                    "[transcription-fallback] {} step failed, trying next provider: {err}",
+                    step.mode()
+                );
+            }
+        }
+    }
+
+    let warnings = attempts
+        .iter()
+        .enumerate()
+        .map(|(index, attempt)| attempt.to_warning(index + 1))
+        .collect::<Vec<_>>();
+
+    let Some((step, model_size, transcription)) = served else {
+        return Err(format!(
+            "All transcription providers failed. {}",
+            warnings.join(" ")
+        ));
+    };
+
+    let transcription = apply_dictionary_terms(database.pool(), &user_id, transcription).await;
+
+    Ok(TranscribeWithFallbackResponse {
+        transcription,
+        transcription_mode: step.mode().to_string(),
+        transcription_api_key_id: step.api_key_id().map(str::to_string),
+        model_size,
+        attempts,
+        warnings,
+    })
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessTextArgs {
+    /// Caller-chosen id used for streamed events and cancellation.
+    pub request_id: String,
+    #[serde(default)]
+    pub system: Option<String>,
+    pub prompt: String,
+    #[serde(default)]
+    pub json_response: Option<crate::platform::llm::JsonResponseFormat>,
+    /// Defaults to the post-processing key from preferences.
+    #[serde(default)]
+    pub api_key_id: Option<String>,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessTextResponse {
+    pub text: String,
+    pub model: String,
+    pub api_key_id: String,
+    pub inference_device: String,
+    pub duration_ms: u64,
+}
+
+async fn resolve_post_processing_api_key_id(
+    pool: sqlx::SqlitePool,
+    explicit: Option<String>,
+) -> Result<String, String> {
+    if let Some(api_key_id) = explicit.filter(|value| !value.trim().is_empty()) {
+        return Ok(api_key_id);
+    }
+
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        pool,
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .map_err(|err| err.to_string())?;
+
+    match preferences {
+        Some(prefs) if prefs.post_processing_mode.as_deref() == Some("api") => prefs
+            .post_processing_api_key_id
+            .ok_or_else(|| "No API key selected for post-processing".to_string()),
+        _ => Err("Post-processing is not configured to use an API key".to_string()),
+    }
+}
+
+/// Runs post-processing through the provider behind an API key, streaming
+/// deltas as `post_process_delta` events until done or cancelled.
+#[tauri::command]
+pub async fn post_process_text(
+    app: AppHandle,
+    args: PostProcessTextArgs,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    post_processing: State<'_, crate::state::PostProcessingState>,
+) -> Result<PostProcessTextResponse, String> {
+    let PostProcessTextArgs {
+        request_id,
+        system,
+        prompt,
+        json_response,
+        api_key_id,
+        timeout_ms,
+    } = args;
+
+    let api_key_id = resolve_post_processing_api_key_id(database.pool(), api_key_id).await?;
+    let api_key = crate::db::api_key_queries::fetch_api_key_by_id(database.pool(), &api_key_id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("API key {api_key_id} not found"))?;
+    let secret =
+        reveal_api_key(&api_key.salt, &api_key.key_ciphertext).map_err(|err| err.to_string())?;
+
+    // Ollama answers a missing model with a bare 404, so check first and say
+    // how to fix it. Keys with a secret sit behind a proxy whose management
+    // API may not be reachable, so those are left to the request itself.
+    if api_key.provider == "ollama" && secret.trim().is_empty() {
+        if let Some(model) = api_key
+            .post_processing_model
+            .clone()
+            .filter(|model| !model.trim().is_empty())
+        {
+            let client = crate::platform::llm::OllamaClient::new(api_key.base_url.as_deref())
+                .map_err(|err| err.to_string())?;
+            run_ollama(client, move |client| client.ensure_installed(&model)).await?;
+        }
+    }
+
+    let mut policy = crate::platform::remote::RetryPolicy::default();
+    if let Some(timeout_ms) = timeout_ms.filter(|value| *value > 0) {
+        policy.request_timeout = std::time::Duration::from_millis(timeout_ms);
+    }
+    let provider = crate::platform::llm::provider_for_api_key(&api_key, secret, policy)
+        .map_err(|err| err.to_string())?;
+
+    let request = crate::platform::llm::LlmRequest {
+        system,
+        prompt,
+        json_response,
+        ..Default::default()
+    };
+    let cancel = post_processing.begin(&request_id);
+    let stream_cancel = cancel.clone();
+    let started = std::time::Instant::now();
+
+    let emit_handle = app.clone();
+    let stream_request_id = request_id.clone();
+    let join_result = tauri::async_runtime::spawn_blocking(move || {
+        let emit = |delta: &str, done: bool| {
+            let payload = PostProcessDeltaPayload {
+                request_id: stream_request_id.clone(),
+                delta: delta.to_string(),
+                done,
+            };
+            if let Err(err) =
+                emit_handle.emit_to(EventTarget::any(), EVT_POST_PROCESS_DELTA, payload)
+            {
+                eprintln!("[post-processing] Failed to emit post_process_delta event: {err}");
+            }
+        };
+
+        let result = provider
+            .generate(&request, &stream_cancel, &mut |delta| emit(delta, false))
+            .map(|output| (output, provider.label().to_string()));
+        emit("", true);
+        result
+    })
+    .await;
+    post_processing.finish(&request_id, &cancel);
+
+    let (output, inference_device) = join_result
+        .map_err(|err| format!("Post-processing task join error: {err}"))?
+        .map_err(|err| {
+            eprintln!("[post-processing] {api_key_id} failed: {err}");
+            err.to_string()
+        })?;
+
+    Ok(PostProcessTextResponse {
+        text: output.text,
+        model: output.model,
+        api_key_id,
+        inference_device,
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// Returns whether a running post-processing request was found.
+#[tauri::command]
+pub fn cancel_post_processing(
+    request_id: String,
+    post_processing: State<'_, crate::state::PostProcessingState>,
+) -> bool {
+    post_processing.cancel(&request_id)
+}
+
+/// Explicit values win over the stored Ollama preferences.
+async fn ollama_client(
+    pool: sqlx::SqlitePool,
+    base_url: Option<String>,
+    api_key: Option<String>,
+) -> Result<(crate::platform::llm::OllamaClient, Option<String>), String> {
+    let preferences = crate::db::preferences_queries::fetch_user_preferences(
+        pool,
+        crate::db::preferences_queries::LOCAL_USER_ID,
+    )
+    .await
+    .unwrap_or_else(|err| {
+        eprintln!("[ollama] Failed to load Ollama preferences: {err}");
+        None
+    });
+    let (stored_url, stored_model) = preferences
+        .map(|prefs| {
+            (
+                prefs.post_processing_ollama_url,
+                prefs.post_processing_ollama_model,
+            )
+        })
+        .unwrap_or_default();
+
+    let base_url = base_url.or(stored_url);
+    let client = crate::platform::llm::OllamaClient::new(base_url.as_deref())
+        .map_err(|err| err.to_string())?
+        .with_api_key(api_key);
+    Ok((
+        client,
+        stored_model.filter(|model| !model.trim().is_empty()),
+    ))
+}
+
+fn resolve_ollama_model(
+    explicit: Option<String>,
+    stored: Option<String>,
+) -> Result<String, String> {
+    explicit
+        .filter(|model| !model.trim().is_empty())
+        .or(stored)
+        .ok_or_else(|| {
+            "No Ollama model selected. Choose a model in post-processing settings.".to_string()
+        })
+}
+
+async fn run_ollama<T, F>(client: crate::platform::llm::OllamaClient, task: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce(&crate::platform::llm::OllamaClient) -> Result<T, crate::platform::llm::OllamaError>
+        + Send
+        + 'static,
+{
+    tauri::async_runtime::spawn_blocking(move || task(&client))
+        .await
+        .map_err(|err| format!("Ollama task join error: {err}"))?
+        .map_err(|err| {
+            eprintln!("[ollama] {err}");
+            err.to_string()
+        })
+}
+
+#[tauri::command]
+pub async fn ollama_probe(
+    base_url: Option<String>,
+    api_key: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<crate::platform::llm::ollama::OllamaStatus, String> {
+    let (client, _) = ollama_client(database.pool(), base_url, api_key).await?;
+    run_ollama(client, |client| client.probe()).await
+}
+
+#[tauri::command]
+pub async fn ollama_list_models(
+    base_url: Option<String>,
+    api_key: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<Vec<crate::platform::llm::ollama::OllamaModel>, String> {
+    let (client, _) = ollama_client(database.pool(), base_url, api_key).await?;
+    run_ollama(client, |client| client.list_models()).await
+}
+
+/// Pulls a model, emitting `ollama_pull_progress` events as layers download.
+#[tauri::command]
+pub async fn ollama_pull_model(
+    app: AppHandle,
+    base_url: Option<String>,
+    api_key: Option<String>,
+    model: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    let (client, stored_model) = ollama_client(database.pool(), base_url, api_key).await?;
+    let model = resolve_ollama_model(model, stored_model)?;
+
+    run_ollama(client, move |client| {
+        client.pull(&model, |progress| {
+            let payload = OllamaPullProgressPayload {
+                model: model.clone(),
+                status: progress.status.clone(),
+                digest: progress.digest.clone(),
+                total: progress.total,
+                completed: progress.completed,
+            };
+            if let Err(err) = app.emit_to(EventTarget::any(), EVT_OLLAMA_PULL_PROGRESS, payload) {
+                eprintln!("[ollama] Failed to emit ollama_pull_progress event: {err}");
+            }
+        })
+    })
+    .await
+}
+
+/// Loads the model into memory ahead of the first post-processing request.
+#[tauri::command]
+pub async fn ollama_warm_up(
+    base_url: Option<String>,
+    api_key: Option<String>,
+    model: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+) -> Result<(), String> {
+    let (client, stored_model) = ollama_client(database.pool(), base_url, api_key).await?;
+    let model = resolve_ollama_model(model, stored_model)?;
+    run_ollama(client, move |client| client.warm_up(&model)).await
+}
+
 #[tauri::command]
 pub async fn purge_stale_transcription_audio(
     app: AppHandle,
@@ -1072,18 +2543,69 @@ pub fn restore_overlay_focus() {
     }
 }
 
-#[tauri::command]
-pub async fn paste(text: String, keybind: Option<String>) -> Result<(), String> {
-    let join_result =
-        tauri::async_runtime::spawn_blocking(move || platform_paste_text(&text, keybind.as_deref())).await;
+/// The app target text is being inserted into, or `None` to use the
+/// defaults.
+async fn fetch_injection_target(
+    pool: sqlx::SqlitePool,
+    app_target_id: Option<&str>,
+) -> Option<crate::domain::AppTarget> {
+    let id = app_target_id?;
+    crate::db::app_target_queries::fetch_app_target_by_id(pool, id)
+        .await
+        .map_err(|err| eprintln!("[paste] Failed to load app target {id}: {err}"))
+        .ok()
+        .flatten()
+}
+
+/// How to insert text into `target`; an explicit `keybind` wins over the
+/// target's paste shortcut.
+fn injection_options(
+    target: Option<&crate::domain::AppTarget>,
+    keybind: Option<String>,
+    html: Option<String>,
+) -> crate::domain::InjectionOptions {
+    crate::domain::InjectionOptions {
+        strategy: target
+            .map(|target| target.injection_strategy)
+            .unwrap_or_default(),
+        keybind: keybind.or_else(|| target.and_then(|target| target.paste_keybind.clone())),
+        typing_delay: target
+            .and_then(|target| target.typing_delay_ms)
+            .map(|ms| std::time::Duration::from_millis(u64::from(ms))),
+        html,
+    }
+}
 
-    match join_result {
-        Ok(result) => {
-            if let Err(err) = result.as_ref() {
-                eprintln!("Paste failed: {err}");
+/// Inserts `text` into the focused app and remembers it for
+/// `undo_last_paste` when it arrived.
+async fn inject_and_record(
+    text: String,
+    options: crate::domain::InjectionOptions,
+    paste_history: &crate::state::PasteHistoryState,
+) -> Result<crate::domain::InjectionReport, String> {
+    let join_result = tauri::async_runtime::spawn_blocking(move || {
+        let report = crate::platform::injection::inject_text(&text, &options)?;
+        let last_paste = match report.outcome {
+            crate::domain::InjectionOutcome::Verified
+            | crate::domain::InjectionOutcome::Unverified => {
+                last_paste_record(&text, options.html.is_some(), report.app_name.clone())
             }
+            _ => None,
+        };
+        Ok((report, last_paste))
+    })
+    .await;
 
-            result
+    match join_result {
+        Ok(Ok((report, last_paste))) => {
+            if let Some(last_paste) = last_paste {
+                paste_history.record(last_paste);
+            }
+            Ok(report)
+        }
+        Ok(Err(err)) => {
+            eprintln!("Paste failed: {err}");
+            Err(err)
         }
         Err(err) => {
             let message = format!("Paste task join error: {err}");
@@ -1093,6 +2615,143 @@ pub async fn paste(text: String, keybind: Option<String>) -> Result<(), String>
     }
 }
 
+/// Inserts `text` into the focused app the way its app target asks.
+/// `tone_id` is the tone that post-processed the text, if any.
+#[tauri::command]
+pub async fn paste(
+    text: String,
+    keybind: Option<String>,
+    app_target_id: Option<String>,
+    tone_id: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    paste_history: State<'_, crate::state::PasteHistoryState>,
+) -> Result<crate::domain::InjectionReport, String> {
+    let target = fetch_injection_target(database.pool(), app_target_id.as_deref()).await;
+    let produces_markdown = match tone_id {
+        Some(id) if target.as_ref().is_some_and(|target| target.rich_text_paste) => {
+            crate::db::tone_queries::fetch_tone_by_id(database.pool(), &id)
+                .await
+                .map_err(|err| eprintln!("[paste] Failed to load tone {id}: {err}"))
+                .ok()
+                .flatten()
+                .is_some_and(|tone| tone.produces_markdown())
+        }
+        _ => false,
+    };
+    // Markdown from a tone that writes it is pasted formatted, with the
+    // Markdown itself as the plain-text version.
+    let html = produces_markdown
+        .then(|| crate::text::markdown_to_html(&text))
+        .flatten();
+    let options = injection_options(target.as_ref(), keybind, html);
+
+    inject_and_record(text, options, &paste_history).await
+}
+
+/// How long after a paste `undo_last_paste` may still remove it.
+const UNDO_PASTE_WINDOW: std::time::Duration = std::time::Duration::from_secs(2 * 60);
+/// Longer pastes are not removed key by key.
+const UNDO_PASTE_MAX_BACKSPACES: usize = 5_000;
+
+/// What `undo_last_paste` needs to remove `text` again, or `None` when the
+/// paste skipped it. `rich` pastes went in as HTML, so the field may hold
+/// more or fewer characters than `text`.
+fn last_paste_record(
+    text: &str,
+    rich: bool,
+    app_name: Option<String>,
+) -> Option<crate::state::paste_history::LastPaste> {
+    if text.trim().is_empty() {
+        return None;
+    }
+
+    Some(crate::state::paste_history::LastPaste {
+        text: text.to_string(),
+        backspaces: (!rich).then(|| crate::text::backspaces_to_delete(text)),
+        app_name,
+        pasted_at: std::time::Instant::now(),
+    })
+}
+
+/// The focused field's text left of the caret, when accessibility exposes
+/// it. macOS reports the caret in UTF-16 code units and Windows as a byte
+/// offset into the text.
+fn text_before_caret(info: &TextFieldInfo) -> Option<String> {
+    let text = info.text_content.as_deref()?;
+    let caret = info.cursor_position?;
+    if cfg!(target_os = "macos") {
+        let units: Vec<u16> = text.encode_utf16().take(caret).collect();
+        (units.len() == caret)
+            .then(|| String::from_utf16(&units).ok())
+            .flatten()
+    } else {
+        text.get(..caret).map(str::to_string)
+    }
+}
+
+/// Backspaces that remove `last_paste` from the focused field. Where the
+/// field can be read, the text before the caret must still end with the
+/// paste; elsewhere only plain-text pastes have a known length.
+fn undo_backspaces(last_paste: &crate::state::paste_history::LastPaste) -> Result<usize, String> {
+    let field = crate::platform::accessibility::get_text_field_info();
+    match text_before_caret(&field) {
+        Some(before_caret) => {
+            crate::text::backspaces_to_remove_suffix(&before_caret, &last_paste.text).ok_or_else(
+                || "The text before the cursor no longer ends with the last dictation".to_string(),
+            )
+        }
+        None => last_paste
+            .backspaces
+            .ok_or_else(|| "Unable to check formatted text in this app to undo it".to_string()),
+    }
+}
+
+/// Removes the text the last `paste` inserted with one Backspace per
+/// character. Refuses when the paste is old, another app has focus or the
+/// text before the caret has changed, where the keystrokes would delete
+/// something else.
+#[tauri::command]
+pub async fn undo_last_paste(
+    paste_history: State<'_, crate::state::PasteHistoryState>,
+) -> Result<(), String> {
+    let last_paste = paste_history
+        .last()
+        .ok_or_else(|| "There is no dictation to undo".to_string())?;
+    let refusal = if last_paste.pasted_at.elapsed() > UNDO_PASTE_WINDOW {
+        Some("The last dictation is too old to undo")
+    } else if last_paste.app_name.is_none() {
+        Some("Unable to tell which app received the last dictation")
+    } else {
+        None
+    };
+    if let Some(refusal) = refusal {
+        paste_history.forget(&last_paste);
+        return Err(refusal.to_string());
+    }
+
+    let expected_app = last_paste.app_name.clone().unwrap_or_default();
+    let paste = last_paste.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let current_app = crate::platform::app_info::get_focused_app_name()
+            .map_err(|err| format!("Unable to check the focused app: {err}"))?;
+        if current_app != expected_app {
+            return Err(format!(
+                "Switch back to {expected_app} to undo the last dictation"
+            ));
+        }
+        let backspaces = undo_backspaces(&paste)?;
+        if backspaces > UNDO_PASTE_MAX_BACKSPACES {
+            return Err("The last dictation is too long to undo".to_string());
+        }
+        crate::platform::input::delete_backward(backspaces)
+    })
+    .await
+    .map_err(|err| format!("Undo task join error: {err}"))??;
+
+    paste_history.forget(&last_paste);
+    Ok(())
+}
+
 #[tauri::command]
 pub fn set_phase(
     app: AppHandle,
@@ -1121,8 +2780,16 @@ pub fn set_pill_hover_enabled(
 }
 
 #[tauri::command]
-pub fn start_key_listener(app: AppHandle) -> Result<(), String> {
-    crate::platform::keyboard::start_key_listener(&app)
+pub async fn start_key_listener(
+    app: AppHandle,
+    hotkeys: Vec<crate::domain::Hotkey>,
+) -> Result<crate::domain::KeySource, String> {
+    crate::platform::keyboard::start_key_listener(&app, hotkeys).await
+}
+
+#[tauri::command]
+pub fn get_key_listener_source() -> Option<crate::domain::KeySource> {
+    crate::platform::keyboard::key_listener_source()
 }
 
 #[tauri::command]
@@ -1165,35 +2832,166 @@ pub async fn get_selected_text() -> Result<Option<String>, String> {
         .map_err(|err| err.to_string())
 }
 
+/// A rewrite that took longer than this is not pasted; the user has likely
+/// moved on and the selection may be gone.
+const SELECTION_REWRITE_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionRewriteStartResponse {
+    pub selected_text: String,
+    pub app_name: Option<String>,
+}
+
+fn restore_clipboard(snapshot: crate::platform::clipboard::ClipboardSnapshot) {
+    if let Err(err) = snapshot.restore() {
+        eprintln!("[selection-rewrite] {err}");
+    }
+}
+
+/// Reads the selection in the focused app and remembers it, together with
+/// the clipboard, until `selection_rewrite_apply` or `selection_rewrite_cancel`.
+/// Returns `None` when nothing is selected.
+#[tauri::command]
+pub async fn selection_rewrite_begin(
+    selection_rewrite: State<'_, crate::state::SelectionRewriteState>,
+) -> Result<Option<SelectionRewriteStartResponse>, String> {
+    let session = tauri::async_runtime::spawn_blocking(|| {
+        let clipboard = crate::platform::clipboard::ClipboardSnapshot::capture();
+        let app_name = crate::platform::app_info::get_focused_app_name()
+            .map_err(|err| eprintln!("[selection-rewrite] Focused app unavailable: {err}"))
+            .ok();
+        let selected_text = crate::platform::accessibility::get_selected_text()
+            .filter(|text| !text.trim().is_empty())?;
+
+        Some(crate::state::selection_rewrite::SelectionRewriteSession {
+            selected_text,
+            clipboard,
+            app_name,
+            started_at: std::time::Instant::now(),
+        })
+    })
+    .await
+    .map_err(|err| err.to_string())?;
+
+    let Some(session) = session else {
+        return Ok(None);
+    };
+    let response = SelectionRewriteStartResponse {
+        selected_text: session.selected_text.clone(),
+        app_name: session.app_name.clone(),
+    };
+    selection_rewrite.begin(session);
+    Ok(Some(response))
+}
+
+/// Refuses to paste when the selection can no longer be trusted to be where
+/// the user left it, so nothing is typed into the wrong place, and fails when
+/// the rewrite was seen not to arrive.
+fn paste_selection_rewrite(
+    session: &crate::state::selection_rewrite::SelectionRewriteSession,
+    text: &str,
+    options: &crate::domain::InjectionOptions,
+) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Err("The rewrite was empty; the selection was left unchanged".to_string());
+    }
+    if session.started_at.elapsed() > SELECTION_REWRITE_MAX_AGE {
+        return Err("The selection is too old to replace".to_string());
+    }
+    if let Some(expected) = session.app_name.as_deref() {
+        match crate::platform::app_info::get_focused_app_name() {
+            Ok(current) if current != expected => {
+                return Err(format!(
+                    "Focus moved from {expected} to {current}; the selection was left unchanged"
+                ));
+            }
+            Ok(_) => {}
+            Err(err) => eprintln!("[selection-rewrite] Focused app unavailable: {err}"),
+        }
+    }
+
+    let report = crate::platform::injection::inject_text(text, options)?;
+    if report.outcome == crate::domain::InjectionOutcome::Failed {
+        return Err(report
+            .message
+            .unwrap_or_else(|| "The rewrite did not reach the selection".to_string()));
+    }
+    Ok(())
+}
+
+/// Inserts `text` over the selection captured by `selection_rewrite_begin`
+/// the way `paste` inserts dictation into the app target. On failure the
+/// clipboard is restored; on success the paste itself puts the old clipboard
+/// back.
+#[tauri::command]
+pub async fn selection_rewrite_apply(
+    text: String,
+    keybind: Option<String>,
+    app_target_id: Option<String>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
+    selection_rewrite: State<'_, crate::state::SelectionRewriteState>,
+) -> Result<(), String> {
+    let session = selection_rewrite
+        .take()
+        .ok_or_else(|| "No selection rewrite is in progress".to_string())?;
+    let target = fetch_injection_target(database.pool(), app_target_id.as_deref()).await;
+    let options = injection_options(target.as_ref(), keybind, None);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = paste_selection_rewrite(&session, &text, &options);
+        if let Err(err) = &result {
+            eprintln!("[selection-rewrite] {err}");
+            restore_clipboard(session.clipboard);
+        }
+        result
+    })
+    .await
+    .map_err(|err| format!("Selection rewrite task join error: {err}"))?
+}
+
+/// Abandons the rewrite, leaving the selection as it was and restoring the
+/// clipboard.
+#[tauri::command]
+pub async fn selection_rewrite_cancel(
+    selection_rewrite: State<'_, crate::state::SelectionRewriteState>,
+) -> Result<(), String> {
+    let Some(session) = selection_rewrite.take() else {
+        return Ok(());
+    };
+    tauri::async_runtime::spawn_blocking(move || restore_clipboard(session.clipboard))
+        .await
+        .map_err(|err| err.to_string())
+}
+
 #[tauri::command]
 pub async fn initialize_local_transcriber(
     app: AppHandle,
     transcriber_state: State<'_, crate::state::TranscriberState>,
+    database: State<'_, crate::state::OptionKeyDatabase>,
 ) -> Result<bool, String> {
-    if transcriber_state.is_initialized() {
+    let engine = resolve_local_transcription_engine(database.pool(), None).await;
+    if transcriber_state.is_initialized(engine) {
         return Ok(false);
     }
 
-    eprintln!("[initialize_local_transcriber] Pre-warming Whisper transcriber...");
+    eprintln!("[initialize_local_transcriber] Pre-warming {engine} transcriber...");
 
     let default_model_size = WhisperModelSize::default();
-    let model_path = {
+    let new_transcriber = {
         let handle = app.clone();
         tauri::async_runtime::spawn_blocking(move || {
-            crate::system::models::ensure_whisper_model(&handle, default_model_size)
-                .map_err(|err| err.to_string())
+            let model_path =
+                crate::system::models::ensure_local_model(&handle, engine, default_model_size)
+                    .map_err(|err| err.to_string())?;
+            crate::platform::load_local_transcriber(engine, &model_path)
         })
         .await
         .map_err(|err| err.to_string())??
     };
 
-    let new_transcriber: Arc<dyn crate::platform::Transcriber> = Arc::new(
-        crate::platform::whisper::WhisperTranscriber::new(&model_path)
-            .map_err(|err| format!("Failed to initialize Whisper transcriber: {err}"))?,
-    );
-
-    transcriber_state.initialize(new_transcriber)?;
-    eprintln!("[initialize_local_transcriber] Whisper transcriber initialized successfully");
+    transcriber_state.initialize(engine, new_transcriber)?;
+    eprintln!("[initialize_local_transcriber] {engine} transcriber initialized successfully");
 
     Ok(true)
 }
@@ -1,7 +1,25 @@
-use sqlx::{Row, SqlitePool};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
 use crate::domain::{ApiKey, ApiKeyUpdateRequest};
 
+fn row_to_api_key(row: SqliteRow) -> ApiKey {
+    ApiKey {
+        id: row.get::<String, _>("id"),
+        name: row.get::<String, _>("name"),
+        provider: row.get::<String, _>("provider"),
+        created_at: row.get::<i64, _>("created_at"),
+        salt: row.get::<String, _>("salt"),
+        key_hash: row.get::<String, _>("key_hash"),
+        key_ciphertext: row.get::<String, _>("key_ciphertext"),
+        key_suffix: row.get::<Option<String>, _>("key_suffix"),
+        transcription_model: row.get::<Option<String>, _>("transcription_model"),
+        post_processing_model: row.get::<Option<String>, _>("post_processing_model"),
+        openrouter_config: row.get::<Option<String>, _>("openrouter_config"),
+        base_url: row.get::<Option<String>, _>("base_url"),
+        azure_region: row.get::<Option<String>, _>("azure_region"),
+    }
+}
+
 pub async fn insert_api_key(pool: SqlitePool, api_key: &ApiKey) -> Result<ApiKey, sqlx::Error> {
     sqlx::query(
         "INSERT INTO api_keys (id, name, provider, created_at, salt, key_hash, key_ciphertext, key_suffix, transcription_model, post_processing_model, openrouter_config, base_url, azure_region)
@@ -35,28 +53,28 @@ pub async fn fetch_api_keys(pool: SqlitePool) -> Result<Vec<ApiKey>, sqlx::Error
     .fetch_all(&pool)
     .await?;
 
-    let api_keys = rows
-        .into_iter()
-        .map(|row| ApiKey {
-            id: row.get::<String, _>("id"),
-            name: row.get::<String, _>("name"),
-            provider: row.get::<String, _>("provider"),
-            created_at: row.get::<i64, _>("created_at"),
-            salt: row.get::<String, _>("salt"),
-            key_hash: row.get::<String, _>("key_hash"),
-            key_ciphertext: row.get::<String, _>("key_ciphertext"),
-            key_suffix: row.get::<Option<String>, _>("key_suffix"),
-            transcription_model: row.get::<Option<String>, _>("transcription_model"),
-            post_processing_model: row.get::<Option<String>, _>("post_processing_model"),
-            openrouter_config: row.get::<Option<String>, _>("openrouter_config"),
-            base_url: row.get::<Option<String>, _>("base_url"),
-            azure_region: row.get::<Option<String>, _>("azure_region"),
-        })
-        .collect();
+    let api_keys = rows.into_iter().map(row_to_api_key).collect();
 
     Ok(api_keys)
 }
 
+pub async fn fetch_api_key_by_id(
+    pool: SqlitePool,
+    id: &str,
+) -> Result<Option<ApiKey>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT id, name, provider, created_at, salt, key_hash, key_ciphertext, key_suffix, transcription_model, post_processing_model, openrouter_config, base_url, azure_region
+         FROM api_keys
+         WHERE id = ?1
+         LIMIT 1",
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
+
+    Ok(row.map(row_to_api_key))
+}
+
 pub async fn update_api_key(pool: SqlitePool, request: &ApiKeyUpdateRequest) -> Result<(), sqlx::Error> {
     sqlx::query(
         "UPDATE api_keys SET transcription_model = ?2, post_processing_model = ?3, openrouter_config = ?4, base_url = ?5, azure_region = ?6 WHERE id = ?1",
@@ -1,79 +1,97 @@
-use chrono::Utc;
-use sqlx::{Row, SqlitePool};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
 use crate::domain::AppTarget;
 
+const SELECT_APP_TARGET: &str = "SELECT id, name, created_at, tone_id, icon_path, paste_keybind,
+        inverse_text_normalization, code_mode_enabled, injection_strategy, typing_delay_ms,
+        rich_text_paste
+ FROM app_targets";
+
+fn row_to_app_target(row: &SqliteRow) -> Result<AppTarget, sqlx::Error> {
+    Ok(AppTarget {
+        id: row.get("id"),
+        name: row.get("name"),
+        created_at: row.get("created_at"),
+        tone_id: row.try_get("tone_id")?,
+        icon_path: row.try_get("icon_path")?,
+        paste_keybind: row.try_get("paste_keybind")?,
+        inverse_text_normalization: row.try_get("inverse_text_normalization")?,
+        code_mode_enabled: row
+            .try_get::<i64, _>("code_mode_enabled")
+            .map(|v| v != 0)
+            .unwrap_or(false),
+        injection_strategy: row
+            .try_get::<String, _>("injection_strategy")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default(),
+        typing_delay_ms: row
+            .try_get::<Option<i64>, _>("typing_delay_ms")?
+            .map(|value| value.clamp(0, u32::MAX as i64) as u32),
+        rich_text_paste: row
+            .try_get::<i64, _>("rich_text_paste")
+            .map(|v| v != 0)
+            .unwrap_or(false),
+    })
+}
+
+/// Inserts or updates `target`. `created_at` is only written on insert.
 pub async fn upsert_app_target(
     pool: SqlitePool,
-    id: &str,
-    name: &str,
-    tone_id: Option<String>,
-    icon_path: Option<String>,
-    paste_keybind: Option<String>,
+    target: &AppTarget,
 ) -> Result<AppTarget, sqlx::Error> {
-    let existing_created_at =
-        sqlx::query_scalar::<_, Option<String>>("SELECT created_at FROM app_targets WHERE id = ?1")
-            .bind(id)
-            .fetch_optional(&pool)
-            .await?;
-
-    let created_at = existing_created_at
-        .flatten()
-        .unwrap_or_else(|| Utc::now().to_rfc3339());
-
     sqlx::query(
-        "INSERT INTO app_targets (id, name, created_at, tone_id, icon_path, paste_keybind)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "INSERT INTO app_targets (id, name, created_at, tone_id, icon_path, paste_keybind, inverse_text_normalization, code_mode_enabled, injection_strategy, typing_delay_ms, rich_text_paste)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
          ON CONFLICT(id) DO UPDATE SET
            name = excluded.name,
            tone_id = excluded.tone_id,
            icon_path = excluded.icon_path,
-           paste_keybind = excluded.paste_keybind",
+           paste_keybind = excluded.paste_keybind,
+           inverse_text_normalization = excluded.inverse_text_normalization,
+           code_mode_enabled = excluded.code_mode_enabled,
+           injection_strategy = excluded.injection_strategy,
+           typing_delay_ms = excluded.typing_delay_ms,
+           rich_text_paste = excluded.rich_text_paste",
     )
-    .bind(id)
-    .bind(name)
-    .bind(&created_at)
-    .bind(tone_id)
-    .bind(icon_path)
-    .bind(paste_keybind)
+    .bind(&target.id)
+    .bind(&target.name)
+    .bind(&target.created_at)
+    .bind(&target.tone_id)
+    .bind(&target.icon_path)
+    .bind(&target.paste_keybind)
+    .bind(target.inverse_text_normalization)
+    .bind(target.code_mode_enabled)
+    .bind(target.injection_strategy.as_str())
+    .bind(target.typing_delay_ms.map(i64::from))
+    .bind(target.rich_text_paste)
     .execute(&pool)
     .await?;
 
-    let row = sqlx::query(
-        "SELECT id, name, created_at, tone_id, icon_path, paste_keybind FROM app_targets WHERE id = ?1",
-    )
-        .bind(id)
+    let row = sqlx::query(&format!("{SELECT_APP_TARGET} WHERE id = ?1"))
+        .bind(&target.id)
         .fetch_one(&pool)
         .await?;
 
-    Ok(AppTarget {
-        id: row.get("id"),
-        name: row.get("name"),
-        created_at: row.get("created_at"),
-        tone_id: row.try_get("tone_id")?,
-        icon_path: row.try_get("icon_path")?,
-        paste_keybind: row.try_get("paste_keybind")?,
-    })
+    row_to_app_target(&row)
 }
 
-pub async fn fetch_app_targets(pool: SqlitePool) -> Result<Vec<AppTarget>, sqlx::Error> {
-    let rows = sqlx::query(
-        "SELECT id, name, created_at, tone_id, icon_path, paste_keybind FROM app_targets ORDER BY created_at DESC",
-    )
-    .fetch_all(&pool)
-    .await?;
+pub async fn fetch_app_target_by_id(
+    pool: SqlitePool,
+    id: &str,
+) -> Result<Option<AppTarget>, sqlx::Error> {
+    let row = sqlx::query(&format!("{SELECT_APP_TARGET} WHERE id = ?1"))
+        .bind(id)
+        .fetch_optional(&pool)
+        .await?;
 
-    let mut targets = Vec::with_capacity(rows.len());
-    for row in rows {
-        targets.push(AppTarget {
-            id: row.get("id"),
-            name: row.get("name"),
-            created_at: row.get("created_at"),
-            tone_id: row.try_get("tone_id")?,
-            icon_path: row.try_get("icon_path")?,
-            paste_keybind: row.try_get("paste_keybind")?,
-        });
-    }
+    row.as_ref().map(row_to_app_target).transpose()
+}
+
+pub async fn fetch_app_targets(pool: SqlitePool) -> Result<Vec<AppTarget>, sqlx::Error> {
+    let rows = sqlx::query(&format!("{SELECT_APP_TARGET} ORDER BY created_at DESC"))
+        .fetch_all(&pool)
+        .await?;
 
-    Ok(targets)
+    rows.iter().map(row_to_app_target).collect()
 }
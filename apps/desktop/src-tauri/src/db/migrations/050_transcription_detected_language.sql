@@ -0,0 +1,2 @@
+ALTER TABLE transcriptions ADD COLUMN detected_language TEXT;
+ALTER TABLE transcriptions ADD COLUMN language_probabilities_json TEXT;
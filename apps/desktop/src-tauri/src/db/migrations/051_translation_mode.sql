@@ -0,0 +1,3 @@
+ALTER TABLE tones ADD COLUMN translation_mode TEXT;
+ALTER TABLE transcriptions ADD COLUMN translated_transcript TEXT;
+ALTER TABLE transcriptions ADD COLUMN translation_mode TEXT;
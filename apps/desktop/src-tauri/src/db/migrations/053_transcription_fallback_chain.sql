@@ -0,0 +1 @@
+ALTER TABLE user_preferences ADD COLUMN transcription_fallback_chain_json TEXT;
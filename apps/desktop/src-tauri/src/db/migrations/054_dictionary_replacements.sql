@@ -0,0 +1,2 @@
+ALTER TABLE terms ADD COLUMN match_mode TEXT NOT NULL DEFAULT 'exact';
+ALTER TABLE transcriptions ADD COLUMN applied_replacements_json TEXT;
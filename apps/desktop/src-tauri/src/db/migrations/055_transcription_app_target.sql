@@ -0,0 +1 @@
+ALTER TABLE transcriptions ADD COLUMN app_target_id TEXT;
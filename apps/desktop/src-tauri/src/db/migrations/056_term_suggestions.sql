@@ -0,0 +1,20 @@
+-- Word substitutions users make when correcting transcripts
+CREATE TABLE IF NOT EXISTS term_corrections (
+  transcription_id TEXT NOT NULL,
+  source_key TEXT NOT NULL,
+  source_value TEXT NOT NULL,
+  destination_value TEXT NOT NULL,
+  created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_term_corrections_transcription_id ON term_corrections(transcription_id);
+
+-- Suggestions the user accepted or rejected, so they are not offered again
+CREATE TABLE IF NOT EXISTS term_suggestion_decisions (
+  user_id TEXT NOT NULL,
+  source_key TEXT NOT NULL,
+  destination_value TEXT NOT NULL,
+  status TEXT NOT NULL,
+  decided_at INTEGER NOT NULL,
+  PRIMARY KEY (user_id, source_key, destination_value)
+);
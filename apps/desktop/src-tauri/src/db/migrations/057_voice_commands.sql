@@ -0,0 +1,12 @@
+-- Per-user spoken command grammar layered over the built-in commands
+CREATE TABLE IF NOT EXISTS voice_commands (
+  id TEXT NOT NULL,
+  user_id TEXT NOT NULL,
+  language TEXT NOT NULL,
+  phrase TEXT NOT NULL,
+  action TEXT NOT NULL,
+  value TEXT,
+  is_enabled INTEGER NOT NULL DEFAULT 1,
+  created_at INTEGER NOT NULL,
+  PRIMARY KEY (user_id, id)
+);
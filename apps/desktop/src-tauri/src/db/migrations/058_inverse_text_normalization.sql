@@ -0,0 +1,3 @@
+-- Per-tone and per-app switch for rewriting spoken numbers, dates and units
+ALTER TABLE tones ADD COLUMN inverse_text_normalization INTEGER;
+ALTER TABLE app_targets ADD COLUMN inverse_text_normalization INTEGER;
@@ -0,0 +1,3 @@
+-- Local filler-word and stutter cleanup before post-processing
+ALTER TABLE user_preferences ADD COLUMN disfluency_cleanup_enabled INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_preferences ADD COLUMN filler_words_json TEXT;
@@ -0,0 +1,2 @@
+-- Languages whose transcripts get local spacing and punctuation correction
+ALTER TABLE user_preferences ADD COLUMN spacing_correction_languages_json TEXT;
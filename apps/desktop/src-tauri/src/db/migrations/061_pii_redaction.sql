@@ -0,0 +1,5 @@
+-- Masking of personal data before cloud post-processing and before saving
+ALTER TABLE user_preferences ADD COLUMN redact_before_cloud INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_preferences ADD COLUMN redact_before_storage INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_preferences ADD COLUMN redaction_detectors_json TEXT;
+ALTER TABLE user_preferences ADD COLUMN redaction_patterns_json TEXT;
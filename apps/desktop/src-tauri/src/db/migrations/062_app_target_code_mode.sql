@@ -0,0 +1,2 @@
+-- Per-app code dictation: identifier casing and spoken symbols
+ALTER TABLE app_targets ADD COLUMN code_mode_enabled INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,10 @@
+-- Per-user text snippets inserted by a spoken trigger phrase
+CREATE TABLE IF NOT EXISTS snippets (
+  id TEXT PRIMARY KEY,
+  created_at INTEGER NOT NULL,
+  created_by_user_id TEXT NOT NULL,
+  trigger TEXT NOT NULL,
+  expansion TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_snippets_user ON snippets (created_by_user_id);
@@ -0,0 +1,3 @@
+-- Per-app text injection: how dictated text is inserted and how fast it is typed
+ALTER TABLE app_targets ADD COLUMN injection_strategy TEXT NOT NULL DEFAULT 'clipboard';
+ALTER TABLE app_targets ADD COLUMN typing_delay_ms INTEGER;
@@ -0,0 +1,2 @@
+-- Per-app rich-text paste: Markdown transcripts are pasted as HTML
+ALTER TABLE app_targets ADD COLUMN rich_text_paste INTEGER NOT NULL DEFAULT 0;
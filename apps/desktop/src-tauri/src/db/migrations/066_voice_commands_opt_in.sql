@@ -0,0 +1,2 @@
+-- Spoken voice commands only run once the user turns them on
+ALTER TABLE user_preferences ADD COLUMN voice_commands_enabled INTEGER NOT NULL DEFAULT 0;
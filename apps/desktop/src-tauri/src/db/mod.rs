@@ -2,10 +2,13 @@ pub mod api_key_queries;
 pub mod app_target_queries;
 pub mod hotkey_queries;
 pub mod preferences_queries;
+pub mod snippet_queries;
 pub mod term_queries;
+pub mod term_suggestion_queries;
 pub mod tone_queries;
 pub mod transcription_queries;
 pub mod user_queries;
+pub mod voice_command_queries;
 
 pub const DB_FILENAME: &str = "voquill.db";
 pub const DB_CONNECTION: &str = "sqlite:voquill.db";
@@ -91,6 +94,37 @@ pub const USER_SCOPED_DATA_MIGRATION_SQL: &str =
     include_str!("migrations/048_user_scoped_data.sql");
 pub const PILL_VISIBILITY_PERSISTENT_DEFAULT_MIGRATION_SQL: &str =
     include_str!("migrations/049_pill_visibility_persistent_default.sql");
+pub const TRANSCRIPTION_DETECTED_LANGUAGE_MIGRATION_SQL: &str =
+    include_str!("migrations/050_transcription_detected_language.sql");
+pub const TRANSLATION_MODE_MIGRATION_SQL: &str =
+    include_str!("migrations/051_translation_mode.sql");
+pub const LOCAL_TRANSCRIPTION_ENGINE_MIGRATION_SQL: &str =
+    include_str!("migrations/052_local_transcription_engine.sql");
+pub const TRANSCRIPTION_FALLBACK_CHAIN_MIGRATION_SQL: &str =
+    include_str!("migrations/053_transcription_fallback_chain.sql");
+pub const DICTIONARY_REPLACEMENTS_MIGRATION_SQL: &str =
+    include_str!("migrations/054_dictionary_replacements.sql");
+pub const TRANSCRIPTION_APP_TARGET_MIGRATION_SQL: &str =
+    include_str!("migrations/055_transcription_app_target.sql");
+pub const TERM_SUGGESTIONS_MIGRATION_SQL: &str =
+    include_str!("migrations/056_term_suggestions.sql");
+pub const VOICE_COMMANDS_MIGRATION_SQL: &str = include_str!("migrations/057_voice_commands.sql");
+pub const INVERSE_TEXT_NORMALIZATION_MIGRATION_SQL: &str =
+    include_str!("migrations/058_inverse_text_normalization.sql");
+pub const DISFLUENCY_CLEANUP_MIGRATION_SQL: &str =
+    include_str!("migrations/059_disfluency_cleanup.sql");
+pub const SPACING_CORRECTION_LANGUAGES_MIGRATION_SQL: &str =
+    include_str!("migrations/060_spacing_correction_languages.sql");
+pub const PII_REDACTION_MIGRATION_SQL: &str = include_str!("migrations/061_pii_redaction.sql");
+pub const APP_TARGET_CODE_MODE_MIGRATION_SQL: &str =
+    include_str!("migrations/062_app_target_code_mode.sql");
+pub const SNIPPETS_MIGRATION_SQL: &str = include_str!("migrations/063_snippets.sql");
+pub const APP_TARGET_INJECTION_MIGRATION_SQL: &str =
+    include_str!("migrations/064_app_target_injection.sql");
+pub const APP_TARGET_RICH_TEXT_PASTE_MIGRATION_SQL: &str =
+    include_str!("migrations/065_app_target_rich_text_paste.sql");
+pub const VOICE_COMMANDS_OPT_IN_MIGRATION_SQL: &str =
+    include_str!("migrations/066_voice_commands_opt_in.sql");
 
 pub fn migrations() -> Vec<tauri_plugin_sql::Migration> {
     vec![
@@ -388,5 +422,107 @@ pub fn migrations() -> Vec<tauri_plugin_sql::Migration> {
             sql: PILL_VISIBILITY_PERSISTENT_DEFAULT_MIGRATION_SQL,
             kind: tauri_plugin_sql::MigrationKind::Up,
         },
+        tauri_plugin_sql::Migration {
+            version: 50,
+            description: "add_transcription_detected_language",
+            sql: TRANSCRIPTION_DETECTED_LANGUAGE_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 51,
+            description: "add_translation_mode",
+            sql: TRANSLATION_MODE_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 52,
+            description: "add_local_transcription_engine",
+            sql: LOCAL_TRANSCRIPTION_ENGINE_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 53,
+            description: "add_transcription_fallback_chain",
+            sql: TRANSCRIPTION_FALLBACK_CHAIN_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 54,
+            description: "add_dictionary_replacements",
+            sql: DICTIONARY_REPLACEMENTS_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 55,
+            description: "add_transcription_app_target",
+            sql: TRANSCRIPTION_APP_TARGET_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 56,
+            description: "add_term_suggestions",
+            sql: TERM_SUGGESTIONS_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 57,
+            description: "add_voice_commands",
+            sql: VOICE_COMMANDS_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 58,
+            description: "add_inverse_text_normalization",
+            sql: INVERSE_TEXT_NORMALIZATION_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 59,
+            description: "add_disfluency_cleanup",
+            sql: DISFLUENCY_CLEANUP_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 60,
+            description: "add_spacing_correction_languages",
+            sql: SPACING_CORRECTION_LANGUAGES_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 61,
+            description: "add_pii_redaction",
+            sql: PII_REDACTION_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 62,
+            description: "add_app_target_code_mode",
+            sql: APP_TARGET_CODE_MODE_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 63,
+            description: "create_snippets_table",
+            sql: SNIPPETS_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 64,
+            description: "add_app_target_injection",
+            sql: APP_TARGET_INJECTION_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 65,
+            description: "add_app_target_rich_text_paste",
+            sql: APP_TARGET_RICH_TEXT_PASTE_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
+        tauri_plugin_sql::Migration {
+            version: 66,
+            description: "add_voice_commands_opt_in",
+            sql: VOICE_COMMANDS_OPT_IN_MIGRATION_SQL,
+            kind: tauri_plugin_sql::MigrationKind::Up,
+        },
     ]
 }
@@ -1,6 +1,36 @@
 use sqlx::{Row, SqlitePool};
 
-use crate::domain::UserPreferences;
+use crate::domain::{parse_fallback_chain, PiiKind, TranscriptionFallbackStep, UserPreferences};
+
+fn serialize_fallback_chain(chain: &Option<Vec<TranscriptionFallbackStep>>) -> Option<String> {
+    chain
+        .as_ref()
+        .filter(|steps| !steps.is_empty())
+        .and_then(|steps| serde_json::to_string(steps).ok())
+}
+
+fn serialize_string_list(list: &Option<Vec<String>>) -> Option<String> {
+    list.as_ref()
+        .and_then(|values| serde_json::to_string(values).ok())
+}
+
+fn parse_string_list(json: &str) -> Option<Vec<String>> {
+    serde_json::from_str::<Vec<String>>(json).ok()
+}
+
+fn serialize_pii_kinds(kinds: &Option<Vec<PiiKind>>) -> Option<String> {
+    kinds
+        .as_ref()
+        .and_then(|values| serde_json::to_string(values).ok())
+}
+
+/// Unknown detector names from a newer build are dropped rather than
+/// discarding the whole list.
+fn parse_pii_kinds(json: &str) -> Option<Vec<PiiKind>> {
+    serde_json::from_str::<Vec<String>>(json)
+        .ok()
+        .map(|names| names.iter().filter_map(|name| name.parse().ok()).collect())
+}
 
 pub async fn upsert_user_preferences(
     pool: SqlitePool,
@@ -32,9 +62,19 @@ pub async fn upsert_user_preferences(
              ignore_update_dialog,
              incognito_mode_enabled,
              incognito_mode_include_in_stats,
-             dictation_pill_visibility
+             dictation_pill_visibility,
+             local_transcription_engine,
+             transcription_fallback_chain_json,
+             disfluency_cleanup_enabled,
+             filler_words_json,
+             spacing_correction_languages_json,
+             redact_before_cloud,
+             redact_before_storage,
+             redaction_detectors_json,
+             redaction_patterns_json,
+             voice_commands_enabled
          )
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33, ?34, ?35)
          ON CONFLICT(user_id) DO UPDATE SET
             transcription_mode = excluded.transcription_mode,
             transcription_api_key_id = excluded.transcription_api_key_id,
@@ -59,7 +99,17 @@ pub async fn upsert_user_preferences(
             ignore_update_dialog = excluded.ignore_update_dialog,
             incognito_mode_enabled = excluded.incognito_mode_enabled,
             incognito_mode_include_in_stats = excluded.incognito_mode_include_in_stats,
-            dictation_pill_visibility = excluded.dictation_pill_visibility",
+            dictation_pill_visibility = excluded.dictation_pill_visibility,
+            local_transcription_engine = excluded.local_transcription_engine,
+            transcription_fallback_chain_json = excluded.transcription_fallback_chain_json,
+            disfluency_cleanup_enabled = excluded.disfluency_cleanup_enabled,
+            filler_words_json = excluded.filler_words_json,
+            spacing_correction_languages_json = excluded.spacing_correction_languages_json,
+            redact_before_cloud = excluded.redact_before_cloud,
+            redact_before_storage = excluded.redact_before_storage,
+            redaction_detectors_json = excluded.redaction_detectors_json,
+            redaction_patterns_json = excluded.redaction_patterns_json,
+            voice_commands_enabled = excluded.voice_commands_enabled",
     )
     .bind(&preferences.user_id)
     .bind(&preferences.transcription_mode)
@@ -86,6 +136,16 @@ pub async fn upsert_user_preferences(
     .bind(preferences.incognito_mode_enabled)
     .bind(preferences.incognito_mode_include_in_stats)
     .bind(&preferences.dictation_pill_visibility)
+    .bind(&preferences.local_transcription_engine)
+    .bind(serialize_fallback_chain(&preferences.transcription_fallback_chain))
+    .bind(preferences.disfluency_cleanup_enabled)
+    .bind(serialize_string_list(&preferences.filler_words))
+    .bind(serialize_string_list(&preferences.spacing_correction_languages))
+    .bind(preferences.redact_before_cloud)
+    .bind(preferences.redact_before_storage)
+    .bind(serialize_pii_kinds(&preferences.redaction_detectors))
+    .bind(serialize_string_list(&preferences.redaction_patterns))
+    .bind(preferences.voice_commands_enabled)
     .execute(&pool)
     .await?;
 
@@ -122,7 +182,17 @@ pub async fn fetch_user_preferences(
             ignore_update_dialog,
             incognito_mode_enabled,
             incognito_mode_include_in_stats,
-            dictation_pill_visibility
+            dictation_pill_visibility,
+            local_transcription_engine,
+            transcription_fallback_chain_json,
+            disfluency_cleanup_enabled,
+            filler_words_json,
+            spacing_correction_languages_json,
+            redact_before_cloud,
+            redact_before_storage,
+            redaction_detectors_json,
+            redaction_patterns_json,
+            voice_commands_enabled
          FROM user_preferences
          WHERE user_id = ?1
          LIMIT 1",
@@ -211,6 +281,45 @@ pub async fn fetch_user_preferences(
         dictation_pill_visibility: row
             .try_get::<String, _>("dictation_pill_visibility")
             .unwrap_or_else(|_| "while_active".to_string()),
+        local_transcription_engine: row
+            .try_get::<Option<String>, _>("local_transcription_engine")
+            .unwrap_or(None),
+        transcription_fallback_chain: row
+            .try_get::<Option<String>, _>("transcription_fallback_chain_json")
+            .unwrap_or(None)
+            .map(|json| parse_fallback_chain(&json)),
+        disfluency_cleanup_enabled: row
+            .try_get::<i64, _>("disfluency_cleanup_enabled")
+            .map(|v| v != 0)
+            .unwrap_or(false),
+        filler_words: row
+            .try_get::<Option<String>, _>("filler_words_json")
+            .unwrap_or(None)
+            .and_then(|json| parse_string_list(&json)),
+        spacing_correction_languages: row
+            .try_get::<Option<String>, _>("spacing_correction_languages_json")
+            .unwrap_or(None)
+            .and_then(|json| parse_string_list(&json)),
+        redact_before_cloud: row
+            .try_get::<i64, _>("redact_before_cloud")
+            .map(|v| v != 0)
+            .unwrap_or(false),
+        redact_before_storage: row
+            .try_get::<i64, _>("redact_before_storage")
+            .map(|v| v != 0)
+            .unwrap_or(false),
+        redaction_detectors: row
+            .try_get::<Option<String>, _>("redaction_detectors_json")
+            .unwrap_or(None)
+            .and_then(|json| parse_pii_kinds(&json)),
+        redaction_patterns: row
+            .try_get::<Option<String>, _>("redaction_patterns_json")
+            .unwrap_or(None)
+            .and_then(|json| parse_string_list(&json)),
+        voice_commands_enabled: row
+            .try_get::<i64, _>("voice_commands_enabled")
+            .map(|v| v != 0)
+            .unwrap_or(false),
     });
 
     Ok(preferences)
@@ -241,3 +350,32 @@ pub async fn fetch_transcription_mode(pool: SqlitePool) -> Result<Option<String>
 
     Ok(row.flatten())
 }
+
+pub async fn fetch_local_transcription_engine(
+    pool: SqlitePool,
+) -> Result<Option<String>, sqlx::Error> {
+    let row: Option<Option<String>> = sqlx::query_scalar(
+        "SELECT local_transcription_engine FROM user_preferences WHERE user_id = ?1 LIMIT 1",
+    )
+    .bind(LOCAL_USER_ID)
+    .fetch_optional(&pool)
+    .await?;
+
+    Ok(row.flatten())
+}
+
+pub async fn fetch_transcription_fallback_chain(
+    pool: SqlitePool,
+) -> Result<Vec<TranscriptionFallbackStep>, sqlx::Error> {
+    let row: Option<Option<String>> = sqlx::query_scalar(
+        "SELECT transcription_fallback_chain_json FROM user_preferences WHERE user_id = ?1 LIMIT 1",
+    )
+    .bind(LOCAL_USER_ID)
+    .fetch_optional(&pool)
+    .await?;
+
+    Ok(row
+        .flatten()
+        .map(|json| parse_fallback_chain(&json))
+        .unwrap_or_default())
+}
@@ -0,0 +1,72 @@
+use sqlx::{Row, SqlitePool};
+
+use crate::domain::Snippet;
+
+pub async fn insert_snippet(pool: SqlitePool, snippet: &Snippet) -> Result<Snippet, sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO snippets (id, created_at, created_by_user_id, trigger, expansion)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )
+    .bind(&snippet.id)
+    .bind(snippet.created_at)
+    .bind(&snippet.created_by_user_id)
+    .bind(&snippet.trigger)
+    .bind(&snippet.expansion)
+    .execute(&pool)
+    .await?;
+
+    Ok(snippet.clone())
+}
+
+/// Oldest first, so the first snippet saved wins when triggers collide.
+pub async fn fetch_snippets(pool: SqlitePool, user_id: &str) -> Result<Vec<Snippet>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT id, created_at, created_by_user_id, trigger, expansion
+         FROM snippets
+         WHERE created_by_user_id = ?1
+         ORDER BY created_at ASC",
+    )
+    .bind(user_id)
+    .fetch_all(&pool)
+    .await?;
+
+    let snippets = rows
+        .into_iter()
+        .map(|row| Snippet {
+            id: row.get::<String, _>("id"),
+            created_at: row.get::<i64, _>("created_at"),
+            created_by_user_id: row.get::<String, _>("created_by_user_id"),
+            trigger: row.get::<String, _>("trigger"),
+            expansion: row.get::<String, _>("expansion"),
+        })
+        .collect();
+
+    Ok(snippets)
+}
+
+pub async fn update_snippet(pool: SqlitePool, snippet: &Snippet) -> Result<Snippet, sqlx::Error> {
+    sqlx::query(
+        "UPDATE snippets
+         SET trigger = ?3,
+             expansion = ?4
+         WHERE id = ?1 AND created_by_user_id = ?2",
+    )
+    .bind(&snippet.id)
+    .bind(&snippet.created_by_user_id)
+    .bind(&snippet.trigger)
+    .bind(&snippet.expansion)
+    .execute(&pool)
+    .await?;
+
+    Ok(snippet.clone())
+}
+
+pub async fn delete_snippet(pool: SqlitePool, id: &str, user_id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM snippets WHERE id = ?1 AND created_by_user_id = ?2")
+        .bind(id)
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
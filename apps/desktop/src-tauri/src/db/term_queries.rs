@@ -4,8 +4,8 @@ use crate::domain::Term;
 
 pub async fn insert_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Error> {
     sqlx::query(
-        "INSERT INTO terms (id, created_at, created_by_user_id, source_value, destination_value, is_replacement, is_deleted)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        "INSERT INTO terms (id, created_at, created_by_user_id, source_value, destination_value, is_replacement, is_deleted, match_mode)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
     )
     .bind(&term.id)
     .bind(term.created_at)
@@ -14,6 +14,7 @@ pub async fn insert_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Er
     .bind(&term.destination_value)
     .bind(term.is_replacement as i64)
     .bind(term.is_deleted as i64)
+    .bind(term.match_mode.as_str())
     .execute(&pool)
     .await?;
 
@@ -22,7 +23,7 @@ pub async fn insert_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Er
 
 pub async fn fetch_terms(pool: SqlitePool, user_id: &str) -> Result<Vec<Term>, sqlx::Error> {
     let rows = sqlx::query(
-        "SELECT id, created_at, created_by_user_id, source_value, destination_value, is_replacement, is_deleted
+        "SELECT id, created_at, created_by_user_id, source_value, destination_value, is_replacement, is_deleted, match_mode
          FROM terms
          WHERE is_deleted = 0 AND created_by_user_id = ?1
          ORDER BY created_at DESC",
@@ -41,6 +42,10 @@ pub async fn fetch_terms(pool: SqlitePool, user_id: &str) -> Result<Vec<Term>, s
             destination_value: row.get::<String, _>("destination_value"),
             is_replacement: row.get::<i64, _>("is_replacement") != 0,
             is_deleted: row.get::<i64, _>("is_deleted") != 0,
+            match_mode: row
+                .get::<String, _>("match_mode")
+                .parse()
+                .unwrap_or_default(),
         })
         .collect();
 
@@ -53,7 +58,8 @@ pub async fn update_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Er
          SET source_value = ?2,
              destination_value = ?3,
              is_replacement = ?4,
-             is_deleted = ?5
+             is_deleted = ?5,
+             match_mode = ?6
          WHERE id = ?1",
     )
     .bind(&term.id)
@@ -61,6 +67,7 @@ pub async fn update_term(pool: SqlitePool, term: &Term) -> Result<Term, sqlx::Er
     .bind(&term.destination_value)
     .bind(term.is_replacement as i64)
     .bind(term.is_deleted as i64)
+    .bind(term.match_mode.as_str())
     .execute(&pool)
     .await?;
 
@@ -0,0 +1,108 @@
+use sqlx::{Row, SqlitePool};
+
+use crate::domain::TermSuggestion;
+use crate::text::WordSubstitution;
+
+/// Suggestions are keyed case-insensitively on the misheard text.
+pub fn suggestion_source_key(source_value: &str) -> String {
+    source_value.trim().to_lowercase()
+}
+
+/// Replaces the corrections recorded for a transcription with `substitutions`.
+pub async fn replace_transcription_corrections(
+    pool: SqlitePool,
+    transcription_id: &str,
+    substitutions: &[WordSubstitution],
+    created_at: i64,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM term_corrections WHERE transcription_id = ?1")
+        .bind(transcription_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for substitution in substitutions {
+        sqlx::query(
+            "INSERT INTO term_corrections (transcription_id, source_key, source_value, destination_value, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )
+        .bind(transcription_id)
+        .bind(suggestion_source_key(&substitution.source))
+        .bind(&substitution.source)
+        .bind(&substitution.destination)
+        .bind(created_at)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
+}
+
+/// Corrections made in at least `min_occurrences` of the user's transcriptions
+/// that have not been accepted or rejected yet, most frequent first.
+pub async fn fetch_term_suggestions(
+    pool: SqlitePool,
+    user_id: &str,
+    min_occurrences: i64,
+) -> Result<Vec<TermSuggestion>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT MAX(c.source_value) AS source_value,
+                c.destination_value,
+                COUNT(*) AS occurrences,
+                MAX(c.created_at) AS last_seen_at
+         FROM term_corrections c
+         JOIN transcriptions t ON t.id = c.transcription_id
+         WHERE t.user_id = ?1
+           AND NOT EXISTS (
+             SELECT 1 FROM term_suggestion_decisions d
+             WHERE d.user_id = ?1
+               AND d.source_key = c.source_key
+               AND d.destination_value = c.destination_value
+           )
+         GROUP BY c.source_key, c.destination_value
+         HAVING COUNT(*) >= ?2
+         ORDER BY occurrences DESC, last_seen_at DESC",
+    )
+    .bind(user_id)
+    .bind(min_occurrences)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(TermSuggestion {
+                source_value: row.try_get::<String, _>("source_value")?,
+                destination_value: row.try_get::<String, _>("destination_value")?,
+                occurrences: row.try_get::<i64, _>("occurrences")?,
+                last_seen_at: row.try_get::<i64, _>("last_seen_at")?,
+            })
+        })
+        .collect()
+}
+
+pub async fn record_term_suggestion_decision(
+    pool: SqlitePool,
+    user_id: &str,
+    source_value: &str,
+    destination_value: &str,
+    status: &str,
+    decided_at: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO term_suggestion_decisions (user_id, source_key, destination_value, status, decided_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(user_id, source_key, destination_value) DO UPDATE SET
+           status = excluded.status,
+           decided_at = excluded.decided_at",
+    )
+    .bind(user_id)
+    .bind(suggestion_source_key(source_value))
+    .bind(destination_value)
+    .bind(status)
+    .bind(decided_at)
+    .execute(&pool)
+    .await?;
+
+    Ok(())
+}
@@ -1,6 +1,23 @@
-use sqlx::{Row, SqlitePool};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
-use crate::domain::Tone;
+use crate::domain::{Tone, TranslationMode};
+
+fn row_to_tone(row: SqliteRow) -> Tone {
+    Tone {
+        id: row.get::<String, _>("id"),
+        name: row.get::<String, _>("name"),
+        prompt_template: row.get::<String, _>("prompt_template"),
+        created_at: row.get::<i64, _>("created_at"),
+        sort_order: row.get::<i32, _>("sort_order"),
+        translation_mode: row
+            .try_get::<Option<String>, _>("translation_mode")
+            .unwrap_or(None)
+            .and_then(|value| value.parse::<TranslationMode>().ok()),
+        inverse_text_normalization: row
+            .try_get::<Option<bool>, _>("inverse_text_normalization")
+            .unwrap_or(None),
+    }
+}
 
 pub async fn insert_tone(pool: SqlitePool, tone: &Tone, user_id: &str) -> Result<Tone, sqlx::Error> {
     sqlx::query(
@@ -10,9 +27,11 @@ pub async fn insert_tone(pool: SqlitePool, tone: &Tone, user_id: &str) -> Result
              prompt_template,
              created_at,
              sort_order,
-             user_id
+             user_id,
+             translation_mode,
+             inverse_text_normalization
          )
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
     )
     .bind(&tone.id)
     .bind(&tone.name)
@@ -20,6 +39,8 @@ pub async fn insert_tone(pool: SqlitePool, tone: &Tone, user_id: &str) -> Result
     .bind(tone.created_at)
     .bind(tone.sort_order)
     .bind(user_id)
+    .bind(tone.translation_mode.map(TranslationMode::as_str))
+    .bind(tone.inverse_text_normalization)
     .execute(&pool)
     .await?;
 
@@ -31,13 +52,17 @@ pub async fn update_tone(pool: SqlitePool, tone: &Tone) -> Result<Tone, sqlx::Er
         "UPDATE tones SET
             name = ?2,
             prompt_template = ?3,
-            sort_order = ?4
+            sort_order = ?4,
+            translation_mode = ?5,
+            inverse_text_normalization = ?6
          WHERE id = ?1",
     )
     .bind(&tone.id)
     .bind(&tone.name)
     .bind(&tone.prompt_template)
     .bind(tone.sort_order)
+    .bind(tone.translation_mode.map(TranslationMode::as_str))
+    .bind(tone.inverse_text_normalization)
     .execute(&pool)
     .await?;
 
@@ -55,26 +80,23 @@ pub async fn delete_tone(pool: SqlitePool, id: &str) -> Result<(), sqlx::Error>
 
 pub async fn fetch_tone_by_id(pool: SqlitePool, id: &str) -> Result<Option<Tone>, sqlx::Error> {
     let row = sqlx::query(
-        "SELECT id, name, prompt_template, created_at, sort_order FROM tones WHERE id = ?1 LIMIT 1",
+        "SELECT id, name, prompt_template, created_at, sort_order, translation_mode,
+                inverse_text_normalization
+         FROM tones
+         WHERE id = ?1
+         LIMIT 1",
     )
     .bind(id)
     .fetch_optional(&pool)
     .await?;
 
-    let tone = row.map(|row| Tone {
-        id: row.get::<String, _>("id"),
-        name: row.get::<String, _>("name"),
-        prompt_template: row.get::<String, _>("prompt_template"),
-        created_at: row.get::<i64, _>("created_at"),
-        sort_order: row.get::<i32, _>("sort_order"),
-    });
-
-    Ok(tone)
+    Ok(row.map(row_to_tone))
 }
 
 pub async fn fetch_all_tones(pool: SqlitePool, user_id: &str) -> Result<Vec<Tone>, sqlx::Error> {
     let rows = sqlx::query(
-        "SELECT id, name, prompt_template, created_at, sort_order
+        "SELECT id, name, prompt_template, created_at, sort_order, translation_mode,
+                inverse_text_normalization
          FROM tones
          WHERE user_id = ?1
          ORDER BY sort_order ASC, created_at ASC",
@@ -83,18 +105,7 @@ pub async fn fetch_all_tones(pool: SqlitePool, user_id: &str) -> Result<Vec<Tone
     .fetch_all(&pool)
     .await?;
 
-    let tones = rows
-        .into_iter()
-        .map(|row| Tone {
-            id: row.get::<String, _>("id"),
-            name: row.get::<String, _>("name"),
-            prompt_template: row.get::<String, _>("prompt_template"),
-            created_at: row.get::<i64, _>("created_at"),
-            sort_order: row.get::<i32, _>("sort_order"),
-        })
-        .collect();
-
-    Ok(tones)
+    Ok(rows.into_iter().map(row_to_tone).collect())
 }
 
 pub async fn count_tones(pool: SqlitePool, user_id: &str) -> Result<i64, sqlx::Error> {
@@ -1,6 +1,9 @@
 use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 
-use crate::domain::{Transcription, TranscriptionAudioSnapshot};
+use crate::domain::{
+    AppliedReplacement, LanguageProbability, RecentDictation, Transcription,
+    TranscriptionAudioSnapshot,
+};
 
 fn serialize_warnings(warnings: &Option<Vec<String>>) -> Option<String> {
     warnings
@@ -8,10 +11,29 @@ fn serialize_warnings(warnings: &Option<Vec<String>>) -> Option<String> {
         .and_then(|list| serde_json::to_string(list).ok())
 }
 
+fn serialize_language_probabilities(
+    probabilities: &Option<Vec<LanguageProbability>>,
+) -> Option<String> {
+    probabilities
+        .as_ref()
+        .and_then(|list| serde_json::to_string(list).ok())
+}
+
+fn serialize_applied_replacements(
+    replacements: &Option<Vec<AppliedReplacement>>,
+) -> Option<String> {
+    replacements
+        .as_ref()
+        .and_then(|list| serde_json::to_string(list).ok())
+}
+
 fn row_to_transcription(row: SqliteRow) -> Result<Transcription, sqlx::Error> {
     let audio_path: Option<String> = row.try_get("audio_path")?;
     let audio_duration: Option<i64> = row.try_get("audio_duration_ms")?;
     let warnings_json: Option<String> = row.try_get("warnings_json")?;
+    let language_probabilities_json: Option<String> =
+        row.try_get("language_probabilities_json")?;
+    let applied_replacements_json: Option<String> = row.try_get("applied_replacements_json")?;
 
     let audio = audio_path.map(|file_path| TranscriptionAudioSnapshot {
         file_path,
@@ -24,6 +46,10 @@ fn row_to_transcription(row: SqliteRow) -> Result<Transcription, sqlx::Error> {
         },
         None => None,
     };
+    let language_probabilities = language_probabilities_json
+        .and_then(|json| serde_json::from_str::<Vec<LanguageProbability>>(&json).ok());
+    let applied_replacements = applied_replacements_json
+        .and_then(|json| serde_json::from_str::<Vec<AppliedReplacement>>(&json).ok());
 
     Ok(Transcription {
         id: row.get::<String, _>("id"),
@@ -44,6 +70,12 @@ fn row_to_transcription(row: SqliteRow) -> Result<Transcription, sqlx::Error> {
         transcription_duration_ms: row.try_get::<Option<i64>, _>("transcription_duration_ms")?,
         postprocess_duration_ms: row.try_get::<Option<i64>, _>("postprocess_duration_ms")?,
         warnings,
+        detected_language: row.try_get::<Option<String>, _>("detected_language")?,
+        language_probabilities,
+        translated_transcript: row.try_get::<Option<String>, _>("translated_transcript")?,
+        translation_mode: row.try_get::<Option<String>, _>("translation_mode")?,
+        applied_replacements,
+        app_target_id: row.try_get::<Option<String>, _>("app_target_id")?,
     })
 }
 
@@ -73,9 +105,15 @@ pub async fn insert_transcription(
              transcription_duration_ms,
              postprocess_duration_ms,
              warnings_json,
-             user_id
+             user_id,
+             detected_language,
+             language_probabilities_json,
+             translated_transcript,
+             translation_mode,
+             applied_replacements_json,
+             app_target_id
          )
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26)",
     )
     .bind(&transcription.id)
     .bind(&transcription.transcript)
@@ -102,6 +140,16 @@ pub async fn insert_transcription(
     .bind(transcription.postprocess_duration_ms)
     .bind(serialize_warnings(&transcription.warnings))
     .bind(user_id)
+    .bind(transcription.detected_language.as_deref())
+    .bind(serialize_language_probabilities(
+        &transcription.language_probabilities,
+    ))
+    .bind(transcription.translated_transcript.as_deref())
+    .bind(transcription.translation_mode.as_deref())
+    .bind(serialize_applied_replacements(
+        &transcription.applied_replacements,
+    ))
+    .bind(transcription.app_target_id.as_deref())
     .execute(&pool)
     .await?;
 
@@ -133,7 +181,13 @@ pub async fn fetch_transcriptions(
                 post_process_device,
                 transcription_duration_ms,
                 postprocess_duration_ms,
-                warnings_json
+                warnings_json,
+                detected_language,
+                language_probabilities_json,
+                translated_transcript,
+                translation_mode,
+                applied_replacements_json,
+                app_target_id
          FROM transcriptions
          WHERE user_id = ?3
          ORDER BY timestamp DESC
@@ -154,6 +208,34 @@ pub async fn fetch_transcriptions(
     Ok(transcriptions)
 }
 
+/// Newest first; the input for ranking vocabulary terms.
+pub async fn fetch_recent_dictations(
+    pool: SqlitePool,
+    user_id: &str,
+    limit: u32,
+) -> Result<Vec<RecentDictation>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT transcript, app_target_id
+         FROM transcriptions
+         WHERE user_id = ?1
+         ORDER BY timestamp DESC
+         LIMIT ?2",
+    )
+    .bind(user_id)
+    .bind(limit as i64)
+    .fetch_all(&pool)
+    .await?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(RecentDictation {
+                transcript: row.try_get::<String, _>("transcript")?,
+                app_target_id: row.try_get::<Option<String>, _>("app_target_id")?,
+            })
+        })
+        .collect()
+}
+
 pub async fn update_transcription(
     pool: SqlitePool,
     transcription: &Transcription,
@@ -177,7 +259,13 @@ pub async fn update_transcription(
              post_process_device = ?16,
              transcription_duration_ms = ?17,
              postprocess_duration_ms = ?18,
-             warnings_json = ?19
+             warnings_json = ?19,
+             detected_language = ?20,
+             language_probabilities_json = ?21,
+             translated_transcript = ?22,
+             translation_mode = ?23,
+             applied_replacements_json = ?24,
+             app_target_id = ?25
          WHERE id = ?1",
     )
     .bind(&transcription.id)
@@ -204,6 +292,16 @@ pub async fn update_transcription(
     .bind(transcription.transcription_duration_ms)
     .bind(transcription.postprocess_duration_ms)
     .bind(serialize_warnings(&transcription.warnings))
+    .bind(transcription.detected_language.as_deref())
+    .bind(serialize_language_probabilities(
+        &transcription.language_probabilities,
+    ))
+    .bind(transcription.translated_transcript.as_deref())
+    .bind(transcription.translation_mode.as_deref())
+    .bind(serialize_applied_replacements(
+        &transcription.applied_replacements,
+    ))
+    .bind(transcription.app_target_id.as_deref())
     .execute(&pool)
     .await?;
 
@@ -226,7 +324,13 @@ pub async fn update_transcription(
                 post_process_device,
                 transcription_duration_ms,
                 postprocess_duration_ms,
-                warnings_json
+                warnings_json,
+                detected_language,
+                language_probabilities_json,
+                translated_transcript,
+                translation_mode,
+                applied_replacements_json,
+                app_target_id
          FROM transcriptions
          WHERE id = ?1",
     )
@@ -238,6 +342,46 @@ pub async fn update_transcription(
     row_to_transcription(row)
 }
 
+pub async fn fetch_transcription_by_id(
+    pool: SqlitePool,
+    id: &str,
+) -> Result<Option<Transcription>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT id,
+                transcript,
+                timestamp,
+                audio_path,
+                audio_duration_ms,
+                model_size,
+                inference_device,
+                raw_transcript,
+                sanitized_transcript,
+                transcription_prompt,
+                post_process_prompt,
+                transcription_api_key_id,
+                post_process_api_key_id,
+                transcription_mode,
+                post_process_mode,
+                post_process_device,
+                transcription_duration_ms,
+                postprocess_duration_ms,
+                warnings_json,
+                detected_language,
+                language_probabilities_json,
+                translated_transcript,
+                translation_mode,
+                applied_replacements_json,
+                app_target_id
+         FROM transcriptions
+         WHERE id = ?1",
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
+
+    row.map(row_to_transcription).transpose()
+}
+
 pub async fn delete_transcription(pool: SqlitePool, id: &str) -> Result<(), sqlx::Error> {
     sqlx::query(
         "DELETE FROM transcriptions
@@ -0,0 +1,85 @@
+use sqlx::{Row, SqlitePool};
+
+use crate::domain::VoiceCommand;
+
+pub async fn fetch_voice_commands(
+    pool: SqlitePool,
+    user_id: &str,
+) -> Result<Vec<VoiceCommand>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT id, language, phrase, action, value, is_enabled
+         FROM voice_commands
+         WHERE user_id = ?1
+         ORDER BY language, created_at",
+    )
+    .bind(user_id)
+    .fetch_all(&pool)
+    .await?;
+
+    let mut commands = Vec::with_capacity(rows.len());
+    for row in rows {
+        let action: String = row.try_get("action")?;
+        let Ok(action) = action.parse() else {
+            eprintln!("[voice-commands] Skipping command with unknown action '{action}'");
+            continue;
+        };
+        commands.push(VoiceCommand {
+            id: row.try_get("id")?,
+            language: row.try_get("language")?,
+            phrase: row.try_get("phrase")?,
+            action,
+            value: row.try_get("value")?,
+            is_enabled: row.try_get::<i64, _>("is_enabled")? != 0,
+            is_default: false,
+        });
+    }
+
+    Ok(commands)
+}
+
+pub async fn upsert_voice_command(
+    pool: SqlitePool,
+    command: &VoiceCommand,
+    user_id: &str,
+    created_at: i64,
+) -> Result<VoiceCommand, sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO voice_commands (id, user_id, language, phrase, action, value, is_enabled, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+         ON CONFLICT(user_id, id) DO UPDATE SET
+           language = excluded.language,
+           phrase = excluded.phrase,
+           action = excluded.action,
+           value = excluded.value,
+           is_enabled = excluded.is_enabled",
+    )
+    .bind(&command.id)
+    .bind(user_id)
+    .bind(&command.language)
+    .bind(&command.phrase)
+    .bind(command.action.as_str())
+    .bind(&command.value)
+    .bind(command.is_enabled as i64)
+    .bind(created_at)
+    .execute(&pool)
+    .await?;
+
+    Ok(VoiceCommand {
+        is_default: false,
+        ..command.clone()
+    })
+}
+
+pub async fn delete_voice_command(
+    pool: SqlitePool,
+    id: &str,
+    user_id: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM voice_commands WHERE id = ?1 AND user_id = ?2")
+        .bind(id)
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
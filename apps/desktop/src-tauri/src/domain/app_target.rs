@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::InjectionStrategy;
+
 pub const EVT_REGISTER_CURRENT_APP: &str = "voquill:register-current-app";
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -12,4 +14,18 @@ pub struct AppTarget {
     pub icon_path: Option<String>,
     #[serde(default)]
     pub paste_keybind: Option<String>,
+    #[serde(default)]
+    pub inverse_text_normalization: Option<bool>,
+    /// Formats dictation as code: identifier casing and spoken symbols.
+    #[serde(default)]
+    pub code_mode_enabled: bool,
+    /// How dictated text is inserted into the app.
+    #[serde(default)]
+    pub injection_strategy: InjectionStrategy,
+    /// Pause between typed characters, or chunks, for the typing strategies.
+    #[serde(default)]
+    pub typing_delay_ms: Option<u32>,
+    /// Pastes Markdown from tones that write it as formatted HTML.
+    #[serde(default)]
+    pub rich_text_paste: bool,
 }
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Wait before restoring the clipboard in apps whose read time is unknown.
+pub const DEFAULT_CLIPBOARD_RESTORE_DELAY: Duration = Duration::from_millis(800);
+const MIN_CLIPBOARD_RESTORE_DELAY: Duration = Duration::from_millis(300);
+/// Longest wait before the clipboard is restored, whether or not the paste
+/// was seen to arrive.
+pub const MAX_CLIPBOARD_RESTORE_DELAY: Duration = Duration::from_secs(3);
+/// Added on top of the slowest read seen, as apps vary from paste to paste.
+const READ_LATENCY_MARGIN: Duration = Duration::from_millis(200);
+
+/// How long each app takes to read the clipboard after the paste shortcut,
+/// learned from pastes that could be seen to arrive.
+#[derive(Clone, Debug, Default)]
+pub struct ClipboardRestoreTimings {
+    delays: HashMap<String, Duration>,
+}
+
+impl ClipboardRestoreTimings {
+    pub fn restore_delay(&self, app_name: Option<&str>) -> Duration {
+        app_name
+            .and_then(|app_name| self.delays.get(app_name))
+            .copied()
+            .unwrap_or(DEFAULT_CLIPBOARD_RESTORE_DELAY)
+    }
+
+    /// Records that `app_name` read the clipboard `latency` after the paste.
+    /// Slower reads raise the delay at once; faster ones lower it gradually
+    /// so one quick paste does not undo what a slow one taught.
+    pub fn record_read(&mut self, app_name: &str, latency: Duration) {
+        let observed = (latency * 2 + READ_LATENCY_MARGIN)
+            .clamp(MIN_CLIPBOARD_RESTORE_DELAY, MAX_CLIPBOARD_RESTORE_DELAY);
+        let current = self.restore_delay(Some(app_name));
+        let next = if observed >= current {
+            observed
+        } else {
+            (current * 3 + observed) / 4
+        };
+        self.delays.insert(app_name.to_string(), next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapts_restore_delay_per_app() {
+        let mut timings = ClipboardRestoreTimings::default();
+        assert_eq!(
+            timings.restore_delay(Some("Slack")),
+            DEFAULT_CLIPBOARD_RESTORE_DELAY
+        );
+
+        timings.record_read("Slack", Duration::from_millis(900));
+        assert_eq!(
+            timings.restore_delay(Some("Slack")),
+            Duration::from_millis(2000)
+        );
+
+        timings.record_read("Slack", Duration::from_millis(50));
+        assert_eq!(
+            timings.restore_delay(Some("Slack")),
+            Duration::from_millis(1575)
+        );
+
+        timings.record_read("Notes", Duration::from_secs(10));
+        assert_eq!(
+            timings.restore_delay(Some("Notes")),
+            MAX_CLIPBOARD_RESTORE_DELAY
+        );
+        assert_eq!(timings.restore_delay(None), DEFAULT_CLIPBOARD_RESTORE_DELAY);
+    }
+}
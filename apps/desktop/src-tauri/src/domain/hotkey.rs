@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+pub const HOTKEY_ACTION_DICTATE_TRANSLATE: &str = "dictate-translate";
+pub const HOTKEY_ACTION_DICTATE_BILINGUAL: &str = "dictate-bilingual";
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Hotkey {
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How dictated text is put into an app.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InjectionStrategy {
+    /// Clipboard plus the app's paste shortcut, typing the text if that fails.
+    #[default]
+    Clipboard,
+    /// One keystroke per character with a pause between them.
+    Typing,
+    /// Bursts of typed text with a pause between them, for remote desktops
+    /// and apps that drop fast input.
+    ChunkedTyping,
+    /// X11 primary selection pasted with a middle click (Linux only).
+    PrimarySelection,
+    /// Only copies the text; the user pastes it.
+    ClipboardOnly,
+}
+
+impl InjectionStrategy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Clipboard => "clipboard",
+            Self::Typing => "typing",
+            Self::ChunkedTyping => "chunked_typing",
+            Self::PrimarySelection => "primary_selection",
+            Self::ClipboardOnly => "clipboard_only",
+        }
+    }
+
+    /// Pause between keystrokes, or between chunks, when the app target does
+    /// not set one.
+    pub fn default_typing_delay(self) -> Duration {
+        match self {
+            Self::ChunkedTyping => Duration::from_millis(60),
+            _ => Duration::from_millis(5),
+        }
+    }
+}
+
+impl FromStr for InjectionStrategy {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "clipboard" => Ok(Self::Clipboard),
+            "typing" => Ok(Self::Typing),
+            "chunked_typing" => Ok(Self::ChunkedTyping),
+            "primary_selection" => Ok(Self::PrimarySelection),
+            "clipboard_only" => Ok(Self::ClipboardOnly),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Characters typed in one burst by [`InjectionStrategy::ChunkedTyping`].
+pub const TYPING_CHUNK_CHARS: usize = 24;
+
+#[derive(Clone, Debug, Default)]
+pub struct InjectionOptions {
+    pub strategy: InjectionStrategy,
+    /// Paste shortcut for [`InjectionStrategy::Clipboard`], e.g. "ctrl+shift+v".
+    pub keybind: Option<String>,
+    pub typing_delay: Option<Duration>,
+    /// Formatted version of the text, pasted by the clipboard strategies.
+    pub html: Option<String>,
+}
+
+impl InjectionOptions {
+    pub fn typing_delay(&self) -> Duration {
+        self.typing_delay
+            .unwrap_or_else(|| self.strategy.default_typing_delay())
+    }
+}
+
+/// Whether injected text was seen to arrive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InjectionOutcome {
+    /// The focused field now contains the text.
+    Verified,
+    /// The app does not expose its field contents, so arrival is unknown.
+    Unverified,
+    /// Focus moved away or the field did not change.
+    Failed,
+    /// The text was only copied, as the strategy asks.
+    Copied,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionReport {
+    pub strategy: InjectionStrategy,
+    pub outcome: InjectionOutcome,
+    /// App that had focus after the injection.
+    pub app_name: Option<String>,
+    /// Why the injection is considered failed.
+    pub message: Option<String>,
+}
+
+/// The focused app and field contents, read before and after injecting.
+#[derive(Clone, Debug, Default)]
+pub struct FocusSnapshot {
+    pub app_name: Option<String>,
+    pub field_text: Option<String>,
+}
+
+fn occurrences(haystack: &str, needle: &str) -> usize {
+    let haystack = haystack.split_whitespace().collect::<Vec<_>>().join(" ");
+    let needle = needle.split_whitespace().collect::<Vec<_>>().join(" ");
+    if needle.is_empty() {
+        return 0;
+    }
+    haystack.matches(needle.as_str()).count()
+}
+
+/// Decides whether `text` arrived by comparing the focus before and after.
+/// Apps may reflow whitespace, so it is ignored when looking for the text.
+pub fn assess_injection(
+    text: &str,
+    before: &FocusSnapshot,
+    after: &FocusSnapshot,
+) -> (InjectionOutcome, Option<String>) {
+    if let (Some(expected), Some(current)) = (&before.app_name, &after.app_name) {
+        if expected != current {
+            return (
+                InjectionOutcome::Failed,
+                Some(format!("Focus moved from {expected} to {current}")),
+            );
+        }
+    }
+
+    match (&before.field_text, &after.field_text) {
+        (before_text, Some(after_text))
+            if occurrences(after_text, text)
+                > before_text
+                    .as_deref()
+                    .map_or(0, |before_text| occurrences(before_text, text)) =>
+        {
+            (InjectionOutcome::Verified, None)
+        }
+        (Some(before_text), Some(after_text)) if before_text == after_text => (
+            InjectionOutcome::Failed,
+            Some("The text field did not change".to_string()),
+        ),
+        _ => (InjectionOutcome::Unverified, None),
+    }
+}
+
+/// Splits `text` into bursts of about `size` characters, breaking after
+/// whitespace where possible so words are not split across pauses.
+pub fn typing_chunks(text: &str, size: usize) -> Vec<&str> {
+    let size = size.max(1);
+    let mut chunks = Vec::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let boundaries: Vec<usize> = rest
+            .char_indices()
+            .map(|(index, _)| index)
+            .skip(1)
+            .chain(std::iter::once(rest.len()))
+            .take(size)
+            .collect();
+        let hard_end = *boundaries.last().unwrap_or(&rest.len());
+        let end = if hard_end == rest.len() {
+            hard_end
+        } else {
+            boundaries
+                .iter()
+                .rev()
+                .copied()
+                .find(|&index| rest[..index].ends_with(char::is_whitespace))
+                .unwrap_or(hard_end)
+        };
+        chunks.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(app_name: &str, field_text: Option<&str>) -> FocusSnapshot {
+        FocusSnapshot {
+            app_name: Some(app_name.to_string()),
+            field_text: field_text.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn assesses_injection_from_focus_snapshots() {
+        let text = "See you at 5.";
+        let verdict =
+            |before: FocusSnapshot, after: FocusSnapshot| assess_injection(text, &before, &after).0;
+
+        assert_eq!(
+            verdict(
+                snapshot("Notes", Some("Hi. ")),
+                snapshot("Notes", Some("Hi. See you\nat 5."))
+            ),
+            InjectionOutcome::Verified
+        );
+        assert_eq!(
+            verdict(
+                snapshot("Notes", Some("Hi. ")),
+                snapshot("Notes", Some("Hi. "))
+            ),
+            InjectionOutcome::Failed
+        );
+        assert_eq!(
+            verdict(snapshot("Notes", None), snapshot("Slack", None)),
+            InjectionOutcome::Failed
+        );
+        assert_eq!(
+            verdict(snapshot("Terminal", None), snapshot("Terminal", None)),
+            InjectionOutcome::Unverified
+        );
+        assert_eq!(
+            verdict(
+                snapshot("Notes", Some("See you at 5.")),
+                snapshot("Notes", Some("See you at 5. Bye"))
+            ),
+            InjectionOutcome::Unverified
+        );
+    }
+
+    #[test]
+    fn splits_typing_chunks_at_word_boundaries() {
+        assert_eq!(
+            typing_chunks("the quick brown fox", 8),
+            vec!["the ", "quick ", "brown ", "fox"]
+        );
+        assert_eq!(typing_chunks("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(
+            typing_chunks("안녕하세요 반가워요", 4),
+            vec!["안녕하세", "요 ", "반가워요"]
+        );
+        assert!(typing_chunks("", 4).is_empty());
+    }
+}
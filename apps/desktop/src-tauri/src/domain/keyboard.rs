@@ -13,3 +13,56 @@ pub struct KeyboardListenerErrorPayload {
     pub message: String,
     pub consecutive_failures: u32,
 }
+
+/// Where the keyboard listener reads key presses from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeySource {
+    /// rdev hooks: X11 on Linux, the native APIs elsewhere.
+    Rdev,
+    /// Raw `/dev/input` devices, readable by members of the `input` group.
+    Evdev,
+    /// The xdg-desktop-portal GlobalShortcuts API.
+    Portal,
+}
+
+impl KeySource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Rdev => "rdev",
+            Self::Evdev => "evdev",
+            Self::Portal => "portal",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "rdev" | "x11" => Some(Self::Rdev),
+            "evdev" => Some(Self::Evdev),
+            "portal" => Some(Self::Portal),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_key_sources() {
+        assert_eq!(KeySource::parse("rdev"), Some(KeySource::Rdev));
+        assert_eq!(KeySource::parse("X11"), Some(KeySource::Rdev));
+        assert_eq!(KeySource::parse(" evdev "), Some(KeySource::Evdev));
+        assert_eq!(KeySource::parse("Portal"), Some(KeySource::Portal));
+        assert_eq!(KeySource::parse("uinput"), None);
+        assert_eq!(KeySource::parse(""), None);
+    }
+
+    #[test]
+    fn key_source_names_round_trip() {
+        for source in [KeySource::Rdev, KeySource::Evdev, KeySource::Portal] {
+            assert_eq!(KeySource::parse(source.as_str()), Some(source));
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/// Primary language subtag of a locale tag: `ko-KR`, `en_US` and `KO` become
+/// `ko`, `en` and `ko`.
+pub fn language_subtag(language: &str) -> String {
+    language
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
@@ -1,34 +1,73 @@
 pub mod api_key;
 pub mod app_target;
+pub mod clipboard;
 pub mod hotkey;
+pub mod injection;
 pub mod keyboard;
+pub mod language;
 pub mod monitor;
 pub mod overlay;
 pub mod permissions;
+pub mod post_processing;
 pub mod preferences;
 pub mod recording;
+pub mod redaction;
+pub mod snippet;
 pub mod term;
 pub mod tone;
+pub mod tone_template;
 pub mod transcription;
+pub mod transcription_fallback;
+pub mod translation;
 pub mod user;
+pub mod voice_command;
 
 pub use api_key::{ApiKey, ApiKeyCreateRequest, ApiKeyUpdateRequest, ApiKeyView};
 pub use app_target::{AppTarget, EVT_REGISTER_CURRENT_APP};
+pub use clipboard::{
+    ClipboardRestoreTimings, DEFAULT_CLIPBOARD_RESTORE_DELAY, MAX_CLIPBOARD_RESTORE_DELAY,
+};
 pub use hotkey::Hotkey;
+pub use injection::{
+    assess_injection, typing_chunks, FocusSnapshot, InjectionOptions, InjectionOutcome,
+    InjectionReport, InjectionStrategy, TYPING_CHUNK_CHARS,
+};
 pub use keyboard::{
-    KeyboardListenerErrorPayload, KeysHeldPayload, EVT_KEYBOARD_LISTENER_ERROR, EVT_KEYS_HELD,
+    KeySource, KeyboardListenerErrorPayload, KeysHeldPayload, EVT_KEYBOARD_LISTENER_ERROR,
+    EVT_KEYS_HELD,
 };
+pub use language::language_subtag;
 pub use monitor::{MonitorAtCursor, OverlayAnchor, ScreenVisibleArea};
 pub use overlay::{
     OverlayPhase, OverlayPhasePayload, PillExpandedPayload, EVT_OVERLAY_PHASE, EVT_PILL_EXPANDED,
 };
 pub use permissions::{PermissionKind, PermissionState, PermissionStatus};
-pub use preferences::UserPreferences;
+pub use post_processing::{
+    OllamaPullProgressPayload, PostProcessDeltaPayload, EVT_OLLAMA_PULL_PROGRESS,
+    EVT_POST_PROCESS_DELTA,
+};
+pub use preferences::{UserPreferences, DEFAULT_SPACING_CORRECTION_LANGUAGES};
 pub use recording::{
     AudioChunkPayload, RecordedAudio, RecordingLevelPayload, RecordingMetrics, RecordingResult,
     EVT_AUDIO_CHUNK, EVT_REC_LEVEL,
 };
-pub use term::Term;
+pub use redaction::{redaction_warning, PiiKind, Redaction, RedactionStage, DEFAULT_PII_KINDS};
+pub use snippet::{Snippet, SnippetContext, SnippetExpansion, SNIPPET_VARIABLES};
+pub use term::{AppliedReplacement, Term, TermMatchMode, TermSuggestion};
 pub use tone::Tone;
-pub use transcription::{Transcription, TranscriptionAudioSnapshot};
+pub use tone_template::{
+    render_tone_template, tone_template_variables, validate_tone_template, ToneTemplateContext,
+    ToneTemplateError,
+};
+pub use transcription::{
+    LanguageProbability, RecentDictation, Transcription, TranscriptionAudioSnapshot,
+};
+pub use transcription_fallback::{
+    parse_fallback_chain, TranscriptionAttempt, TranscriptionFallbackStep,
+};
+pub use translation::TranslationMode;
 pub use user::User;
+pub use voice_command::{
+    default_voice_commands, merge_voice_commands, ExecutedVoiceCommand, VoiceCommand,
+    VoiceCommandAction,
+};
@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+pub const EVT_POST_PROCESS_DELTA: &str = "post_process_delta";
+
+/// Streamed post-processing output for the overlay. The final event of a
+/// request has `done` set and an empty `delta`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessDeltaPayload {
+    pub request_id: String,
+    pub delta: String,
+    pub done: bool,
+}
+
+pub const EVT_OLLAMA_PULL_PROGRESS: &str = "ollama_pull_progress";
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OllamaPullProgressPayload {
+    pub model: String,
+    pub status: String,
+    pub digest: Option<String>,
+    pub total: Option<u64>,
+    pub completed: Option<u64>,
+}
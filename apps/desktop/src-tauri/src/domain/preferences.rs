@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::{PiiKind, TranscriptionFallbackStep};
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserPreferences {
@@ -13,6 +15,10 @@ pub struct UserPreferences {
     #[serde(default)]
     pub transcription_model_size: Option<String>,
     #[serde(default)]
+    pub local_transcription_engine: Option<String>,
+    #[serde(default)]
+    pub transcription_fallback_chain: Option<Vec<TranscriptionFallbackStep>>,
+    #[serde(default)]
     pub post_processing_mode: Option<String>,
     #[serde(default)]
     pub post_processing_api_key_id: Option<String>,
@@ -52,8 +58,34 @@ pub struct UserPreferences {
     pub incognito_mode_include_in_stats: bool,
     #[serde(default = "default_dictation_pill_visibility")]
     pub dictation_pill_visibility: String,
+    /// Local filler-word and stutter cleanup runs only when this is on.
+    #[serde(default)]
+    pub disfluency_cleanup_enabled: bool,
+    /// Custom filler words; `None` uses the built-in list.
+    #[serde(default)]
+    pub filler_words: Option<Vec<String>>,
+    /// Languages whose transcripts get local spacing and punctuation
+    /// correction; `None` uses [`DEFAULT_SPACING_CORRECTION_LANGUAGES`].
+    #[serde(default)]
+    pub spacing_correction_languages: Option<Vec<String>>,
+    #[serde(default)]
+    pub redact_before_cloud: bool,
+    /// Applied by the transcription commands as the record is written.
+    #[serde(default)]
+    pub redact_before_storage: bool,
+    /// Built-in detectors to run; `None` uses [`super::DEFAULT_PII_KINDS`].
+    #[serde(default)]
+    pub redaction_detectors: Option<Vec<PiiKind>>,
+    /// Extra regular expressions whose matches are always redacted.
+    #[serde(default)]
+    pub redaction_patterns: Option<Vec<String>>,
+    /// Spoken commands such as "new line" run only when this is on.
+    #[serde(default)]
+    pub voice_commands_enabled: bool,
 }
 
+pub const DEFAULT_SPACING_CORRECTION_LANGUAGES: &[&str] = &["ko"];
+
 fn default_dictation_pill_visibility() -> String {
     "persistent".to_string()
 }
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// A kind of personal data the redaction engine can detect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiKind {
+    Email,
+    PhoneNumber,
+    /// Payment card numbers that pass the Luhn check.
+    CardNumber,
+    /// Korean resident registration numbers (주민등록번호).
+    ResidentRegistrationNumber,
+    /// Matches of a user-supplied pattern.
+    Custom,
+}
+
+/// Built-in detectors, in the order they run. Earlier detectors win when
+/// matches overlap, so the stricter formats come first.
+pub const DEFAULT_PII_KINDS: &[PiiKind] = &[
+    PiiKind::ResidentRegistrationNumber,
+    PiiKind::CardNumber,
+    PiiKind::Email,
+    PiiKind::PhoneNumber,
+];
+
+impl PiiKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::PhoneNumber => "phone_number",
+            Self::CardNumber => "card_number",
+            Self::ResidentRegistrationNumber => "resident_registration_number",
+            Self::Custom => "custom",
+        }
+    }
+
+    /// Label used inside placeholders, such as `[PHONE_1]`.
+    pub fn placeholder_label(self) -> &'static str {
+        match self {
+            Self::Email => "EMAIL",
+            Self::PhoneNumber => "PHONE",
+            Self::CardNumber => "CARD",
+            Self::ResidentRegistrationNumber => "RRN",
+            Self::Custom => "REDACTED",
+        }
+    }
+
+    fn describe(self, count: usize) -> String {
+        let (singular, plural) = match self {
+            Self::Email => ("email address", "email addresses"),
+            Self::PhoneNumber => ("phone number", "phone numbers"),
+            Self::CardNumber => ("card number", "card numbers"),
+            Self::ResidentRegistrationNumber => (
+                "resident registration number",
+                "resident registration numbers",
+            ),
+            Self::Custom => ("custom match", "custom matches"),
+        };
+        format!("{count} {}", if count == 1 { singular } else { plural })
+    }
+}
+
+impl FromStr for PiiKind {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "email" => Ok(Self::Email),
+            "phone_number" => Ok(Self::PhoneNumber),
+            "card_number" => Ok(Self::CardNumber),
+            "resident_registration_number" => Ok(Self::ResidentRegistrationNumber),
+            "custom" => Ok(Self::Custom),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Where in the pipeline text was redacted, used to word the warning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionStage {
+    PostProcessing,
+    Storage,
+}
+
+impl RedactionStage {
+    fn describe(self) -> &'static str {
+        match self {
+            Self::PostProcessing => "before post-processing",
+            Self::Storage => "before saving",
+        }
+    }
+}
+
+/// A span of text replaced by a placeholder. `original` never leaves the
+/// device and is never stored; it lets the caller restore the value in
+/// post-processed output.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Redaction {
+    pub kind: PiiKind,
+    pub placeholder: String,
+    pub original: String,
+}
+
+/// Summarises redactions for the transcription's `warnings`, e.g.
+/// "Redacted 2 phone numbers and 1 email address before saving".
+pub fn redaction_warning(stage: RedactionStage, redactions: &[Redaction]) -> Option<String> {
+    let mut counts: Vec<(PiiKind, usize)> = Vec::new();
+    for redaction in redactions {
+        match counts.iter_mut().find(|(kind, _)| *kind == redaction.kind) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((redaction.kind, 1)),
+        }
+    }
+
+    let parts: Vec<String> = counts
+        .iter()
+        .map(|(kind, count)| kind.describe(*count))
+        .collect();
+    let (last, rest) = parts.split_last()?;
+    let summary = if rest.is_empty() {
+        last.clone()
+    } else {
+        format!("{} and {last}", rest.join(", "))
+    };
+    Some(format!("Redacted {summary} {}", stage.describe()))
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+/// Placeholders a snippet expansion may contain, written as `{{date}}`.
+pub const SNIPPET_VARIABLES: &[&str] = &["date", "time", "clipboard", "selected_text"];
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snippet {
+    pub id: String,
+    pub created_at: i64,
+    pub created_by_user_id: String,
+    /// Phrase that inserts the snippet, such as "signature".
+    pub trigger: String,
+    /// Text pasted in place of the dictation; may contain placeholders from
+    /// [`SNIPPET_VARIABLES`].
+    pub expansion: String,
+}
+
+/// Values substituted into an expansion. Missing values render as an empty
+/// string.
+#[derive(Clone, Debug, Default)]
+pub struct SnippetContext {
+    pub date: Option<String>,
+    pub time: Option<String>,
+    pub clipboard: Option<String>,
+    pub selected_text: Option<String>,
+}
+
+impl SnippetContext {
+    fn value(&self, variable: &str) -> Option<&str> {
+        let value = match variable {
+            "date" => &self.date,
+            "time" => &self.time,
+            "clipboard" => &self.clipboard,
+            "selected_text" => &self.selected_text,
+            _ => return None,
+        };
+        Some(value.as_deref().unwrap_or_default())
+    }
+}
+
+/// A snippet that matched a dictation, rendered and ready to paste.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnippetExpansion {
+    pub snippet_id: String,
+    pub trigger: String,
+    pub text: String,
+    /// How the expansion was inserted into the focused app.
+    pub report: super::InjectionReport,
+}
+
+/// Splits `expansion` into literal text and `{{variable}}` names. Unclosed
+/// braces are literal text.
+fn segments(expansion: &str) -> Vec<(&str, Option<&str>)> {
+    let mut segments = Vec::new();
+    let mut rest = expansion;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(length) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let raw = &rest[start..start + 2 + length + 2];
+        let name = rest[start + 2..start + 2 + length].trim();
+        segments.push((&rest[..start], None));
+        segments.push((raw, Some(name)));
+        rest = &rest[start + raw.len()..];
+    }
+
+    segments.push((rest, None));
+    segments
+}
+
+impl Snippet {
+    pub fn uses_variable(&self, variable: &str) -> bool {
+        segments(&self.expansion)
+            .iter()
+            .any(|(_, name)| *name == Some(variable))
+    }
+
+    /// Fills in known placeholders; unknown ones such as `{{name}}` are kept
+    /// as written so a typo stays visible.
+    pub fn render(&self, context: &SnippetContext) -> String {
+        segments(&self.expansion)
+            .into_iter()
+            .map(|(raw, name)| name.and_then(|name| context.value(name)).unwrap_or(raw))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippet(expansion: &str) -> Snippet {
+        Snippet {
+            id: "snippet".to_string(),
+            created_at: 0,
+            created_by_user_id: "user".to_string(),
+            trigger: "signature".to_string(),
+            expansion: expansion.to_string(),
+        }
+    }
+
+    #[test]
+    fn renders_known_placeholders() {
+        let context = SnippetContext {
+            date: Some("2026-10-18".to_string()),
+            clipboard: Some("https://example.com".to_string()),
+            ..SnippetContext::default()
+        };
+
+        assert_eq!(
+            snippet("Sent {{ date }}: {{clipboard}} {{selected_text}}").render(&context),
+            "Sent 2026-10-18: https://example.com "
+        );
+        assert_eq!(
+            snippet("Hi {{name}}, {{date").render(&context),
+            "Hi {{name}}, {{date"
+        );
+    }
+
+    #[test]
+    fn reports_used_variables() {
+        let snippet = snippet("> {{selected_text}}\n-- {{ date }}");
+        assert!(snippet.uses_variable("selected_text"));
+        assert!(snippet.uses_variable("date"));
+        assert!(!snippet.uses_variable("clipboard"));
+    }
+}
@@ -1,4 +1,41 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How a replacement term's `source_value` is matched against a transcript.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermMatchMode {
+    /// Whole words, ignoring case.
+    #[default]
+    Exact,
+    /// Sound-alike spellings, including near-identical Hangul syllables.
+    Fuzzy,
+    /// `source_value` is a regular expression; `destination_value` may use `$1`.
+    Regex,
+}
+
+impl TermMatchMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::Fuzzy => "fuzzy",
+            Self::Regex => "regex",
+        }
+    }
+}
+
+impl FromStr for TermMatchMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "exact" => Ok(Self::Exact),
+            "fuzzy" => Ok(Self::Fuzzy),
+            "regex" => Ok(Self::Regex),
+            _ => Err(()),
+        }
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -10,4 +47,26 @@ pub struct Term {
     pub destination_value: String,
     pub is_replacement: bool,
     pub is_deleted: bool,
+    #[serde(default)]
+    pub match_mode: TermMatchMode,
+}
+
+/// A dictionary replacement made in a transcript, kept for the history view.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppliedReplacement {
+    pub term_id: String,
+    pub original: String,
+    pub replacement: String,
+    pub match_mode: TermMatchMode,
+}
+
+/// A replacement learned from recurring edits to transcripts.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TermSuggestion {
+    pub source_value: String,
+    pub destination_value: String,
+    pub occurrences: i64,
+    pub last_seen_at: i64,
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::TranslationMode;
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Tone {
@@ -8,4 +10,18 @@ pub struct Tone {
     pub prompt_template: String,
     pub created_at: i64,
     pub sort_order: i32,
+    #[serde(default)]
+    pub translation_mode: Option<TranslationMode>,
+    /// Rewrites spoken numbers, dates and units; `None` leaves it to the app.
+    #[serde(default)]
+    pub inverse_text_normalization: Option<bool>,
+}
+
+impl Tone {
+    /// Tones opt in to formatted paste by asking for Markdown in their
+    /// prompt; other tones' output is pasted as it is, even when it happens
+    /// to look like Markdown.
+    pub fn produces_markdown(&self) -> bool {
+        self.prompt_template.to_lowercase().contains("markdown")
+    }
 }
@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+
+pub const TONE_TEMPLATE_VARIABLES: &[&str] = &[
+    "transcript",
+    "app_name",
+    "selected_text",
+    "user.name",
+    "user.title",
+    "language",
+    "date",
+];
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
+pub enum ToneTemplateError {
+    #[error("Unclosed template variable at character {0}; close it with `}}}}`")]
+    Unclosed(usize),
+    #[error(
+        "Unknown template variables: {}. They are kept as written; supported variables: {}",
+        braced(.0),
+        braced(TONE_TEMPLATE_VARIABLES)
+    )]
+    UnknownVariables(Vec<String>),
+}
+
+fn braced<S: AsRef<str>>(names: &[S]) -> String {
+    names
+        .iter()
+        .map(|name| format!("{{{{{}}}}}", name.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Values substituted into a tone's `prompt_template`. Missing values render
+/// as an empty string.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToneTemplateContext {
+    pub transcript: String,
+    pub app_name: Option<String>,
+    pub selected_text: Option<String>,
+    pub user_name: Option<String>,
+    pub user_title: Option<String>,
+    pub language: Option<String>,
+    pub date: Option<String>,
+}
+
+impl ToneTemplateContext {
+    fn value(&self, variable: &str) -> &str {
+        let value = match variable {
+            "transcript" => Some(&self.transcript),
+            "app_name" => self.app_name.as_ref(),
+            "selected_text" => self.selected_text.as_ref(),
+            "user.name" => self.user_name.as_ref(),
+            "user.title" => self.user_title.as_ref(),
+            "language" => self.language.as_ref(),
+            "date" => self.date.as_ref(),
+            _ => None,
+        };
+        value.map(String::as_str).unwrap_or_default()
+    }
+}
+
+enum Segment<'a> {
+    Text(&'a str),
+    Variable { raw: &'a str, name: &'a str },
+}
+
+/// Splits a template into text and `{{ name }}` placeholders. An unclosed `{{`
+/// is left in the trailing text.
+fn parse(template: &str) -> (Vec<Segment<'_>>, Option<usize>) {
+    let mut segments = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let after_open = &rest[start + 2..];
+        let Some(end) = after_open.find("}}") else {
+            let offset = template.len() - rest.len() + start;
+            segments.push(Segment::Text(rest));
+            return (segments, Some(template[..offset].chars().count()));
+        };
+        if start > 0 {
+            segments.push(Segment::Text(&rest[..start]));
+        }
+        segments.push(Segment::Variable {
+            raw: &rest[start..start + end + 4],
+            name: after_open[..end].trim(),
+        });
+        rest = &after_open[end + 2..];
+    }
+
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+    (segments, None)
+}
+
+/// Returns the distinct supported variables a template references, in order of
+/// first use.
+pub fn tone_template_variables(template: &str) -> Vec<String> {
+    let mut used: Vec<String> = Vec::new();
+    for segment in parse(template).0 {
+        if let Segment::Variable { name, .. } = segment {
+            if TONE_TEMPLATE_VARIABLES.contains(&name) && !used.iter().any(|used| used == name) {
+                used.push(name.to_string());
+            }
+        }
+    }
+    used
+}
+
+/// Reports placeholders that will not be filled in. These are warnings rather
+/// than hard errors: tones written before variables existed may contain literal
+/// braces, and those render exactly as written.
+pub fn validate_tone_template(template: &str) -> Result<(), ToneTemplateError> {
+    let (segments, unclosed) = parse(template);
+
+    let mut unknown: Vec<String> = Vec::new();
+    for segment in segments {
+        if let Segment::Variable { name, .. } = segment {
+            if !TONE_TEMPLATE_VARIABLES.contains(&name) && !unknown.iter().any(|seen| seen == name)
+            {
+                unknown.push(name.to_string());
+            }
+        }
+    }
+
+    if !unknown.is_empty() {
+        return Err(ToneTemplateError::UnknownVariables(unknown));
+    }
+    match unclosed {
+        Some(position) => Err(ToneTemplateError::Unclosed(position)),
+        None => Ok(()),
+    }
+}
+
+/// Substitutes the supported variables. Unknown placeholders and an unclosed
+/// `{{` are kept verbatim.
+pub fn render_tone_template(template: &str, context: &ToneTemplateContext) -> String {
+    let mut rendered = String::with_capacity(template.len() + context.transcript.len());
+    for segment in parse(template).0 {
+        match segment {
+            Segment::Text(text) => rendered.push_str(text),
+            Segment::Variable { raw, name } if !TONE_TEMPLATE_VARIABLES.contains(&name) => {
+                rendered.push_str(raw)
+            }
+            Segment::Variable { name, .. } => rendered.push_str(context.value(name)),
+        }
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_known_variables() {
+        let context = ToneTemplateContext {
+            transcript: "ship it friday".to_string(),
+            app_name: Some("Slack".to_string()),
+            user_name: Some("Dana".to_string()),
+            date: Some("2026-10-18".to_string()),
+            ..Default::default()
+        };
+
+        let rendered = render_tone_template(
+            "Reply in {{ app_name }} as {{user.name}} ({{user.title}}) on {{date}}: {{transcript}}",
+            &context,
+        );
+
+        assert_eq!(
+            rendered,
+            "Reply in Slack as Dana () on 2026-10-18: ship it friday"
+        );
+    }
+
+    #[test]
+    fn plain_templates_pass_through() {
+        let template = "Keep it formal. Use {braces} freely.";
+        assert_eq!(validate_tone_template(template), Ok(()));
+        assert_eq!(tone_template_variables(template), Vec::<String>::new());
+        assert_eq!(
+            render_tone_template(template, &ToneTemplateContext::default()),
+            template
+        );
+    }
+
+    #[test]
+    fn keeps_literal_braces_from_existing_tones() {
+        let template =
+            "Format as JSON like {{\"summary\": \"...\"}} then {{transcript}}. Keep {{ this";
+        let context = ToneTemplateContext {
+            transcript: "hello".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            tone_template_variables(template),
+            vec!["transcript".to_string()]
+        );
+        assert!(matches!(
+            validate_tone_template(template),
+            Err(ToneTemplateError::UnknownVariables(_))
+        ));
+        assert_eq!(
+            render_tone_template(template, &context),
+            "Format as JSON like {{\"summary\": \"...\"}} then hello. Keep {{ this"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_and_unclosed_variables() {
+        assert_eq!(
+            validate_tone_template("{{transcript}} {{user.email}} {{mood}} {{mood}}"),
+            Err(ToneTemplateError::UnknownVariables(vec![
+                "user.email".to_string(),
+                "mood".to_string(),
+            ]))
+        );
+        assert_eq!(
+            validate_tone_template("Hi {{transcript"),
+            Err(ToneTemplateError::Unclosed(3))
+        );
+
+        let message = validate_tone_template("{{mood}}").unwrap_err().to_string();
+        assert!(message.starts_with("Unknown template variables: {{mood}}. They are kept"));
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::AppliedReplacement;
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TranscriptionAudioSnapshot {
@@ -7,6 +9,13 @@ pub struct TranscriptionAudioSnapshot {
     pub duration_ms: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageProbability {
+    pub language: String,
+    pub probability: f32,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Transcription {
@@ -43,4 +52,23 @@ pub struct Transcription {
     pub postprocess_duration_ms: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_probabilities: Option<Vec<LanguageProbability>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translated_transcript: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_replacements: Option<Vec<AppliedReplacement>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_target_id: Option<String>,
+}
+
+/// The slice of a stored transcription used to rank vocabulary terms.
+#[derive(Clone, Debug)]
+pub struct RecentDictation {
+    pub transcript: String,
+    pub app_target_id: Option<String>,
 }
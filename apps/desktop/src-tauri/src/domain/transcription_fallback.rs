@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+/// One provider in the user's ordered transcription fallback chain, e.g.
+/// Groq → OpenAI → local Whisper small. Stored as JSON in preferences.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum TranscriptionFallbackStep {
+    Api {
+        #[serde(rename = "apiKeyId")]
+        api_key_id: String,
+    },
+    Local {
+        #[serde(default, rename = "modelSize")]
+        model_size: Option<String>,
+        #[serde(default)]
+        engine: Option<String>,
+    },
+}
+
+impl TranscriptionFallbackStep {
+    /// Matches the `transcription_mode` values stored on transcriptions.
+    pub fn mode(&self) -> &'static str {
+        match self {
+            Self::Api { .. } => "api",
+            Self::Local { .. } => "local",
+        }
+    }
+
+    pub fn api_key_id(&self) -> Option<&str> {
+        match self {
+            Self::Api { api_key_id } => Some(api_key_id),
+            Self::Local { .. } => None,
+        }
+    }
+}
+
+/// Parses the stored chain, dropping steps that no longer deserialize (for
+/// example after a mode is removed) instead of discarding the whole chain.
+pub fn parse_fallback_chain(json: &str) -> Vec<TranscriptionFallbackStep> {
+    let values = match serde_json::from_str::<Vec<serde_json::Value>>(json) {
+        Ok(values) => values,
+        Err(err) => {
+            eprintln!("[transcription-fallback] ignoring malformed chain: {err}");
+            return Vec::new();
+        }
+    };
+
+    values
+        .into_iter()
+        .filter_map(
+            |value| match serde_json::from_value::<TranscriptionFallbackStep>(value) {
+                Ok(step) => Some(step),
+                Err(err) => {
+                    eprintln!("[transcription-fallback] skipping invalid step: {err}");
+                    None
+                }
+            },
+        )
+        .collect()
+}
+
+/// A single provider attempt made while serving a dictation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionAttempt {
+    pub mode: String,
+    pub api_key_id: Option<String>,
+    /// Human readable provider, e.g. `groq` or `whisper_cpp small`.
+    pub provider: String,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+impl TranscriptionAttempt {
+    /// Line recorded in `Transcription.warnings`.
+    pub fn to_warning(&self, position: usize) -> String {
+        let target = match self.api_key_id.as_deref() {
+            Some(key_id) => format!("{} ({} key {key_id})", self.provider, self.mode),
+            None => format!("{} ({})", self.provider, self.mode),
+        };
+
+        match self.error.as_deref() {
+            Some(error) => format!(
+                "Transcription attempt {position} via {target} failed after {} ms: {error}",
+                self.latency_ms
+            ),
+            None => format!(
+                "Transcription attempt {position} via {target} succeeded in {} ms",
+                self.latency_ms
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_chain_and_skips_invalid_steps() {
+        let chain = parse_fallback_chain(
+            r#"[
+                {"mode":"api","apiKeyId":"groq-key"},
+                {"mode":"cloud"},
+                {"mode":"local","modelSize":"small"}
+            ]"#,
+        );
+
+        assert_eq!(
+            chain,
+            vec![
+                TranscriptionFallbackStep::Api {
+                    api_key_id: "groq-key".to_string()
+                },
+                TranscriptionFallbackStep::Local {
+                    model_size: Some("small".to_string()),
+                    engine: None
+                },
+            ]
+        );
+        assert!(parse_fallback_chain("not json").is_empty());
+    }
+
+    #[test]
+    fn warnings_record_latency_and_reason() {
+        let failed = TranscriptionAttempt {
+            mode: "api".to_string(),
+            api_key_id: Some("groq-key".to_string()),
+            provider: "groq".to_string(),
+            latency_ms: 812,
+            error: Some("Rate limited by provider".to_string()),
+        };
+        let served = TranscriptionAttempt {
+            mode: "local".to_string(),
+            api_key_id: None,
+            provider: "whisper_cpp small".to_string(),
+            latency_ms: 1432,
+            error: None,
+        };
+
+        assert_eq!(
+            failed.to_warning(1),
+            "Transcription attempt 1 via groq (api key groq-key) failed after 812 ms: Rate limited by provider"
+        );
+        assert_eq!(
+            served.to_warning(2),
+            "Transcription attempt 2 via whisper_cpp small (local) succeeded in 1432 ms"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::hotkey::{HOTKEY_ACTION_DICTATE_BILINGUAL, HOTKEY_ACTION_DICTATE_TRANSLATE};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationMode {
+    #[default]
+    Off,
+    English,
+    Bilingual,
+}
+
+impl TranslationMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::English => "english",
+            Self::Bilingual => "bilingual",
+        }
+    }
+
+    pub fn for_hotkey_action(action_name: &str) -> Option<Self> {
+        match action_name {
+            HOTKEY_ACTION_DICTATE_TRANSLATE => Some(Self::English),
+            HOTKEY_ACTION_DICTATE_BILINGUAL => Some(Self::Bilingual),
+            _ => None,
+        }
+    }
+
+    pub fn requires_translation(self) -> bool {
+        !matches!(self, Self::Off)
+    }
+
+    /// Builds the text handed back for pasting: the original, the English
+    /// translation, or both separated by a blank line.
+    pub fn compose(self, original: &str, translation: Option<&str>) -> String {
+        let translation = translation.map(str::trim).filter(|value| !value.is_empty());
+        match (self, translation) {
+            (Self::English, Some(english)) => english.to_string(),
+            (Self::Bilingual, Some(english)) if english != original.trim() => {
+                format!("{}\n\n{}", original.trim(), english)
+            }
+            _ => original.trim().to_string(),
+        }
+    }
+}
+
+impl FromStr for TranslationMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(Self::Off),
+            "english" => Ok(Self::English),
+            "bilingual" => Ok(Self::Bilingual),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TranslationMode;
+
+    #[test]
+    fn compose_respects_mode() {
+        let original = "안녕하세요";
+        let english = Some("Hello");
+
+        assert_eq!(TranslationMode::Off.compose(original, english), "안녕하세요");
+        assert_eq!(TranslationMode::English.compose(original, english), "Hello");
+        assert_eq!(
+            TranslationMode::Bilingual.compose(original, english),
+            "안녕하세요\n\nHello"
+        );
+    }
+
+    #[test]
+    fn compose_falls_back_to_original_without_translation() {
+        assert_eq!(TranslationMode::English.compose(" hi ", None), "hi");
+        assert_eq!(TranslationMode::Bilingual.compose("hi", Some("hi")), "hi");
+    }
+}
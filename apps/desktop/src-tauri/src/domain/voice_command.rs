@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::language_subtag;
+
+/// What a spoken command does to the dictated text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoiceCommandAction {
+    /// Inserts `value`, such as punctuation or a line break.
+    Insert,
+    /// Removes the last sentence or line break.
+    DeleteLast,
+    /// Upper-cases the following words until the next command.
+    AllCaps,
+    /// Types the next command phrase literally instead of running it.
+    Escape,
+}
+
+impl VoiceCommandAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Insert => "insert",
+            Self::DeleteLast => "delete_last",
+            Self::AllCaps => "all_caps",
+            Self::Escape => "escape",
+        }
+    }
+}
+
+impl FromStr for VoiceCommandAction {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "insert" => Ok(Self::Insert),
+            "delete_last" => Ok(Self::DeleteLast),
+            "all_caps" => Ok(Self::AllCaps),
+            "escape" => Ok(Self::Escape),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceCommand {
+    pub id: String,
+    /// Primary language subtag, such as `en` or `ko`.
+    pub language: String,
+    pub phrase: String,
+    pub action: VoiceCommandAction,
+    #[serde(default)]
+    pub value: Option<String>,
+    pub is_enabled: bool,
+    /// Built-in commands are not stored until the user changes them.
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+/// A command recognised in a transcript, reported back for the history view.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutedVoiceCommand {
+    pub phrase: String,
+    pub action: VoiceCommandAction,
+}
+
+fn phrase_key(language: &str, phrase: &str) -> (String, String) {
+    (
+        language_subtag(language),
+        phrase.split_whitespace().collect::<String>().to_lowercase(),
+    )
+}
+
+pub fn default_voice_commands() -> Vec<VoiceCommand> {
+    use VoiceCommandAction::{AllCaps, DeleteLast, Escape, Insert};
+
+    const DEFAULT_VOICE_COMMANDS: &[(&str, &str, VoiceCommandAction, Option<&str>)] = &[
+        ("en", "new line", Insert, Some("\n")),
+        ("en", "new paragraph", Insert, Some("\n\n")),
+        ("en", "comma", Insert, Some(",")),
+        ("en", "full stop", Insert, Some(".")),
+        ("en", "question mark", Insert, Some("?")),
+        ("en", "exclamation mark", Insert, Some("!")),
+        ("en", "exclamation point", Insert, Some("!")),
+        ("en", "semicolon", Insert, Some(";")),
+        ("en", "delete that", DeleteLast, None),
+        ("en", "scratch that", DeleteLast, None),
+        ("en", "all caps", AllCaps, None),
+        ("en", "literal", Escape, None),
+        ("ko", "줄 바꿈", Insert, Some("\n")),
+        ("ko", "새 줄", Insert, Some("\n")),
+        ("ko", "새 문단", Insert, Some("\n\n")),
+        ("ko", "문단 바꿈", Insert, Some("\n\n")),
+        ("ko", "쉼표", Insert, Some(",")),
+        ("ko", "마침표", Insert, Some(".")),
+        ("ko", "물음표", Insert, Some("?")),
+        ("ko", "느낌표", Insert, Some("!")),
+        ("ko", "콜론", Insert, Some(":")),
+        ("ko", "세미콜론", Insert, Some(";")),
+        ("ko", "방금 거 지워", DeleteLast, None),
+        ("ko", "모두 대문자", AllCaps, None),
+        ("ko", "그대로", Escape, None),
+    ];
+
+    DEFAULT_VOICE_COMMANDS
+        .iter()
+        .map(|(language, phrase, action, value)| VoiceCommand {
+            id: format!("default:{language}:{phrase}"),
+            language: language.to_string(),
+            phrase: phrase.to_string(),
+            action: *action,
+            value: value.map(str::to_string),
+            is_enabled: true,
+            is_default: true,
+        })
+        .collect()
+}
+
+/// The built-in grammar with the user's stored commands layered on top; a
+/// stored command replaces a built-in one with the same language and phrase.
+pub fn merge_voice_commands(custom: Vec<VoiceCommand>) -> Vec<VoiceCommand> {
+    let mut merged: Vec<VoiceCommand> = default_voice_commands()
+        .into_iter()
+        .filter(|command| {
+            let key = phrase_key(&command.language, &command.phrase);
+            !custom
+                .iter()
+                .any(|stored| phrase_key(&stored.language, &stored.phrase) == key)
+        })
+        .collect();
+    merged.extend(custom);
+    merged
+}
@@ -43,7 +43,19 @@ pub enum AppInfoError {
 pub fn get_current_app_info() -> Result<CurrentAppInfo, AppInfoError> {
     let config = FocusTrackerConfig::new().with_icon_size(DEFAULT_ICON_SIZE);
     let icon_size = config.icon.get_size_or_default();
-    let tracker = FocusTracker::with_config(config.clone());
+    let window = capture_focused_window(config)?;
+    build_app_info(window, icon_size)
+}
+
+/// Name of the focused application, without encoding its icon. Cheap enough
+/// to compare focus before and after a slow operation.
+pub fn get_focused_app_name() -> Result<String, AppInfoError> {
+    let window = capture_focused_window(FocusTrackerConfig::new())?;
+    Ok(resolve_app_name(&window))
+}
+
+fn capture_focused_window(config: FocusTrackerConfig) -> Result<FocusedWindow, AppInfoError> {
+    let tracker = FocusTracker::with_config(config);
     let stop_signal = AtomicBool::new(false);
     let mut captured: Option<FocusedWindow> = None;
 
@@ -58,8 +70,7 @@ pub fn get_current_app_info() -> Result<CurrentAppInfo, AppInfoError> {
         )
         .map_err(map_focus_error)?;
 
-    let window = captured.ok_or(AppInfoError::NotAvailable)?;
-    build_app_info(window, icon_size)
+    captured.ok_or(AppInfoError::NotAvailable)
 }
 
 fn map_focus_error(err: ferrous_focus::FerrousFocusError) -> AppInfoError {
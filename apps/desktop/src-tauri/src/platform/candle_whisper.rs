@@ -0,0 +1,494 @@
+use crate::domain::LanguageProbability;
+use crate::platform::whisper::{
+    candidate_language_codes, prepare_samples, rank_candidate_languages, WHISPER_SAMPLE_RATE,
+};
+use crate::platform::{
+    Transcriber, TranscriptionDevice, TranscriptionOutput, TranscriptionRequest,
+};
+use candle_core::{Device, IndexOp, Tensor, D};
+use candle_nn::VarBuilder;
+use candle_transformers::models::whisper::{self as m, audio, model::Whisper, Config};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokenizers::Tokenizer;
+
+pub const CONFIG_FILENAME: &str = "config.json";
+pub const TOKENIZER_FILENAME: &str = "tokenizer.json";
+pub const WEIGHTS_FILENAME: &str = "model.safetensors";
+
+/// Whisper decodes at most this many tokens per 30 second window.
+const MAX_DECODE_TOKENS: usize = 224;
+
+/// In-process, CPU-only Whisper implementation built on candle. It consumes the
+/// Hugging Face `openai/whisper-*` checkpoints (config, tokenizer and
+/// safetensors weights) instead of the ggml files used by whisper.cpp.
+pub struct CandleWhisperTranscriber {
+    model_dir: PathBuf,
+    models: Mutex<HashMap<PathBuf, Arc<Mutex<LoadedModel>>>>,
+}
+
+struct LoadedModel {
+    model: Whisper,
+    config: Config,
+    tokenizer: Tokenizer,
+    mel_filters: Vec<f32>,
+    suppress_tokens: Tensor,
+    tokens: SpecialTokens,
+}
+
+struct SpecialTokens {
+    sot: u32,
+    eot: u32,
+    transcribe: u32,
+    translate: u32,
+    no_timestamps: u32,
+    start_of_prev: Option<u32>,
+}
+
+impl CandleWhisperTranscriber {
+    pub fn new(model_dir: &Path) -> Result<Self, String> {
+        let loaded = LoadedModel::load(model_dir)?;
+        let mut models = HashMap::new();
+        models.insert(model_dir.to_path_buf(), Arc::new(Mutex::new(loaded)));
+
+        Ok(Self {
+            model_dir: model_dir.to_path_buf(),
+            models: Mutex::new(models),
+        })
+    }
+
+    fn model_for_request(
+        &self,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<Arc<Mutex<LoadedModel>>, String> {
+        let model_dir = request
+            .and_then(|req| req.model_path.as_deref())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| self.model_dir.clone());
+
+        let mut models = self
+            .models
+            .lock()
+            .map_err(|_| "Candle model cache poisoned".to_string())?;
+        if let Some(existing) = models.get(&model_dir) {
+            return Ok(existing.clone());
+        }
+
+        eprintln!(
+            "[candle-whisper] loading model from {}",
+            model_dir.display()
+        );
+        let loaded = Arc::new(Mutex::new(LoadedModel::load(&model_dir)?));
+        models.insert(model_dir, loaded.clone());
+        Ok(loaded)
+    }
+}
+
+impl Transcriber for CandleWhisperTranscriber {
+    fn transcribe(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, String> {
+        let processed = prepare_samples(samples, sample_rate)?;
+
+        if let Some(TranscriptionDevice::Gpu(_)) = request.and_then(|req| req.device.as_ref()) {
+            eprintln!("[candle-whisper] GPU requested but this engine is CPU-only; using CPU");
+        }
+
+        let model = self.model_for_request(request)?;
+        let mut model = model
+            .lock()
+            .map_err(|_| "Candle model poisoned".to_string())?;
+
+        let segments = model.encode_segments(&processed)?;
+        let Some(first_segment) = segments.first() else {
+            return Ok(TranscriptionOutput::default());
+        };
+
+        let forced_language = request
+            .and_then(|req| req.language.as_deref())
+            .map(|value| value.trim().to_ascii_lowercase())
+            .filter(|value| !value.is_empty());
+        let candidates = request
+            .map(|req| candidate_language_codes(&req.candidate_languages))
+            .unwrap_or_default();
+
+        let mut language_probabilities = Vec::new();
+        if forced_language.is_none() && candidates.len() != 1 {
+            match model.detect_language(first_segment, &candidates) {
+                Ok(ranked) => language_probabilities = ranked,
+                Err(err) => eprintln!("[candle-whisper] language detection failed: {err}"),
+            }
+        }
+        let detected_language = language_probabilities
+            .first()
+            .map(|entry| entry.language.clone());
+
+        // Same precedence as the whisper.cpp engine: a forced language, then
+        // detection (among the user's languages when there are several), then
+        // the only candidate.
+        let language_code: Option<String> = forced_language
+            .clone()
+            .or_else(|| detected_language.clone())
+            .or_else(|| candidates.first().cloned());
+
+        eprintln!("[candle-whisper] using language code: {:?}", language_code);
+
+        let initial_prompt = request
+            .and_then(|req| req.initial_prompt.as_ref())
+            .map(|prompt| prompt.chars().filter(|ch| *ch != '\0').collect::<String>())
+            .filter(|prompt| !prompt.trim().is_empty());
+
+        let text = model.decode_segments(
+            &segments,
+            language_code.as_deref(),
+            initial_prompt.as_deref().map(str::trim),
+            false,
+        )?;
+
+        // Auto-detection over the whole vocabulary is only reported as a
+        // ranking when the user restricted it to their own languages.
+        if candidates.len() < 2 {
+            language_probabilities.clear();
+        }
+
+        let translation_mode = request.map(|req| req.translation_mode).unwrap_or_default();
+        let translation = if !translation_mode.requires_translation() {
+            None
+        } else if text.is_empty() || language_code.as_deref() == Some("en") {
+            Some(text.clone())
+        } else {
+            Some(model.decode_segments(&segments, language_code.as_deref(), None, true)?)
+        };
+
+        Ok(TranscriptionOutput {
+            text,
+            detected_language,
+            language_probabilities,
+            translation,
+        })
+    }
+}
+
+impl LoadedModel {
+    fn load(model_dir: &Path) -> Result<Self, String> {
+        let device = Device::Cpu;
+
+        let config_raw = std::fs::read_to_string(model_dir.join(CONFIG_FILENAME))
+            .map_err(|err| format!("Failed to read candle Whisper config: {err}"))?;
+        let config: Config = serde_json::from_str(&config_raw)
+            .map_err(|err| format!("Invalid candle Whisper config: {err}"))?;
+
+        let tokenizer = Tokenizer::from_file(model_dir.join(TOKENIZER_FILENAME))
+            .map_err(|err| format!("Failed to load Whisper tokenizer: {err}"))?;
+
+        // SAFETY: the weights file is owned by the app's model directory and is
+        // not modified while the transcriber holds the mapping.
+        let vb = unsafe {
+            VarBuilder::from_mmaped_safetensors(
+                &[model_dir.join(WEIGHTS_FILENAME)],
+                m::DTYPE,
+                &device,
+            )
+        }
+        .map_err(|err| format!("Failed to map candle Whisper weights: {err}"))?;
+        let model = Whisper::load(&vb, config.clone())
+            .map_err(|err| format!("Failed to load candle Whisper model: {err}"))?;
+
+        let token = |name: &str| {
+            tokenizer
+                .token_to_id(name)
+                .ok_or_else(|| format!("Whisper tokenizer is missing token {name}"))
+        };
+        let tokens = SpecialTokens {
+            sot: token(m::SOT_TOKEN)?,
+            eot: token(m::EOT_TOKEN)?,
+            transcribe: token(m::TRANSCRIBE_TOKEN)?,
+            translate: token(m::TRANSLATE_TOKEN)?,
+            no_timestamps: token(m::NO_TIMESTAMPS_TOKEN)?,
+            start_of_prev: tokenizer.token_to_id("<|startofprev|>"),
+        };
+
+        let suppress: Vec<f32> = (0..config.vocab_size as u32)
+            .map(|index| {
+                if config.suppress_tokens.contains(&index) || index == tokens.no_timestamps {
+                    f32::NEG_INFINITY
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        let suppress_tokens = Tensor::new(suppress.as_slice(), &device)
+            .map_err(|err| format!("Failed to build suppression mask: {err}"))?;
+
+        let mel_filters = mel_filterbank(config.num_mel_bins, m::N_FFT, WHISPER_SAMPLE_RATE);
+
+        Ok(Self {
+            model,
+            config,
+            tokenizer,
+            mel_filters,
+            suppress_tokens,
+            tokens,
+        })
+    }
+
+    /// Splits the audio into 30 second windows and runs the encoder on each.
+    fn encode_segments(&mut self, samples: &[f32]) -> Result<Vec<Tensor>, String> {
+        let n_mels = self.config.num_mel_bins;
+        let mel = audio::pcm_to_mel(&self.config, samples, &self.mel_filters);
+        let frames = mel.len() / n_mels;
+        let mel = Tensor::from_vec(mel, (1, n_mels, frames), &Device::Cpu)
+            .map_err(|err| format!("Failed to build mel tensor: {err}"))?;
+
+        // `pcm_to_mel` pads by one window; only frames backed by audio count.
+        let content_frames = samples.len().div_ceil(m::HOP_LENGTH).min(frames);
+        let mut segments = Vec::new();
+        let mut offset = 0;
+        while offset < content_frames {
+            let size = usize::min(frames - offset, m::N_FRAMES);
+            let encoded = mel
+                .narrow(2, offset, size)
+                .and_then(|segment| self.model.encoder.forward(&segment, true))
+                .map_err(|err| format!("Failed to run Whisper encoder: {err}"))?;
+            segments.push(encoded);
+            offset += m::N_FRAMES;
+        }
+
+        Ok(segments)
+    }
+
+    fn language_tokens(&self, candidates: &[String]) -> Vec<(String, u32)> {
+        if candidates.is_empty() {
+            let mut all: Vec<(String, u32)> = self
+                .tokenizer
+                .get_vocab(true)
+                .into_iter()
+                .filter_map(|(token, id)| language_code_of_token(&token).map(|code| (code, id)))
+                .collect();
+            all.sort_by_key(|(_, id)| *id);
+            return all;
+        }
+
+        candidates
+            .iter()
+            .filter_map(|code| {
+                self.tokenizer
+                    .token_to_id(&format!("<|{code}|>"))
+                    .map(|id| (code.clone(), id))
+            })
+            .collect()
+    }
+
+    fn detect_language(
+        &mut self,
+        features: &Tensor,
+        candidates: &[String],
+    ) -> Result<Vec<LanguageProbability>, String> {
+        let languages = self.language_tokens(candidates);
+        if languages.is_empty() {
+            return Err(format!(
+                "None of the configured languages {candidates:?} are known to Whisper"
+            ));
+        }
+
+        let logits = self
+            .next_token_logits(&[self.tokens.sot], features, true)
+            .map_err(|err| format!("Failed to run Whisper language detection: {err}"))?;
+        let logits: Vec<f32> = logits
+            .to_vec1()
+            .map_err(|err| format!("Failed to read language logits: {err}"))?;
+
+        let codes: Vec<String> = languages.iter().map(|(code, _)| code.clone()).collect();
+        let max = languages
+            .iter()
+            .filter_map(|(_, id)| logits.get(*id as usize).copied())
+            .fold(f32::NEG_INFINITY, f32::max);
+
+        Ok(rank_candidate_languages(&codes, |code| {
+            languages
+                .iter()
+                .find(|(candidate, _)| candidate == code)
+                .and_then(|(_, id)| logits.get(*id as usize))
+                .map(|logit| (logit - max).exp())
+        }))
+    }
+
+    fn decode_segments(
+        &mut self,
+        segments: &[Tensor],
+        language: Option<&str>,
+        initial_prompt: Option<&str>,
+        translate: bool,
+    ) -> Result<String, String> {
+        let mut prefix = Vec::new();
+        if let (Some(prompt), Some(start_of_prev)) = (initial_prompt, self.tokens.start_of_prev) {
+            let encoded = self
+                .tokenizer
+                .encode(format!(" {prompt}"), false)
+                .map_err(|err| format!("Failed to tokenize initial prompt: {err}"))?;
+            let ids = encoded.get_ids();
+            let budget = self.config.max_target_positions / 2 - 1;
+            prefix.push(start_of_prev);
+            prefix.extend_from_slice(&ids[ids.len().saturating_sub(budget)..]);
+        }
+
+        prefix.push(self.tokens.sot);
+        if let Some(id) =
+            language.and_then(|code| self.tokenizer.token_to_id(&format!("<|{code}|>")))
+        {
+            prefix.push(id);
+        }
+        prefix.push(if translate {
+            self.tokens.translate
+        } else {
+            self.tokens.transcribe
+        });
+        prefix.push(self.tokens.no_timestamps);
+
+        let mut transcript = String::new();
+        for features in segments {
+            let text = self
+                .decode_greedy(features, &prefix)
+                .map_err(|err| format!("Failed to run Whisper decoder: {err}"))?;
+            let text = text.trim();
+            if !text.is_empty() {
+                if !transcript.is_empty() {
+                    transcript.push(' ');
+                }
+                transcript.push_str(text);
+            }
+        }
+
+        Ok(transcript)
+    }
+
+    fn decode_greedy(&mut self, features: &Tensor, prefix: &[u32]) -> candle_core::Result<String> {
+        let mut tokens = prefix.to_vec();
+        let limit = (prefix.len() + MAX_DECODE_TOKENS).min(self.config.max_target_positions);
+
+        for step in 0..limit.saturating_sub(prefix.len()) {
+            let logits = self
+                .next_token_logits(&tokens, features, step == 0)?
+                .broadcast_add(&self.suppress_tokens)?;
+            let next = logits.argmax(D::Minus1)?.to_scalar::<u32>()?;
+            if next == self.tokens.eot {
+                break;
+            }
+            tokens.push(next);
+        }
+
+        let generated: Vec<u32> = tokens[prefix.len()..]
+            .iter()
+            .copied()
+            .filter(|token| *token < self.tokens.eot)
+            .collect();
+        self.tokenizer
+            .decode(&generated, true)
+            .map_err(|err| candle_core::Error::Msg(err.to_string()))
+    }
+
+    fn next_token_logits(
+        &mut self,
+        tokens: &[u32],
+        features: &Tensor,
+        flush_kv_cache: bool,
+    ) -> candle_core::Result<Tensor> {
+        let tokens_t = Tensor::new(tokens, &Device::Cpu)?.unsqueeze(0)?;
+        let hidden = self
+            .model
+            .decoder
+            .forward(&tokens_t, features, flush_kv_cache)?;
+        let last = hidden.i((..1, tokens.len() - 1..))?;
+        self.model.decoder.final_linear(&last)?.i(0)?.i(0)
+    }
+}
+
+fn language_code_of_token(token: &str) -> Option<String> {
+    let code = token.strip_prefix("<|")?.strip_suffix("|>")?;
+    let valid = (2..=3).contains(&code.len()) && code.chars().all(|ch| ch.is_ascii_lowercase());
+    valid.then(|| code.to_string())
+}
+
+/// Slaney-style mel filterbank matching `librosa.filters.mel(norm="slaney")`,
+/// laid out row-major as `[n_mels][n_fft / 2 + 1]` for `audio::pcm_to_mel`.
+fn mel_filterbank(n_mels: usize, n_fft: usize, sample_rate: u32) -> Vec<f32> {
+    const F_SP: f64 = 200.0 / 3.0;
+    const MIN_LOG_HZ: f64 = 1000.0;
+    const MIN_LOG_MEL: f64 = MIN_LOG_HZ / F_SP;
+    let log_step = 6.4f64.ln() / 27.0;
+
+    let hz_to_mel = |hz: f64| {
+        if hz >= MIN_LOG_HZ {
+            MIN_LOG_MEL + (hz / MIN_LOG_HZ).ln() / log_step
+        } else {
+            hz / F_SP
+        }
+    };
+    let mel_to_hz = |mel: f64| {
+        if mel >= MIN_LOG_MEL {
+            MIN_LOG_HZ * (log_step * (mel - MIN_LOG_MEL)).exp()
+        } else {
+            mel * F_SP
+        }
+    };
+
+    let n_freqs = n_fft / 2 + 1;
+    let sample_rate = f64::from(sample_rate);
+    let max_mel = hz_to_mel(sample_rate / 2.0);
+    let mel_points: Vec<f64> = (0..n_mels + 2)
+        .map(|i| mel_to_hz(max_mel * i as f64 / (n_mels + 1) as f64))
+        .collect();
+
+    let mut filters = vec![0f32; n_mels * n_freqs];
+    for mel in 0..n_mels {
+        let (lower, center, upper) = (mel_points[mel], mel_points[mel + 1], mel_points[mel + 2]);
+        let norm = 2.0 / (upper - lower);
+        for bin in 0..n_freqs {
+            let freq = bin as f64 * sample_rate / n_fft as f64;
+            let rising = (freq - lower) / (center - lower);
+            let falling = (upper - freq) / (upper - center);
+            let weight = rising.min(falling).max(0.0) * norm;
+            filters[mel * n_freqs + bin] = weight as f32;
+        }
+    }
+
+    filters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mel_filterbank_has_one_triangle_per_band() {
+        let n_freqs = m::N_FFT / 2 + 1;
+        let filters = mel_filterbank(80, m::N_FFT, WHISPER_SAMPLE_RATE);
+        assert_eq!(filters.len(), 80 * n_freqs);
+
+        for band in filters.chunks(n_freqs) {
+            assert!(band.iter().all(|weight| *weight >= 0.0));
+            assert!(band.iter().any(|weight| *weight > 0.0));
+        }
+
+        // The first band is centred on ~37 Hz, so it peaks in the 40 Hz bin.
+        let peak = filters[..n_freqs]
+            .iter()
+            .enumerate()
+            .max_by(|left, right| left.1.total_cmp(right.1))
+            .map(|(bin, _)| bin);
+        assert_eq!(peak, Some(1));
+    }
+
+    #[test]
+    fn language_tokens_are_recognised() {
+        assert_eq!(language_code_of_token("<|en|>").as_deref(), Some("en"));
+        assert_eq!(language_code_of_token("<|haw|>").as_deref(), Some("haw"));
+        assert_eq!(language_code_of_token("<|nocaptions|>"), None);
+        assert_eq!(language_code_of_token("<|EN|>"), None);
+        assert_eq!(language_code_of_token("en"), None);
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use arboard::{Clipboard, ImageData};
+
+use crate::domain::{
+    assess_injection, ClipboardRestoreTimings, FocusSnapshot, InjectionOutcome,
+    MAX_CLIPBOARD_RESTORE_DELAY,
+};
+
+const READ_POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// Kept after the paste shows up, as some apps finish reading afterwards.
+const READ_GRACE: Duration = Duration::from_millis(100);
+
+/// The clipboard contents before a paste, as far as they can be put back.
+///
+/// `arboard` replaces the whole clipboard on every write and can only write
+/// HTML together with a plain-text alternative, so a snapshot keeps the
+/// richest format it can read: files, then HTML with its plain-text
+/// alternative, then an image, then plain text. Anything else that was on
+/// the clipboard is lost when it is restored: a second format copied next
+/// to the kept one, such as the text that came with an image, and formats
+/// `arboard` cannot read at all, such as RTF or app-specific data. `read`
+/// logs the formats it had to drop.
+pub enum ClipboardSnapshot {
+    Empty,
+    Text(String),
+    Html {
+        html: String,
+        alt_text: Option<String>,
+    },
+    Image(ImageData<'static>),
+    Files(Vec<PathBuf>),
+}
+
+impl ClipboardSnapshot {
+    pub fn capture() -> Self {
+        match Clipboard::new() {
+            Ok(mut clipboard) => Self::read(&mut clipboard),
+            Err(err) => {
+                eprintln!("[clipboard] Clipboard unavailable: {err}");
+                Self::Empty
+            }
+        }
+    }
+
+    fn read(clipboard: &mut Clipboard) -> Self {
+        let html = clipboard.get().html().ok();
+        if let Ok(files) = clipboard.get().file_list() {
+            if !files.is_empty() {
+                if html.is_some() {
+                    eprintln!("[clipboard] Only the copied files will be restored, not their HTML");
+                }
+                return Self::Files(files);
+            }
+        }
+        if let Some(html) = html {
+            return Self::Html {
+                html,
+                alt_text: clipboard.get_text().ok(),
+            };
+        }
+        if let Ok(image) = clipboard.get_image() {
+            if clipboard.get_text().is_ok() {
+                eprintln!("[clipboard] Only the copied image will be restored, not its text");
+            }
+            return Self::Image(image);
+        }
+        match clipboard.get_text() {
+            Ok(text) => Self::Text(text),
+            Err(_) => Self::Empty,
+        }
+    }
+
+    pub fn restore(self) -> Result<(), String> {
+        let mut clipboard =
+            Clipboard::new().map_err(|err| format!("clipboard unavailable: {err}"))?;
+        match self {
+            Self::Empty => clipboard.clear(),
+            Self::Text(text) => clipboard.set_text(text),
+            Self::Html { html, alt_text } => clipboard.set_html(html, alt_text),
+            Self::Image(image) => clipboard.set_image(image),
+            Self::Files(files) => clipboard.set().file_list(&files),
+        }
+        .map_err(|err| format!("failed to restore clipboard: {err}"))
+    }
+}
+
+/// The user's clipboard, waiting to be put back after a paste.
+struct PendingRestore {
+    id: u64,
+    snapshot: ClipboardSnapshot,
+}
+
+fn pending_restore() -> &'static Mutex<Option<PendingRestore>> {
+    static PENDING: OnceLock<Mutex<Option<PendingRestore>>> = OnceLock::new();
+    PENDING.get_or_init(|| Mutex::new(None))
+}
+
+fn restore_timings() -> &'static Mutex<ClipboardRestoreTimings> {
+    static TIMINGS: OnceLock<Mutex<ClipboardRestoreTimings>> = OnceLock::new();
+    TIMINGS.get_or_init(|| Mutex::new(ClipboardRestoreTimings::default()))
+}
+
+/// Puts `text` on the clipboard, with `html` as its formatted version when
+/// given, runs `send_paste` to press the paste shortcut and puts the previous
+/// clipboard back once the app has read it.
+pub fn paste_preserving_clipboard(
+    text: &str,
+    html: Option<&str>,
+    send_paste: impl FnOnce() -> Result<(), String>,
+) -> Result<(), String> {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    let mut clipboard = Clipboard::new().map_err(|err| format!("clipboard unavailable: {err}"))?;
+    // While an earlier paste waits to restore, the clipboard holds that
+    // paste's text and the user's own clipboard is in its snapshot.
+    let previous = pending_restore()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .take()
+        .map(|pending| pending.snapshot)
+        .unwrap_or_else(|| ClipboardSnapshot::read(&mut clipboard));
+    let before = super::injection::focus_snapshot();
+
+    let result = match html {
+        Some(html) => clipboard.set_html(html, Some(text)),
+        None => clipboard.set_text(text),
+    }
+    .map_err(|err| format!("failed to store clipboard text: {err}"))
+    .and_then(|()| send_paste());
+    if let Err(err) = result {
+        if let Err(restore_err) = previous.restore() {
+            eprintln!("[clipboard] {restore_err}");
+        }
+        return Err(err);
+    }
+
+    let pasted_at = Instant::now();
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    *pending_restore()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner()) = Some(PendingRestore {
+        id,
+        snapshot: previous,
+    });
+
+    let text = text.to_string();
+    thread::spawn(move || {
+        wait_for_read(&text, &before, pasted_at);
+
+        let snapshot = {
+            let mut pending = pending_restore()
+                .lock()
+                .unwrap_or_else(|err| err.into_inner());
+            match pending.take() {
+                Some(restore) if restore.id == id => restore.snapshot,
+                // A later paste took over the restore.
+                other => {
+                    *pending = other;
+                    return;
+                }
+            }
+        };
+
+        // Leave anything the user copied since the paste. Windows may have
+        // converted the line endings.
+        let still_ours = Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .is_ok_and(|current| current.replace("\r\n", "\n") == text.replace("\r\n", "\n"));
+        if !still_ours {
+            return;
+        }
+        if let Err(err) = snapshot.restore() {
+            eprintln!("[clipboard] {err}");
+        }
+    });
+
+    Ok(())
+}
+
+/// Waits until the focused field shows `text`, which means the app has read
+/// the clipboard, and learns how long that took. Apps whose fields cannot be
+/// read get the delay learned earlier, or the default.
+fn wait_for_read(text: &str, before: &FocusSnapshot, pasted_at: Instant) {
+    let app_name = before.app_name.as_deref();
+    let fallback = restore_timings()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .restore_delay(app_name);
+
+    if before.field_text.is_some() {
+        while pasted_at.elapsed() < MAX_CLIPBOARD_RESTORE_DELAY {
+            let after = super::injection::focus_snapshot();
+            if after.field_text.is_none() || after.app_name != before.app_name {
+                break;
+            }
+            if assess_injection(text, before, &after).0 == InjectionOutcome::Verified {
+                if let Some(app_name) = app_name {
+                    restore_timings()
+                        .lock()
+                        .unwrap_or_else(|err| err.into_inner())
+                        .record_read(app_name, pasted_at.elapsed());
+                }
+                thread::sleep(READ_GRACE);
+                return;
+            }
+            thread::sleep(READ_POLL_INTERVAL);
+        }
+    }
+
+    thread::sleep(fallback.saturating_sub(pasted_at.elapsed()));
+}
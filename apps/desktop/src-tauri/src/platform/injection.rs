@@ -0,0 +1,141 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::domain::{
+    assess_injection, typing_chunks, FocusSnapshot, InjectionOptions, InjectionOutcome,
+    InjectionReport, InjectionStrategy, TYPING_CHUNK_CHARS,
+};
+
+/// How long to wait for the text to show up in the focused field.
+const VERIFY_TIMEOUT: Duration = Duration::from_millis(600);
+const VERIFY_INTERVAL: Duration = Duration::from_millis(100);
+
+pub(crate) fn focus_snapshot() -> FocusSnapshot {
+    FocusSnapshot {
+        app_name: super::app_info::get_focused_app_name()
+            .map_err(|err| eprintln!("[injection] Focused app unavailable: {err}"))
+            .ok(),
+        field_text: super::accessibility::get_text_field_info().text_content,
+    }
+}
+
+fn copy_to_clipboard(text: &str, html: Option<&str>) -> Result<(), String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|err| format!("clipboard unavailable: {err}"))?;
+    match html {
+        Some(html) => clipboard.set_html(html, Some(text)),
+        None => clipboard.set_text(text),
+    }
+    .map_err(|err| format!("failed to store clipboard text: {err}"))
+}
+
+#[cfg(target_os = "linux")]
+fn paste_via_primary_selection(text: &str) -> Result<(), String> {
+    super::input::paste_via_primary_selection(text)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn paste_via_primary_selection(_text: &str) -> Result<(), String> {
+    Err("the primary selection is only available on Linux".to_string())
+}
+
+/// Inserts `text` with `strategy`, returning the strategy that actually ran.
+fn run_strategy(
+    text: &str,
+    strategy: InjectionStrategy,
+    options: &InjectionOptions,
+) -> Result<InjectionStrategy, String> {
+    match strategy {
+        InjectionStrategy::Clipboard => match options.html.as_deref() {
+            Some(html) => super::input::paste_rich_text_into_focused_field(
+                text,
+                html,
+                options.keybind.as_deref(),
+            )?,
+            None => super::input::paste_text_into_focused_field(text, options.keybind.as_deref())?,
+        },
+        InjectionStrategy::Typing => super::input::type_text(text, options.typing_delay())?,
+        InjectionStrategy::ChunkedTyping => {
+            for (index, chunk) in typing_chunks(text, TYPING_CHUNK_CHARS).iter().enumerate() {
+                if index > 0 {
+                    thread::sleep(options.typing_delay());
+                }
+                super::input::type_text(chunk, Duration::ZERO)?;
+            }
+        }
+        InjectionStrategy::PrimarySelection => {
+            if let Err(err) = paste_via_primary_selection(text) {
+                eprintln!("[injection] Primary selection paste failed ({err}), using clipboard");
+                return run_strategy(text, InjectionStrategy::Clipboard, options);
+            }
+        }
+        InjectionStrategy::ClipboardOnly => copy_to_clipboard(text, options.html.as_deref())?,
+    }
+
+    Ok(strategy)
+}
+
+/// Inserts `text` into the focused field and checks that it arrived. Apps
+/// that do not expose their text through accessibility APIs can only be
+/// checked for a change of focus, so they report
+/// [`InjectionOutcome::Unverified`]. A failed insert leaves the text on the
+/// clipboard.
+pub fn inject_text(text: &str, options: &InjectionOptions) -> Result<InjectionReport, String> {
+    if text.trim().is_empty() {
+        return Ok(InjectionReport {
+            strategy: options.strategy,
+            outcome: InjectionOutcome::Unverified,
+            app_name: None,
+            message: None,
+        });
+    }
+
+    let before = focus_snapshot();
+    let strategy = run_strategy(text, options.strategy, options)?;
+
+    if strategy == InjectionStrategy::ClipboardOnly {
+        return Ok(InjectionReport {
+            strategy,
+            outcome: InjectionOutcome::Copied,
+            app_name: before.app_name,
+            message: None,
+        });
+    }
+
+    let deadline = Instant::now() + VERIFY_TIMEOUT;
+    let (after, outcome, message) = loop {
+        let after = focus_snapshot();
+        let (outcome, message) = assess_injection(text, &before, &after);
+        if outcome == InjectionOutcome::Verified
+            || after.field_text.is_none()
+            || Instant::now() >= deadline
+        {
+            break (after, outcome, message);
+        }
+        thread::sleep(VERIFY_INTERVAL);
+    };
+
+    let message = message.map(|message| {
+        eprintln!(
+            "[injection] {} insert into {:?} failed: {message}",
+            strategy.as_str(),
+            before.app_name
+        );
+        match copy_to_clipboard(text, options.html.as_deref()) {
+            Ok(()) => format!("{message}. The text is on the clipboard."),
+            Err(err) => {
+                eprintln!("[injection] Failed to copy the text after a failed insert: {err}");
+                message
+            }
+        }
+    });
+
+    Ok(InjectionReport {
+        strategy,
+        outcome,
+        app_name: after.app_name,
+        message,
+    })
+}
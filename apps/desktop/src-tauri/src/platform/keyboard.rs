@@ -1,5 +1,6 @@
 use crate::domain::{
-    KeyboardListenerErrorPayload, KeysHeldPayload, EVT_KEYBOARD_LISTENER_ERROR, EVT_KEYS_HELD,
+    Hotkey, KeySource, KeyboardListenerErrorPayload, KeysHeldPayload, EVT_KEYBOARD_LISTENER_ERROR,
+    EVT_KEYS_HELD,
 };
 use rdev::{Event, EventType, Key as RdevKey};
 use std::collections::HashSet;
@@ -101,6 +102,14 @@ struct ListenerHandle {
     join_handle: JoinHandle<()>,
     running: Arc<AtomicBool>,
     emitter: Arc<KeyEventEmitter>,
+    source: KeySource,
+}
+
+/// What the listener child process is started with.
+struct ChildConfig {
+    source: KeySource,
+    /// JSON hotkeys for sources that register shortcuts up front.
+    hotkeys_json: String,
 }
 
 fn listener_state() -> &'static Mutex<Option<ListenerHandle>> {
@@ -112,23 +121,57 @@ fn keys_payload(keys: Vec<String>) -> KeysHeldPayload {
     KeysHeldPayload { keys }
 }
 
-pub fn start_key_listener(app: &AppHandle) -> Result<(), String> {
+async fn detect_key_source() -> KeySource {
+    #[cfg(target_os = "linux")]
+    {
+        super::linux::session::detect_key_source().await
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        KeySource::Rdev
+    }
+}
+
+/// Starts listening for keys and returns the source in use. `hotkeys` are
+/// registered with sources that only report bound shortcuts.
+pub async fn start_key_listener(
+    app: &AppHandle,
+    hotkeys: Vec<Hotkey>,
+) -> Result<KeySource, String> {
+    let source = detect_key_source().await;
+    let hotkeys_json = serde_json::to_string(&hotkeys)
+        .map_err(|err| format!("failed to serialize hotkeys: {err}"))?;
+
     stop_key_listener()?;
 
     let mut state = listener_state()
         .lock()
         .unwrap_or_else(|poisoned| poisoned.into_inner());
 
-    eprintln!("Starting keyboard listener");
+    eprintln!("Starting keyboard listener ({})", source.as_str());
     let emitter = Arc::new(KeyEventEmitter::new(app));
-    let (join_handle, running) = start_external_listener(emitter.clone())?;
+    let config = ChildConfig {
+        source,
+        hotkeys_json,
+    };
+    let (join_handle, running) = start_external_listener(emitter.clone(), config)?;
     *state = Some(ListenerHandle {
         join_handle,
         running,
         emitter,
+        source,
     });
 
-    Ok(())
+    Ok(source)
+}
+
+/// The source of the running listener, if any.
+pub fn key_listener_source() -> Option<KeySource> {
+    listener_state()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .map(|handle| handle.source)
 }
 
 pub fn stop_key_listener() -> Result<(), String> {
@@ -152,13 +195,23 @@ pub fn stop_key_listener() -> Result<(), String> {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-enum WireEventKind {
+pub(crate) enum WireEventKind {
     Press,
     Release,
 }
 
+impl WireEventKind {
+    fn from_pressed(is_pressed: bool) -> Self {
+        if is_pressed {
+            Self::Press
+        } else {
+            Self::Release
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct KeyboardEventPayload {
+pub(crate) struct KeyboardEventPayload {
     kind: WireEventKind,
     key_label: String,
     raw_code: Option<u32>,
@@ -166,6 +219,30 @@ struct KeyboardEventPayload {
     scan_code: u32,
 }
 
+impl KeyboardEventPayload {
+    pub(crate) fn for_key(key: RdevKey, is_pressed: bool, scan_code: u32) -> Self {
+        Self {
+            kind: WireEventKind::from_pressed(is_pressed),
+            key_label: key_to_label(key),
+            raw_code: key_raw_code(key),
+            scan_code,
+        }
+    }
+
+    /// An event for a key named as in stored hotkeys, e.g. "ControlLeft".
+    pub(crate) fn for_label(label: &str, is_pressed: bool) -> Self {
+        Self {
+            kind: WireEventKind::from_pressed(is_pressed),
+            key_label: label.to_string(),
+            raw_code: parse_unknown_label(label),
+            scan_code: 0,
+        }
+    }
+}
+
+/// Where key sources in the listener process send their events.
+pub(crate) type KeyEventSink = Arc<dyn Fn(KeyboardEventPayload) + Send + Sync>;
+
 fn debug_keys_enabled() -> bool {
     static DEBUG: OnceLock<bool> = OnceLock::new();
     *DEBUG.get_or_init(|| matches!(env::var("VOQUILL_DEBUG_KEYS"), Ok(value) if value == "1"))
@@ -178,6 +255,7 @@ fn child_store() -> &'static Mutex<Option<Child>> {
 
 fn start_external_listener(
     emitter: Arc<KeyEventEmitter>,
+    config: ChildConfig,
 ) -> Result<(JoinHandle<()>, Arc<AtomicBool>), String> {
     let listener = TcpListener::bind(("127.0.0.1", 0))
         .map_err(|err| format!("failed to bind keyboard listener socket: {err}"))?;
@@ -194,7 +272,7 @@ fn start_external_listener(
     let thread_emitter = emitter.clone();
 
     let handle = thread::spawn(move || {
-        run_listener_thread(listener, port, thread_running, thread_emitter);
+        run_listener_thread(listener, port, &config, thread_running, thread_emitter);
     });
 
     Ok((handle, running))
@@ -206,6 +284,7 @@ const BACKOFF_MAX_MS: u64 = 5000;
 fn run_listener_thread(
     listener: TcpListener,
     port: u16,
+    config: &ChildConfig,
     running: Arc<AtomicBool>,
     emitter: Arc<KeyEventEmitter>,
 ) {
@@ -213,7 +292,7 @@ fn run_listener_thread(
     let mut backoff_ms: u64 = BACKOFF_INITIAL_MS;
 
     while running.load(Ordering::SeqCst) {
-        if let Err(err) = ensure_listener_child(port) {
+        if let Err(err) = ensure_listener_child(port, config) {
             consecutive_failures = consecutive_failures.saturating_add(1);
             eprintln!(
                 "Keyboard listener child error (attempt {}): {err}",
@@ -249,7 +328,7 @@ fn run_listener_thread(
 
     stop_listener_child();
 }
-fn spawn_listener_child(port: u16) -> Result<Child, String> {
+fn spawn_listener_child(port: u16, config: &ChildConfig) -> Result<Child, String> {
     let exe = std::env::current_exe()
         .map_err(|err| format!("failed to resolve current executable: {err}"))?;
 
@@ -257,6 +336,8 @@ fn spawn_listener_child(port: u16) -> Result<Child, String> {
     command
         .env("VOQUILL_KEYBOARD_LISTENER", "1")
         .env("VOQUILL_KEYBOARD_PORT", port.to_string())
+        .env("VOQUILL_KEYBOARD_SOURCE", config.source.as_str())
+        .env("VOQUILL_KEYBOARD_HOTKEYS", &config.hotkeys_json)
         .stdin(Stdio::null())
         .stdout(Stdio::null())
         .stderr(Stdio::inherit());
@@ -273,7 +354,7 @@ fn spawn_listener_child(port: u16) -> Result<Child, String> {
         .map_err(|err| format!("failed to spawn keyboard listener process: {err}"))
 }
 
-fn ensure_listener_child(port: u16) -> Result<(), String> {
+fn ensure_listener_child(port: u16, config: &ChildConfig) -> Result<(), String> {
     let should_spawn = {
         let mut guard = child_store()
             .lock()
@@ -303,7 +384,7 @@ fn ensure_listener_child(port: u16) -> Result<(), String> {
         return Ok(());
     }
 
-    let child = spawn_listener_child(port)?;
+    let child = spawn_listener_child(port, config)?;
     let mut guard = child_store()
         .lock()
         .unwrap_or_else(|poisoned| poisoned.into_inner());
@@ -437,43 +518,59 @@ pub fn run_listener_process() -> Result<(), String> {
         .set_nodelay(true)
         .map_err(|err| format!("failed to configure listener socket: {err}"))?;
 
-    let writer = Arc::new(Mutex::new(BufWriter::new(stream)));
-
-    let result = rdev::listen({
-        let writer = writer.clone();
-        move |event| {
-            let payload = match event.event_type {
-                EventType::KeyPress(key) => Some(KeyboardEventPayload {
-                    kind: WireEventKind::Press,
-                    key_label: key_to_label(key),
-                    raw_code: key_raw_code(key),
-                    scan_code: event.position_code,
-                }),
-                EventType::KeyRelease(key) => Some(KeyboardEventPayload {
-                    kind: WireEventKind::Release,
-                    key_label: key_to_label(key),
-                    raw_code: key_raw_code(key),
-                    scan_code: event.position_code,
-                }),
-                _ => None,
-            };
-
-            if let Some(payload) = payload {
-                if let Ok(json) = serde_json::to_string(&payload) {
-                    if let Ok(mut guard) = writer.lock() {
-                        if let Err(err) = writeln!(guard, "{json}") {
-                            eprintln!("Keyboard listener write error: {err}");
-                            std::process::exit(1);
-                        }
-                        if let Err(err) = guard.flush() {
-                            eprintln!("Keyboard listener flush error: {err}");
-                            std::process::exit(1);
-                        }
-                    }
-                }
+    let writer = Mutex::new(BufWriter::new(stream));
+    let sink: KeyEventSink = Arc::new(move |payload: KeyboardEventPayload| {
+        let Ok(json) = serde_json::to_string(&payload) else {
+            return;
+        };
+        if let Ok(mut guard) = writer.lock() {
+            if let Err(err) = writeln!(guard, "{json}") {
+                eprintln!("Keyboard listener write error: {err}");
+                std::process::exit(1);
+            }
+            if let Err(err) = guard.flush() {
+                eprintln!("Keyboard listener flush error: {err}");
+                std::process::exit(1);
             }
         }
     });
 
+    let source = env::var("VOQUILL_KEYBOARD_SOURCE")
+        .ok()
+        .and_then(|value| KeySource::parse(&value))
+        .unwrap_or(KeySource::Rdev);
+    eprintln!("Keyboard listener reading keys through {}", source.as_str());
+
+    #[cfg(target_os = "linux")]
+    match source {
+        KeySource::Evdev => return super::linux::evdev_keys::listen(sink),
+        KeySource::Portal => {
+            let hotkeys: Vec<Hotkey> = env::var("VOQUILL_KEYBOARD_HOTKEYS")
+                .ok()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default();
+            return super::linux::global_shortcuts::listen(hotkeys, sink);
+        }
+        KeySource::Rdev => {}
+    }
+
+    listen_with_rdev(sink)
+}
+
+fn listen_with_rdev(sink: KeyEventSink) -> Result<(), String> {
+    let result = rdev::listen(move |event| match event.event_type {
+        EventType::KeyPress(key) => sink(KeyboardEventPayload::for_key(
+            key,
+            true,
+            event.position_code,
+        )),
+        EventType::KeyRelease(key) => sink(KeyboardEventPayload::for_key(
+            key,
+            false,
+            event.position_code,
+        )),
+        _ => {}
+    });
+
     result.map_err(|err| format!("keyboard listener error: {err:?}"))
 }
@@ -1,6 +1,5 @@
 use crate::commands::{ScreenContextInfo, TextFieldInfo};
 use arboard::Clipboard;
-use enigo::{Enigo, Key, KeyboardControllable};
 use std::{thread, time::Duration};
 
 pub fn get_text_field_info() -> TextFieldInfo {
@@ -21,32 +20,25 @@ pub fn get_screen_context() -> ScreenContextInfo {
 
 pub fn get_selected_text() -> Option<String> {
     let mut clipboard = Clipboard::new().ok()?;
-    let previous = clipboard.get_text().ok();
+    let previous = crate::platform::clipboard::ClipboardSnapshot::capture();
+    // With nothing selected the copy leaves the clipboard alone, which would
+    // otherwise read back as a selection.
+    let _ = clipboard.clear();
 
-    let mut enigo = Enigo::new();
-    enigo.key_up(Key::Shift);
-    enigo.key_up(Key::Control);
-    enigo.key_up(Key::Alt);
-    thread::sleep(Duration::from_millis(30));
-
-    enigo.key_down(Key::Control);
-    enigo.key_down(Key::Layout('c'));
-    thread::sleep(Duration::from_millis(15));
-    enigo.key_up(Key::Layout('c'));
-    enigo.key_up(Key::Control);
+    if let Err(err) = super::input::press_control('c') {
+        eprintln!("[linux::accessibility] {err}");
+    }
 
     thread::sleep(Duration::from_millis(50));
 
     let selected = clipboard.get_text().ok();
 
-    if let Some(old) = previous {
-        thread::spawn(move || {
-            thread::sleep(Duration::from_millis(100));
-            if let Ok(mut cb) = Clipboard::new() {
-                let _ = cb.set_text(old);
-            }
-        });
-    }
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        if let Err(err) = previous.restore() {
+            eprintln!("[linux::accessibility] {err}");
+        }
+    });
 
     selected.filter(|s| !s.is_empty())
 }
@@ -0,0 +1,246 @@
+use evdev::{Device, EventSummary, KeyCode};
+use rdev::Key as RdevKey;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::platform::keyboard::{KeyEventSink, KeyboardEventPayload};
+
+/// How often to look for keyboards plugged in after the listener started.
+const RESCAN_INTERVAL: Duration = Duration::from_secs(2);
+const KEY_RELEASED: i32 = 0;
+const KEY_PRESSED: i32 = 1;
+/// rdev on X11 reports unknown keys by X keycode, which is offset from evdev.
+const X11_KEYCODE_OFFSET: u32 = 8;
+
+/// Input devices that look like keyboards rather than mice or power buttons.
+fn keyboards() -> Vec<(PathBuf, Device)> {
+    evdev::enumerate()
+        .filter(|(_, device)| {
+            device.supported_keys().is_some_and(|keys| {
+                keys.contains(KeyCode::KEY_A) && keys.contains(KeyCode::KEY_ENTER)
+            })
+        })
+        .collect()
+}
+
+/// Whether this user can read keyboards directly, usually through membership
+/// of the `input` group.
+pub(crate) fn is_available() -> bool {
+    !keyboards().is_empty()
+}
+
+/// Reads every keyboard until the process is stopped, picking up keyboards
+/// plugged in later. Devices are not grabbed, so apps still get every key.
+pub(crate) fn listen(sink: KeyEventSink) -> Result<(), String> {
+    let open_paths: Arc<Mutex<HashSet<PathBuf>>> = Arc::new(Mutex::new(HashSet::new()));
+
+    loop {
+        let devices = keyboards();
+        let mut guard = open_paths
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if devices.is_empty() && guard.is_empty() {
+            return Err("no readable keyboards in /dev/input".to_string());
+        }
+
+        for (path, device) in devices {
+            if !guard.insert(path.clone()) {
+                continue;
+            }
+            eprintln!(
+                "[keys] Reading {} ({})",
+                device.name().unwrap_or("keyboard"),
+                path.display()
+            );
+            let sink = sink.clone();
+            let open_paths = open_paths.clone();
+            thread::spawn(move || {
+                if let Err(err) = read_device(device, &sink) {
+                    eprintln!("[keys] Stopped reading {}: {err}", path.display());
+                }
+                open_paths
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .remove(&path);
+            });
+        }
+        drop(guard);
+
+        thread::sleep(RESCAN_INTERVAL);
+    }
+}
+
+fn read_device(mut device: Device, sink: &KeyEventSink) -> Result<(), String> {
+    loop {
+        let events = device.fetch_events().map_err(|err| err.to_string())?;
+        for event in events {
+            let EventSummary::Key(_, code, value) = event.destructure() else {
+                continue;
+            };
+            // Auto-repeat (value 2) would only re-press a held key.
+            let is_pressed = match value {
+                KEY_PRESSED => true,
+                KEY_RELEASED => false,
+                _ => continue,
+            };
+            let key = rdev_key(code).unwrap_or(RdevKey::Unknown(
+                u32::from(code.code()) + X11_KEYCODE_OFFSET,
+            ));
+            sink(KeyboardEventPayload::for_key(
+                key,
+                is_pressed,
+                code.code().into(),
+            ));
+        }
+    }
+}
+
+/// The key rdev would report for `code` on a US layout, so hotkeys recorded
+/// under X11 keep matching.
+fn rdev_key(code: KeyCode) -> Option<RdevKey> {
+    let key = match code {
+        KeyCode::KEY_ESC => RdevKey::Escape,
+        KeyCode::KEY_1 => RdevKey::Num1,
+        KeyCode::KEY_2 => RdevKey::Num2,
+        KeyCode::KEY_3 => RdevKey::Num3,
+        KeyCode::KEY_4 => RdevKey::Num4,
+        KeyCode::KEY_5 => RdevKey::Num5,
+        KeyCode::KEY_6 => RdevKey::Num6,
+        KeyCode::KEY_7 => RdevKey::Num7,
+        KeyCode::KEY_8 => RdevKey::Num8,
+        KeyCode::KEY_9 => RdevKey::Num9,
+        KeyCode::KEY_0 => RdevKey::Num0,
+        KeyCode::KEY_MINUS => RdevKey::Minus,
+        KeyCode::KEY_EQUAL => RdevKey::Equal,
+        KeyCode::KEY_BACKSPACE => RdevKey::Backspace,
+        KeyCode::KEY_TAB => RdevKey::Tab,
+        KeyCode::KEY_Q => RdevKey::KeyQ,
+        KeyCode::KEY_W => RdevKey::KeyW,
+        KeyCode::KEY_E => RdevKey::KeyE,
+        KeyCode::KEY_R => RdevKey::KeyR,
+        KeyCode::KEY_T => RdevKey::KeyT,
+        KeyCode::KEY_Y => RdevKey::KeyY,
+        KeyCode::KEY_U => RdevKey::KeyU,
+        KeyCode::KEY_I => RdevKey::KeyI,
+        KeyCode::KEY_O => RdevKey::KeyO,
+        KeyCode::KEY_P => RdevKey::KeyP,
+        KeyCode::KEY_LEFTBRACE => RdevKey::LeftBracket,
+        KeyCode::KEY_RIGHTBRACE => RdevKey::RightBracket,
+        KeyCode::KEY_ENTER => RdevKey::Return,
+        KeyCode::KEY_LEFTCTRL => RdevKey::ControlLeft,
+        KeyCode::KEY_A => RdevKey::KeyA,
+        KeyCode::KEY_S => RdevKey::KeyS,
+        KeyCode::KEY_D => RdevKey::KeyD,
+        KeyCode::KEY_F => RdevKey::KeyF,
+        KeyCode::KEY_G => RdevKey::KeyG,
+        KeyCode::KEY_H => RdevKey::KeyH,
+        KeyCode::KEY_J => RdevKey::KeyJ,
+        KeyCode::KEY_K => RdevKey::KeyK,
+        KeyCode::KEY_L => RdevKey::KeyL,
+        KeyCode::KEY_SEMICOLON => RdevKey::SemiColon,
+        KeyCode::KEY_APOSTROPHE => RdevKey::Quote,
+        KeyCode::KEY_GRAVE => RdevKey::BackQuote,
+        KeyCode::KEY_LEFTSHIFT => RdevKey::ShiftLeft,
+        KeyCode::KEY_BACKSLASH => RdevKey::BackSlash,
+        KeyCode::KEY_Z => RdevKey::KeyZ,
+        KeyCode::KEY_X => RdevKey::KeyX,
+        KeyCode::KEY_C => RdevKey::KeyC,
+        KeyCode::KEY_V => RdevKey::KeyV,
+        KeyCode::KEY_B => RdevKey::KeyB,
+        KeyCode::KEY_N => RdevKey::KeyN,
+        KeyCode::KEY_M => RdevKey::KeyM,
+        KeyCode::KEY_COMMA => RdevKey::Comma,
+        KeyCode::KEY_DOT => RdevKey::Dot,
+        KeyCode::KEY_SLASH => RdevKey::Slash,
+        KeyCode::KEY_RIGHTSHIFT => RdevKey::ShiftRight,
+        KeyCode::KEY_KPASTERISK => RdevKey::KpMultiply,
+        KeyCode::KEY_LEFTALT => RdevKey::Alt,
+        KeyCode::KEY_SPACE => RdevKey::Space,
+        KeyCode::KEY_CAPSLOCK => RdevKey::CapsLock,
+        KeyCode::KEY_F1 => RdevKey::F1,
+        KeyCode::KEY_F2 => RdevKey::F2,
+        KeyCode::KEY_F3 => RdevKey::F3,
+        KeyCode::KEY_F4 => RdevKey::F4,
+        KeyCode::KEY_F5 => RdevKey::F5,
+        KeyCode::KEY_F6 => RdevKey::F6,
+        KeyCode::KEY_F7 => RdevKey::F7,
+        KeyCode::KEY_F8 => RdevKey::F8,
+        KeyCode::KEY_F9 => RdevKey::F9,
+        KeyCode::KEY_F10 => RdevKey::F10,
+        KeyCode::KEY_NUMLOCK => RdevKey::NumLock,
+        KeyCode::KEY_SCROLLLOCK => RdevKey::ScrollLock,
+        KeyCode::KEY_KP7 => RdevKey::Kp7,
+        KeyCode::KEY_KP8 => RdevKey::Kp8,
+        KeyCode::KEY_KP9 => RdevKey::Kp9,
+        KeyCode::KEY_KPMINUS => RdevKey::KpMinus,
+        KeyCode::KEY_KP4 => RdevKey::Kp4,
+        KeyCode::KEY_KP5 => RdevKey::Kp5,
+        KeyCode::KEY_KP6 => RdevKey::Kp6,
+        KeyCode::KEY_KPPLUS => RdevKey::KpPlus,
+        KeyCode::KEY_KP1 => RdevKey::Kp1,
+        KeyCode::KEY_KP2 => RdevKey::Kp2,
+        KeyCode::KEY_KP3 => RdevKey::Kp3,
+        KeyCode::KEY_KP0 => RdevKey::Kp0,
+        KeyCode::KEY_KPDOT => RdevKey::KpDelete,
+        KeyCode::KEY_102ND => RdevKey::IntlBackslash,
+        KeyCode::KEY_F11 => RdevKey::F11,
+        KeyCode::KEY_F12 => RdevKey::F12,
+        KeyCode::KEY_KPENTER => RdevKey::KpReturn,
+        KeyCode::KEY_RIGHTCTRL => RdevKey::ControlRight,
+        KeyCode::KEY_KPSLASH => RdevKey::KpDivide,
+        KeyCode::KEY_SYSRQ => RdevKey::PrintScreen,
+        KeyCode::KEY_RIGHTALT => RdevKey::AltGr,
+        KeyCode::KEY_HOME => RdevKey::Home,
+        KeyCode::KEY_UP => RdevKey::UpArrow,
+        KeyCode::KEY_PAGEUP => RdevKey::PageUp,
+        KeyCode::KEY_LEFT => RdevKey::LeftArrow,
+        KeyCode::KEY_RIGHT => RdevKey::RightArrow,
+        KeyCode::KEY_END => RdevKey::End,
+        KeyCode::KEY_DOWN => RdevKey::DownArrow,
+        KeyCode::KEY_PAGEDOWN => RdevKey::PageDown,
+        KeyCode::KEY_INSERT => RdevKey::Insert,
+        KeyCode::KEY_DELETE => RdevKey::Delete,
+        KeyCode::KEY_PAUSE => RdevKey::Pause,
+        KeyCode::KEY_LEFTMETA => RdevKey::MetaLeft,
+        KeyCode::KEY_RIGHTMETA => RdevKey::MetaRight,
+        _ => return None,
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_keys_like_rdev() {
+        assert_eq!(rdev_key(KeyCode::KEY_A), Some(RdevKey::KeyA));
+        assert_eq!(rdev_key(KeyCode::KEY_1), Some(RdevKey::Num1));
+        assert_eq!(rdev_key(KeyCode::KEY_ENTER), Some(RdevKey::Return));
+        assert_eq!(rdev_key(KeyCode::KEY_SPACE), Some(RdevKey::Space));
+        assert_eq!(rdev_key(KeyCode::KEY_F12), Some(RdevKey::F12));
+        assert_eq!(rdev_key(KeyCode::KEY_UP), Some(RdevKey::UpArrow));
+    }
+
+    #[test]
+    fn maps_modifiers_by_side() {
+        assert_eq!(rdev_key(KeyCode::KEY_LEFTCTRL), Some(RdevKey::ControlLeft));
+        assert_eq!(
+            rdev_key(KeyCode::KEY_RIGHTCTRL),
+            Some(RdevKey::ControlRight)
+        );
+        assert_eq!(rdev_key(KeyCode::KEY_LEFTALT), Some(RdevKey::Alt));
+        assert_eq!(rdev_key(KeyCode::KEY_RIGHTALT), Some(RdevKey::AltGr));
+        assert_eq!(rdev_key(KeyCode::KEY_LEFTMETA), Some(RdevKey::MetaLeft));
+        assert_eq!(rdev_key(KeyCode::KEY_RIGHTMETA), Some(RdevKey::MetaRight));
+    }
+
+    #[test]
+    fn leaves_unmapped_keys_to_the_caller() {
+        assert_eq!(rdev_key(KeyCode::KEY_VOLUMEUP), None);
+        assert_eq!(rdev_key(KeyCode::KEY_F13), None);
+    }
+}
@@ -0,0 +1,220 @@
+use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+use futures_util::{stream, StreamExt};
+use std::collections::HashMap;
+use std::pin::pin;
+
+use crate::domain::Hotkey;
+use crate::platform::keyboard::{KeyEventSink, KeyboardEventPayload};
+
+/// Whether the desktop portal offers global shortcuts (GNOME 48+, KDE).
+pub(crate) async fn is_available() -> bool {
+    GlobalShortcuts::new().await.is_ok()
+}
+
+/// The "shortcuts" XDG spec form of `keys`, such as `CTRL+SHIFT+l`.
+/// Modifier-only hotkeys trigger on their last modifier's own key, e.g.
+/// `LOGO+Control_L` for Super+Ctrl. Returns `None` for keys the spec cannot
+/// express, which the user then assigns in the portal's own dialog.
+fn preferred_trigger(keys: &[String]) -> Option<String> {
+    let mut modifiers = Vec::new();
+    let mut last_modifier = None;
+    let mut trigger = None;
+    for key in keys {
+        if let Some((modifier, keysym)) = modifier_name(key) {
+            if !modifiers.contains(&modifier) {
+                modifiers.push(modifier);
+            }
+            last_modifier = Some((modifier, keysym));
+            continue;
+        }
+        if trigger.is_some() {
+            return None;
+        }
+        trigger = Some(keysym_name(key)?);
+    }
+
+    let trigger = match trigger {
+        Some(trigger) => trigger,
+        None => {
+            let (modifier, keysym) = last_modifier?;
+            modifiers.retain(|other| *other != modifier);
+            keysym.to_string()
+        }
+    };
+    let mut parts: Vec<String> = modifiers.into_iter().map(str::to_string).collect();
+    parts.push(trigger);
+    Some(parts.join("+"))
+}
+
+/// The spec modifier for `label` and the keysym of the key itself.
+fn modifier_name(label: &str) -> Option<(&'static str, &'static str)> {
+    let names = match label {
+        "ControlLeft" => ("CTRL", "Control_L"),
+        "ControlRight" => ("CTRL", "Control_R"),
+        "ShiftLeft" => ("SHIFT", "Shift_L"),
+        "ShiftRight" => ("SHIFT", "Shift_R"),
+        "Alt" => ("ALT", "Alt_L"),
+        "AltGr" => ("ALT", "ISO_Level3_Shift"),
+        "MetaLeft" => ("LOGO", "Super_L"),
+        "MetaRight" => ("LOGO", "Super_R"),
+        _ => return None,
+    };
+    Some(names)
+}
+
+fn keysym_name(label: &str) -> Option<String> {
+    if let Some(letter) = label.strip_prefix("Key") {
+        return Some(letter.to_ascii_lowercase());
+    }
+    if let Some(digit) = label.strip_prefix("Num").filter(|rest| rest.len() == 1) {
+        return Some(digit.to_string());
+    }
+    if label.len() <= 3 && label.starts_with('F') && label[1..].parse::<u8>().is_ok() {
+        return Some(label.to_string());
+    }
+    let name = match label {
+        "Space" => "space",
+        "Return" => "Return",
+        "Escape" => "Escape",
+        "Tab" => "Tab",
+        "Backspace" => "BackSpace",
+        "UpArrow" => "Up",
+        "DownArrow" => "Down",
+        "LeftArrow" => "Left",
+        "RightArrow" => "Right",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// Binds `hotkeys` through the portal and reports each activation as its
+/// keys being pressed, and each deactivation as them being released, so the
+/// app sees the same events as from a key hook.
+pub(crate) fn listen(hotkeys: Vec<Hotkey>, sink: KeyEventSink) -> Result<(), String> {
+    if hotkeys.is_empty() {
+        return Err("no hotkeys to register with the desktop portal".to_string());
+    }
+    tauri::async_runtime::block_on(run(hotkeys, sink))
+}
+
+async fn run(hotkeys: Vec<Hotkey>, sink: KeyEventSink) -> Result<(), String> {
+    let portal = GlobalShortcuts::new()
+        .await
+        .map_err(|err| format!("global shortcuts portal unavailable: {err}"))?;
+    let session = portal
+        .create_session()
+        .await
+        .map_err(|err| format!("failed to create shortcuts session: {err}"))?;
+
+    let shortcuts: Vec<NewShortcut> = hotkeys
+        .iter()
+        .map(|hotkey| {
+            NewShortcut::new(hotkey.id.as_str(), hotkey.action_name.as_str())
+                .preferred_trigger(preferred_trigger(&hotkey.keys).as_deref())
+        })
+        .collect();
+    let bound = portal
+        .bind_shortcuts(&session, &shortcuts, None)
+        .await
+        .and_then(|request| request.response())
+        .map_err(|err| format!("failed to bind shortcuts: {err}"))?;
+    for shortcut in bound.shortcuts() {
+        eprintln!(
+            "[keys] Portal shortcut {} bound to {}",
+            shortcut.id(),
+            shortcut.trigger_description()
+        );
+    }
+
+    let keys_by_id: HashMap<String, Vec<String>> = hotkeys
+        .into_iter()
+        .map(|hotkey| (hotkey.id, hotkey.keys))
+        .collect();
+
+    let activated = portal
+        .receive_activated()
+        .await
+        .map_err(|err| format!("failed to watch shortcuts: {err}"))?
+        .map(|event| (event.shortcut_id().to_string(), true));
+    let deactivated = portal
+        .receive_deactivated()
+        .await
+        .map_err(|err| format!("failed to watch shortcuts: {err}"))?
+        .map(|event| (event.shortcut_id().to_string(), false));
+    let mut events = pin!(stream::select(activated, deactivated));
+
+    while let Some((id, is_pressed)) = events.next().await {
+        let Some(keys) = keys_by_id.get(&id) else {
+            continue;
+        };
+        if is_pressed {
+            for key in keys {
+                sink(KeyboardEventPayload::for_label(key, true));
+            }
+        } else {
+            for key in keys.iter().rev() {
+                sink(KeyboardEventPayload::for_label(key, false));
+            }
+        }
+    }
+
+    Err("the desktop portal closed the shortcuts session".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|label| label.to_string()).collect()
+    }
+
+    #[test]
+    fn names_keysyms() {
+        assert_eq!(keysym_name("KeyL").as_deref(), Some("l"));
+        assert_eq!(keysym_name("Num7").as_deref(), Some("7"));
+        assert_eq!(keysym_name("F1").as_deref(), Some("F1"));
+        assert_eq!(keysym_name("F12").as_deref(), Some("F12"));
+        assert_eq!(keysym_name("Space").as_deref(), Some("space"));
+        assert_eq!(keysym_name("Backspace").as_deref(), Some("BackSpace"));
+        assert_eq!(keysym_name("UpArrow").as_deref(), Some("Up"));
+        assert_eq!(keysym_name("Function"), None);
+        assert_eq!(keysym_name("Unknown(42)"), None);
+    }
+
+    #[test]
+    fn builds_triggers_with_modifiers() {
+        assert_eq!(
+            preferred_trigger(&keys(&["ControlLeft", "ShiftLeft", "KeyL"])).as_deref(),
+            Some("CTRL+SHIFT+l")
+        );
+        assert_eq!(
+            preferred_trigger(&keys(&["KeyL", "ControlLeft", "ControlRight"])).as_deref(),
+            Some("CTRL+l")
+        );
+        assert_eq!(preferred_trigger(&keys(&["F9"])).as_deref(), Some("F9"));
+    }
+
+    #[test]
+    fn modifier_only_hotkeys_trigger_on_the_last_modifier() {
+        assert_eq!(
+            preferred_trigger(&keys(&["MetaLeft", "ControlLeft"])).as_deref(),
+            Some("LOGO+Control_L")
+        );
+        assert_eq!(
+            preferred_trigger(&keys(&["ControlLeft", "MetaLeft"])).as_deref(),
+            Some("CTRL+Super_L")
+        );
+        assert_eq!(
+            preferred_trigger(&keys(&["AltGr"])).as_deref(),
+            Some("ISO_Level3_Shift")
+        );
+    }
+
+    #[test]
+    fn rejects_keys_the_spec_cannot_express() {
+        assert_eq!(preferred_trigger(&[]), None);
+        assert_eq!(preferred_trigger(&keys(&["KeyA", "KeyB"])), None);
+        assert_eq!(preferred_trigger(&keys(&["ControlLeft", "Function"])), None);
+    }
+}
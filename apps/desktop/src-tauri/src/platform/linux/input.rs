@@ -1,7 +1,30 @@
-use enigo::{Enigo, Key, KeyboardControllable};
+use arboard::{LinuxClipboardKind, SetExtLinux};
+use enigo::{Enigo, Key, KeyboardControllable, MouseButton, MouseControllable};
 use std::{env, thread, time::Duration};
 
+use super::session::{self, InputBackend, KeyAction};
+
+/// Pause between Backspace presses so slow apps don't drop any.
+const BACKSPACE_INTERVAL: Duration = Duration::from_millis(2);
+
 pub(crate) fn paste_text_into_focused_field(text: &str, keybind: Option<&str>) -> Result<(), String> {
+    paste_into_focused_field(text, None, keybind)
+}
+
+/// Pastes `html` where the app accepts formatted text and `text` elsewhere.
+pub(crate) fn paste_rich_text_into_focused_field(
+    text: &str,
+    html: &str,
+    keybind: Option<&str>,
+) -> Result<(), String> {
+    paste_into_focused_field(text, Some(html), keybind)
+}
+
+fn paste_into_focused_field(
+    text: &str,
+    html: Option<&str>,
+    keybind: Option<&str>,
+) -> Result<(), String> {
     if text.trim().is_empty() {
         return Ok(());
     }
@@ -13,57 +36,146 @@ pub(crate) fn paste_text_into_focused_field(text: &str, keybind: Option<&str>) -
         target.chars().count()
     );
 
-    paste_via_clipboard(target, keybind).or_else(|err| {
+    paste_via_clipboard(target, html, keybind).or_else(|err| {
         eprintln!("Clipboard paste failed ({err}). Falling back to simulated typing.");
-        let mut enigo = Enigo::new();
-        enigo.key_up(Key::Shift);
-        enigo.key_up(Key::Control);
-        enigo.key_up(Key::Alt);
-        thread::sleep(Duration::from_millis(30));
-        enigo.key_sequence(target);
-        Ok(())
+        send_keys(&KeyAction::Text(target), Duration::ZERO)
     })
 }
 
-fn paste_via_clipboard(text: &str, keybind: Option<&str>) -> Result<(), String> {
-    let mut clipboard =
-        arboard::Clipboard::new().map_err(|err| format!("clipboard unavailable: {err}"))?;
-    let previous = clipboard.get_text().ok();
-    clipboard
-        .set_text(text.to_string())
-        .map_err(|err| format!("failed to store clipboard text: {err}"))?;
+fn paste_via_clipboard(
+    text: &str,
+    html: Option<&str>,
+    keybind: Option<&str>,
+) -> Result<(), String> {
+    crate::platform::clipboard::paste_preserving_clipboard(text, html, || {
+        thread::sleep(Duration::from_millis(40));
 
-    thread::sleep(Duration::from_millis(40));
+        // Use configurable keybind or default to Ctrl+V
+        send_keys(
+            &KeyAction::Control {
+                key: 'v',
+                shift: keybind == Some("ctrl+shift+v"),
+            },
+            Duration::from_millis(15),
+        )
+    })
+}
 
+/// Presses Backspace `count` times in the focused field.
+pub(crate) fn delete_backward(count: usize) -> Result<(), String> {
+    send_keys(&KeyAction::Backspace(count), BACKSPACE_INTERVAL)
+}
+
+/// Types `text` one character at a time, pausing `interval` after each.
+/// uinput keys follow the user's layout, so under uinput the text is pasted
+/// through the clipboard instead and only typed if that fails.
+pub(crate) fn type_text(text: &str, interval: Duration) -> Result<(), String> {
+    if session::input_backend() == InputBackend::Uinput {
+        match paste_via_clipboard(text, None, None) {
+            Ok(()) => return Ok(()),
+            Err(err) => eprintln!("[linux::input] Clipboard paste failed ({err}). Typing instead."),
+        }
+    }
+    send_keys(&KeyAction::Text(text), interval)
+}
+
+/// Presses Ctrl plus `key`, e.g. Ctrl+C to copy the selection.
+pub(crate) fn press_control(key: char) -> Result<(), String> {
+    send_keys(
+        &KeyAction::Control { key, shift: false },
+        Duration::from_millis(15),
+    )
+}
+
+/// Sends `action` through the session's input backend. Wayland backends fall
+/// back to XTest, which still reaches XWayland apps.
+fn send_keys(action: &KeyAction, interval: Duration) -> Result<(), String> {
+    let result = match session::input_backend() {
+        InputBackend::VirtualKeyboard => super::virtual_keyboard::send(action, interval),
+        InputBackend::Uinput => super::uinput::send(action, interval),
+        InputBackend::X11 => return send_keys_x11(action, interval),
+    };
+    result.or_else(|err| {
+        eprintln!("[linux::input] Wayland input failed ({err}). Falling back to X11.");
+        send_keys_x11(action, interval)
+    })
+}
+
+fn send_keys_x11(action: &KeyAction, interval: Duration) -> Result<(), String> {
     let mut enigo = Enigo::new();
     enigo.key_up(Key::Shift);
     enigo.key_up(Key::Control);
     enigo.key_up(Key::Alt);
+    enigo.key_up(Key::Meta);
     thread::sleep(Duration::from_millis(30));
 
-    // Use configurable keybind or default to Ctrl+V
-    let use_shift = keybind == Some("ctrl+shift+v");
-
-    enigo.key_down(Key::Control);
-    if use_shift {
-        enigo.key_down(Key::Shift);
-    }
-    enigo.key_down(Key::Layout('v'));
-    thread::sleep(Duration::from_millis(15));
-    enigo.key_up(Key::Layout('v'));
-    if use_shift {
-        enigo.key_up(Key::Shift);
-    }
-    enigo.key_up(Key::Control);
-
-    if let Some(old) = previous {
-        thread::spawn(move || {
-            thread::sleep(Duration::from_millis(800));
-            if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                let _ = clipboard.set_text(old);
+    match action {
+        KeyAction::Text(text) if interval.is_zero() => enigo.key_sequence(text),
+        KeyAction::Text(text) => {
+            let mut buffer = [0u8; 4];
+            for ch in text.chars() {
+                enigo.key_sequence(ch.encode_utf8(&mut buffer));
+                thread::sleep(interval);
+            }
+        }
+        KeyAction::Control { key, shift } => {
+            enigo.key_down(Key::Control);
+            if *shift {
+                enigo.key_down(Key::Shift);
             }
-        });
+            enigo.key_down(Key::Layout(*key));
+            thread::sleep(interval);
+            enigo.key_up(Key::Layout(*key));
+            if *shift {
+                enigo.key_up(Key::Shift);
+            }
+            enigo.key_up(Key::Control);
+        }
+        KeyAction::Backspace(count) => {
+            for _ in 0..*count {
+                enigo.key_click(Key::Backspace);
+                thread::sleep(interval);
+            }
+        }
     }
 
     Ok(())
 }
+
+/// Puts `text` in the primary selection and middle-clicks to paste it.
+/// Most apps insert it under the mouse pointer, terminals at the cursor.
+pub(crate) fn paste_via_primary_selection(text: &str) -> Result<(), String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|err| format!("clipboard unavailable: {err}"))?;
+    clipboard
+        .set()
+        .clipboard(LinuxClipboardKind::Primary)
+        .text(text.to_string())
+        .map_err(|err| format!("failed to store primary selection: {err}"))?;
+
+    thread::sleep(Duration::from_millis(40));
+
+    middle_click();
+
+    // The selection is served from this process; keep it until the app has
+    // read it.
+    thread::sleep(Duration::from_millis(200));
+    drop(clipboard);
+
+    Ok(())
+}
+
+fn middle_click() {
+    if session::input_backend() != InputBackend::X11 && super::uinput::is_available() {
+        match super::uinput::middle_click() {
+            Ok(()) => return,
+            Err(err) => eprintln!("[linux::input] uinput middle click failed: {err}"),
+        }
+    }
+
+    let mut enigo = Enigo::new();
+    enigo.key_up(Key::Shift);
+    enigo.key_up(Key::Control);
+    enigo.key_up(Key::Alt);
+    enigo.mouse_click(MouseButton::Middle);
+}
@@ -1,8 +1,13 @@
 pub mod accessibility;
 pub mod audio;
+pub mod evdev_keys;
 pub mod feedback;
+pub mod global_shortcuts;
 pub mod input;
 pub mod monitor;
 pub mod permissions;
 pub mod position;
+pub mod session;
+pub mod uinput;
+pub mod virtual_keyboard;
 pub mod window;
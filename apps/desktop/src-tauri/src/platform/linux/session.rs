@@ -0,0 +1,209 @@
+use crate::domain::KeySource;
+use std::{env, sync::OnceLock};
+
+/// Keyboard input the injection backends know how to send.
+pub(crate) enum KeyAction<'a> {
+    /// Types text as if entered on a keyboard.
+    Text(&'a str),
+    /// Ctrl plus a letter, with Shift when `shift` is set, e.g. Ctrl+Shift+V.
+    Control {
+        key: char,
+        shift: bool,
+    },
+    Backspace(usize),
+}
+
+/// How keystrokes reach other apps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum InputBackend {
+    /// The zwp_virtual_keyboard_v1 Wayland protocol (wlroots, KDE).
+    VirtualKeyboard,
+    /// A kernel uinput device, for Wayland compositors without virtual
+    /// keyboards such as GNOME.
+    Uinput,
+    /// XTest through X11 or XWayland.
+    X11,
+}
+
+impl InputBackend {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::VirtualKeyboard => "virtual-keyboard",
+            Self::Uinput => "uinput",
+            Self::X11 => "x11",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "virtual-keyboard" | "wayland" => Some(Self::VirtualKeyboard),
+            "uinput" => Some(Self::Uinput),
+            "x11" => Some(Self::X11),
+            _ => None,
+        }
+    }
+}
+
+fn non_empty_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.trim().is_empty())
+}
+
+pub(crate) fn is_wayland_session() -> bool {
+    non_empty_var("XDG_SESSION_TYPE").is_some_and(|kind| kind.eq_ignore_ascii_case("wayland"))
+        || non_empty_var("WAYLAND_DISPLAY").is_some()
+}
+
+fn detect_input_backend() -> InputBackend {
+    choose_input_backend(
+        non_empty_var("VOQUILL_INPUT_BACKEND").as_deref(),
+        is_wayland_session(),
+        super::virtual_keyboard::is_available,
+        super::uinput::is_available,
+    )
+}
+
+/// Picks a backend from the `VOQUILL_INPUT_BACKEND` override and what the
+/// session offers. The probes only run when they can change the answer.
+fn choose_input_backend(
+    requested: Option<&str>,
+    wayland: bool,
+    virtual_keyboard_available: impl FnOnce() -> bool,
+    uinput_available: impl FnOnce() -> bool,
+) -> InputBackend {
+    if let Some(value) = requested {
+        match InputBackend::parse(value) {
+            Some(backend) => return backend,
+            None => eprintln!("[linux::input] Unknown VOQUILL_INPUT_BACKEND {value:?}"),
+        }
+    }
+
+    if !wayland {
+        return InputBackend::X11;
+    }
+    if virtual_keyboard_available() {
+        return InputBackend::VirtualKeyboard;
+    }
+    if uinput_available() {
+        return InputBackend::Uinput;
+    }
+
+    eprintln!(
+        "[linux::input] No Wayland input backend: the compositor has no virtual keyboard \
+         protocol and /dev/uinput is not writable. Only XWayland apps will receive text."
+    );
+    InputBackend::X11
+}
+
+/// The backend for this session, detected on first use.
+pub(crate) fn input_backend() -> InputBackend {
+    static BACKEND: OnceLock<InputBackend> = OnceLock::new();
+    *BACKEND.get_or_init(|| {
+        let backend = detect_input_backend();
+        eprintln!("[linux::input] Using {} input backend", backend.as_str());
+        backend
+    })
+}
+
+/// Picks where hotkeys are read from. Under Wayland rdev only sees keys
+/// typed into XWayland windows, so raw input devices are preferred, then the
+/// desktop portal.
+pub(crate) async fn detect_key_source() -> KeySource {
+    if let Some(value) = non_empty_var("VOQUILL_KEYBOARD_SOURCE") {
+        match KeySource::parse(&value) {
+            Some(source) => return source,
+            None => eprintln!("[linux::input] Unknown VOQUILL_KEYBOARD_SOURCE {value:?}"),
+        }
+    }
+
+    if !is_wayland_session() {
+        return KeySource::Rdev;
+    }
+    if super::evdev_keys::is_available() {
+        return KeySource::Evdev;
+    }
+    if super::global_shortcuts::is_available().await {
+        return KeySource::Portal;
+    }
+
+    eprintln!(
+        "[linux::input] No Wayland hotkey source: /dev/input is not readable (join the \
+         input group) and the desktop portal has no global shortcuts. Hotkeys will only \
+         work while an XWayland app is focused."
+    );
+    KeySource::Rdev
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_backend_names() {
+        assert_eq!(
+            InputBackend::parse("virtual-keyboard"),
+            Some(InputBackend::VirtualKeyboard)
+        );
+        assert_eq!(
+            InputBackend::parse(" Wayland "),
+            Some(InputBackend::VirtualKeyboard)
+        );
+        assert_eq!(InputBackend::parse("UINPUT"), Some(InputBackend::Uinput));
+        assert_eq!(InputBackend::parse("x11"), Some(InputBackend::X11));
+        assert_eq!(InputBackend::parse("xtest"), None);
+    }
+
+    #[test]
+    fn backend_names_round_trip() {
+        for backend in [
+            InputBackend::VirtualKeyboard,
+            InputBackend::Uinput,
+            InputBackend::X11,
+        ] {
+            assert_eq!(InputBackend::parse(backend.as_str()), Some(backend));
+        }
+    }
+
+    #[test]
+    fn override_wins_without_probing() {
+        let backend = choose_input_backend(
+            Some("uinput"),
+            false,
+            || panic!("virtual keyboard probed"),
+            || panic!("uinput probed"),
+        );
+        assert_eq!(backend, InputBackend::Uinput);
+    }
+
+    #[test]
+    fn unknown_override_falls_back_to_detection() {
+        let backend = choose_input_backend(Some("xtest"), true, || true, || true);
+        assert_eq!(backend, InputBackend::VirtualKeyboard);
+    }
+
+    #[test]
+    fn x11_sessions_use_xtest() {
+        let backend = choose_input_backend(
+            None,
+            false,
+            || panic!("virtual keyboard probed"),
+            || panic!("uinput probed"),
+        );
+        assert_eq!(backend, InputBackend::X11);
+    }
+
+    #[test]
+    fn wayland_prefers_virtual_keyboard_then_uinput() {
+        assert_eq!(
+            choose_input_backend(None, true, || true, || true),
+            InputBackend::VirtualKeyboard
+        );
+        assert_eq!(
+            choose_input_backend(None, true, || false, || true),
+            InputBackend::Uinput
+        );
+        assert_eq!(
+            choose_input_backend(None, true, || false, || false),
+            InputBackend::X11
+        );
+    }
+}
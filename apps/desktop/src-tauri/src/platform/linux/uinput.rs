@@ -0,0 +1,317 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    mem,
+    os::{fd::AsRawFd, unix::fs::OpenOptionsExt},
+    sync::{Mutex, OnceLock},
+    thread,
+    time::Duration,
+};
+
+use super::session::KeyAction;
+
+const UINPUT_PATH: &str = "/dev/uinput";
+
+// ioctl requests from linux/uinput.h.
+const UI_SET_EVBIT: libc::c_ulong = 0x4004_5564;
+const UI_SET_KEYBIT: libc::c_ulong = 0x4004_5565;
+const UI_SET_RELBIT: libc::c_ulong = 0x4004_5566;
+const UI_DEV_SETUP: libc::c_ulong = 0x405c_5503;
+const UI_DEV_CREATE: libc::c_ulong = 0x5501;
+
+const BUS_VIRTUAL: u16 = 0x06;
+const EV_SYN: u16 = 0x00;
+const EV_KEY: u16 = 0x01;
+const EV_REL: u16 = 0x02;
+const SYN_REPORT: u16 = 0;
+const REL_X: u16 = 0x00;
+const REL_Y: u16 = 0x01;
+const BTN_MIDDLE: u16 = 0x112;
+
+const KEY_BACKSPACE: u16 = 14;
+const KEY_TAB: u16 = 15;
+const KEY_ENTER: u16 = 28;
+const KEY_LEFTCTRL: u16 = 29;
+const KEY_LEFTSHIFT: u16 = 42;
+const KEY_SPACE: u16 = 57;
+/// Every key the device can press; keycodes up to KEY_SPACE cover the main
+/// block of the keyboard.
+const MAX_KEYCODE: u16 = KEY_SPACE;
+
+/// The compositor needs a moment to pick up a new input device.
+const DEVICE_SETTLE: Duration = Duration::from_millis(200);
+
+/// A virtual keyboard and pointer created through the kernel. It works on
+/// every compositor but needs write access to `/dev/uinput`, and keys are
+/// translated by the user's layout like those of a physical keyboard.
+struct UinputDevice {
+    file: File,
+}
+
+fn ioctl(file: &File, request: libc::c_ulong, value: libc::c_ulong) -> Result<(), String> {
+    // SAFETY: every request used here takes an integer argument.
+    if unsafe { libc::ioctl(file.as_raw_fd(), request as _, value) } < 0 {
+        return Err(format!(
+            "uinput setup failed: {}",
+            std::io::Error::last_os_error()
+        ));
+    }
+    Ok(())
+}
+
+impl UinputDevice {
+    fn create() -> Result<Self, String> {
+        let file = OpenOptions::new()
+            .write(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(UINPUT_PATH)
+            .map_err(|err| format!("cannot open {UINPUT_PATH}: {err}"))?;
+
+        ioctl(&file, UI_SET_EVBIT, EV_KEY.into())?;
+        for keycode in 1..=MAX_KEYCODE {
+            ioctl(&file, UI_SET_KEYBIT, keycode.into())?;
+        }
+        ioctl(&file, UI_SET_KEYBIT, BTN_MIDDLE.into())?;
+        // Relative axes make the device a pointer, so the middle click counts.
+        ioctl(&file, UI_SET_EVBIT, EV_REL.into())?;
+        ioctl(&file, UI_SET_RELBIT, REL_X.into())?;
+        ioctl(&file, UI_SET_RELBIT, REL_Y.into())?;
+
+        // SAFETY: `uinput_setup` is plain data and all zeroes is valid.
+        let mut setup: libc::uinput_setup = unsafe { mem::zeroed() };
+        setup.id.bustype = BUS_VIRTUAL;
+        for (slot, byte) in setup.name.iter_mut().zip(b"Voquill virtual keyboard") {
+            *slot = *byte as libc::c_char;
+        }
+        // SAFETY: UI_DEV_SETUP reads a `uinput_setup` that outlives the call.
+        if unsafe { libc::ioctl(file.as_raw_fd(), UI_DEV_SETUP as _, &setup) } < 0 {
+            return Err(format!(
+                "uinput setup failed: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+        ioctl(&file, UI_DEV_CREATE, 0)?;
+
+        thread::sleep(DEVICE_SETTLE);
+        Ok(Self { file })
+    }
+
+    fn emit(&mut self, kind: u16, code: u16, value: i32) -> Result<(), String> {
+        // SAFETY: `input_event` is plain data and all zeroes is valid.
+        let mut event: libc::input_event = unsafe { mem::zeroed() };
+        event.type_ = kind;
+        event.code = code;
+        event.value = value;
+        // SAFETY: the slice covers exactly the initialized event.
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                (&event as *const libc::input_event).cast::<u8>(),
+                mem::size_of::<libc::input_event>(),
+            )
+        };
+        self.file
+            .write_all(bytes)
+            .map_err(|err| format!("uinput write failed: {err}"))
+    }
+
+    fn set_keys(&mut self, keycodes: &[u16], pressed: bool) -> Result<(), String> {
+        for &keycode in keycodes {
+            self.emit(EV_KEY, keycode, i32::from(pressed))?;
+        }
+        self.emit(EV_SYN, SYN_REPORT, 0)
+    }
+
+    /// Presses `keycode` while holding `modifiers`.
+    fn tap(&mut self, modifiers: &[u16], keycode: u16, interval: Duration) -> Result<(), String> {
+        if !modifiers.is_empty() {
+            self.set_keys(modifiers, true)?;
+        }
+        self.set_keys(&[keycode], true)?;
+        self.set_keys(&[keycode], false)?;
+        if !modifiers.is_empty() {
+            self.set_keys(modifiers, false)?;
+        }
+        thread::sleep(interval.max(Duration::from_millis(1)));
+        Ok(())
+    }
+}
+
+fn device() -> &'static Mutex<Option<UinputDevice>> {
+    static DEVICE: OnceLock<Mutex<Option<UinputDevice>>> = OnceLock::new();
+    DEVICE.get_or_init(|| Mutex::new(None))
+}
+
+fn with_device<T>(
+    operation: impl FnOnce(&mut UinputDevice) -> Result<T, String>,
+) -> Result<T, String> {
+    let mut device = device().lock().unwrap_or_else(|err| err.into_inner());
+    if device.is_none() {
+        *device = Some(UinputDevice::create()?);
+    }
+    let result = operation(device.as_mut().expect("device was just created"));
+    if result.is_err() {
+        // Recreate the device next time in case it was removed.
+        *device = None;
+    }
+    result
+}
+
+/// Whether this user may create uinput devices.
+pub(crate) fn is_available() -> bool {
+    OpenOptions::new().write(true).open(UINPUT_PATH).is_ok()
+}
+
+/// Keycode for `ch` on a US layout, and whether Shift is needed.
+///
+/// uinput sends key positions, not characters, so the compositor reads them
+/// through the user's layout: on AZERTY or Dvorak these keys type other
+/// characters, and there is no key at all for text such as Hangul. Callers
+/// prefer pasting text through the clipboard and only type with this as a
+/// fallback. Shortcuts like Ctrl+V still work on most layouts because apps
+/// match them against the US position when the layout is not Latin.
+fn us_keycode(ch: char) -> Option<(u16, bool)> {
+    const DIGITS: [u16; 10] = [11, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    const LETTERS: [u16; 26] = [
+        30, 48, 46, 32, 18, 33, 34, 35, 23, 36, 37, 38, 50, 49, 24, 25, 16, 19, 31, 20, 22, 47, 17,
+        45, 21, 44,
+    ];
+    let unshifted = |ch: char| -> Option<u16> {
+        match ch {
+            'a'..='z' => Some(LETTERS[ch as usize - 'a' as usize]),
+            '0'..='9' => Some(DIGITS[ch as usize - '0' as usize]),
+            '-' => Some(12),
+            '=' => Some(13),
+            '[' => Some(26),
+            ']' => Some(27),
+            ';' => Some(39),
+            '\'' => Some(40),
+            '`' => Some(41),
+            '\\' => Some(43),
+            ',' => Some(51),
+            '.' => Some(52),
+            '/' => Some(53),
+            ' ' => Some(KEY_SPACE),
+            '\n' => Some(KEY_ENTER),
+            '\t' => Some(KEY_TAB),
+            _ => None,
+        }
+    };
+    let shifted_base = match ch {
+        'A'..='Z' => Some(ch.to_ascii_lowercase()),
+        '!' => Some('1'),
+        '@' => Some('2'),
+        '#' => Some('3'),
+        '$' => Some('4'),
+        '%' => Some('5'),
+        '^' => Some('6'),
+        '&' => Some('7'),
+        '*' => Some('8'),
+        '(' => Some('9'),
+        ')' => Some('0'),
+        '_' => Some('-'),
+        '+' => Some('='),
+        '{' => Some('['),
+        '}' => Some(']'),
+        ':' => Some(';'),
+        '"' => Some('\''),
+        '~' => Some('`'),
+        '|' => Some('\\'),
+        '<' => Some(','),
+        '>' => Some('.'),
+        '?' => Some('/'),
+        _ => None,
+    };
+    match shifted_base {
+        Some(base) => unshifted(base).map(|keycode| (keycode, true)),
+        None => unshifted(ch).map(|keycode| (keycode, false)),
+    }
+}
+
+pub(crate) fn send(action: &KeyAction, interval: Duration) -> Result<(), String> {
+    match action {
+        KeyAction::Text(text) => {
+            // Check everything first so nothing is half typed.
+            let keys = text
+                .chars()
+                .map(|ch| {
+                    us_keycode(ch).ok_or_else(|| format!("cannot type {ch:?} through uinput"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            with_device(|device| {
+                for (keycode, shift) in keys {
+                    let modifiers: &[u16] = if shift { &[KEY_LEFTSHIFT] } else { &[] };
+                    device.tap(modifiers, keycode, interval)?;
+                }
+                Ok(())
+            })
+        }
+        KeyAction::Control { key, shift } => {
+            let (keycode, _) = us_keycode(key.to_ascii_lowercase())
+                .ok_or_else(|| format!("cannot press Ctrl+{key} through uinput"))?;
+            let modifiers: &[u16] = if *shift {
+                &[KEY_LEFTCTRL, KEY_LEFTSHIFT]
+            } else {
+                &[KEY_LEFTCTRL]
+            };
+            with_device(|device| device.tap(modifiers, keycode, interval))
+        }
+        KeyAction::Backspace(count) => with_device(|device| {
+            for _ in 0..*count {
+                device.tap(&[], KEY_BACKSPACE, interval)?;
+            }
+            Ok(())
+        }),
+    }
+}
+
+/// Clicks the middle mouse button where the pointer is.
+pub(crate) fn middle_click() -> Result<(), String> {
+    with_device(|device| {
+        device.set_keys(&[BTN_MIDDLE], true)?;
+        device.set_keys(&[BTN_MIDDLE], false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_letters_and_digits() {
+        assert_eq!(us_keycode('a'), Some((30, false)));
+        assert_eq!(us_keycode('v'), Some((47, false)));
+        assert_eq!(us_keycode('z'), Some((44, false)));
+        assert_eq!(us_keycode('0'), Some((11, false)));
+        assert_eq!(us_keycode('1'), Some((2, false)));
+    }
+
+    #[test]
+    fn shifted_characters_share_the_base_key() {
+        assert_eq!(us_keycode('V'), Some((47, true)));
+        assert_eq!(us_keycode('!'), Some((2, true)));
+        assert_eq!(us_keycode('?'), Some((53, true)));
+        assert_eq!(us_keycode('"'), Some((40, true)));
+    }
+
+    #[test]
+    fn maps_whitespace() {
+        assert_eq!(us_keycode(' '), Some((KEY_SPACE, false)));
+        assert_eq!(us_keycode('\n'), Some((KEY_ENTER, false)));
+        assert_eq!(us_keycode('\t'), Some((KEY_TAB, false)));
+    }
+
+    #[test]
+    fn every_keycode_is_registered_on_the_device() {
+        for ch in (' '..='~').chain(['\n', '\t']) {
+            let (keycode, _) = us_keycode(ch).expect("printable ASCII has a key");
+            assert!((1..=MAX_KEYCODE).contains(&keycode), "{ch:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_characters_without_a_us_key() {
+        assert_eq!(us_keycode('é'), None);
+        assert_eq!(us_keycode('한'), None);
+        assert_eq!(us_keycode('€'), None);
+    }
+}
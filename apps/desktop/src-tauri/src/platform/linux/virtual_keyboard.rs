@@ -0,0 +1,291 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Write,
+    os::fd::{AsFd, FromRawFd, OwnedFd},
+    thread,
+    time::{Duration, Instant},
+};
+
+use wayland_client::{
+    delegate_noop,
+    protocol::{wl_keyboard::KeymapFormat, wl_registry, wl_seat},
+    Connection, Dispatch, EventQueue, QueueHandle,
+};
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::{
+    zwp_virtual_keyboard_manager_v1::ZwpVirtualKeyboardManagerV1,
+    zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1,
+};
+
+use super::session::KeyAction;
+
+/// Keycodes are assigned per keymap, and X keycodes stop at 255.
+const MAX_KEYMAP_KEYS: usize = 200;
+const MOD_SHIFT: u32 = 1;
+const MOD_CONTROL: u32 = 1 << 2;
+const KEY_PRESSED: u32 = 1;
+const KEY_RELEASED: u32 = 0;
+
+#[derive(Default)]
+struct Globals {
+    seat: Option<wl_seat::WlSeat>,
+    manager: Option<ZwpVirtualKeyboardManagerV1>,
+}
+
+impl Dispatch<wl_registry::WlRegistry, ()> for Globals {
+    fn event(
+        state: &mut Self,
+        registry: &wl_registry::WlRegistry,
+        event: wl_registry::Event,
+        _: &(),
+        _: &Connection,
+        qh: &QueueHandle<Self>,
+    ) {
+        if let wl_registry::Event::Global {
+            name, interface, ..
+        } = event
+        {
+            match interface.as_str() {
+                "wl_seat" if state.seat.is_none() => {
+                    state.seat = Some(registry.bind(name, 1, qh, ()));
+                }
+                "zwp_virtual_keyboard_manager_v1" => {
+                    state.manager = Some(registry.bind(name, 1, qh, ()));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+delegate_noop!(Globals: ignore wl_seat::WlSeat);
+delegate_noop!(Globals: ZwpVirtualKeyboardManagerV1);
+delegate_noop!(Globals: ZwpVirtualKeyboardV1);
+
+fn connect() -> Result<(Connection, EventQueue<Globals>, Globals), String> {
+    let connection =
+        Connection::connect_to_env().map_err(|err| format!("Wayland unavailable: {err}"))?;
+    let mut queue = connection.new_event_queue();
+    connection.display().get_registry(&queue.handle(), ());
+
+    let mut globals = Globals::default();
+    queue
+        .roundtrip(&mut globals)
+        .map_err(|err| format!("Wayland roundtrip failed: {err}"))?;
+    Ok((connection, queue, globals))
+}
+
+/// Whether the compositor offers virtual keyboards (wlroots, KDE; not GNOME).
+pub(crate) fn is_available() -> bool {
+    connect()
+        .map(|(_, _, globals)| globals.seat.is_some() && globals.manager.is_some())
+        .unwrap_or(false)
+}
+
+/// A key in the uploaded keymap, by X keysym name such as "Return" or "U00E9".
+fn keysym_name(ch: char) -> String {
+    match ch {
+        '\n' => "Return".to_string(),
+        '\t' => "Tab".to_string(),
+        ' ' => "space".to_string(),
+        ch if ch.is_ascii_alphanumeric() => ch.to_string(),
+        ch => format!("U{:04X}", ch as u32),
+    }
+}
+
+/// Keymap with one key per symbol; symbol `i` is evdev keycode `i + 1`.
+fn build_keymap(symbols: &[String]) -> String {
+    let mut keymap = String::from("xkb_keymap {\nxkb_keycodes \"voquill\" {\n");
+    keymap.push_str(&format!("minimum = 8;\nmaximum = {};\n", symbols.len() + 9));
+    for index in 0..symbols.len() {
+        keymap.push_str(&format!("<K{index}> = {};\n", index + 9));
+    }
+    keymap.push_str("};\nxkb_types \"voquill\" { include \"complete\" };\n");
+    keymap.push_str("xkb_compatibility \"voquill\" { include \"complete\" };\n");
+    keymap.push_str("xkb_symbols \"voquill\" {\n");
+    for (index, symbol) in symbols.iter().enumerate() {
+        keymap.push_str(&format!("key <K{index}> {{ [ {symbol} ] }};\n"));
+    }
+    keymap.push_str("};\n};\n");
+    keymap
+}
+
+fn keymap_file(keymap: &str) -> Result<(OwnedFd, u32), String> {
+    // SAFETY: the name is a valid C string and the returned descriptor is
+    // checked before being owned.
+    let fd = unsafe { libc::memfd_create(c"voquill-keymap".as_ptr(), libc::MFD_CLOEXEC) };
+    if fd < 0 {
+        return Err(format!(
+            "failed to create keymap file: {}",
+            std::io::Error::last_os_error()
+        ));
+    }
+    // SAFETY: `fd` was just created and is owned by nothing else.
+    let mut file = File::from(unsafe { OwnedFd::from_raw_fd(fd) });
+    file.write_all(keymap.as_bytes())
+        .and_then(|()| file.write_all(&[0]))
+        .map_err(|err| format!("failed to write keymap: {err}"))?;
+    Ok((OwnedFd::from(file), keymap.len() as u32 + 1))
+}
+
+/// Sends key presses through a virtual keyboard whose keymap holds exactly
+/// the symbols being typed, so any character can be entered regardless of
+/// the user's layout.
+struct Typist {
+    connection: Connection,
+    queue: EventQueue<Globals>,
+    globals: Globals,
+    keyboard: ZwpVirtualKeyboardV1,
+    keycodes: HashMap<String, u32>,
+    started_at: Instant,
+}
+
+impl Typist {
+    fn new() -> Result<Self, String> {
+        let (connection, queue, globals) = connect()?;
+        let (Some(seat), Some(manager)) = (&globals.seat, &globals.manager) else {
+            return Err("the compositor does not offer virtual keyboards".to_string());
+        };
+        let keyboard = manager.create_virtual_keyboard(seat, &queue.handle(), ());
+        Ok(Self {
+            connection,
+            queue,
+            globals,
+            keyboard,
+            keycodes: HashMap::new(),
+            started_at: Instant::now(),
+        })
+    }
+
+    fn load_keymap(&mut self, symbols: Vec<String>) -> Result<(), String> {
+        let (fd, size) = keymap_file(&build_keymap(&symbols))?;
+        self.keyboard
+            .keymap(KeymapFormat::XkbV1 as u32, fd.as_fd(), size);
+        self.keycodes = symbols
+            .into_iter()
+            .enumerate()
+            .map(|(index, symbol)| (symbol, index as u32 + 1))
+            .collect();
+        self.sync()
+    }
+
+    fn sync(&mut self) -> Result<(), String> {
+        self.queue
+            .roundtrip(&mut self.globals)
+            .map(|_| ())
+            .map_err(|err| format!("Wayland roundtrip failed: {err}"))
+    }
+
+    fn time(&self) -> u32 {
+        self.started_at.elapsed().as_millis() as u32
+    }
+
+    fn tap(&mut self, symbol: &str, modifiers: u32, interval: Duration) -> Result<(), String> {
+        let keycode = *self
+            .keycodes
+            .get(symbol)
+            .ok_or_else(|| format!("{symbol} is missing from the keymap"))?;
+        if modifiers != 0 {
+            self.keyboard.modifiers(modifiers, 0, 0, 0);
+        }
+        self.keyboard.key(self.time(), keycode, KEY_PRESSED);
+        self.keyboard.key(self.time(), keycode, KEY_RELEASED);
+        if modifiers != 0 {
+            self.keyboard.modifiers(0, 0, 0, 0);
+        }
+        self.connection
+            .flush()
+            .map_err(|err| format!("Wayland flush failed: {err}"))?;
+        if !interval.is_zero() {
+            thread::sleep(interval);
+        }
+        Ok(())
+    }
+
+    fn type_text(&mut self, text: &str, interval: Duration) -> Result<(), String> {
+        let names: Vec<String> = text.chars().map(keysym_name).collect();
+        let mut start = 0;
+        while start < names.len() {
+            // Load as many upcoming symbols as fit, then type them.
+            let mut symbols: Vec<String> = Vec::new();
+            let mut end = start;
+            while end < names.len() {
+                if !symbols.contains(&names[end]) {
+                    if symbols.len() == MAX_KEYMAP_KEYS {
+                        break;
+                    }
+                    symbols.push(names[end].clone());
+                }
+                end += 1;
+            }
+            self.load_keymap(symbols)?;
+            for name in &names[start..end] {
+                self.tap(name, 0, interval)?;
+            }
+            start = end;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn send(action: &KeyAction, interval: Duration) -> Result<(), String> {
+    let mut typist = Typist::new()?;
+    match action {
+        KeyAction::Text(text) => typist.type_text(text, interval)?,
+        KeyAction::Control { key, shift } => {
+            let symbol = key.to_ascii_lowercase().to_string();
+            typist.load_keymap(vec![symbol.clone()])?;
+            let modifiers = if *shift {
+                MOD_CONTROL | MOD_SHIFT
+            } else {
+                MOD_CONTROL
+            };
+            typist.tap(&symbol, modifiers, interval)?;
+        }
+        KeyAction::Backspace(count) => {
+            typist.load_keymap(vec!["BackSpace".to_string()])?;
+            for _ in 0..*count {
+                typist.tap("BackSpace", 0, interval)?;
+            }
+        }
+    }
+    typist.sync()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_keysyms() {
+        assert_eq!(keysym_name('a'), "a");
+        assert_eq!(keysym_name('Z'), "Z");
+        assert_eq!(keysym_name('7'), "7");
+        assert_eq!(keysym_name('\n'), "Return");
+        assert_eq!(keysym_name('\t'), "Tab");
+        assert_eq!(keysym_name(' '), "space");
+        assert_eq!(keysym_name('.'), "U002E");
+        assert_eq!(keysym_name('é'), "U00E9");
+        assert_eq!(keysym_name('한'), "UD55C");
+        assert_eq!(keysym_name('😀'), "U1F600");
+    }
+
+    #[test]
+    fn keymap_has_one_key_per_symbol() {
+        let symbols = vec!["a".to_string(), "U00E9".to_string()];
+        let keymap = build_keymap(&symbols);
+        assert!(keymap.contains("minimum = 8;\nmaximum = 11;\n"));
+        assert!(keymap.contains("<K0> = 9;\n<K1> = 10;\n"));
+        assert!(keymap.contains("key <K0> { [ a ] };\n"));
+        assert!(keymap.contains("key <K1> { [ U00E9 ] };\n"));
+        assert!(keymap.ends_with("};\n};\n"));
+    }
+
+    #[test]
+    fn empty_keymap_is_still_complete() {
+        let keymap = build_keymap(&[]);
+        assert!(keymap.starts_with("xkb_keymap {\n"));
+        assert!(!keymap.contains("<K0>"));
+        assert!(keymap.contains("xkb_symbols \"voquill\" {\n};\n"));
+    }
+}
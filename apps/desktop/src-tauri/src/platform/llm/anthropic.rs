@@ -0,0 +1,228 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{
+    open, parse_event, read_events, CancellationToken, LlmError, LlmOutput, LlmProvider, LlmRequest,
+};
+use crate::platform::remote::RetryPolicy;
+
+const DEFAULT_BASE_URL: &str = "https://api.anthropic.com";
+const DEFAULT_MODEL: &str = "claude-sonnet-4-20250514";
+const API_VERSION: &str = "2023-06-01";
+/// The Messages API requires a limit; this leaves room for long transcripts.
+const DEFAULT_MAX_TOKENS: u32 = 4096;
+
+/// Anthropic Messages API with `stream: true`. There is no JSON mode, so a
+/// requested schema is described in the system prompt instead.
+pub struct AnthropicProvider {
+    api_key: String,
+    base_url: String,
+    model: String,
+    policy: RetryPolicy,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StreamEvent {
+    MessageStart {
+        message: StartedMessage,
+    },
+    ContentBlockDelta {
+        delta: BlockDelta,
+    },
+    MessageStop,
+    Error {
+        error: StreamError,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct StartedMessage {
+    model: String,
+}
+
+#[derive(Deserialize)]
+struct BlockDelta {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StreamError {
+    #[serde(rename = "type")]
+    kind: String,
+    message: String,
+}
+
+impl AnthropicProvider {
+    pub fn new(
+        api_key: String,
+        base_url: Option<String>,
+        model: Option<String>,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            api_key,
+            base_url: base_url
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            model: model.unwrap_or_else(|| DEFAULT_MODEL.to_string()),
+            policy,
+        }
+    }
+}
+
+impl LlmProvider for AnthropicProvider {
+    fn generate(
+        &self,
+        request: &LlmRequest,
+        cancel: &CancellationToken,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<LlmOutput, LlmError> {
+        let mut body = json!({
+            "model": self.model,
+            "max_tokens": request.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
+            "messages": [{ "role": "user", "content": request.prompt }],
+            "stream": true,
+        });
+        if let Some(system) = request.system_with_schema() {
+            body["system"] = json!(system);
+        }
+        if let Some(temperature) = request.temperature {
+            body["temperature"] = json!(temperature);
+        }
+
+        let url = format!("{}/v1/messages", self.base_url);
+        let response = open(&self.policy, cancel, |client| {
+            client
+                .post(&url)
+                .header("x-api-key", &self.api_key)
+                .header("anthropic-version", API_VERSION)
+                .json(&body)
+        })?;
+
+        let mut text = String::new();
+        let mut model = self.model.clone();
+        read_events(std::io::BufReader::new(response), cancel, |_, data| {
+            match parse_event::<StreamEvent>(data)? {
+                StreamEvent::MessageStart { message } => model = message.model,
+                StreamEvent::ContentBlockDelta { delta } => {
+                    if let Some(delta) = delta.text.filter(|delta| !delta.is_empty()) {
+                        on_delta(&delta);
+                        text.push_str(&delta);
+                    }
+                }
+                StreamEvent::MessageStop => return Ok(false),
+                StreamEvent::Error { error } => {
+                    return Err(match error.kind.as_str() {
+                        "overloaded_error" => LlmError::Http {
+                            status: 529,
+                            message: error.message,
+                        },
+                        "rate_limit_error" => LlmError::RateLimited { retry_after: None },
+                        _ => LlmError::InvalidResponse(error.message),
+                    })
+                }
+                StreamEvent::Other => {}
+            }
+            Ok(true)
+        })?;
+
+        Ok(LlmOutput { text, model })
+    }
+
+    fn label(&self) -> &str {
+        "API • Claude"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::llm::test_support::fast_policy;
+    use mockito::Matcher;
+
+    #[test]
+    fn streams_text_deltas() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/messages")
+            .match_header("x-api-key", "sk-ant-test")
+            .match_header("anthropic-version", API_VERSION)
+            .match_body(Matcher::PartialJson(json!({
+                "model": DEFAULT_MODEL,
+                "system": "Clean up the transcript.",
+                "stream": true,
+            })))
+            .with_body(concat!(
+                "event: message_start\n",
+                "data: {\"type\":\"message_start\",\"message\":{\"model\":\"claude-sonnet-4-20250514\"}}\n\n",
+                "event: ping\n",
+                "data: {\"type\":\"ping\"}\n\n",
+                "event: content_block_delta\n",
+                "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hello\"}}\n\n",
+                "event: content_block_delta\n",
+                "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\" there.\"}}\n\n",
+                "event: message_stop\n",
+                "data: {\"type\":\"message_stop\"}\n\n",
+            ))
+            .create();
+
+        let provider = AnthropicProvider::new(
+            "sk-ant-test".to_string(),
+            Some(server.url()),
+            None,
+            fast_policy(),
+        );
+        let request = LlmRequest {
+            system: Some("Clean up the transcript.".to_string()),
+            prompt: "um hello there".to_string(),
+            ..Default::default()
+        };
+        let mut streamed = String::new();
+        let output = provider
+            .generate(&request, &CancellationToken::new(), &mut |delta| {
+                streamed.push_str(delta)
+            })
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(streamed, "Hello there.");
+        assert_eq!(output.text, "Hello there.");
+    }
+
+    #[test]
+    fn stream_errors_are_typed() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/v1/messages")
+            .with_body(
+                "event: error\ndata: {\"type\":\"error\",\"error\":{\"type\":\"overloaded_error\",\"message\":\"Overloaded\"}}\n\n",
+            )
+            .create();
+
+        let err = AnthropicProvider::new(
+            "sk-ant-test".to_string(),
+            Some(server.url()),
+            None,
+            fast_policy(),
+        )
+        .generate(
+            &LlmRequest::default(),
+            &CancellationToken::new(),
+            &mut |_| {},
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            LlmError::Http {
+                status: 529,
+                message: "Overloaded".to_string()
+            }
+        );
+    }
+}
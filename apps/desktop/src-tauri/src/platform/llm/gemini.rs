@@ -0,0 +1,180 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{
+    open, parse_event, read_events, CancellationToken, LlmError, LlmOutput, LlmProvider, LlmRequest,
+};
+use crate::platform::remote::RetryPolicy;
+
+const DEFAULT_BASE_URL: &str = "https://generativelanguage.googleapis.com";
+const DEFAULT_MODEL: &str = "gemini-2.5-flash";
+
+/// Gemini `streamGenerateContent` over server-sent events.
+pub struct GeminiProvider {
+    api_key: String,
+    base_url: String,
+    model: String,
+    policy: RetryPolicy,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamChunk {
+    #[serde(default)]
+    candidates: Vec<Candidate>,
+    #[serde(default)]
+    model_version: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Candidate {
+    #[serde(default)]
+    content: Option<Content>,
+}
+
+#[derive(Deserialize)]
+struct Content {
+    #[serde(default)]
+    parts: Vec<Part>,
+}
+
+#[derive(Deserialize)]
+struct Part {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+impl GeminiProvider {
+    pub fn new(
+        api_key: String,
+        base_url: Option<String>,
+        model: Option<String>,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            api_key,
+            base_url: base_url
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            model: model.unwrap_or_else(|| DEFAULT_MODEL.to_string()),
+            policy,
+        }
+    }
+}
+
+impl LlmProvider for GeminiProvider {
+    fn generate(
+        &self,
+        request: &LlmRequest,
+        cancel: &CancellationToken,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<LlmOutput, LlmError> {
+        let mut generation_config = json!({});
+        if let Some(max_tokens) = request.max_tokens {
+            generation_config["maxOutputTokens"] = json!(max_tokens);
+        }
+        if let Some(temperature) = request.temperature {
+            generation_config["temperature"] = json!(temperature);
+        }
+        // Like the frontend, only the MIME type is enforced; the schema
+        // travels in the system prompt.
+        if request.json_response.is_some() {
+            generation_config["responseMimeType"] = json!("application/json");
+        }
+
+        let mut body = json!({
+            "contents": [{ "role": "user", "parts": [{ "text": request.prompt }] }],
+            "generationConfig": generation_config,
+        });
+        if let Some(system) = request.system_with_schema() {
+            body["systemInstruction"] = json!({ "parts": [{ "text": system }] });
+        }
+
+        let url = format!(
+            "{}/v1beta/models/{}:streamGenerateContent",
+            self.base_url, self.model
+        );
+        let response = open(&self.policy, cancel, |client| {
+            client
+                .post(&url)
+                .query(&[("alt", "sse")])
+                .header("x-goog-api-key", &self.api_key)
+                .json(&body)
+        })?;
+
+        let mut text = String::new();
+        let mut model = self.model.clone();
+        read_events(std::io::BufReader::new(response), cancel, |_, data| {
+            let chunk: StreamChunk = parse_event(data)?;
+            if let Some(version) = chunk.model_version {
+                model = version;
+            }
+            let parts = chunk
+                .candidates
+                .into_iter()
+                .take(1)
+                .filter_map(|candidate| candidate.content)
+                .flat_map(|content| content.parts)
+                .filter_map(|part| part.text);
+            for delta in parts.filter(|delta| !delta.is_empty()) {
+                on_delta(&delta);
+                text.push_str(&delta);
+            }
+            Ok(true)
+        })?;
+
+        Ok(LlmOutput { text, model })
+    }
+
+    fn label(&self) -> &str {
+        "API • Gemini"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::llm::test_support::{fast_policy, sse};
+    use crate::platform::llm::JsonResponseFormat;
+    use mockito::Matcher;
+
+    #[test]
+    fn streams_candidate_parts() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1beta/models/gemini-2.5-flash:streamGenerateContent")
+            .match_query(Matcher::UrlEncoded("alt".to_string(), "sse".to_string()))
+            .match_header("x-goog-api-key", "gm-test")
+            .match_body(Matcher::PartialJson(json!({
+                "generationConfig": { "responseMimeType": "application/json" },
+            })))
+            .with_body(sse(&[
+                r#"{"candidates":[{"content":{"parts":[{"text":"{\"processed"}]}}],"modelVersion":"gemini-2.5-flash-001"}"#,
+                r#"{"candidates":[{"content":{"parts":[{"text":"Transcription\":\"Hi\"}"}]},"finishReason":"STOP"}]}"#,
+            ]))
+            .create();
+
+        let request = LlmRequest {
+            prompt: "hi".to_string(),
+            json_response: Some(JsonResponseFormat {
+                name: "transcription_cleaning".to_string(),
+                description: None,
+                schema: json!({ "type": "object" }),
+            }),
+            ..Default::default()
+        };
+        let output = GeminiProvider::new(
+            "gm-test".to_string(),
+            Some(server.url()),
+            None,
+            fast_policy(),
+        )
+        .generate(&request, &CancellationToken::new(), &mut |_| {})
+        .unwrap();
+
+        mock.assert();
+        assert_eq!(output.text, r#"{"processedTranscription":"Hi"}"#);
+        assert_eq!(output.model, "gemini-2.5-flash-001");
+    }
+}
@@ -0,0 +1,397 @@
+//! Text generation providers used for transcript post-processing.
+//!
+//! Every provider streams its answer through `LlmProvider::generate`, which
+//! hands each text delta to a callback as it arrives, honours a
+//! `CancellationToken` between chunks and reports failures as `LlmError`.
+
+pub mod anthropic;
+pub mod gemini;
+pub mod ollama;
+pub mod openai;
+
+use std::io::BufRead;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::blocking::{Client, RequestBuilder, Response};
+
+use crate::domain::ApiKey;
+use crate::platform::remote::{ProviderError, RetryPolicy};
+
+pub use anthropic::AnthropicProvider;
+pub use gemini::GeminiProvider;
+pub use ollama::{OllamaClient, OllamaError};
+pub use openai::{ChatAuth, ChatCompletionsProvider};
+
+pub const OLLAMA_DEFAULT_URL: &str = "http://127.0.0.1:11434";
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum LlmError {
+    #[error("unsupported post-processing provider: {0}")]
+    UnsupportedProvider(String),
+    #[error("missing provider configuration: {0}")]
+    MissingConfiguration(&'static str),
+    #[error("generation was cancelled")]
+    Cancelled,
+    #[error("request timed out")]
+    Timeout,
+    #[error("network error: {0}")]
+    Network(String),
+    #[error("provider rejected the API key ({status})")]
+    Unauthorized { status: u16 },
+    #[error("rate limited by provider")]
+    RateLimited { retry_after: Option<Duration> },
+    #[error("provider returned {status}: {message}")]
+    Http { status: u16, message: String },
+    #[error("unexpected provider response: {0}")]
+    InvalidResponse(String),
+}
+
+impl LlmError {
+    /// Failures worth another attempt before any token has been streamed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(_) | Self::RateLimited { .. } => true,
+            Self::Http { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl ProviderError for LlmError {
+    const LOG_TAG: &'static str = "llm";
+
+    fn timeout() -> Self {
+        Self::Timeout
+    }
+
+    fn network(message: String) -> Self {
+        Self::Network(message)
+    }
+
+    fn unauthorized(status: u16) -> Self {
+        Self::Unauthorized { status }
+    }
+
+    fn rate_limited(retry_after: Option<Duration>) -> Self {
+        Self::RateLimited { retry_after }
+    }
+
+    fn http(status: u16, message: String) -> Self {
+        Self::Http { status, message }
+    }
+
+    fn is_retryable(&self) -> bool {
+        LlmError::is_retryable(self)
+    }
+
+    fn rate_limit(&self) -> Option<Option<Duration>> {
+        match self {
+            Self::RateLimited { retry_after } => Some(*retry_after),
+            _ => None,
+        }
+    }
+}
+
+/// Shared flag flipped by `cancel_post_processing`; providers check it
+/// between streamed chunks.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Whether both handles belong to the same request.
+    pub fn same_as(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    fn check(&self) -> Result<(), LlmError> {
+        if self.is_cancelled() {
+            Err(LlmError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Structured output requested from the model, mirroring `JsonResponse` in
+/// `@repo/types`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResponseFormat {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub schema: serde_json::Value,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct LlmRequest {
+    pub system: Option<String>,
+    pub prompt: String,
+    pub json_response: Option<JsonResponseFormat>,
+    /// Left to the provider's own limit when unset.
+    pub max_tokens: Option<u32>,
+    pub temperature: Option<f32>,
+}
+
+impl LlmRequest {
+    /// System prompt for providers without native JSON schema support: the
+    /// schema is spelled out so the answer can still be parsed.
+    pub(crate) fn system_with_schema(&self) -> Option<String> {
+        let Some(format) = self.json_response.as_ref() else {
+            return self.system.clone();
+        };
+
+        let instruction = format!(
+            "Respond only with a JSON object matching this schema ({}):\n{}",
+            format.name, format.schema
+        );
+        Some(match self.system.as_deref() {
+            Some(system) if !system.trim().is_empty() => format!("{system}\n\n{instruction}"),
+            _ => instruction,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LlmOutput {
+    pub text: String,
+    pub model: String,
+}
+
+pub trait LlmProvider: Send + Sync {
+    /// Generates a completion, passing each text delta to `on_delta` as it
+    /// streams in. The returned text is the concatenation of all deltas.
+    fn generate(
+        &self,
+        request: &LlmRequest,
+        cancel: &CancellationToken,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<LlmOutput, LlmError>;
+
+    /// Short label for logs and `post_process_device`, e.g. `API • Claude`.
+    fn label(&self) -> &str;
+}
+
+/// Opens the streaming response, retrying transient failures. Once the
+/// stream has started nothing is retried, since deltas were already
+/// delivered. The policy's `request_timeout` bounds the wait for the headers
+/// and for each chunk, not the whole stream, so long answers keep streaming
+/// as long as the provider keeps sending.
+fn open<F>(policy: &RetryPolicy, cancel: &CancellationToken, build: F) -> Result<Response, LlmError>
+where
+    F: Fn(&Client) -> RequestBuilder,
+{
+    policy.send_with(|| cancel.check(), build)
+}
+
+/// Feeds each server-sent event's `data` payload (with its `event` name, if
+/// any) to `on_event` until it returns `false` or the stream ends.
+pub(crate) fn read_events<R, F>(
+    reader: R,
+    cancel: &CancellationToken,
+    mut on_event: F,
+) -> Result<(), LlmError>
+where
+    R: BufRead,
+    F: FnMut(Option<&str>, &str) -> Result<bool, LlmError>,
+{
+    let mut event: Option<String> = None;
+    let mut data = String::new();
+
+    for line in reader.lines() {
+        cancel.check()?;
+        let line = line.map_err(read_error)?;
+
+        if line.is_empty() {
+            if !data.is_empty() && !on_event(event.as_deref(), &data)? {
+                return Ok(());
+            }
+            event = None;
+            data.clear();
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix("event:") {
+            event = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("data:") {
+            if !data.is_empty() {
+                data.push('\n');
+            }
+            data.push_str(value.strip_prefix(' ').unwrap_or(value));
+        }
+    }
+
+    if !data.is_empty() {
+        on_event(event.as_deref(), &data)?;
+    }
+    cancel.check()
+}
+
+/// reqwest reports a stalled body as an I/O error wrapping its own timeout.
+fn read_error(err: std::io::Error) -> LlmError {
+    let timed_out = err.kind() == std::io::ErrorKind::TimedOut
+        || err
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<reqwest::Error>())
+            .is_some_and(reqwest::Error::is_timeout);
+    if timed_out {
+        LlmError::Timeout
+    } else {
+        LlmError::Network(err.to_string())
+    }
+}
+
+pub(crate) fn parse_event<T: serde::de::DeserializeOwned>(data: &str) -> Result<T, LlmError> {
+    serde_json::from_str(data).map_err(|err| LlmError::InvalidResponse(err.to_string()))
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Builds the provider for a stored API key, using the key's
+/// `post_processing_model` when set. `secret` is the decrypted key.
+pub fn provider_for_api_key(
+    api_key: &ApiKey,
+    secret: String,
+    policy: RetryPolicy,
+) -> Result<Arc<dyn LlmProvider>, LlmError> {
+    let base_url = non_empty(api_key.base_url.as_deref());
+    let model = non_empty(api_key.post_processing_model.as_deref());
+
+    let provider: Arc<dyn LlmProvider> = match api_key.provider.as_str() {
+        "openai" | "groq" | "deepseek" => {
+            let defaults = openai::ChatDefaults::for_provider(&api_key.provider);
+            Arc::new(ChatCompletionsProvider::new(
+                defaults.label,
+                format!(
+                    "{}/chat/completions",
+                    base_url
+                        .as_deref()
+                        .unwrap_or(defaults.base_url)
+                        .trim_end_matches('/')
+                ),
+                ChatAuth::Bearer(secret),
+                model.unwrap_or_else(|| defaults.model.to_string()),
+                policy,
+            ))
+        }
+        "openrouter" => Arc::new(openai::openrouter(
+            secret,
+            model,
+            api_key.openrouter_config.as_deref(),
+            policy,
+        )),
+        "azure" => {
+            let endpoint = base_url.ok_or(LlmError::MissingConfiguration(
+                "Azure OpenAI needs an endpoint",
+            ))?;
+            Arc::new(openai::azure(secret, &endpoint, model, policy))
+        }
+        "ollama" => {
+            let model = model.ok_or(LlmError::MissingConfiguration(
+                "no model configured for Ollama post-processing",
+            ))?;
+            let base_url = base_url.unwrap_or_else(|| OLLAMA_DEFAULT_URL.to_string());
+            Arc::new(openai::ollama(
+                &base_url,
+                model,
+                non_empty(Some(&secret)),
+                policy,
+            ))
+        }
+        "claude" => Arc::new(AnthropicProvider::new(secret, base_url, model, policy)),
+        "gemini" => Arc::new(GeminiProvider::new(secret, base_url, model, policy)),
+        other => return Err(LlmError::UnsupportedProvider(other.to_string())),
+    };
+
+    Ok(provider)
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    pub(crate) use crate::platform::remote::test_support::fast_policy;
+
+    /// Server-sent events body with one `data:` line per payload.
+    pub(crate) fn sse(events: &[&str]) -> String {
+        events
+            .iter()
+            .map(|event| format!("data: {event}\n\n"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn reads_multiline_and_named_events() {
+        let body = "event: ping\ndata: {}\n\n: comment\ndata: a\ndata: b\n\ndata: tail";
+        let mut seen = Vec::new();
+        read_events(
+            Cursor::new(body),
+            &CancellationToken::new(),
+            |event, data| {
+                seen.push((event.map(str::to_string), data.to_string()));
+                Ok(true)
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            seen,
+            vec![
+                (Some("ping".to_string()), "{}".to_string()),
+                (None, "a\nb".to_string()),
+                (None, "tail".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn cancelled_streams_stop_reading() {
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let err = read_events(Cursor::new("data: x\n\n"), &cancel, |_, _| Ok(true)).unwrap_err();
+        assert_eq!(err, LlmError::Cancelled);
+    }
+
+    #[test]
+    fn schema_is_spelled_out_for_providers_without_json_mode() {
+        let request = LlmRequest {
+            system: Some("Clean up the transcript.".to_string()),
+            json_response: Some(JsonResponseFormat {
+                name: "transcription_cleaning".to_string(),
+                description: None,
+                schema: serde_json::json!({"type": "object"}),
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            request.system_with_schema().as_deref(),
+            Some(
+                "Clean up the transcript.\n\nRespond only with a JSON object matching this schema (transcription_cleaning):\n{\"type\":\"object\"}"
+            )
+        );
+    }
+}
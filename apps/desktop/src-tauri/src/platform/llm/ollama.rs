@@ -0,0 +1,481 @@
+//! Ollama's native management API: health checks, installed models, pulls
+//! and warm-up. Generation itself goes through the OpenAI-compatible
+//! endpoint in `openai.rs`.
+
+use std::io::BufRead;
+use std::time::Duration;
+
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::OLLAMA_DEFAULT_URL;
+use crate::platform::remote::error_message;
+
+/// How long Ollama keeps a warmed-up model in memory.
+const WARM_UP_KEEP_ALIVE: &str = "10m";
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum OllamaError {
+    #[error("Ollama is not reachable at {url}. Make sure it is running (`ollama serve`) and the URL is correct.")]
+    Unreachable { url: String },
+    #[error(
+        "Ollama at {url} did not respond in time. It may still be loading; try again shortly."
+    )]
+    Timeout { url: String },
+    #[error(
+        "Model \"{model}\" is not installed in Ollama. Pull it first (`ollama pull {model}`)."
+    )]
+    ModelNotInstalled { model: String },
+    #[error("Model \"{model}\" does not exist in the Ollama library. Check the name and tag.")]
+    ModelNotFound { model: String },
+    #[error("Ollama rejected the request ({status}): {message}")]
+    Http { status: u16, message: String },
+    #[error("Unexpected response from Ollama: {0}")]
+    InvalidResponse(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OllamaStatus {
+    pub url: String,
+    pub version: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OllamaModel {
+    pub name: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default, rename(deserialize = "modified_at"))]
+    pub modified_at: Option<String>,
+    #[serde(default, skip_deserializing)]
+    pub parameter_size: Option<String>,
+    #[serde(default, skip_deserializing)]
+    pub quantization_level: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OllamaPullProgress {
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub digest: Option<String>,
+    #[serde(default)]
+    pub total: Option<u64>,
+    #[serde(default)]
+    pub completed: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct VersionResponse {
+    version: String,
+}
+
+#[derive(Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    models: Vec<TagEntry>,
+}
+
+#[derive(Deserialize)]
+struct TagEntry {
+    #[serde(flatten)]
+    model: OllamaModel,
+    #[serde(default)]
+    details: Option<TagDetails>,
+}
+
+#[derive(Deserialize)]
+struct TagDetails {
+    #[serde(default)]
+    parameter_size: Option<String>,
+    #[serde(default)]
+    quantization_level: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PullLine {
+    #[serde(flatten)]
+    progress: OllamaPullProgress,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+pub struct OllamaClient {
+    base_url: String,
+    client: Client,
+    /// Pulls and cold model loads can take minutes, so they get their own
+    /// (much longer) limit.
+    long_client: Client,
+    /// Sent as a bearer token for instances behind an authenticating proxy.
+    api_key: Option<String>,
+}
+
+impl OllamaClient {
+    pub fn new(base_url: Option<&str>) -> Result<Self, OllamaError> {
+        let base_url = base_url
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(OLLAMA_DEFAULT_URL)
+            .trim_end_matches('/')
+            // Keys saved for the OpenAI-compatible endpoint may include it.
+            .trim_end_matches("/v1")
+            .to_string();
+
+        let build = |timeout: Option<Duration>| {
+            Client::builder()
+                .connect_timeout(Duration::from_secs(3))
+                .timeout(timeout)
+                .build()
+                .map_err(|err| OllamaError::InvalidResponse(err.to_string()))
+        };
+
+        Ok(Self {
+            client: build(Some(Duration::from_secs(10)))?,
+            long_client: build(None)?,
+            base_url,
+            api_key: None,
+        })
+    }
+
+    pub fn with_api_key(mut self, api_key: Option<String>) -> Self {
+        self.api_key = api_key.filter(|key| !key.trim().is_empty());
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn probe(&self) -> Result<OllamaStatus, OllamaError> {
+        let response = self.check(self.get("/api/version").send(), None)?;
+        let parsed: VersionResponse = response
+            .json()
+            .map_err(|err| OllamaError::InvalidResponse(err.to_string()))?;
+
+        Ok(OllamaStatus {
+            url: self.base_url.clone(),
+            version: parsed.version,
+        })
+    }
+
+    pub fn list_models(&self) -> Result<Vec<OllamaModel>, OllamaError> {
+        let response = self.check(self.get("/api/tags").send(), None)?;
+        let parsed: TagsResponse = response
+            .json()
+            .map_err(|err| OllamaError::InvalidResponse(err.to_string()))?;
+
+        Ok(parsed
+            .models
+            .into_iter()
+            .map(|entry| {
+                let mut model = entry.model;
+                if let Some(details) = entry.details {
+                    model.parameter_size = details.parameter_size;
+                    model.quantization_level = details.quantization_level;
+                }
+                model
+            })
+            .collect())
+    }
+
+    /// Errors with `ModelNotInstalled` unless `model` is installed. A bare
+    /// name matches its `:latest` tag, as in the Ollama CLI.
+    pub fn ensure_installed(&self, model: &str) -> Result<(), OllamaError> {
+        let installed = self.list_models()?;
+        if installed.iter().any(|entry| same_model(&entry.name, model)) {
+            Ok(())
+        } else {
+            Err(OllamaError::ModelNotInstalled {
+                model: model.to_string(),
+            })
+        }
+    }
+
+    /// Pulls `model`, reporting each progress line as Ollama streams it.
+    pub fn pull(
+        &self,
+        model: &str,
+        mut on_progress: impl FnMut(&OllamaPullProgress),
+    ) -> Result<(), OllamaError> {
+        let response = self.check(
+            self.post("/api/pull")
+                .json(&json!({ "model": model, "stream": true }))
+                .send(),
+            Some(model),
+        )?;
+
+        for line in std::io::BufReader::new(response).lines() {
+            let line = line.map_err(|err| OllamaError::InvalidResponse(err.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let parsed: PullLine = serde_json::from_str(&line)
+                .map_err(|err| OllamaError::InvalidResponse(err.to_string()))?;
+            if let Some(error) = parsed.error {
+                return Err(pull_error(model, error));
+            }
+            on_progress(&parsed.progress);
+            if parsed.progress.status == "success" {
+                return Ok(());
+            }
+        }
+
+        Err(OllamaError::InvalidResponse(
+            "pull ended without a success status".to_string(),
+        ))
+    }
+
+    /// Loads `model` into memory with an empty prompt so the first real
+    /// request does not pay the load time.
+    pub fn warm_up(&self, model: &str) -> Result<(), OllamaError> {
+        self.ensure_installed(model)?;
+        self.check(
+            self.post("/api/generate")
+                .json(&json!({
+                    "model": model,
+                    "prompt": "",
+                    "stream": false,
+                    "keep_alive": WARM_UP_KEEP_ALIVE,
+                }))
+                .send(),
+            Some(model),
+        )?;
+        Ok(())
+    }
+
+    fn get(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.client.get(format!("{}{path}", self.base_url)))
+    }
+
+    /// Pulls and generation use the client without a total timeout.
+    fn post(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.long_client.post(format!("{}{path}", self.base_url)))
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.api_key {
+            Some(api_key) => request.bearer_auth(api_key),
+            None => request,
+        }
+    }
+
+    fn check(
+        &self,
+        result: reqwest::Result<Response>,
+        model: Option<&str>,
+    ) -> Result<Response, OllamaError> {
+        let response = result.map_err(|err| {
+            if err.is_timeout() {
+                OllamaError::Timeout {
+                    url: self.base_url.clone(),
+                }
+            } else {
+                OllamaError::Unreachable {
+                    url: self.base_url.clone(),
+                }
+            }
+        })?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let message = error_message(&response.text().unwrap_or_default());
+        match (status.as_u16(), model) {
+            (404, Some(model)) => Err(OllamaError::ModelNotInstalled {
+                model: model.to_string(),
+            }),
+            _ => Err(OllamaError::Http {
+                status: status.as_u16(),
+                message,
+            }),
+        }
+    }
+}
+
+fn same_model(installed: &str, requested: &str) -> bool {
+    let normalize = |name: &str| {
+        let name = name.trim();
+        if name.contains(':') {
+            name.to_string()
+        } else {
+            format!("{name}:latest")
+        }
+    };
+    normalize(installed) == normalize(requested)
+}
+
+fn pull_error(model: &str, message: String) -> OllamaError {
+    if message.contains("file does not exist") || message.contains("not found") {
+        OllamaError::ModelNotFound {
+            model: model.to_string(),
+        }
+    } else {
+        OllamaError::Http {
+            status: 500,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Matcher;
+
+    fn client(server: &mockito::Server) -> OllamaClient {
+        OllamaClient::new(Some(&format!("{}/v1/", server.url()))).unwrap()
+    }
+
+    #[test]
+    fn probes_the_version() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/api/version")
+            .with_body(r#"{"version":"0.6.2"}"#)
+            .create();
+
+        let status = client(&server).probe().unwrap();
+
+        assert_eq!(status.version, "0.6.2");
+        assert_eq!(status.url, server.url());
+    }
+
+    #[test]
+    fn sends_the_api_key_as_a_bearer_token() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/api/tags")
+            .match_header("authorization", "Bearer ol-test")
+            .with_body(r#"{"models":[]}"#)
+            .create();
+
+        let models = client(&server)
+            .with_api_key(Some("ol-test".to_string()))
+            .list_models()
+            .unwrap();
+
+        mock.assert();
+        assert!(models.is_empty());
+    }
+
+    #[test]
+    fn lists_models_and_checks_installation() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/api/tags")
+            .with_body(
+                r#"{"models":[{"name":"llama3.2:latest","size":2019393189,"modified_at":"2025-01-01T00:00:00Z","details":{"parameter_size":"3.2B","quantization_level":"Q4_K_M"}}]}"#,
+            )
+            .create();
+
+        let ollama = client(&server);
+        let models = ollama.list_models().unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].name, "llama3.2:latest");
+        assert_eq!(models[0].parameter_size.as_deref(), Some("3.2B"));
+        assert!(ollama.ensure_installed("llama3.2").is_ok());
+        assert_eq!(
+            ollama.ensure_installed("qwen2.5"),
+            Err(OllamaError::ModelNotInstalled {
+                model: "qwen2.5".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn unreachable_servers_get_an_actionable_error() {
+        // Nothing listens on a freshly released port.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let url = format!("http://127.0.0.1:{port}");
+
+        let err = OllamaClient::new(Some(&url))
+            .unwrap()
+            .ensure_installed("llama3.2")
+            .unwrap_err();
+
+        assert_eq!(err, OllamaError::Unreachable { url: url.clone() });
+        assert!(err.to_string().contains("ollama serve"));
+    }
+
+    #[test]
+    fn pull_reports_progress_until_success() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/api/pull")
+            .match_body(Matcher::PartialJson(json!({ "model": "llama3.2" })))
+            .with_body(concat!(
+                "{\"status\":\"pulling manifest\"}\n",
+                "{\"status\":\"pulling abc\",\"digest\":\"sha256:abc\",\"total\":100,\"completed\":40}\n",
+                "{\"status\":\"pulling abc\",\"digest\":\"sha256:abc\",\"total\":100,\"completed\":100}\n",
+                "{\"status\":\"success\"}\n",
+            ))
+            .create();
+
+        let mut completed = Vec::new();
+        client(&server)
+            .pull("llama3.2", |progress| completed.push(progress.completed))
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(completed, vec![None, Some(40), Some(100), None]);
+    }
+
+    #[test]
+    fn pull_of_unknown_model_is_explained() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/api/pull")
+            .with_body(
+                "{\"status\":\"pulling manifest\"}\n{\"error\":\"pull model manifest: file does not exist\"}\n",
+            )
+            .create();
+
+        let err = client(&server).pull("llama9", |_| {}).unwrap_err();
+        assert_eq!(
+            err,
+            OllamaError::ModelNotFound {
+                model: "llama9".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn warm_up_loads_installed_models_only() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("GET", "/api/tags")
+            .with_body(r#"{"models":[{"name":"llama3.2:latest"}]}"#)
+            .expect(2)
+            .create();
+        let generate = server
+            .mock("POST", "/api/generate")
+            .match_body(Matcher::PartialJson(json!({
+                "model": "llama3.2",
+                "prompt": "",
+                "keep_alive": WARM_UP_KEEP_ALIVE,
+            })))
+            .with_body(r#"{"done":true}"#)
+            .expect(1)
+            .create();
+
+        let ollama = client(&server);
+        ollama.warm_up("llama3.2").unwrap();
+        assert!(matches!(
+            ollama.warm_up("mistral"),
+            Err(OllamaError::ModelNotInstalled { .. })
+        ));
+
+        generate.assert();
+    }
+}
@@ -0,0 +1,523 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    open, parse_event, read_events, CancellationToken, LlmError, LlmOutput, LlmProvider, LlmRequest,
+};
+use crate::platform::remote::RetryPolicy;
+
+const OPENROUTER_BASE_URL: &str = "https://openrouter.ai/api/v1";
+const OPENROUTER_DEFAULT_MODEL: &str = "openai/gpt-4o-mini";
+const OPENROUTER_APP_NAME: &str = "Voquill";
+const OPENROUTER_APP_URL: &str = "https://voquill.com";
+const AZURE_API_VERSION: &str = "2024-10-21";
+const AZURE_DEFAULT_DEPLOYMENT: &str = "gpt-4o-mini";
+
+pub(crate) struct ChatDefaults {
+    pub label: &'static str,
+    pub base_url: &'static str,
+    pub model: &'static str,
+}
+
+impl ChatDefaults {
+    /// Same defaults as the frontend generate-text repos.
+    pub(crate) fn for_provider(provider: &str) -> Self {
+        match provider {
+            "groq" => Self {
+                label: "API • Groq",
+                base_url: "https://api.groq.com/openai/v1",
+                model: "meta-llama/llama-4-scout-17b-16e-instruct",
+            },
+            "deepseek" => Self {
+                label: "API • DeepSeek",
+                base_url: "https://api.deepseek.com/v1",
+                model: "deepseek-chat",
+            },
+            _ => Self {
+                label: "API • OpenAI",
+                base_url: "https://api.openai.com/v1",
+                model: "gpt-4o-mini",
+            },
+        }
+    }
+}
+
+pub enum ChatAuth {
+    Bearer(String),
+    /// Azure OpenAI's `api-key` header.
+    ApiKeyHeader(String),
+    None,
+}
+
+/// Streaming `/chat/completions` client shared by OpenAI, Groq, DeepSeek,
+/// OpenRouter, Azure OpenAI and Ollama's OpenAI-compatible endpoint.
+pub struct ChatCompletionsProvider {
+    label: &'static str,
+    url: String,
+    auth: ChatAuth,
+    model: String,
+    headers: Vec<(&'static str, String)>,
+    /// Provider-specific top-level fields merged into the request body.
+    extra_body: Option<Value>,
+    policy: RetryPolicy,
+}
+
+#[derive(Deserialize)]
+struct ChunkEvent {
+    #[serde(default)]
+    choices: Vec<ChunkChoice>,
+    #[serde(default)]
+    model: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ChunkChoice {
+    #[serde(default)]
+    delta: Option<ChunkDelta>,
+}
+
+#[derive(Deserialize)]
+struct ChunkDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+impl ChatCompletionsProvider {
+    pub fn new(
+        label: &'static str,
+        url: String,
+        auth: ChatAuth,
+        model: String,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            label,
+            url,
+            auth,
+            model,
+            headers: Vec::new(),
+            extra_body: None,
+            policy,
+        }
+    }
+
+    pub fn with_header(mut self, name: &'static str, value: String) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    pub fn with_extra_body(mut self, extra_body: Value) -> Self {
+        self.extra_body = Some(extra_body);
+        self
+    }
+
+    fn body(&self, request: &LlmRequest) -> Value {
+        let mut messages = Vec::new();
+        if let Some(system) = request.system.as_deref() {
+            messages.push(json!({ "role": "system", "content": system }));
+        }
+        messages.push(json!({ "role": "user", "content": request.prompt }));
+
+        let mut body = json!({
+            "model": self.model,
+            "messages": messages,
+            "stream": true,
+            "temperature": request.temperature.unwrap_or(1.0),
+        });
+        if let Some(max_tokens) = request.max_tokens {
+            body["max_tokens"] = json!(max_tokens);
+        }
+        if let Some(format) = request.json_response.as_ref() {
+            body["response_format"] = json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": format.name,
+                    "description": format.description,
+                    "schema": format.schema,
+                    "strict": true,
+                },
+            });
+        }
+        if let Some(Value::Object(extra)) = self.extra_body.as_ref() {
+            for (key, value) in extra {
+                body[key] = value.clone();
+            }
+        }
+
+        body
+    }
+}
+
+impl LlmProvider for ChatCompletionsProvider {
+    fn generate(
+        &self,
+        request: &LlmRequest,
+        cancel: &CancellationToken,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<LlmOutput, LlmError> {
+        let body = self.body(request);
+        let response = open(&self.policy, cancel, |client| {
+            let mut builder = client.post(&self.url).json(&body);
+            builder = match &self.auth {
+                ChatAuth::Bearer(key) => builder.bearer_auth(key),
+                ChatAuth::ApiKeyHeader(key) => builder.header("api-key", key),
+                ChatAuth::None => builder,
+            };
+            for (name, value) in &self.headers {
+                builder = builder.header(*name, value);
+            }
+            builder
+        })?;
+
+        let mut text = String::new();
+        let mut model = self.model.clone();
+        read_events(std::io::BufReader::new(response), cancel, |_, data| {
+            if data.trim() == "[DONE]" {
+                return Ok(false);
+            }
+
+            let chunk: ChunkEvent = parse_event(data)?;
+            if let Some(served_by) = chunk.model.filter(|value| !value.is_empty()) {
+                model = served_by;
+            }
+            for choice in chunk.choices {
+                if let Some(delta) = choice.delta.and_then(|delta| delta.content) {
+                    if !delta.is_empty() {
+                        on_delta(&delta);
+                        text.push_str(&delta);
+                    }
+                }
+            }
+            Ok(true)
+        })?;
+
+        Ok(LlmOutput { text, model })
+    }
+
+    fn label(&self) -> &str {
+        self.label
+    }
+}
+
+pub(crate) fn openrouter(
+    api_key: String,
+    model: Option<String>,
+    config_json: Option<&str>,
+    policy: RetryPolicy,
+) -> ChatCompletionsProvider {
+    let provider = ChatCompletionsProvider::new(
+        "API • OpenRouter",
+        format!("{OPENROUTER_BASE_URL}/chat/completions"),
+        ChatAuth::Bearer(api_key),
+        model.unwrap_or_else(|| OPENROUTER_DEFAULT_MODEL.to_string()),
+        policy,
+    )
+    .with_header("HTTP-Referer", OPENROUTER_APP_URL.to_string())
+    .with_header("X-Title", OPENROUTER_APP_NAME.to_string());
+
+    // `openRouterConfig` is stored as the frontend's JSON; only the
+    // provider routing is forwarded to the API.
+    let routing = config_json
+        .and_then(|json| serde_json::from_str::<Value>(json).ok())
+        .and_then(|config| config.get("providerRouting").cloned())
+        .filter(|routing| !routing.is_null());
+    match routing {
+        Some(routing) => provider.with_extra_body(json!({ "provider": routing })),
+        None => provider,
+    }
+}
+
+pub(crate) fn azure(
+    api_key: String,
+    endpoint: &str,
+    deployment: Option<String>,
+    policy: RetryPolicy,
+) -> ChatCompletionsProvider {
+    let deployment = deployment.unwrap_or_else(|| AZURE_DEFAULT_DEPLOYMENT.to_string());
+    ChatCompletionsProvider::new(
+        "API • Azure OpenAI",
+        format!(
+            "{}/openai/deployments/{deployment}/chat/completions?api-version={AZURE_API_VERSION}",
+            endpoint.trim_end_matches('/')
+        ),
+        ChatAuth::ApiKeyHeader(api_key),
+        deployment,
+        policy,
+    )
+}
+
+pub(crate) fn ollama(
+    base_url: &str,
+    model: String,
+    api_key: Option<String>,
+    policy: RetryPolicy,
+) -> ChatCompletionsProvider {
+    ChatCompletionsProvider::new(
+        "API • Ollama",
+        format!("{}/v1/chat/completions", base_url.trim_end_matches('/')),
+        api_key.map(ChatAuth::Bearer).unwrap_or(ChatAuth::None),
+        model,
+        policy,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::llm::test_support::{fast_policy, sse};
+    use crate::platform::llm::JsonResponseFormat;
+    use mockito::Matcher;
+    use std::time::Duration;
+
+    fn request() -> LlmRequest {
+        LlmRequest {
+            system: Some("Clean up the transcript.".to_string()),
+            prompt: "um hello there".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn streams_deltas_in_order() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/chat/completions")
+            .match_header("authorization", "Bearer sk-test")
+            .match_body(Matcher::PartialJson(json!({
+                "model": "gpt-4o-mini",
+                "stream": true,
+                "messages": [
+                    { "role": "system", "content": "Clean up the transcript." },
+                    { "role": "user", "content": "um hello there" },
+                ],
+            })))
+            .with_header("content-type", "text/event-stream")
+            .with_body(sse(&[
+                r#"{"model":"gpt-4o-mini-2024","choices":[{"delta":{"role":"assistant"}}]}"#,
+                r#"{"choices":[{"delta":{"content":"Hello"}}]}"#,
+                r#"{"choices":[{"delta":{"content":" there."}}]}"#,
+                "[DONE]",
+            ]))
+            .create();
+
+        let provider = ChatCompletionsProvider::new(
+            "API • OpenAI",
+            format!("{}/v1/chat/completions", server.url()),
+            ChatAuth::Bearer("sk-test".to_string()),
+            "gpt-4o-mini".to_string(),
+            fast_policy(),
+        );
+        let mut deltas = Vec::new();
+        let output = provider
+            .generate(&request(), &CancellationToken::new(), &mut |delta| {
+                deltas.push(delta.to_string())
+            })
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(deltas, vec!["Hello", " there."]);
+        assert_eq!(output.text, "Hello there.");
+        assert_eq!(output.model, "gpt-4o-mini-2024");
+    }
+
+    fn slow_stream(server: &mut mockito::Server, gap: Duration) -> mockito::Mock {
+        server
+            .mock("POST", "/v1/chat/completions")
+            .with_header("content-type", "text/event-stream")
+            .with_chunked_body(move |writer| {
+                for word in ["One", " two", " three", " four"] {
+                    let event = json!({ "choices": [{ "delta": { "content": word } }] });
+                    writer.write_all(sse(&[&event.to_string()]).as_bytes())?;
+                    writer.flush()?;
+                    std::thread::sleep(gap);
+                }
+                writer.write_all(sse(&["[DONE]"]).as_bytes())
+            })
+            .create()
+    }
+
+    fn slow_provider(server: &mockito::Server) -> ChatCompletionsProvider {
+        ChatCompletionsProvider::new(
+            "API • Ollama",
+            format!("{}/v1/chat/completions", server.url()),
+            ChatAuth::None,
+            "llama3.2".to_string(),
+            RetryPolicy {
+                request_timeout: Duration::from_millis(400),
+                max_attempts: 1,
+                ..fast_policy()
+            },
+        )
+    }
+
+    #[test]
+    fn keeps_streaming_past_the_request_timeout() {
+        let mut server = mockito::Server::new();
+        let mock = slow_stream(&mut server, Duration::from_millis(150));
+
+        let output = slow_provider(&server)
+            .generate(&request(), &CancellationToken::new(), &mut |_| {})
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(output.text, "One two three four");
+    }
+
+    #[test]
+    fn times_out_when_the_stream_stalls() {
+        let mut server = mockito::Server::new();
+        slow_stream(&mut server, Duration::from_millis(800));
+
+        let mut deltas = Vec::new();
+        let err = slow_provider(&server)
+            .generate(&request(), &CancellationToken::new(), &mut |delta| {
+                deltas.push(delta.to_string())
+            })
+            .unwrap_err();
+
+        assert_eq!(err, LlmError::Timeout);
+        assert_eq!(deltas, vec!["One"]);
+    }
+
+    #[test]
+    fn leaves_max_tokens_to_the_provider_unless_set() {
+        let provider = ollama(
+            "http://127.0.0.1:1",
+            "llama3.2".to_string(),
+            None,
+            fast_policy(),
+        );
+        assert!(provider.body(&request()).get("max_tokens").is_none());
+
+        let request = LlmRequest {
+            max_tokens: Some(256),
+            ..request()
+        };
+        assert_eq!(provider.body(&request)["max_tokens"], json!(256));
+    }
+
+    #[test]
+    fn requests_json_schema_and_openrouter_routing() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .match_header("x-title", OPENROUTER_APP_NAME)
+            .match_body(Matcher::PartialJson(json!({
+                "provider": { "order": ["groq"] },
+                "response_format": {
+                    "type": "json_schema",
+                    "json_schema": { "name": "transcription_cleaning" },
+                },
+            })))
+            .with_body(sse(&[
+                r#"{"choices":[{"delta":{"content":"{}"}}]}"#,
+                "[DONE]",
+            ]))
+            .create();
+
+        let mut provider = openrouter(
+            "or-test".to_string(),
+            None,
+            Some(r#"{"providerRouting":{"order":["groq"]}}"#),
+            fast_policy(),
+        );
+        provider.url = format!("{}/chat/completions", server.url());
+
+        let request = LlmRequest {
+            json_response: Some(JsonResponseFormat {
+                name: "transcription_cleaning".to_string(),
+                description: None,
+                schema: json!({ "type": "object" }),
+            }),
+            ..request()
+        };
+        let output = provider
+            .generate(&request, &CancellationToken::new(), &mut |_| {})
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(output.text, "{}");
+    }
+
+    #[test]
+    fn azure_uses_deployment_url_and_api_key_header() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/openai/deployments/cleanup/chat/completions")
+            .match_query(Matcher::UrlEncoded(
+                "api-version".to_string(),
+                AZURE_API_VERSION.to_string(),
+            ))
+            .match_header("api-key", "az-test")
+            .with_status(401)
+            .expect(1)
+            .create();
+
+        let provider = azure(
+            "az-test".to_string(),
+            &format!("{}/", server.url()),
+            Some("cleanup".to_string()),
+            fast_policy(),
+        );
+        let err = provider
+            .generate(&request(), &CancellationToken::new(), &mut |_| {})
+            .unwrap_err();
+
+        mock.assert();
+        assert_eq!(err, LlmError::Unauthorized { status: 401 });
+    }
+
+    #[test]
+    fn cancellation_stops_mid_stream() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/v1/chat/completions")
+            .with_body(sse(&[
+                r#"{"choices":[{"delta":{"content":"Hello"}}]}"#,
+                r#"{"choices":[{"delta":{"content":" world"}}]}"#,
+                "[DONE]",
+            ]))
+            .create();
+
+        let provider = ollama(&server.url(), "llama3.2".to_string(), None, fast_policy());
+        let cancel = CancellationToken::new();
+        let mut deltas = Vec::new();
+        let err = provider
+            .generate(&request(), &cancel, &mut |delta| {
+                deltas.push(delta.to_string());
+                cancel.cancel();
+            })
+            .unwrap_err();
+
+        assert_eq!(err, LlmError::Cancelled);
+        assert_eq!(deltas, vec!["Hello"]);
+    }
+
+    #[test]
+    fn stalled_servers_time_out() {
+        // Accepts connections but never answers.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/v1/chat/completions",
+            listener.local_addr().unwrap()
+        );
+
+        let mut policy = fast_policy();
+        policy.request_timeout = std::time::Duration::from_millis(200);
+        policy.max_attempts = 1;
+        let provider = ChatCompletionsProvider::new(
+            "API • OpenAI",
+            url,
+            ChatAuth::None,
+            "gpt-4o-mini".to_string(),
+            policy,
+        );
+
+        let err = provider
+            .generate(&request(), &CancellationToken::new(), &mut |_| {})
+            .unwrap_err();
+        drop(listener);
+
+        assert_eq!(err, LlmError::Timeout);
+    }
+}
@@ -3,35 +3,35 @@ use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
 use std::{thread, time::Duration};
 
 const KEY_V: CGKeyCode = 9;
+const KEY_DELETE: CGKeyCode = 51;
+
+/// Pause between Backspace presses so slow apps don't drop any.
+const BACKSPACE_INTERVAL: Duration = Duration::from_millis(2);
 
 pub(crate) fn paste_text_into_focused_field(text: &str, _keybind: Option<&str>) -> Result<(), String> {
     if text.trim().is_empty() {
         return Ok(());
     }
-    paste_via_clipboard(text)
+    paste_via_clipboard(text, None)
 }
 
-fn paste_via_clipboard(text: &str) -> Result<(), String> {
-    let mut clipboard =
-        arboard::Clipboard::new().map_err(|err| format!("clipboard unavailable: {err}"))?;
-    let previous = clipboard.get_text().ok();
-    clipboard
-        .set_text(text.to_string())
-        .map_err(|err| format!("failed to store clipboard text: {err}"))?;
-
-    thread::sleep(Duration::from_millis(50));
-    simulate_cmd_v()?;
-
-    if let Some(old) = previous {
-        thread::spawn(move || {
-            thread::sleep(Duration::from_millis(800));
-            if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                let _ = clipboard.set_text(old);
-            }
-        });
+/// Pastes `html` where the app accepts formatted text and `text` elsewhere.
+pub(crate) fn paste_rich_text_into_focused_field(
+    text: &str,
+    html: &str,
+    _keybind: Option<&str>,
+) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Ok(());
     }
+    paste_via_clipboard(text, Some(html))
+}
 
-    Ok(())
+fn paste_via_clipboard(text: &str, html: Option<&str>) -> Result<(), String> {
+    crate::platform::clipboard::paste_preserving_clipboard(text, html, || {
+        thread::sleep(Duration::from_millis(50));
+        simulate_cmd_v()
+    })
 }
 
 fn simulate_cmd_v() -> Result<(), String> {
@@ -52,3 +52,45 @@ fn simulate_cmd_v() -> Result<(), String> {
 
     Ok(())
 }
+
+/// Presses Backspace `count` times in the focused field.
+pub(crate) fn delete_backward(count: usize) -> Result<(), String> {
+    let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState)
+        .map_err(|_| "failed to create event source")?;
+
+    for _ in 0..count {
+        for is_down in [true, false] {
+            let event = CGEvent::new_keyboard_event(source.clone(), KEY_DELETE, is_down)
+                .map_err(|_| "failed to create delete key event")?;
+            // Held hotkey modifiers would otherwise turn this into Option or
+            // Cmd+Delete and remove whole words or lines.
+            event.set_flags(CGEventFlags::CGEventFlagNull);
+            event.post(CGEventTapLocation::HID);
+        }
+        thread::sleep(BACKSPACE_INTERVAL);
+    }
+
+    Ok(())
+}
+
+/// Types `text` one character at a time, pausing `interval` after each.
+pub(crate) fn type_text(text: &str, interval: Duration) -> Result<(), String> {
+    let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState)
+        .map_err(|_| "failed to create event source")?;
+
+    let mut buffer = [0u8; 4];
+    for ch in text.chars() {
+        for is_down in [true, false] {
+            let event = CGEvent::new_keyboard_event(source.clone(), 0, is_down)
+                .map_err(|_| "failed to create typing event")?;
+            event.set_flags(CGEventFlags::CGEventFlagNull);
+            event.set_string(ch.encode_utf8(&mut buffer));
+            event.post(CGEventTapLocation::HID);
+        }
+        if !interval.is_zero() {
+            thread::sleep(interval);
+        }
+    }
+
+    Ok(())
+}
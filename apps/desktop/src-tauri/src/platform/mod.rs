@@ -46,10 +46,18 @@ pub use windows::position;
 pub use windows::window;
 
 pub mod app_info;
+pub mod clipboard;
+pub mod injection;
 
 pub mod audio;
+pub mod candle_whisper;
+pub mod llm;
+pub mod remote;
 pub mod whisper;
 
+#[cfg(test)]
+mod transcriber_conformance;
+
 #[cfg(desktop)]
 pub mod keyboard;
 
@@ -62,6 +70,21 @@ pub struct TranscriptionRequest {
     pub model_path: Option<String>,
     pub initial_prompt: Option<String>,
     pub language: Option<String>,
+    /// Languages the user dictates in. When two or more are present and no
+    /// explicit `language` is forced, detection is restricted to this set.
+    pub candidate_languages: Vec<String>,
+    pub translation_mode: crate::domain::TranslationMode,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TranscriptionOutput {
+    pub text: String,
+    /// Language the engine detected in the audio; `None` when the request
+    /// fixed the language.
+    pub detected_language: Option<String>,
+    pub language_probabilities: Vec<crate::domain::LanguageProbability>,
+    /// English translation of `text`, present when the request asked for one.
+    pub translation: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -96,5 +119,25 @@ pub trait Transcriber: Send + Sync {
         samples: &[f32],
         sample_rate: u32,
         request: Option<&TranscriptionRequest>,
-    ) -> Result<String, String>;
+    ) -> Result<TranscriptionOutput, String>;
+}
+
+pub fn load_local_transcriber(
+    engine: crate::system::models::LocalTranscriptionEngine,
+    model_path: &std::path::Path,
+) -> Result<Arc<dyn Transcriber>, String> {
+    use crate::system::models::LocalTranscriptionEngine;
+
+    let transcriber: Arc<dyn Transcriber> = match engine {
+        LocalTranscriptionEngine::WhisperCpp => Arc::new(
+            whisper::WhisperTranscriber::new(model_path)
+                .map_err(|err| format!("Failed to initialize Whisper transcriber: {err}"))?,
+        ),
+        LocalTranscriptionEngine::Candle => Arc::new(
+            candle_whisper::CandleWhisperTranscriber::new(model_path)
+                .map_err(|err| format!("Failed to initialize candle Whisper transcriber: {err}"))?,
+        ),
+    };
+
+    Ok(transcriber)
 }
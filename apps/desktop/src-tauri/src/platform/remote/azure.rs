@@ -0,0 +1,291 @@
+use serde::Deserialize;
+
+use super::{requested_languages, RemoteTranscriptionError, RetryPolicy, WavPayload};
+use crate::platform::{Transcriber, TranscriptionOutput, TranscriptionRequest};
+
+const RECOGNITION_PATH: &str = "/speech/recognition/conversation/cognitiveservices/v1";
+/// The short-audio endpoint rejects anything longer.
+const MAX_AUDIO_SECONDS: u32 = 60;
+
+/// Azure Speech short-audio REST API. Requests are limited to 60 seconds of
+/// audio, so longer recordings fail with `AudioTooLong` before anything is
+/// sent and the fallback chain can move on. Only the first candidate
+/// language is recognised: the endpoint takes a single locale and neither
+/// detects the language nor translates.
+pub struct AzureSpeechTranscriber {
+    subscription_key: String,
+    endpoint: String,
+    policy: RetryPolicy,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RecognitionResponse {
+    recognition_status: String,
+    #[serde(default)]
+    display_text: Option<String>,
+}
+
+impl AzureSpeechTranscriber {
+    /// `endpoint` overrides the regional endpoint, e.g. for private links.
+    pub fn new(
+        subscription_key: String,
+        region: Option<String>,
+        endpoint: Option<String>,
+        policy: RetryPolicy,
+    ) -> Self {
+        let endpoint = endpoint.unwrap_or_else(|| {
+            format!(
+                "https://{}.stt.speech.microsoft.com",
+                region.unwrap_or_default()
+            )
+        });
+
+        Self {
+            subscription_key,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            policy,
+        }
+    }
+
+    pub fn transcribe_remote(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, RemoteTranscriptionError> {
+        let seconds = samples.len() as f32 / sample_rate.max(1) as f32;
+        if seconds > MAX_AUDIO_SECONDS as f32 {
+            return Err(RemoteTranscriptionError::AudioTooLong {
+                seconds,
+                limit_seconds: MAX_AUDIO_SECONDS,
+            });
+        }
+
+        let audio = WavPayload::from_samples(samples, sample_rate)?;
+        let language = requested_languages(request).into_iter().next();
+        let locale = azure_locale(language.as_deref());
+
+        let url = format!("{}{RECOGNITION_PATH}", self.endpoint);
+        let response = self.policy.send(|client| {
+            client
+                .post(&url)
+                .query(&[("language", locale), ("format", "simple")])
+                .header("Ocp-Apim-Subscription-Key", &self.subscription_key)
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    "audio/wav; codecs=audio/pcm; samplerate=16000",
+                )
+                .body(reqwest::blocking::Body::sized(audio.reader(), audio.len()))
+        })?;
+
+        let parsed: RecognitionResponse = response
+            .json()
+            .map_err(|err| RemoteTranscriptionError::InvalidResponse(err.to_string()))?;
+
+        let text = match parsed.recognition_status.as_str() {
+            "Success" => parsed.display_text.unwrap_or_default().trim().to_string(),
+            "NoMatch" | "InitialSilenceTimeout" => String::new(),
+            other => {
+                return Err(RemoteTranscriptionError::InvalidResponse(format!(
+                    "recognition status {other}"
+                )))
+            }
+        };
+
+        Ok(TranscriptionOutput {
+            text,
+            // The locale is chosen up front; Azure does not detect it.
+            detected_language: None,
+            language_probabilities: Vec::new(),
+            translation: None,
+        })
+    }
+}
+
+impl Transcriber for AzureSpeechTranscriber {
+    fn transcribe(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, String> {
+        self.transcribe_remote(samples, sample_rate, request)
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// Mirrors `mapToAzureLocale` in `@repo/voice-ai` so both code paths pick the
+/// same recognition locale.
+fn azure_locale(language: Option<&str>) -> &'static str {
+    match language.map(str::trim).unwrap_or_default() {
+        "es" => "es-ES",
+        "fr" => "fr-FR",
+        "de" => "de-DE",
+        "it" => "it-IT",
+        "pt" => "pt-PT",
+        "ru" => "ru-RU",
+        "ja" => "ja-JP",
+        "ko" => "ko-KR",
+        "zh" => "zh-CN",
+        "ar" => "ar-SA",
+        "nl" => "nl-NL",
+        "sv" => "sv-SE",
+        "tr" => "tr-TR",
+        "pl" => "pl-PL",
+        "ca" => "ca-ES",
+        "id" => "id-ID",
+        "hi" => "hi-IN",
+        "fi" => "fi-FI",
+        "vi" => "vi-VN",
+        "he" => "he-IL",
+        "uk" => "uk-UA",
+        "el" => "el-GR",
+        "ms" => "ms-MY",
+        "cs" => "cs-CZ",
+        "ro" => "ro-RO",
+        "da" => "da-DK",
+        "hu" => "hu-HU",
+        "ta" => "ta-IN",
+        "no" => "nb-NO",
+        "th" => "th-TH",
+        "ur" => "ur-PK",
+        "hr" => "hr-HR",
+        "bg" => "bg-BG",
+        "lt" => "lt-LT",
+        "sk" => "sk-SK",
+        "sl" => "sl-SI",
+        "et" => "et-EE",
+        "lv" => "lv-LV",
+        "fa" => "fa-IR",
+        "sr" => "sr-RS",
+        "bn" => "bn-IN",
+        "af" => "af-ZA",
+        "hy" => "hy-AM",
+        "az" => "az-AZ",
+        "eu" => "eu-ES",
+        "bs" => "bs-BA",
+        "gl" => "gl-ES",
+        "gu" => "gu-IN",
+        "is" => "is-IS",
+        "kk" => "kk-KZ",
+        "kn" => "kn-IN",
+        "km" => "km-KH",
+        "lo" => "lo-LA",
+        "mk" => "mk-MK",
+        "ml" => "ml-IN",
+        "mr" => "mr-IN",
+        "mn" => "mn-MN",
+        "ne" => "ne-NP",
+        "ps" => "ps-AF",
+        "si" => "si-LK",
+        "sw" => "sw-KE",
+        "te" => "te-IN",
+        "uz" => "uz-UZ",
+        "cy" => "cy-GB",
+        "am" => "am-ET",
+        "ka" => "ka-GE",
+        "my" => "my-MM",
+        "so" => "so-SO",
+        "sq" => "sq-AL",
+        _ => "en-US",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::remote::test_support::{fast_policy, one_second_of_audio};
+    use mockito::Matcher;
+
+    fn transcriber(server: &mockito::Server) -> AzureSpeechTranscriber {
+        AzureSpeechTranscriber::new(
+            "az-test".to_string(),
+            Some("koreacentral".to_string()),
+            Some(server.url()),
+            fast_policy(),
+        )
+    }
+
+    #[test]
+    fn recognises_with_the_mapped_locale() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", RECOGNITION_PATH)
+            .match_header("ocp-apim-subscription-key", "az-test")
+            .match_query(Matcher::UrlEncoded(
+                "language".to_string(),
+                "ko-KR".to_string(),
+            ))
+            .with_body(r#"{"RecognitionStatus":"Success","DisplayText":"안녕하세요."}"#)
+            .create();
+
+        let request = TranscriptionRequest {
+            language: Some("ko".to_string()),
+            ..Default::default()
+        };
+        let output = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, Some(&request))
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(output.text, "안녕하세요.");
+        assert!(output.detected_language.is_none());
+    }
+
+    #[test]
+    fn silence_is_an_empty_transcript() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", RECOGNITION_PATH)
+            .match_query(Matcher::Any)
+            .with_body(r#"{"RecognitionStatus":"InitialSilenceTimeout"}"#)
+            .create();
+
+        let output = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, None)
+            .unwrap();
+
+        assert_eq!(output.text, "");
+    }
+
+    #[test]
+    fn rejects_audio_over_the_short_audio_limit() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", RECOGNITION_PATH)
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let audio = one_second_of_audio().repeat(61);
+        let err = transcriber(&server)
+            .transcribe_remote(&audio, 16_000, None)
+            .unwrap_err();
+
+        mock.assert();
+        assert!(matches!(
+            err,
+            RemoteTranscriptionError::AudioTooLong {
+                limit_seconds: 60,
+                ..
+            }
+        ));
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn default_endpoint_uses_region() {
+        let transcriber = AzureSpeechTranscriber::new(
+            "key".to_string(),
+            Some("eastus".to_string()),
+            None,
+            fast_policy(),
+        );
+        assert_eq!(
+            transcriber.endpoint,
+            "https://eastus.stt.speech.microsoft.com"
+        );
+        assert_eq!(azure_locale(None), "en-US");
+    }
+}
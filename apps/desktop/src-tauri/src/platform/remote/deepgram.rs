@@ -0,0 +1,210 @@
+use serde::Deserialize;
+
+use super::{requested_languages, RemoteTranscriptionError, RetryPolicy, WavPayload};
+use crate::platform::{Transcriber, TranscriptionOutput, TranscriptionRequest};
+
+const DEFAULT_BASE_URL: &str = "https://api.deepgram.com";
+const DEFAULT_MODEL: &str = "nova-3";
+
+/// Deepgram pre-recorded `/v1/listen`. The WAV body is streamed rather than
+/// wrapped in multipart. Deepgram has no translation endpoint, so
+/// translation modes fall back to the original transcript.
+pub struct DeepgramTranscriber {
+    api_key: String,
+    base_url: String,
+    model: String,
+    policy: RetryPolicy,
+}
+
+#[derive(Deserialize)]
+struct ListenResponse {
+    results: ListenResults,
+}
+
+#[derive(Deserialize)]
+struct ListenResults {
+    channels: Vec<ListenChannel>,
+}
+
+#[derive(Deserialize)]
+struct ListenChannel {
+    #[serde(default)]
+    alternatives: Vec<ListenAlternative>,
+    #[serde(default)]
+    detected_language: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ListenAlternative {
+    transcript: String,
+}
+
+impl DeepgramTranscriber {
+    pub fn new(
+        api_key: String,
+        base_url: Option<String>,
+        model: Option<String>,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            api_key,
+            base_url: base_url
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            model: model.unwrap_or_else(|| DEFAULT_MODEL.to_string()),
+            policy,
+        }
+    }
+
+    pub fn transcribe_remote(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, RemoteTranscriptionError> {
+        let audio = WavPayload::from_samples(samples, sample_rate)?;
+        let languages = requested_languages(request);
+
+        let mut query: Vec<(&str, String)> = vec![
+            ("model", self.model.clone()),
+            ("smart_format", "true".to_string()),
+            ("punctuate", "true".to_string()),
+        ];
+        match languages.as_slice() {
+            [] => query.push(("detect_language", "true".to_string())),
+            [single] => query.push(("language", single.clone())),
+            // Restricts detection to the user's languages.
+            several => query.extend(several.iter().map(|code| ("detect_language", code.clone()))),
+        }
+
+        let url = format!("{}/v1/listen", self.base_url);
+        let response = self.policy.send(|client| {
+            client
+                .post(&url)
+                .query(&query)
+                .header(
+                    reqwest::header::AUTHORIZATION,
+                    format!("Token {}", self.api_key),
+                )
+                .header(reqwest::header::CONTENT_TYPE, "audio/wav")
+                .body(reqwest::blocking::Body::sized(audio.reader(), audio.len()))
+        })?;
+
+        let parsed: ListenResponse = response
+            .json()
+            .map_err(|err| RemoteTranscriptionError::InvalidResponse(err.to_string()))?;
+        let channel = parsed.results.channels.into_iter().next().ok_or_else(|| {
+            RemoteTranscriptionError::InvalidResponse("response has no channels".to_string())
+        })?;
+
+        let text = channel
+            .alternatives
+            .into_iter()
+            .next()
+            .map(|alternative| alternative.transcript.trim().to_string())
+            .unwrap_or_default();
+        let detected_language = channel
+            .detected_language
+            .map(|code| {
+                code.split('-')
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase()
+            })
+            .filter(|code| !code.is_empty());
+
+        let translation_mode = request.map(|req| req.translation_mode).unwrap_or_default();
+        if translation_mode.requires_translation() && detected_language.as_deref() != Some("en") {
+            eprintln!("[deepgram] translation is not supported; returning the original transcript");
+        }
+
+        Ok(TranscriptionOutput {
+            text,
+            detected_language,
+            language_probabilities: Vec::new(),
+            translation: None,
+        })
+    }
+}
+
+impl Transcriber for DeepgramTranscriber {
+    fn transcribe(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, String> {
+        self.transcribe_remote(samples, sample_rate, request)
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::remote::test_support::{fast_policy, one_second_of_audio};
+    use mockito::Matcher;
+
+    #[test]
+    fn streams_wav_and_restricts_detection_to_candidates() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/listen")
+            .match_header("authorization", "Token dg-test")
+            .match_header("content-type", "audio/wav")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("model".to_string(), "nova-3".to_string()),
+                // Repeated keys, which `UrlEncoded` collapses.
+                Matcher::Regex("detect_language=en&detect_language=ko".to_string()),
+            ]))
+            .match_request(|request| {
+                // 44 byte header + one second of 16-bit samples at 16 kHz.
+                request
+                    .body()
+                    .is_ok_and(|body| body.starts_with(b"RIFF") && body.len() == 44 + 32_000)
+            })
+            .with_body(
+                r#"{"results":{"channels":[{"detected_language":"ko","alternatives":[{"transcript":"네 알겠습니다"}]}]}}"#,
+            )
+            .create();
+
+        let request = TranscriptionRequest {
+            candidate_languages: vec!["en-US".to_string(), "ko-KR".to_string()],
+            ..Default::default()
+        };
+        let output = DeepgramTranscriber::new(
+            "dg-test".to_string(),
+            Some(server.url()),
+            None,
+            fast_policy(),
+        )
+        .transcribe_remote(&one_second_of_audio(), 16_000, Some(&request))
+        .unwrap();
+
+        mock.assert();
+        assert_eq!(output.text, "네 알겠습니다");
+        assert_eq!(output.detected_language.as_deref(), Some("ko"));
+    }
+
+    #[test]
+    fn malformed_responses_are_typed() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/v1/listen")
+            .match_query(Matcher::Any)
+            .with_body(r#"{"results":{"channels":[]}}"#)
+            .create();
+
+        let err = DeepgramTranscriber::new(
+            "dg-test".to_string(),
+            Some(server.url()),
+            None,
+            fast_policy(),
+        )
+        .transcribe_remote(&one_second_of_audio(), 16_000, None)
+        .unwrap_err();
+
+        assert!(matches!(err, RemoteTranscriptionError::InvalidResponse(_)));
+    }
+}
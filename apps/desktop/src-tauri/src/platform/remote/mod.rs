@@ -0,0 +1,452 @@
+//! Cloud speech-to-text providers behind the `Transcriber` trait.
+//!
+//! Every provider receives 16 kHz mono 16-bit WAV, goes through the same
+//! timeout/retry policy and reports failures as `RemoteTranscriptionError` so
+//! callers can tell a bad key from a flaky network.
+
+pub mod azure;
+pub mod deepgram;
+pub mod openai;
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::StatusCode;
+
+use crate::domain::ApiKey;
+use crate::platform::whisper::{candidate_language_codes, prepare_samples, WHISPER_SAMPLE_RATE};
+use crate::platform::{Transcriber, TranscriptionRequest};
+
+pub use azure::AzureSpeechTranscriber;
+pub use deepgram::DeepgramTranscriber;
+pub use openai::OpenAiCompatibleTranscriber;
+
+const MAX_ERROR_MESSAGE_LEN: usize = 500;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum RemoteTranscriptionError {
+    #[error("unsupported transcription provider: {0}")]
+    UnsupportedProvider(String),
+    #[error("missing provider configuration: {0}")]
+    MissingConfiguration(&'static str),
+    #[error("invalid audio: {0}")]
+    InvalidAudio(String),
+    #[error("audio is {seconds:.0}s long; the provider accepts at most {limit_seconds}s")]
+    AudioTooLong { seconds: f32, limit_seconds: u32 },
+    #[error("request timed out")]
+    Timeout,
+    #[error("network error: {0}")]
+    Network(String),
+    #[error("provider rejected the API key ({status})")]
+    Unauthorized { status: u16 },
+    #[error("rate limited by provider")]
+    RateLimited { retry_after: Option<Duration> },
+    #[error("provider returned {status}: {message}")]
+    Http { status: u16, message: String },
+    #[error("unexpected provider response: {0}")]
+    InvalidResponse(String),
+}
+
+impl RemoteTranscriptionError {
+    /// Transient failures worth another attempt against the same provider.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Timeout | Self::Network(_) | Self::RateLimited { .. } => true,
+            Self::Http { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// Failures of an HTTP exchange with a provider, implemented by the
+/// transcription and post-processing error types so both go through
+/// `RetryPolicy::send`.
+pub(crate) trait ProviderError: std::fmt::Display + Sized {
+    /// Prefix for retry log lines.
+    const LOG_TAG: &'static str;
+
+    fn timeout() -> Self;
+    fn network(message: String) -> Self;
+    fn unauthorized(status: u16) -> Self;
+    fn rate_limited(retry_after: Option<Duration>) -> Self;
+    fn http(status: u16, message: String) -> Self;
+    fn is_retryable(&self) -> bool;
+    /// The requested wait when this is a rate limit, `None` otherwise.
+    fn rate_limit(&self) -> Option<Option<Duration>>;
+
+    fn from_reqwest(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::timeout()
+        } else {
+            Self::network(err.to_string())
+        }
+    }
+
+    fn from_response(response: Response) -> Self {
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::unauthorized(status.as_u16()),
+            StatusCode::TOO_MANY_REQUESTS => Self::rate_limited(retry_after),
+            _ => Self::http(
+                status.as_u16(),
+                error_message(&response.text().unwrap_or_default()),
+            ),
+        }
+    }
+}
+
+impl ProviderError for RemoteTranscriptionError {
+    const LOG_TAG: &'static str = "remote-transcription";
+
+    fn timeout() -> Self {
+        Self::Timeout
+    }
+
+    fn network(message: String) -> Self {
+        Self::Network(message)
+    }
+
+    fn unauthorized(status: u16) -> Self {
+        Self::Unauthorized { status }
+    }
+
+    fn rate_limited(retry_after: Option<Duration>) -> Self {
+        Self::RateLimited { retry_after }
+    }
+
+    fn http(status: u16, message: String) -> Self {
+        Self::Http { status, message }
+    }
+
+    fn is_retryable(&self) -> bool {
+        RemoteTranscriptionError::is_retryable(self)
+    }
+
+    fn rate_limit(&self) -> Option<Option<Duration>> {
+        match self {
+            Self::RateLimited { retry_after } => Some(*retry_after),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// How long to wait for the response headers, then for each read of the
+    /// body. reqwest's blocking client restarts it on every read, so a
+    /// streamed body may take longer in total while data keeps arriving.
+    pub request_timeout: Duration,
+    pub connect_timeout: Duration,
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Longest `Retry-After` worth waiting for; a provider asking for more
+    /// fails the request instead.
+    pub max_retry_after: Duration,
+    /// Off when another provider can take over, so a rate limit fails fast.
+    pub retry_rate_limits: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(60),
+            connect_timeout: Duration::from_secs(10),
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(4),
+            max_retry_after: Duration::from_secs(10),
+            retry_rate_limits: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sends the request built by `build`, retrying transient failures with
+    /// exponential backoff. `Retry-After` wins over the computed delay, up to
+    /// `max_retry_after`.
+    fn send<F>(&self, build: F) -> Result<Response, RemoteTranscriptionError>
+    where
+        F: Fn(&Client) -> RequestBuilder,
+    {
+        self.send_with(|| Ok(()), build)
+    }
+
+    /// `send` for any provider error type, running `before_attempt` ahead of
+    /// every attempt so the caller can abort, e.g. on cancellation.
+    pub(crate) fn send_with<E, C, F>(&self, before_attempt: C, build: F) -> Result<Response, E>
+    where
+        E: ProviderError,
+        C: Fn() -> Result<(), E>,
+        F: Fn(&Client) -> RequestBuilder,
+    {
+        let client = Client::builder()
+            .timeout(self.request_timeout)
+            .connect_timeout(self.connect_timeout)
+            .build()
+            .map_err(|err| E::network(err.to_string()))?;
+        let max_attempts = self.max_attempts.max(1);
+        let mut backoff = self.initial_backoff;
+
+        for attempt in 1..=max_attempts {
+            before_attempt()?;
+            let error = match build(&client).send() {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => E::from_response(response),
+                Err(err) => E::from_reqwest(err),
+            };
+
+            let rate_limit = error.rate_limit();
+            if !error.is_retryable()
+                || attempt == max_attempts
+                || (rate_limit.is_some() && !self.retry_rate_limits)
+            {
+                return Err(error);
+            }
+
+            let delay = match rate_limit.flatten() {
+                Some(retry_after) if retry_after > self.max_retry_after => return Err(error),
+                Some(retry_after) => retry_after,
+                None => backoff,
+            };
+            eprintln!(
+                "[{}] attempt {attempt}/{max_attempts} failed: {error}; retrying in {}ms",
+                E::LOG_TAG,
+                delay.as_millis()
+            );
+            std::thread::sleep(delay);
+            backoff = (backoff * 2).min(self.max_backoff);
+        }
+
+        unreachable!("the final attempt always returns")
+    }
+}
+
+/// Pulls a human-readable message out of the common JSON error envelopes,
+/// falling back to the (truncated) raw body.
+pub(crate) fn error_message(body: &str) -> String {
+    let parsed = serde_json::from_str::<serde_json::Value>(body).ok();
+    let message = parsed.as_ref().and_then(|value| {
+        value
+            .pointer("/error/message")
+            .or_else(|| value.get("err_msg"))
+            .or_else(|| value.get("message"))
+            .or_else(|| value.get("error"))
+            .and_then(|message| message.as_str())
+    });
+
+    message
+        .unwrap_or(body.trim())
+        .chars()
+        .take(MAX_ERROR_MESSAGE_LEN)
+        .collect()
+}
+
+/// Audio prepared once per transcription and shared across retries without
+/// copying.
+#[derive(Clone)]
+pub(crate) struct WavPayload(Arc<[u8]>);
+
+impl WavPayload {
+    pub(crate) fn from_samples(
+        samples: &[f32],
+        sample_rate: u32,
+    ) -> Result<Self, RemoteTranscriptionError> {
+        let processed = prepare_samples(samples, sample_rate)
+            .map_err(RemoteTranscriptionError::InvalidAudio)?;
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: WHISPER_SAMPLE_RATE,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+
+        let mut cursor = Cursor::new(Vec::with_capacity(44 + processed.len() * 2));
+        let mut writer = hound::WavWriter::new(&mut cursor, spec)
+            .map_err(|err| RemoteTranscriptionError::InvalidAudio(err.to_string()))?;
+        for sample in processed {
+            let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+            writer
+                .write_sample(value)
+                .map_err(|err| RemoteTranscriptionError::InvalidAudio(err.to_string()))?;
+        }
+        writer
+            .finalize()
+            .map_err(|err| RemoteTranscriptionError::InvalidAudio(err.to_string()))?;
+
+        Ok(Self(cursor.into_inner().into()))
+    }
+
+    pub(crate) fn len(&self) -> u64 {
+        self.0.len() as u64
+    }
+
+    /// Streaming request body; the bytes are read straight from the shared
+    /// buffer rather than copied into the request.
+    pub(crate) fn reader(&self) -> Cursor<Arc<[u8]>> {
+        Cursor::new(self.0.clone())
+    }
+}
+
+/// Languages to send to the provider: a forced language wins, otherwise the
+/// user's candidate languages reduced to bare codes.
+pub(crate) fn requested_languages(request: Option<&TranscriptionRequest>) -> Vec<String> {
+    let forced = request
+        .and_then(|req| req.language.as_deref())
+        .map(|value| candidate_language_codes(&[value.to_string()]))
+        .unwrap_or_default();
+    if !forced.is_empty() {
+        return forced;
+    }
+
+    request
+        .map(|req| candidate_language_codes(&req.candidate_languages))
+        .unwrap_or_default()
+}
+
+pub(crate) fn trimmed_prompt(request: Option<&TranscriptionRequest>) -> Option<String> {
+    request
+        .and_then(|req| req.initial_prompt.as_deref())
+        .map(|prompt| prompt.chars().filter(|ch| *ch != '\0').collect::<String>())
+        .map(|prompt| prompt.trim().to_string())
+        .filter(|prompt| !prompt.is_empty())
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Builds the transcriber for a stored API key. `secret` is the decrypted key.
+pub fn transcriber_for_api_key(
+    api_key: &ApiKey,
+    secret: String,
+    policy: RetryPolicy,
+) -> Result<Arc<dyn Transcriber>, RemoteTranscriptionError> {
+    let base_url = non_empty(api_key.base_url.as_deref());
+    let model = non_empty(api_key.transcription_model.as_deref());
+
+    let transcriber: Arc<dyn Transcriber> = match api_key.provider.as_str() {
+        "openai" | "groq" => {
+            let defaults = openai::ProviderDefaults::for_provider(&api_key.provider);
+            Arc::new(OpenAiCompatibleTranscriber::new(
+                secret,
+                base_url.unwrap_or_else(|| defaults.base_url.to_string()),
+                model.unwrap_or_else(|| defaults.model.to_string()),
+                defaults.translation_model.map(str::to_string),
+                policy,
+            ))
+        }
+        "deepgram" => Arc::new(DeepgramTranscriber::new(secret, base_url, model, policy)),
+        "azure" => {
+            let region = non_empty(api_key.azure_region.as_deref());
+            if region.is_none() && base_url.is_none() {
+                return Err(RemoteTranscriptionError::MissingConfiguration(
+                    "Azure Speech needs a region or endpoint",
+                ));
+            }
+            Arc::new(AzureSpeechTranscriber::new(
+                secret, region, base_url, policy,
+            ))
+        }
+        other => {
+            return Err(RemoteTranscriptionError::UnsupportedProvider(
+                other.to_string(),
+            ))
+        }
+    };
+
+    Ok(transcriber)
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::RetryPolicy;
+    use std::time::Duration;
+
+    pub(crate) fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            request_timeout: Duration::from_secs(5),
+            connect_timeout: Duration::from_secs(5),
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+            max_retry_after: Duration::from_secs(1),
+            retry_rate_limits: true,
+        }
+    }
+
+    pub(crate) fn one_second_of_audio() -> Vec<f32> {
+        (0..16_000).map(|i| (i as f32 * 0.05).sin() * 0.2).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_message_prefers_json_envelopes() {
+        assert_eq!(
+            error_message(r#"{"error":{"message":"bad model"}}"#),
+            "bad model"
+        );
+        assert_eq!(error_message(r#"{"err_msg":"no credits"}"#), "no credits");
+        assert_eq!(error_message("  gateway down  "), "gateway down");
+    }
+
+    #[test]
+    fn wav_payload_is_16khz_mono_pcm() {
+        let payload = WavPayload::from_samples(&vec![0.5; 48_000], 48_000).unwrap();
+        let reader = hound::WavReader::new(payload.reader()).unwrap();
+        let spec = reader.spec();
+        assert_eq!(spec.sample_rate, WHISPER_SAMPLE_RATE);
+        assert_eq!(spec.channels, 1);
+        assert_eq!(spec.bits_per_sample, 16);
+        assert_eq!(reader.len(), 16_000);
+    }
+
+    #[test]
+    fn forced_language_wins_over_candidates() {
+        let request = TranscriptionRequest {
+            language: Some("ko-KR".to_string()),
+            candidate_languages: vec!["en".to_string(), "ja".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(requested_languages(Some(&request)), vec!["ko"]);
+
+        let request = TranscriptionRequest {
+            candidate_languages: vec!["en-US".to_string(), "ja".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(requested_languages(Some(&request)), vec!["en", "ja"]);
+    }
+
+    #[test]
+    fn retryable_errors() {
+        assert!(RemoteTranscriptionError::Timeout.is_retryable());
+        assert!(RemoteTranscriptionError::RateLimited { retry_after: None }.is_retryable());
+        assert!(RemoteTranscriptionError::Http {
+            status: 503,
+            message: String::new()
+        }
+        .is_retryable());
+        assert!(!RemoteTranscriptionError::Http {
+            status: 400,
+            message: String::new()
+        }
+        .is_retryable());
+        assert!(!RemoteTranscriptionError::Unauthorized { status: 401 }.is_retryable());
+    }
+}
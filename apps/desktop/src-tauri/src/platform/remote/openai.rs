@@ -0,0 +1,410 @@
+use reqwest::blocking::multipart::{Form, Part};
+use serde::Deserialize;
+
+use super::{
+    requested_languages, trimmed_prompt, RemoteTranscriptionError, RetryPolicy, WavPayload,
+};
+use crate::platform::{Transcriber, TranscriptionOutput, TranscriptionRequest};
+
+pub(crate) struct ProviderDefaults {
+    pub base_url: &'static str,
+    pub model: &'static str,
+    /// Model for `/audio/translations`, which newer transcription models
+    /// do not serve.
+    pub translation_model: Option<&'static str>,
+}
+
+impl ProviderDefaults {
+    pub(crate) fn for_provider(provider: &str) -> Self {
+        match provider {
+            "groq" => Self {
+                base_url: "https://api.groq.com/openai/v1",
+                model: "whisper-large-v3-turbo",
+                translation_model: Some("whisper-large-v3"),
+            },
+            _ => Self {
+                base_url: "https://api.openai.com/v1",
+                model: "whisper-1",
+                translation_model: Some("whisper-1"),
+            },
+        }
+    }
+}
+
+/// Any server speaking OpenAI's `/audio/transcriptions` API: OpenAI, Groq or a
+/// self-hosted Whisper server.
+pub struct OpenAiCompatibleTranscriber {
+    api_key: String,
+    base_url: String,
+    model: String,
+    translation_model: Option<String>,
+    policy: RetryPolicy,
+}
+
+#[derive(Deserialize)]
+struct TranscriptionResponse {
+    text: String,
+    #[serde(default)]
+    language: Option<String>,
+}
+
+impl OpenAiCompatibleTranscriber {
+    pub fn new(
+        api_key: String,
+        base_url: String,
+        model: String,
+        translation_model: Option<String>,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            api_key,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            model,
+            translation_model,
+            policy,
+        }
+    }
+
+    pub fn transcribe_remote(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, RemoteTranscriptionError> {
+        let audio = WavPayload::from_samples(samples, sample_rate)?;
+        let languages = requested_languages(request);
+        // The API takes a single language; with several candidates we let the
+        // provider detect and keep its answer only if it is one of them.
+        let language = (languages.len() == 1).then(|| languages[0].clone());
+        let prompt = trimmed_prompt(request);
+
+        let response = self.post(
+            "audio/transcriptions",
+            &self.model,
+            &audio,
+            language.as_deref(),
+            prompt.as_deref(),
+        )?;
+
+        let detected_language = response
+            .language
+            .as_deref()
+            .and_then(language_code_from_response)
+            .filter(|code| languages.len() < 2 || languages.contains(code))
+            // With a language sent the provider only echoes it back.
+            .filter(|_| language.is_none());
+        let text = response.text.trim().to_string();
+
+        let translation_mode = request.map(|req| req.translation_mode).unwrap_or_default();
+        let translation = if !translation_mode.requires_translation() {
+            None
+        } else if text.is_empty()
+            || detected_language.as_deref().or(language.as_deref()) == Some("en")
+        {
+            Some(text.clone())
+        } else {
+            let model = self.translation_model.as_deref().unwrap_or(&self.model);
+            match self.post("audio/translations", model, &audio, None, None) {
+                Ok(translated) => Some(translated.text.trim().to_string()),
+                Err(err) => {
+                    eprintln!("[openai-transcription] translation failed: {err}");
+                    None
+                }
+            }
+        };
+
+        Ok(TranscriptionOutput {
+            text,
+            detected_language,
+            language_probabilities: Vec::new(),
+            translation,
+        })
+    }
+
+    fn post(
+        &self,
+        endpoint: &str,
+        model: &str,
+        audio: &WavPayload,
+        language: Option<&str>,
+        prompt: Option<&str>,
+    ) -> Result<TranscriptionResponse, RemoteTranscriptionError> {
+        let url = format!("{}/{endpoint}", self.base_url);
+        let response = self.policy.send(|client| {
+            let file = Part::reader_with_length(audio.reader(), audio.len())
+                .file_name("audio.wav")
+                .mime_str("audio/wav")
+                .expect("static mime type is valid");
+            let mut form = Form::new()
+                .part("file", file)
+                .text("model", model.to_string())
+                .text("response_format", "verbose_json")
+                .text("temperature", "0");
+            if let Some(language) = language {
+                form = form.text("language", language.to_string());
+            }
+            if let Some(prompt) = prompt {
+                form = form.text("prompt", prompt.to_string());
+            }
+
+            client.post(&url).bearer_auth(&self.api_key).multipart(form)
+        })?;
+
+        response
+            .json::<TranscriptionResponse>()
+            .map_err(|err| RemoteTranscriptionError::InvalidResponse(err.to_string()))
+    }
+}
+
+impl Transcriber for OpenAiCompatibleTranscriber {
+    fn transcribe(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        request: Option<&TranscriptionRequest>,
+    ) -> Result<TranscriptionOutput, String> {
+        self.transcribe_remote(samples, sample_rate, request)
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// `verbose_json` reports the language as an English name ("korean") on
+/// OpenAI and Groq, and as a code on most self-hosted servers.
+fn language_code_from_response(value: &str) -> Option<String> {
+    let value = value.trim().to_ascii_lowercase();
+    if (2..=3).contains(&value.len()) && value.chars().all(|ch| ch.is_ascii_lowercase()) {
+        return Some(value);
+    }
+
+    let code = match value.as_str() {
+        "english" => "en",
+        "korean" => "ko",
+        "japanese" => "ja",
+        "chinese" => "zh",
+        "spanish" => "es",
+        "french" => "fr",
+        "german" => "de",
+        "italian" => "it",
+        "portuguese" => "pt",
+        "russian" => "ru",
+        "dutch" => "nl",
+        "arabic" => "ar",
+        "hindi" => "hi",
+        "turkish" => "tr",
+        "polish" => "pl",
+        "swedish" => "sv",
+        "vietnamese" => "vi",
+        "thai" => "th",
+        "indonesian" => "id",
+        "ukrainian" => "uk",
+        _ => return None,
+    };
+    Some(code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TranslationMode;
+    use crate::platform::remote::test_support::{fast_policy, one_second_of_audio};
+    use mockito::Matcher;
+    use std::time::Duration;
+
+    fn transcriber(server: &mockito::Server) -> OpenAiCompatibleTranscriber {
+        OpenAiCompatibleTranscriber::new(
+            "sk-test".to_string(),
+            format!("{}/v1/", server.url()),
+            "whisper-large-v3-turbo".to_string(),
+            Some("whisper-large-v3".to_string()),
+            fast_policy(),
+        )
+    }
+
+    #[test]
+    fn uploads_multipart_audio_and_parses_language() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/audio/transcriptions")
+            .match_header("authorization", "Bearer sk-test")
+            .match_header(
+                "content-type",
+                Matcher::Regex("multipart/form-data; boundary=.*".to_string()),
+            )
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex(r#"name="model"\r\n\r\nwhisper-large-v3-turbo"#.to_string()),
+                Matcher::Regex(r#"name="prompt"\r\n\r\nVocally"#.to_string()),
+                Matcher::Regex(r#"filename="audio.wav""#.to_string()),
+            ]))
+            .with_body(r#"{"text":" Hello there ","language":"english"}"#)
+            .create();
+
+        let request = TranscriptionRequest {
+            initial_prompt: Some("Vocally".to_string()),
+            ..Default::default()
+        };
+        let output = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, Some(&request))
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(output.text, "Hello there");
+        assert_eq!(output.detected_language.as_deref(), Some("en"));
+        assert!(output.translation.is_none());
+    }
+
+    #[test]
+    fn retries_rate_limits_then_succeeds() {
+        let mut server = mockito::Server::new();
+        let limited = server
+            .mock("POST", "/v1/audio/transcriptions")
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create();
+        let ok = server
+            .mock("POST", "/v1/audio/transcriptions")
+            .with_body(r#"{"text":"안녕하세요","language":"korean"}"#)
+            .expect(1)
+            .create();
+
+        let output = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, None)
+            .unwrap();
+
+        limited.assert();
+        ok.assert();
+        assert_eq!(output.text, "안녕하세요");
+        assert_eq!(output.detected_language.as_deref(), Some("ko"));
+    }
+
+    #[test]
+    fn gives_up_when_asked_to_wait_too_long() {
+        let mut server = mockito::Server::new();
+        let limited = server
+            .mock("POST", "/v1/audio/transcriptions")
+            .with_status(429)
+            .with_header("retry-after", "120")
+            .expect(1)
+            .create();
+
+        let err = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, None)
+            .unwrap_err();
+
+        limited.assert();
+        assert_eq!(
+            err,
+            RemoteTranscriptionError::RateLimited {
+                retry_after: Some(Duration::from_secs(120))
+            }
+        );
+    }
+
+    #[test]
+    fn rate_limits_fail_fast_when_another_provider_can_serve() {
+        let mut server = mockito::Server::new();
+        let limited = server
+            .mock("POST", "/v1/audio/transcriptions")
+            .with_status(429)
+            .expect(1)
+            .create();
+
+        let err = OpenAiCompatibleTranscriber::new(
+            "sk-test".to_string(),
+            format!("{}/v1/", server.url()),
+            "whisper-large-v3-turbo".to_string(),
+            None,
+            RetryPolicy {
+                retry_rate_limits: false,
+                ..fast_policy()
+            },
+        )
+        .transcribe_remote(&one_second_of_audio(), 16_000, None)
+        .unwrap_err();
+
+        limited.assert();
+        assert_eq!(
+            err,
+            RemoteTranscriptionError::RateLimited { retry_after: None }
+        );
+    }
+
+    #[test]
+    fn does_not_retry_rejected_keys() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/audio/transcriptions")
+            .with_status(401)
+            .with_body(r#"{"error":{"message":"Invalid API key"}}"#)
+            .expect(1)
+            .create();
+
+        let err = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, None)
+            .unwrap_err();
+
+        mock.assert();
+        assert_eq!(err, RemoteTranscriptionError::Unauthorized { status: 401 });
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/audio/transcriptions")
+            .with_status(503)
+            .with_body(r#"{"error":{"message":"overloaded"}}"#)
+            .expect(3)
+            .create();
+
+        let err = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, None)
+            .unwrap_err();
+
+        mock.assert();
+        assert_eq!(
+            err,
+            RemoteTranscriptionError::Http {
+                status: 503,
+                message: "overloaded".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn translates_with_the_translation_model() {
+        let mut server = mockito::Server::new();
+        server
+            .mock("POST", "/v1/audio/transcriptions")
+            .match_body(Matcher::Regex(r#"name="language"\r\n\r\nko"#.to_string()))
+            .with_body(r#"{"text":"안녕하세요"}"#)
+            .create();
+        let translation = server
+            .mock("POST", "/v1/audio/translations")
+            .match_body(Matcher::Regex(
+                r#"name="model"\r\n\r\nwhisper-large-v3\r\n"#.to_string(),
+            ))
+            .with_body(r#"{"text":"Hello"}"#)
+            .create();
+
+        let request = TranscriptionRequest {
+            language: Some("ko".to_string()),
+            translation_mode: TranslationMode::English,
+            ..Default::default()
+        };
+        let output = transcriber(&server)
+            .transcribe_remote(&one_second_of_audio(), 16_000, Some(&request))
+            .unwrap();
+
+        translation.assert();
+        assert!(output.detected_language.is_none());
+        assert_eq!(output.translation.as_deref(), Some("Hello"));
+    }
+
+    #[test]
+    fn language_names_map_to_codes() {
+        assert_eq!(language_code_from_response("Korean").as_deref(), Some("ko"));
+        assert_eq!(language_code_from_response("en").as_deref(), Some("en"));
+        assert_eq!(language_code_from_response("klingon"), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Behaviour every local `Transcriber` must share. Each backend runs the same
+//! checks against a real model, pointed to by an environment variable:
+//!
+//! - `VOQUILL_TEST_WHISPER_MODEL`: path to a ggml Whisper model.
+//! - `VOQUILL_TEST_CANDLE_MODEL_DIR`: directory holding `config.json`,
+//!   `tokenizer.json` and `model.safetensors`.
+//!
+//! The checks are ignored by default; run them with
+//! `cargo test transcriber_conformance -- --ignored`.
+
+use super::{Transcriber, TranscriptionRequest};
+use crate::domain::TranslationMode;
+use crate::system::models::LocalTranscriptionEngine;
+use std::path::PathBuf;
+
+const SAMPLE_RATE: u32 = 16_000;
+
+fn load(engine: LocalTranscriptionEngine) -> std::sync::Arc<dyn Transcriber> {
+    let variable = match engine {
+        LocalTranscriptionEngine::WhisperCpp => "VOQUILL_TEST_WHISPER_MODEL",
+        LocalTranscriptionEngine::Candle => "VOQUILL_TEST_CANDLE_MODEL_DIR",
+    };
+    let path = std::env::var_os(variable)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| panic!("{variable} must point to a {engine} model"));
+
+    super::load_local_transcriber(engine, &path).expect("model should load")
+}
+
+fn silence(seconds: f32, sample_rate: u32) -> Vec<f32> {
+    vec![0.0; (seconds * sample_rate as f32) as usize]
+}
+
+fn rejects_invalid_audio(transcriber: &dyn Transcriber) {
+    assert!(transcriber.transcribe(&[], SAMPLE_RATE, None).is_err());
+    assert!(transcriber
+        .transcribe(&silence(1.0, SAMPLE_RATE), 0, None)
+        .is_err());
+}
+
+fn accepts_any_sample_rate(transcriber: &dyn Transcriber) {
+    for rate in [8_000, 16_000, 44_100, 48_000] {
+        let output = transcriber
+            .transcribe(&silence(1.0, rate), rate, None)
+            .unwrap_or_else(|err| panic!("{rate} Hz audio failed: {err}"));
+        assert!(output.translation.is_none());
+    }
+}
+
+fn honours_forced_language(transcriber: &dyn Transcriber) {
+    let request = TranscriptionRequest {
+        language: Some("ko".to_string()),
+        ..Default::default()
+    };
+    let output = transcriber
+        .transcribe(&silence(1.0, SAMPLE_RATE), SAMPLE_RATE, Some(&request))
+        .expect("transcription should succeed");
+
+    assert!(output.detected_language.is_none());
+    assert!(output.language_probabilities.is_empty());
+}
+
+fn ranks_candidate_languages(transcriber: &dyn Transcriber) {
+    let request = TranscriptionRequest {
+        candidate_languages: vec!["en-US".to_string(), "ko_KR".to_string()],
+        ..Default::default()
+    };
+    let output = transcriber
+        .transcribe(&silence(2.0, SAMPLE_RATE), SAMPLE_RATE, Some(&request))
+        .expect("transcription should succeed");
+
+    let ranked: Vec<&str> = output
+        .language_probabilities
+        .iter()
+        .map(|entry| entry.language.as_str())
+        .collect();
+    assert_eq!(ranked.len(), 2);
+    assert!(ranked.contains(&"en") && ranked.contains(&"ko"));
+    assert_eq!(output.detected_language.as_deref(), Some(ranked[0]));
+
+    let total: f32 = output
+        .language_probabilities
+        .iter()
+        .map(|entry| entry.probability)
+        .sum();
+    assert!((total - 1.0).abs() < 1e-3);
+    assert!(output
+        .language_probabilities
+        .windows(2)
+        .all(|pair| pair[0].probability >= pair[1].probability));
+}
+
+fn translates_only_when_asked(transcriber: &dyn Transcriber) {
+    let audio = silence(1.0, SAMPLE_RATE);
+    for (mode, expects_translation) in [
+        (TranslationMode::Off, false),
+        (TranslationMode::English, true),
+        (TranslationMode::Bilingual, true),
+    ] {
+        let request = TranscriptionRequest {
+            language: Some("en".to_string()),
+            translation_mode: mode,
+            ..Default::default()
+        };
+        let output = transcriber
+            .transcribe(&audio, SAMPLE_RATE, Some(&request))
+            .expect("transcription should succeed");
+        assert_eq!(output.translation.is_some(), expects_translation);
+    }
+}
+
+fn run_suite(engine: LocalTranscriptionEngine) {
+    let transcriber = load(engine);
+    let transcriber = transcriber.as_ref();
+
+    rejects_invalid_audio(transcriber);
+    accepts_any_sample_rate(transcriber);
+    honours_forced_language(transcriber);
+    ranks_candidate_languages(transcriber);
+    translates_only_when_asked(transcriber);
+}
+
+#[test]
+#[ignore = "needs VOQUILL_TEST_WHISPER_MODEL"]
+fn whisper_cpp_conforms() {
+    run_suite(LocalTranscriptionEngine::WhisperCpp);
+}
+
+#[test]
+#[ignore = "needs VOQUILL_TEST_CANDLE_MODEL_DIR"]
+fn candle_conforms() {
+    run_suite(LocalTranscriptionEngine::Candle);
+}
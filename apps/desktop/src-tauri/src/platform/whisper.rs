@@ -1,4 +1,7 @@
-use crate::platform::{GpuDescriptor, Transcriber, TranscriptionDevice, TranscriptionRequest};
+use crate::domain::LanguageProbability;
+use crate::platform::{
+    GpuDescriptor, Transcriber, TranscriptionDevice, TranscriptionOutput, TranscriptionRequest,
+};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use whisper_rs::{
@@ -17,6 +20,8 @@ use whisper_rs::vulkan;
 ))]
 const DISABLE_ENV: &str = "VOQUILL_WHISPER_DISABLE_GPU";
 
+pub(crate) const WHISPER_SAMPLE_RATE: u32 = 16_000;
+
 pub struct WhisperTranscriber {
     model_path: String,
     default_context: Arc<WhisperContext>,
@@ -357,65 +362,225 @@ impl Transcriber for WhisperTranscriber {
         samples: &[f32],
         sample_rate: u32,
         request: Option<&TranscriptionRequest>,
-    ) -> Result<String, String> {
-        const TARGET_SAMPLE_RATE: u32 = 16_000;
-
-        if samples.is_empty() {
-            return Err("No audio samples captured".to_string());
-        }
-        if sample_rate == 0 {
-            return Err("Invalid sample rate (0 Hz)".to_string());
-        }
-
-        let processed = if sample_rate == TARGET_SAMPLE_RATE {
-            samples.to_vec()
-        } else {
-            resample_to_sample_rate(samples, sample_rate, TARGET_SAMPLE_RATE)
-        };
-
-        if processed.is_empty() {
-            return Err("Resampled audio is empty".to_string());
-        }
+    ) -> Result<TranscriptionOutput, String> {
+        let processed = prepare_samples(samples, sample_rate)?;
 
         let context = self.context_for_request(request)?;
         let mut state = context
             .create_state()
             .map_err(|err| format!("Failed to create Whisper state: {err}"))?;
 
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-        let language_code = request
+        let forced_language = request
             .and_then(|req| req.language.as_deref())
             .filter(|value| !value.is_empty());
+        let candidates = request
+            .map(|req| candidate_language_codes(&req.candidate_languages))
+            .unwrap_or_default();
+
+        let mut language_probabilities = Vec::new();
+        if forced_language.is_none() && candidates.len() >= 2 {
+            match Self::detect_candidate_language(&mut state, &processed, &candidates) {
+                Ok(ranked) => language_probabilities = ranked,
+                Err(err) => eprintln!("[whisper] language detection failed: {err}"),
+            }
+        }
+        let ranked_language = language_probabilities
+            .first()
+            .map(|entry| entry.language.clone());
+
+        // A forced language wins over detection among the user's languages; if
+        // neither applies, leave the language unset so Whisper auto-detects.
+        let language_code: Option<String> = forced_language
+            .map(str::to_string)
+            .or_else(|| ranked_language.clone())
+            .or_else(|| candidates.first().cloned());
 
-        // If no language specified, don't call set_language - Whisper will auto-detect
         eprintln!("[whisper] using language code: {:?}", language_code);
-        if let Some(language) = language_code {
+
+        let initial_prompt = request
+            .and_then(|req| req.initial_prompt.as_ref())
+            .map(|prompt| prompt.chars().filter(|ch| *ch != '\0').collect::<String>())
+            .filter(|prompt| !prompt.trim().is_empty());
+
+        let text = Self::run_pass(
+            &mut state,
+            &processed,
+            language_code.as_deref(),
+            initial_prompt.as_deref().map(str::trim),
+            false,
+        )?;
+
+        // Only a language Whisper picked counts as detected, not one the
+        // request chose.
+        let detected_language = if language_code.is_none() {
+            state
+                .full_lang_id_from_state()
+                .ok()
+                .and_then(whisper_rs::get_lang_str)
+                .map(str::to_string)
+        } else {
+            ranked_language
+        };
+        let spoken_language = detected_language.as_deref().or(language_code.as_deref());
+
+        let translation_mode = request.map(|req| req.translation_mode).unwrap_or_default();
+        let translation = if !translation_mode.requires_translation() {
+            None
+        } else if text.is_empty() || spoken_language == Some("en") {
+            Some(text.clone())
+        } else {
+            // The dictionary prompt is written in the source language and
+            // tends to suppress translation, so the second pass runs without it.
+            Some(Self::run_pass(
+                &mut state,
+                &processed,
+                language_code.as_deref(),
+                None,
+                true,
+            )?)
+        };
+
+        Ok(TranscriptionOutput {
+            text,
+            detected_language,
+            language_probabilities,
+            translation,
+        })
+    }
+}
+
+impl WhisperTranscriber {
+    fn run_pass(
+        state: &mut whisper_rs::WhisperState,
+        samples: &[f32],
+        language: Option<&str>,
+        initial_prompt: Option<&str>,
+        translate: bool,
+    ) -> Result<String, String> {
+        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+
+        if let Some(language) = language {
             params.set_language(Some(language));
         }
 
-        params.set_translate(false);
+        params.set_translate(translate);
         params.set_print_special(false);
         params.set_print_progress(false);
         params.set_print_realtime(false);
         params.set_print_timestamps(false);
         params.set_no_context(true);
 
-        if let Some(req) = request {
-            if let Some(prompt) = req.initial_prompt.as_ref() {
-                let sanitized: String = prompt.chars().filter(|ch| *ch != '\0').collect();
-                let trimmed = sanitized.trim();
-                if !trimmed.is_empty() {
-                    params.set_initial_prompt(trimmed);
-                }
-            }
+        if let Some(prompt) = initial_prompt {
+            params.set_initial_prompt(prompt);
         }
 
         state
-            .full(params, &processed)
+            .full(params, samples)
             .map_err(|err| format!("Failed to run Whisper inference: {err}"))?;
 
-        Self::collect_transcription(&state)
+        Self::collect_transcription(state)
     }
+
+    /// Runs Whisper's language identification on the first 30 seconds of audio
+    /// and returns the candidates ranked by probability, renormalised so they
+    /// sum to one across the user's configured languages.
+    fn detect_candidate_language(
+        state: &mut whisper_rs::WhisperState,
+        samples: &[f32],
+        candidates: &[String],
+    ) -> Result<Vec<LanguageProbability>, String> {
+        let threads = inference_thread_count();
+        state
+            .pcm_to_mel(samples, threads)
+            .map_err(|err| format!("Failed to compute Whisper mel spectrogram: {err}"))?;
+        let (_, probabilities) = state
+            .lang_detect(0, threads)
+            .map_err(|err| format!("Failed to run Whisper language detection: {err}"))?;
+
+        let ranked = rank_candidate_languages(candidates, |code| {
+            whisper_rs::get_lang_id(code)
+                .and_then(|id| usize::try_from(id).ok())
+                .and_then(|index| probabilities.get(index).copied())
+        });
+
+        if ranked.is_empty() {
+            return Err(format!(
+                "None of the configured languages {candidates:?} are known to Whisper"
+            ));
+        }
+
+        Ok(ranked)
+    }
+}
+
+fn inference_thread_count() -> usize {
+    std::thread::available_parallelism()
+        .map(|count| count.get().min(4))
+        .unwrap_or(1)
+}
+
+/// Reduces locale tags such as `ko-KR` or `en_US` to the bare language codes
+/// Whisper understands, dropping blanks and duplicates while keeping order.
+pub(crate) fn candidate_language_codes(languages: &[String]) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::with_capacity(languages.len());
+    for language in languages {
+        let code = crate::domain::language_subtag(language);
+        if !code.is_empty() && !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+    codes
+}
+
+pub(crate) fn rank_candidate_languages<F>(
+    candidates: &[String],
+    probability_of: F,
+) -> Vec<LanguageProbability>
+where
+    F: Fn(&str) -> Option<f32>,
+{
+    let mut ranked: Vec<LanguageProbability> = candidates
+        .iter()
+        .filter_map(|code| {
+            probability_of(code).map(|probability| LanguageProbability {
+                language: code.clone(),
+                probability: probability.max(0.0),
+            })
+        })
+        .collect();
+
+    let total: f32 = ranked.iter().map(|entry| entry.probability).sum();
+    if total > 0.0 {
+        for entry in ranked.iter_mut() {
+            entry.probability /= total;
+        }
+    }
+
+    ranked.sort_by(|left, right| right.probability.total_cmp(&left.probability));
+    ranked
+}
+
+/// Validates captured audio and converts it to the 16 kHz mono stream every
+/// Whisper-family model expects.
+pub(crate) fn prepare_samples(samples: &[f32], sample_rate: u32) -> Result<Vec<f32>, String> {
+    if samples.is_empty() {
+        return Err("No audio samples captured".to_string());
+    }
+    if sample_rate == 0 {
+        return Err("Invalid sample rate (0 Hz)".to_string());
+    }
+
+    let processed = if sample_rate == WHISPER_SAMPLE_RATE {
+        samples.to_vec()
+    } else {
+        resample_to_sample_rate(samples, sample_rate, WHISPER_SAMPLE_RATE)
+    };
+
+    if processed.is_empty() {
+        return Err("Resampled audio is empty".to_string());
+    }
+
+    Ok(processed)
 }
 
 fn resample_to_sample_rate(samples: &[f32], input_rate: u32, target_rate: u32) -> Vec<f32> {
@@ -782,7 +947,7 @@ fn configure_windows_gpu_selection(
 
 #[cfg(test)]
 mod tests {
-    use super::resample_to_sample_rate;
+    use super::{candidate_language_codes, rank_candidate_languages, resample_to_sample_rate};
 
     #[test]
     fn resample_returns_empty_for_invalid_input() {
@@ -803,4 +968,30 @@ mod tests {
         let resampled = resample_to_sample_rate(&data, 8_000, 16_000);
         assert_eq!(resampled.len(), 8);
     }
+
+    #[test]
+    fn candidate_codes_strip_regions_and_duplicates() {
+        let languages = vec![
+            "ko-KR".to_string(),
+            " en_US ".to_string(),
+            "ko".to_string(),
+            String::new(),
+        ];
+        assert_eq!(candidate_language_codes(&languages), vec!["ko", "en"]);
+    }
+
+    #[test]
+    fn rank_candidates_renormalises_and_sorts() {
+        let candidates = vec!["en".to_string(), "ko".to_string(), "xx".to_string()];
+        let ranked = rank_candidate_languages(&candidates, |code| match code {
+            "en" => Some(0.1),
+            "ko" => Some(0.3),
+            _ => None,
+        });
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].language, "ko");
+        assert!((ranked[0].probability - 0.75).abs() < 1e-6);
+        assert!((ranked[1].probability - 0.25).abs() < 1e-6);
+    }
 }
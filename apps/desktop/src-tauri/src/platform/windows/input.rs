@@ -2,15 +2,35 @@ use std::{env, mem, thread, time::Duration};
 use windows::Win32::Foundation::HWND;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
     GetAsyncKeyState, SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, INPUT_MOUSE, KEYBDINPUT,
-    KEYEVENTF_KEYUP, MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, MOUSEINPUT, VIRTUAL_KEY,
+    KEYEVENTF_KEYUP, MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, MOUSEINPUT, VIRTUAL_KEY, VK_BACK,
     VK_CONTROL, VK_LCONTROL, VK_LMENU, VK_LSHIFT, VK_LWIN, VK_MENU, VK_RCONTROL, VK_RMENU,
     VK_RSHIFT, VK_RWIN, VK_SHIFT, VK_V,
 };
 use windows::Win32::UI::WindowsAndMessaging::{GetClassNameW, GetForegroundWindow};
 
+/// Pause between Backspace presses so slow apps don't drop any.
+const BACKSPACE_INTERVAL: Duration = Duration::from_millis(2);
+
 pub(crate) fn paste_text_into_focused_field(
     text: &str,
     keybind: Option<&str>,
+) -> Result<(), String> {
+    paste_into_focused_field(text, None, keybind)
+}
+
+/// Pastes `html` where the app accepts formatted text and `text` elsewhere.
+pub(crate) fn paste_rich_text_into_focused_field(
+    text: &str,
+    html: &str,
+    keybind: Option<&str>,
+) -> Result<(), String> {
+    paste_into_focused_field(text, Some(html), keybind)
+}
+
+fn paste_into_focused_field(
+    text: &str,
+    html: Option<&str>,
+    keybind: Option<&str>,
 ) -> Result<(), String> {
     if text.trim().is_empty() {
         return Ok(());
@@ -23,7 +43,7 @@ pub(crate) fn paste_text_into_focused_field(
         target.chars().count()
     );
 
-    paste_via_clipboard(target, keybind).or_else(|err| {
+    paste_via_clipboard(target, html, keybind).or_else(|err| {
         eprintln!("Clipboard paste failed ({err}). Falling back to simulated typing.");
         use enigo::{Enigo, KeyboardControllable};
         let mut enigo = Enigo::new();
@@ -172,28 +192,52 @@ fn send_paste_keys(keybind: Option<&str>) {
     }
 }
 
-fn paste_via_clipboard(text: &str, keybind: Option<&str>) -> Result<(), String> {
-    let mut clipboard =
-        arboard::Clipboard::new().map_err(|err| format!("clipboard unavailable: {err}"))?;
-    let previous = clipboard.get_text().ok();
-    clipboard
-        .set_text(text.to_string())
-        .map_err(|err| format!("failed to store clipboard text: {err}"))?;
+fn paste_via_clipboard(
+    text: &str,
+    html: Option<&str>,
+    keybind: Option<&str>,
+) -> Result<(), String> {
+    crate::platform::clipboard::paste_preserving_clipboard(text, html, || {
+        thread::sleep(Duration::from_millis(50));
+
+        release_modifier_keys();
+        thread::sleep(Duration::from_millis(30));
+
+        send_paste_keys(keybind);
+        Ok(())
+    })
+}
+
+/// Presses Backspace `count` times in the focused field.
+pub(crate) fn delete_backward(count: usize) -> Result<(), String> {
+    release_modifier_keys();
+    thread::sleep(Duration::from_millis(30));
 
-    thread::sleep(Duration::from_millis(50));
+    for _ in 0..count {
+        send_key_down(VK_BACK);
+        send_key_up(VK_BACK);
+        thread::sleep(BACKSPACE_INTERVAL);
+    }
 
+    Ok(())
+}
+
+/// Types `text` one character at a time, pausing `interval` after each.
+pub(crate) fn type_text(text: &str, interval: Duration) -> Result<(), String> {
+    use enigo::{Enigo, KeyboardControllable};
+    let mut enigo = Enigo::new();
     release_modifier_keys();
     thread::sleep(Duration::from_millis(30));
 
-    send_paste_keys(keybind);
+    if interval.is_zero() {
+        enigo.key_sequence(text);
+        return Ok(());
+    }
 
-    if let Some(old) = previous {
-        thread::spawn(move || {
-            thread::sleep(Duration::from_millis(800));
-            if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                let _ = clipboard.set_text(old);
-            }
-        });
+    let mut buffer = [0u8; 4];
+    for ch in text.chars() {
+        enigo.key_sequence(ch.encode_utf8(&mut buffer));
+        thread::sleep(interval);
     }
 
     Ok(())
@@ -1,9 +1,15 @@
 pub mod database;
 pub mod oauth;
 pub mod overlay;
+pub mod paste_history;
+pub mod post_processing;
+pub mod selection_rewrite;
 pub mod transcriber;
 
 pub use database::OptionKeyDatabase;
 pub use oauth::GoogleOAuthState;
 pub use overlay::OverlayState;
+pub use paste_history::PasteHistoryState;
+pub use post_processing::PostProcessingState;
+pub use selection_rewrite::SelectionRewriteState;
 pub use transcriber::TranscriberState;
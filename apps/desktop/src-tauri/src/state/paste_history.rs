@@ -0,0 +1,47 @@
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Text the `paste` command last put into another app.
+#[derive(Clone, Debug)]
+pub struct LastPaste {
+    /// The plain text that was inserted.
+    pub text: String,
+    /// Backspace presses that remove the text again, or `None` for formatted
+    /// pastes, whose length in the field is only known by reading it back.
+    pub backspaces: Option<usize>,
+    /// App that had focus right after the paste; undo only runs there.
+    pub app_name: Option<String>,
+    pub pasted_at: Instant,
+}
+
+#[derive(Default)]
+pub struct PasteHistoryState {
+    last: Mutex<Option<LastPaste>>,
+}
+
+impl PasteHistoryState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, paste: LastPaste) {
+        let mut last = self.last.lock().unwrap_or_else(|err| err.into_inner());
+        *last = Some(paste);
+    }
+
+    pub fn last(&self) -> Option<LastPaste> {
+        let last = self.last.lock().unwrap_or_else(|err| err.into_inner());
+        last.clone()
+    }
+
+    /// Forgets `paste`, unless a newer paste has replaced it meanwhile.
+    pub fn forget(&self, paste: &LastPaste) {
+        let mut last = self.last.lock().unwrap_or_else(|err| err.into_inner());
+        if last
+            .as_ref()
+            .is_some_and(|current| current.pasted_at == paste.pasted_at)
+        {
+            *last = None;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::platform::llm::CancellationToken;
+
+/// Cancellation tokens of the post-processing requests currently streaming,
+/// keyed by the caller's request id.
+#[derive(Default)]
+pub struct PostProcessingState {
+    active: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl PostProcessingState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a request, cancelling any earlier one that used the same id.
+    pub fn begin(&self, request_id: &str) -> CancellationToken {
+        let token = CancellationToken::new();
+        let mut active = self.active.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(previous) = active.insert(request_id.to_string(), token.clone()) {
+            previous.cancel();
+        }
+        token
+    }
+
+    /// Unregisters `token`, leaving a newer request that reused the id alone.
+    pub fn finish(&self, request_id: &str, token: &CancellationToken) {
+        let mut active = self.active.lock().unwrap_or_else(|err| err.into_inner());
+        if active
+            .get(request_id)
+            .is_some_and(|current| current.same_as(token))
+        {
+            active.remove(request_id);
+        }
+    }
+
+    /// Returns whether a request with this id was running.
+    pub fn cancel(&self, request_id: &str) -> bool {
+        let active = self.active.lock().unwrap_or_else(|err| err.into_inner());
+        match active.get(request_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
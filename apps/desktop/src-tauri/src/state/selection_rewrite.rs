@@ -0,0 +1,36 @@
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// What the user had selected when a rewrite-selection recording started,
+/// kept until the rewrite is pasted over it or abandoned.
+pub struct SelectionRewriteSession {
+    pub selected_text: String,
+    /// Clipboard from before the selection was read, put back whether the
+    /// rewrite succeeds or not.
+    pub clipboard: crate::platform::clipboard::ClipboardSnapshot,
+    /// App that had focus; the rewrite is only pasted back into it.
+    pub app_name: Option<String>,
+    pub started_at: Instant,
+}
+
+#[derive(Default)]
+pub struct SelectionRewriteState {
+    session: Mutex<Option<SelectionRewriteSession>>,
+}
+
+impl SelectionRewriteState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a session, dropping any rewrite that was never finished.
+    pub fn begin(&self, session: SelectionRewriteSession) {
+        let mut current = self.session.lock().unwrap_or_else(|err| err.into_inner());
+        *current = Some(session);
+    }
+
+    pub fn take(&self) -> Option<SelectionRewriteSession> {
+        let mut current = self.session.lock().unwrap_or_else(|err| err.into_inner());
+        current.take()
+    }
+}
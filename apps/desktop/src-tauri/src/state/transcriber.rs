@@ -1,36 +1,53 @@
 use std::sync::{Arc, OnceLock};
 
 use crate::platform::Transcriber;
+use crate::system::models::LocalTranscriptionEngine;
 
 pub struct TranscriberState {
-    inner: OnceLock<Arc<dyn Transcriber>>,
+    whisper_cpp: OnceLock<Arc<dyn Transcriber>>,
+    candle: OnceLock<Arc<dyn Transcriber>>,
 }
 
 impl TranscriberState {
     pub fn new() -> Self {
         Self {
-            inner: OnceLock::new(),
+            whisper_cpp: OnceLock::new(),
+            candle: OnceLock::new(),
         }
     }
 
-    pub fn new_initialized(transcriber: Arc<dyn Transcriber>) -> Self {
+    pub fn new_initialized(
+        engine: LocalTranscriptionEngine,
+        transcriber: Arc<dyn Transcriber>,
+    ) -> Self {
         let state = Self::new();
-        let _ = state.inner.set(transcriber);
+        let _ = state.slot(engine).set(transcriber);
         state
     }
 
-    pub fn get(&self) -> Option<&Arc<dyn Transcriber>> {
-        self.inner.get()
+    pub fn get(&self, engine: LocalTranscriptionEngine) -> Option<&Arc<dyn Transcriber>> {
+        self.slot(engine).get()
     }
 
-    pub fn initialize(&self, transcriber: Arc<dyn Transcriber>) -> Result<(), String> {
-        self.inner
+    pub fn initialize(
+        &self,
+        engine: LocalTranscriptionEngine,
+        transcriber: Arc<dyn Transcriber>,
+    ) -> Result<(), String> {
+        self.slot(engine)
             .set(transcriber)
-            .map_err(|_| "Transcriber already initialized".to_string())
+            .map_err(|_| format!("Transcriber for {engine} already initialized"))
     }
 
-    pub fn is_initialized(&self) -> bool {
-        self.inner.get().is_some()
+    pub fn is_initialized(&self, engine: LocalTranscriptionEngine) -> bool {
+        self.slot(engine).get().is_some()
+    }
+
+    fn slot(&self, engine: LocalTranscriptionEngine) -> &OnceLock<Arc<dyn Transcriber>> {
+        match engine {
+            LocalTranscriptionEngine::WhisperCpp => &self.whisper_cpp,
+            LocalTranscriptionEngine::Candle => &self.candle,
+        }
     }
 }
 
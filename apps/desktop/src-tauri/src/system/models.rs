@@ -6,6 +6,50 @@ use std::{
 };
 
 const MODEL_URL_ENV: &str = "VOQUILL_WHISPER_MODEL_URL";
+const CANDLE_REPO_URL_ENV: &str = "VOQUILL_CANDLE_WHISPER_REPO_URL";
+const CANDLE_MODEL_FILES: [&str; 3] = [
+    crate::platform::candle_whisper::CONFIG_FILENAME,
+    crate::platform::candle_whisper::TOKENIZER_FILENAME,
+    crate::platform::candle_whisper::WEIGHTS_FILENAME,
+];
+
+/// Local speech-to-text engine used when transcription mode is `local`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LocalTranscriptionEngine {
+    /// whisper.cpp with ggml weights; supports GPU offload.
+    #[default]
+    WhisperCpp,
+    /// Pure-Rust candle implementation; CPU only.
+    Candle,
+}
+
+impl LocalTranscriptionEngine {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::WhisperCpp => "whisper_cpp",
+            Self::Candle => "candle",
+        }
+    }
+}
+
+impl FromStr for LocalTranscriptionEngine {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let normalized = value.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "whisper_cpp" | "whisper-cpp" | "whispercpp" => Ok(Self::WhisperCpp),
+            "candle" => Ok(Self::Candle),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LocalTranscriptionEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum WhisperModelSize {
@@ -95,6 +139,65 @@ pub fn ensure_whisper_model(app: &tauri::AppHandle, size: WhisperModelSize) -> i
     Ok(model_path)
 }
 
+/// Returns the model location for `engine`: a ggml file for whisper.cpp or a
+/// directory of Hugging Face files for candle. Downloads it when missing.
+pub fn ensure_local_model(
+    app: &tauri::AppHandle,
+    engine: LocalTranscriptionEngine,
+    size: WhisperModelSize,
+) -> io::Result<PathBuf> {
+    match engine {
+        LocalTranscriptionEngine::WhisperCpp => ensure_whisper_model(app, size),
+        LocalTranscriptionEngine::Candle => ensure_candle_whisper_model(app, size),
+    }
+}
+
+pub fn local_model_exists(
+    app: &tauri::AppHandle,
+    engine: LocalTranscriptionEngine,
+    size: WhisperModelSize,
+) -> io::Result<Option<PathBuf>> {
+    let (path, complete) = match engine {
+        LocalTranscriptionEngine::WhisperCpp => {
+            let path = crate::system::paths::whisper_model_path(app, size)?;
+            let complete = path.exists();
+            (path, complete)
+        }
+        LocalTranscriptionEngine::Candle => {
+            let dir = crate::system::paths::candle_whisper_model_dir(app, size)?;
+            let complete = CANDLE_MODEL_FILES
+                .iter()
+                .all(|file| dir.join(file).exists());
+            (dir, complete)
+        }
+    };
+
+    Ok(complete.then_some(path))
+}
+
+pub fn ensure_candle_whisper_model(
+    app: &tauri::AppHandle,
+    size: WhisperModelSize,
+) -> io::Result<PathBuf> {
+    let model_dir = crate::system::paths::candle_whisper_model_dir(app, size)?;
+    let repo_url = std::env::var(CANDLE_REPO_URL_ENV)
+        .ok()
+        .map(|value| value.trim().trim_end_matches('/').to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| format!("https://huggingface.co/openai/whisper-{}", size.as_str()));
+
+    for file in CANDLE_MODEL_FILES {
+        let destination = model_dir.join(file);
+        if destination.exists() {
+            continue;
+        }
+
+        download_model(&format!("{repo_url}/resolve/main/{file}"), &destination)?;
+    }
+
+    Ok(model_dir)
+}
+
 fn resolve_model_url(size: WhisperModelSize) -> io::Result<String> {
     let specific_env = size.env_var_name();
 
@@ -30,6 +30,15 @@ pub fn whisper_model_path(app: &tauri::AppHandle, size: WhisperModelSize) -> io:
     Ok(path)
 }
 
+pub fn candle_whisper_model_dir(
+    app: &tauri::AppHandle,
+    size: WhisperModelSize,
+) -> io::Result<PathBuf> {
+    let mut path = models_dir(app)?;
+    path.push(format!("candle-whisper-{}", size.as_str()));
+    Ok(path)
+}
+
 pub fn models_dir(app: &tauri::AppHandle) -> io::Result<PathBuf> {
     let mut path = app
         .path()
@@ -0,0 +1,361 @@
+//! Formats dictation for code editors: spoken casing commands become
+//! identifiers, spoken symbols become characters, and no prose
+//! capitalization or sentence punctuation is added.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Casing {
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+    Constant,
+}
+
+/// Casing commands, matched against the lower-cased words that follow.
+const CASING_COMMANDS: &[(&[&str], Casing)] = &[
+    (&["camel", "case"], Casing::Camel),
+    (&["camelcase"], Casing::Camel),
+    (&["pascal", "case"], Casing::Pascal),
+    (&["pascalcase"], Casing::Pascal),
+    (&["snake", "case"], Casing::Snake),
+    (&["snakecase"], Casing::Snake),
+    (&["kebab", "case"], Casing::Kebab),
+    (&["kebabcase"], Casing::Kebab),
+    (&["screaming", "snake", "case"], Casing::Constant),
+    (&["constant", "case"], Casing::Constant),
+];
+
+/// How a symbol joins the tokens around it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Spacing {
+    /// Spaced on both sides: `a = b`.
+    Spaced,
+    /// Glued to both sides: `a.b`.
+    Glued,
+    /// Spaced before, glued after: an opening quote.
+    Opening,
+    /// Glued before, spaced after: `,` and `)`.
+    Closing,
+    /// Opens or closes depending on how many came before: `"`.
+    Quote,
+}
+
+/// Spoken symbols, longest phrases first so "double equals" wins over
+/// "equals".
+const SYMBOLS: &[(&[&str], &str, Spacing)] = &[
+    (&["triple", "equals"], "===", Spacing::Spaced),
+    (&["double", "equals"], "==", Spacing::Spaced),
+    (&["not", "equals"], "!=", Spacing::Spaced),
+    (&["fat", "arrow"], "=>", Spacing::Spaced),
+    (&["greater", "than", "or", "equal"], ">=", Spacing::Spaced),
+    (&["less", "than", "or", "equal"], "<=", Spacing::Spaced),
+    (&["open", "paren"], "(", Spacing::Glued),
+    (&["open", "parenthesis"], "(", Spacing::Glued),
+    (&["close", "paren"], ")", Spacing::Closing),
+    (&["close", "parenthesis"], ")", Spacing::Closing),
+    (&["open", "bracket"], "[", Spacing::Glued),
+    (&["close", "bracket"], "]", Spacing::Closing),
+    (&["open", "brace"], "{", Spacing::Spaced),
+    (&["open", "curly"], "{", Spacing::Spaced),
+    (&["close", "brace"], "}", Spacing::Spaced),
+    (&["close", "curly"], "}", Spacing::Spaced),
+    (&["open", "angle"], "<", Spacing::Glued),
+    (&["close", "angle"], ">", Spacing::Closing),
+    (&["less", "than"], "<", Spacing::Spaced),
+    (&["greater", "than"], ">", Spacing::Spaced),
+    (&["and", "and"], "&&", Spacing::Spaced),
+    (&["or", "or"], "||", Spacing::Spaced),
+    (&["plus", "equals"], "+=", Spacing::Spaced),
+    (&["minus", "equals"], "-=", Spacing::Spaced),
+    (&["double", "colon"], "::", Spacing::Glued),
+    (&["question", "mark"], "?", Spacing::Closing),
+    (&["single", "quote"], "'", Spacing::Quote),
+    (&["double", "quote"], "\"", Spacing::Quote),
+    (&["new", "line"], "\n", Spacing::Glued),
+    (&["arrow"], "->", Spacing::Spaced),
+    (&["equals"], "=", Spacing::Spaced),
+    (&["plus"], "+", Spacing::Spaced),
+    (&["minus"], "-", Spacing::Spaced),
+    (&["times"], "*", Spacing::Spaced),
+    (&["star"], "*", Spacing::Glued),
+    (&["asterisk"], "*", Spacing::Glued),
+    (&["slash"], "/", Spacing::Glued),
+    (&["backslash"], "\\", Spacing::Glued),
+    (&["percent"], "%", Spacing::Spaced),
+    (&["pipe"], "|", Spacing::Spaced),
+    (&["ampersand"], "&", Spacing::Glued),
+    (&["bang"], "!", Spacing::Glued),
+    (&["dot"], ".", Spacing::Glued),
+    (&["comma"], ",", Spacing::Closing),
+    (&["colon"], ":", Spacing::Closing),
+    (&["semicolon"], ";", Spacing::Closing),
+    (&["underscore"], "_", Spacing::Glued),
+    (&["dash"], "-", Spacing::Glued),
+    (&["hyphen"], "-", Spacing::Glued),
+    (&["hash"], "#", Spacing::Glued),
+    (&["at", "sign"], "@", Spacing::Glued),
+    (&["dollar"], "$", Spacing::Glued),
+    (&["caret"], "^", Spacing::Glued),
+    (&["tilde"], "~", Spacing::Glued),
+    (&["backtick"], "`", Spacing::Quote),
+    (&["quote"], "\"", Spacing::Quote),
+];
+
+/// Language keywords end a casing command, so "camel case user id return"
+/// stops before `return`. Words that often start identifiers ("type",
+/// "new", "is") are left out.
+const KEYWORDS: &[&str] = &[
+    "async", "await", "break", "class", "const", "continue", "def", "elif", "else", "enum",
+    "export", "extends", "false", "fn", "for", "from", "func", "function", "if", "impl", "import",
+    "let", "mut", "nil", "null", "pub", "return", "struct", "switch", "throw", "trait", "true",
+    "typeof", "var", "while", "yield",
+];
+
+const NUMBER_WORDS: &[&str] = &[
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+];
+
+#[derive(Debug)]
+struct Token {
+    text: String,
+    spacing: Spacing,
+}
+
+fn word_token(text: String) -> Token {
+    Token {
+        text,
+        spacing: Spacing::Spaced,
+    }
+}
+
+/// Whisper's sentence punctuation and capitalization are prose artifacts.
+fn clean_word(word: &str) -> String {
+    let trimmed = word.trim_matches(|ch: char| matches!(ch, '.' | ',' | '?' | '!' | ';' | ':'));
+    let mut chars = trimmed.chars();
+    match chars.next() {
+        // Sentence case ("Return") is lowered; "API" and "userId" are kept.
+        Some(first) if first.is_uppercase() && !chars.any(|ch| ch.is_uppercase()) => {
+            trimmed.to_lowercase()
+        }
+        _ => trimmed.to_string(),
+    }
+}
+
+fn phrase_at(words: &[String], index: usize, phrase: &[&str]) -> bool {
+    words.len() >= index + phrase.len()
+        && phrase
+            .iter()
+            .zip(&words[index..])
+            .all(|(expected, word)| word.to_lowercase().replace('-', "") == *expected)
+}
+
+fn casing_at(words: &[String], index: usize) -> Option<(Casing, usize)> {
+    CASING_COMMANDS
+        .iter()
+        .find(|(phrase, _)| phrase_at(words, index, phrase))
+        .map(|(phrase, casing)| (*casing, phrase.len()))
+}
+
+fn symbol_at(words: &[String], index: usize) -> Option<(&'static str, Spacing, usize)> {
+    SYMBOLS
+        .iter()
+        .find(|(phrase, _, _)| phrase_at(words, index, phrase))
+        .map(|(phrase, text, spacing)| (*text, *spacing, phrase.len()))
+}
+
+fn number_word(word: &str) -> Option<String> {
+    NUMBER_WORDS
+        .iter()
+        .position(|number| word.eq_ignore_ascii_case(number))
+        .map(|value| value.to_string())
+}
+
+fn apply_casing(casing: Casing, parts: &[String]) -> String {
+    let parts: Vec<String> = parts
+        .iter()
+        .flat_map(|part| part.split(['-', '_']))
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_lowercase())
+        .collect();
+    let capitalize = |part: &String| {
+        let mut chars = part.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+            .unwrap_or_default()
+    };
+
+    match casing {
+        Casing::Camel => parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                if index == 0 {
+                    part.clone()
+                } else {
+                    capitalize(part)
+                }
+            })
+            .collect(),
+        Casing::Pascal => parts.iter().map(capitalize).collect(),
+        Casing::Snake => parts.join("_"),
+        Casing::Kebab => parts.join("-"),
+        Casing::Constant => parts.join("_").to_uppercase(),
+    }
+}
+
+fn tokenize(words: &[String]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < words.len() {
+        if let Some((casing, len)) = casing_at(words, index) {
+            index += len;
+            let mut parts = Vec::new();
+            while index < words.len()
+                && casing_at(words, index).is_none()
+                && symbol_at(words, index).is_none()
+                && !KEYWORDS.contains(&words[index].to_lowercase().as_str())
+            {
+                parts.push(number_word(&words[index]).unwrap_or_else(|| words[index].clone()));
+                index += 1;
+            }
+            if !parts.is_empty() {
+                tokens.push(word_token(apply_casing(casing, &parts)));
+            }
+            continue;
+        }
+
+        if let Some((text, spacing, len)) = symbol_at(words, index) {
+            tokens.push(Token {
+                text: text.to_string(),
+                spacing,
+            });
+            index += len;
+            continue;
+        }
+
+        let word = &words[index];
+        tokens.push(word_token(
+            number_word(word).unwrap_or_else(|| word.clone()),
+        ));
+        index += 1;
+    }
+
+    tokens
+}
+
+fn join(tokens: Vec<Token>) -> String {
+    let mut output = String::new();
+    let mut glue_next = true;
+    let mut open_quotes: Vec<String> = Vec::new();
+
+    for token in tokens {
+        let spacing = match token.spacing {
+            Spacing::Quote if open_quotes.last() == Some(&token.text) => {
+                open_quotes.pop();
+                Spacing::Closing
+            }
+            Spacing::Quote => {
+                open_quotes.push(token.text.clone());
+                Spacing::Opening
+            }
+            spacing => spacing,
+        };
+
+        let glue_before = glue_next
+            || token.text == "\n"
+            || output.ends_with('\n')
+            || matches!(spacing, Spacing::Glued | Spacing::Closing);
+        if !glue_before {
+            output.push(' ');
+        }
+        output.push_str(&token.text);
+        glue_next = matches!(spacing, Spacing::Glued | Spacing::Opening);
+    }
+
+    output
+}
+
+/// Formats a dictated transcript as code. Casing commands ("camel case user
+/// id") apply to the words after them up to the next symbol, keyword or
+/// command; line breaks in the input are kept.
+pub fn format_code_dictation(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let words: Vec<String> = line
+                .split_whitespace()
+                .map(clean_word)
+                .filter(|word| !word.is_empty())
+                .collect();
+            join(tokenize(&words))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_identifier_casing() {
+        assert_eq!(format_code_dictation("camel case user id"), "userId");
+        assert_eq!(
+            format_code_dictation("Snake case retry count."),
+            "retry_count"
+        );
+        assert_eq!(
+            format_code_dictation("pascal case http client, constant case max user ID"),
+            "HttpClient MAX_USER_ID"
+        );
+        assert_eq!(
+            format_code_dictation("kebab-case main nav bar"),
+            "main-nav-bar"
+        );
+    }
+
+    #[test]
+    fn converts_symbols() {
+        assert_eq!(
+            format_code_dictation(
+                "Const camel case user id equals camel case get user open paren close paren."
+            ),
+            "const userId = getUser()"
+        );
+        assert_eq!(
+            format_code_dictation(
+                "fn snake case load config open paren close paren arrow pascal case config open brace"
+            ),
+            "fn load_config() -> Config {"
+        );
+        assert_eq!(
+            format_code_dictation("self dot name triple equals quote admin quote"),
+            "self.name === \"admin\""
+        );
+        assert_eq!(
+            format_code_dictation("items open bracket zero close bracket comma two"),
+            "items[0], 2"
+        );
+    }
+
+    #[test]
+    fn stops_casing_at_keywords() {
+        assert_eq!(
+            format_code_dictation("Return camel case is ready and and camel case has data"),
+            "return isReady && hasData"
+        );
+        assert_eq!(
+            format_code_dictation("if camel case count greater than ten return null"),
+            "if count > 10 return null"
+        );
+    }
+
+    #[test]
+    fn keeps_line_breaks_without_prose_punctuation() {
+        assert_eq!(
+            format_code_dictation("Let x equals one.\nLet y equals two."),
+            "let x = 1\nlet y = 2"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+/// Longest phrase, in words, on either side of a learned substitution.
+const MAX_SUBSTITUTION_WORDS: usize = 3;
+/// Transcripts longer than this are not diffed.
+const MAX_DIFF_WORDS: usize = 2000;
+/// Edits touching more than this share of the words are rewrites, not
+/// corrections, and teach nothing about individual terms.
+const MAX_CHANGED_RATIO: f32 = 0.5;
+
+/// A short run of words the user replaced with another while editing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordSubstitution {
+    pub source: String,
+    pub destination: String,
+}
+
+struct Word<'a> {
+    text: &'a str,
+    key: String,
+}
+
+fn words(text: &str) -> Vec<Word<'_>> {
+    text.split_whitespace()
+        .map(|word| word.trim_matches(|ch: char| !ch.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(|word| Word {
+            text: word,
+            key: word.to_lowercase(),
+        })
+        .collect()
+}
+
+/// Word-level substitutions between two versions of a transcript. Case and
+/// surrounding punctuation are ignored, and pure insertions or deletions are
+/// not substitutions.
+pub fn find_substitutions(original: &str, corrected: &str) -> Vec<WordSubstitution> {
+    let before = words(original);
+    let after = words(corrected);
+    if before.is_empty() || after.is_empty() {
+        return Vec::new();
+    }
+    if before.len() > MAX_DIFF_WORDS || after.len() > MAX_DIFF_WORDS {
+        return Vec::new();
+    }
+
+    // lcs[i][j] is the longest common subsequence of before[i..] and after[j..].
+    let (n, m) = (before.len(), after.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if before[i].key == after[j].key {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut hunks: Vec<(Vec<&str>, Vec<&str>)> = Vec::new();
+    let mut current: (Vec<&str>, Vec<&str>) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && before[i].key == after[j].key {
+            if !current.0.is_empty() || !current.1.is_empty() {
+                hunks.push(std::mem::take(&mut current));
+            }
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            current.1.push(after[j].text);
+            j += 1;
+        } else {
+            current.0.push(before[i].text);
+            i += 1;
+        }
+    }
+    if !current.0.is_empty() || !current.1.is_empty() {
+        hunks.push(current);
+    }
+
+    let changed: usize = hunks
+        .iter()
+        .map(|(removed, added)| removed.len() + added.len())
+        .sum();
+    if changed as f32 > (n + m) as f32 * MAX_CHANGED_RATIO {
+        return Vec::new();
+    }
+
+    hunks
+        .into_iter()
+        .filter(|(removed, added)| {
+            (1..=MAX_SUBSTITUTION_WORDS).contains(&removed.len())
+                && (1..=MAX_SUBSTITUTION_WORDS).contains(&added.len())
+        })
+        .map(|(removed, added)| WordSubstitution {
+            source: removed.join(" "),
+            destination: added.join(" "),
+        })
+        .collect()
+}
+
+/// Substitutions the user made when editing a transcript from `previous` to
+/// `corrected`, measured against the `raw` model output. Differences already
+/// present in `previous`, such as post-processing rewrites, are not counted.
+pub fn correction_substitutions(
+    raw: &str,
+    previous: &str,
+    corrected: &str,
+) -> Vec<WordSubstitution> {
+    let existing = find_substitutions(raw, previous);
+    find_substitutions(raw, corrected)
+        .into_iter()
+        .filter(|substitution| !existing.contains(substitution))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn substitution(source: &str, destination: &str) -> WordSubstitution {
+        WordSubstitution {
+            source: source.to_string(),
+            destination: destination.to_string(),
+        }
+    }
+
+    #[test]
+    fn finds_word_and_phrase_substitutions() {
+        assert_eq!(
+            find_substitutions(
+                "we deployed to cooper netties and told jon about it.",
+                "We deployed to Kubernetes, and told Jon about it!",
+            ),
+            vec![substitution("cooper netties", "Kubernetes")]
+        );
+        assert_eq!(
+            find_substitutions("보컬이 앱을 열어 주세요", "보컬리 앱을 열어 주세요"),
+            vec![substitution("보컬이", "보컬리")]
+        );
+    }
+
+    #[test]
+    fn ignores_insertions_and_rewrites() {
+        assert!(find_substitutions("send the file", "please send the file now").is_empty());
+        assert!(find_substitutions(
+            "um so basically the thing is broken",
+            "The release pipeline has failed again.",
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn skips_changes_already_made_before_the_edit() {
+        assert_eq!(
+            correction_substitutions(
+                "we are gonna ship the super base migration today",
+                "we are going to ship the super base migration today",
+                "we are going to ship the Supabase migration today",
+            ),
+            vec![substitution("super base", "Supabase")]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+/// Zero-width joiner; the character after it joins the previous emoji.
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Characters that change the look of the previous one instead of taking a
+/// cursor position of their own.
+fn extends_previous(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | ZERO_WIDTH_JOINER
+    )
+}
+
+/// Number of Backspace presses that remove `text` right after it was typed
+/// or pasted: one per visible character, with CRLF, combining accents and
+/// joined emoji counted once.
+pub fn backspaces_to_delete(text: &str) -> usize {
+    let mut count = 0;
+    let mut previous = None;
+    for ch in text.chars() {
+        let joins = previous == Some(ZERO_WIDTH_JOINER)
+            || (previous == Some('\r') && ch == '\n')
+            || (previous.is_some() && extends_previous(ch));
+        if !joins {
+            count += 1;
+        }
+        previous = Some(ch);
+    }
+    count
+}
+
+/// Backspace presses that remove `pasted` from the end of `before_caret`, the
+/// field text left of the caret, or `None` when the field does not end with
+/// it. Runs of whitespace only need to line up, since a formatted paste may
+/// show a line break as a paragraph gap.
+pub fn backspaces_to_remove_suffix(before_caret: &str, pasted: &str) -> Option<usize> {
+    let mut field = before_caret.char_indices().rev().peekable();
+    let mut expected = pasted.chars().rev().peekable();
+    let mut start = before_caret.len();
+
+    while let Some(ch) = expected.next() {
+        if !ch.is_whitespace() {
+            let (index, actual) = field.next()?;
+            if actual != ch {
+                return None;
+            }
+            start = index;
+            continue;
+        }
+
+        let mut run = 1;
+        while expected.next_if(|next| next.is_whitespace()).is_some() {
+            run += 1;
+        }
+        // Whitespace that begins the paste must not reach into the text
+        // that was already there.
+        let limit = if expected.peek().is_none() {
+            run
+        } else {
+            usize::MAX
+        };
+        let mut matched = 0;
+        while matched < limit {
+            let Some((index, _)) = field.next_if(|(_, actual)| actual.is_whitespace()) else {
+                break;
+            };
+            start = index;
+            matched += 1;
+        }
+        if matched == 0 {
+            return None;
+        }
+    }
+
+    Some(backspaces_to_delete(&before_caret[start..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_visible_characters() {
+        assert_eq!(backspaces_to_delete(""), 0);
+        assert_eq!(backspaces_to_delete("Hello, world."), 13);
+        assert_eq!(backspaces_to_delete("안녕하세요 반갑습니다"), 11);
+        assert_eq!(backspaces_to_delete("Dear team,\r\nThanks\n"), 18);
+    }
+
+    #[test]
+    fn counts_combined_characters_once() {
+        assert_eq!(backspaces_to_delete("cafe\u{301}"), 4);
+        assert_eq!(backspaces_to_delete("👍🏽 ok"), 4);
+        assert_eq!(backspaces_to_delete("👨\u{200D}👩\u{200D}👧"), 1);
+        assert_eq!(backspaces_to_delete("❤\u{FE0F}"), 1);
+    }
+
+    #[test]
+    fn removes_the_paste_only_when_it_precedes_the_caret() {
+        assert_eq!(
+            backspaces_to_remove_suffix("Notes: Hello, world.", " Hello, world."),
+            Some(14)
+        );
+        assert_eq!(
+            backspaces_to_remove_suffix("Notes: Hello, world. More", " Hello, world."),
+            None
+        );
+        assert_eq!(backspaces_to_remove_suffix("Hello", "Hello there"), None);
+        assert_eq!(backspaces_to_remove_suffix("Notes:  ok", " ok"), Some(3));
+    }
+
+    #[test]
+    fn lets_whitespace_runs_differ_in_formatted_pastes() {
+        assert_eq!(
+            backspaces_to_remove_suffix("Intro\nTitle\n\nBody", "Title\nBody"),
+            Some(11)
+        );
+        assert_eq!(
+            backspaces_to_remove_suffix("Title\r\nBody", "Title\nBody"),
+            Some(10)
+        );
+    }
+}
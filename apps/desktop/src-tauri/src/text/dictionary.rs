@@ -0,0 +1,506 @@
+use regex::RegexBuilder;
+
+use crate::domain::{AppliedReplacement, Term, TermMatchMode};
+
+/// Minimum similarity between phonetic keys for a fuzzy term to fire.
+const FUZZY_THRESHOLD: f32 = 0.8;
+/// Shorter keys produce too many false positives to match fuzzily.
+const FUZZY_MIN_KEY_LEN: usize = 4;
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Korean particles that may trail a dictionary word without breaking the
+/// word boundary ("보컬리가" still matches "보컬리").
+const KOREAN_PARTICLES: &[&str] = &[
+    "이", "가", "은", "는", "을", "를", "의", "에", "에서", "에게", "께", "한테", "로", "으로",
+    "와", "과", "랑", "이랑", "도", "만", "까지", "부터", "처럼", "보다",
+];
+
+#[derive(Clone, Copy, Debug)]
+struct Token {
+    start: usize,
+    end: usize,
+}
+
+/// Applies the user's replacement terms to `text`, longest phrases first, then
+/// fuzzy terms, then regex terms. Returns the new text and every replacement
+/// that changed it.
+pub fn apply_dictionary(text: &str, terms: &[Term]) -> (String, Vec<AppliedReplacement>) {
+    let mut rules: Vec<&Term> = terms
+        .iter()
+        .filter(|term| term.is_replacement && !term.is_deleted)
+        .filter(|term| !term.source_value.trim().is_empty())
+        .collect();
+    rules.sort_by_key(|term| {
+        let rank = match term.match_mode {
+            TermMatchMode::Exact => 0,
+            TermMatchMode::Fuzzy => 1,
+            TermMatchMode::Regex => 2,
+        };
+        let words = tokenize(&term.source_value).len();
+        (rank, std::cmp::Reverse(words))
+    });
+
+    let mut output = text.to_string();
+    let mut applied = Vec::new();
+    for term in rules {
+        output = match term.match_mode {
+            TermMatchMode::Exact | TermMatchMode::Fuzzy => {
+                replace_words(&output, term, &mut applied)
+            }
+            TermMatchMode::Regex => replace_regex(&output, term, &mut applied),
+        };
+    }
+
+    (output, applied)
+}
+
+/// Whether `phrase` occurs in `text` as whole words, with the same boundary,
+/// case and particle rules as exact replacement terms.
+pub(crate) fn contains_phrase(text: &str, phrase: &str) -> bool {
+    let source: Vec<String> = tokenize(phrase)
+        .iter()
+        .map(|token| normalize(&phrase[token.start..token.end]))
+        .collect();
+    if source.is_empty() {
+        return false;
+    }
+
+    let tokens = tokenize(text);
+    (0..tokens.len()).any(|index| match_exact(text, &tokens[index..], &source).is_some())
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+fn is_apostrophe(ch: char) -> bool {
+    ch == '\'' || ch == '\u{2019}'
+}
+
+/// Splits text into words, keeping apostrophes inside words ("don't").
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut current: Option<usize> = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, ch)) = chars.next() {
+        let next_is_word = chars.peek().is_some_and(|(_, next)| is_word_char(*next));
+        let continues =
+            is_word_char(ch) || (current.is_some() && is_apostrophe(ch) && next_is_word);
+        match (continues, current) {
+            (true, None) => current = Some(index),
+            (false, Some(start)) => {
+                tokens.push(Token { start, end: index });
+                current = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = current {
+        tokens.push(Token {
+            start,
+            end: text.len(),
+        });
+    }
+    tokens
+}
+
+/// Words in a phrase may be separated by spaces or hyphens, but not by
+/// sentence punctuation.
+fn joins_phrase(gap: &str) -> bool {
+    !gap.is_empty() && gap.chars().all(|ch| ch.is_whitespace() || ch == '-')
+}
+
+fn normalize(word: &str) -> String {
+    word.chars()
+        .filter(|ch| !is_apostrophe(*ch))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_hangul(ch: char) -> bool {
+    ('\u{AC00}'..='\u{D7A3}').contains(&ch)
+}
+
+/// Length of the word part of `word` once a trailing Korean particle is
+/// stripped, if `word` starts with `stem`.
+fn strip_particle(word: &str, stem: &str) -> Option<usize> {
+    let rest = word.strip_prefix(stem)?;
+    if rest.is_empty() {
+        return Some(word.len());
+    }
+    let ends_in_hangul = stem.chars().last().is_some_and(is_hangul);
+    (ends_in_hangul && KOREAN_PARTICLES.contains(&rest)).then_some(stem.len())
+}
+
+fn replace_words(text: &str, term: &Term, applied: &mut Vec<AppliedReplacement>) -> String {
+    let source: Vec<String> = tokenize(&term.source_value)
+        .iter()
+        .map(|token| normalize(&term.source_value[token.start..token.end]))
+        .collect();
+    if source.is_empty() {
+        return text.to_string();
+    }
+    let source_key = phonetic_key(&source.concat());
+    let fuzzy = term.match_mode == TermMatchMode::Fuzzy && source_key.len() >= FUZZY_MIN_KEY_LEN;
+
+    let tokens = tokenize(text);
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0;
+    let mut index = 0;
+
+    while index < tokens.len() {
+        let found = match_exact(text, &tokens[index..], &source).or_else(|| {
+            fuzzy
+                .then(|| match_fuzzy(text, &tokens[index..], source.len(), &source_key))
+                .flatten()
+        });
+
+        let Some((count, end)) = found else {
+            index += 1;
+            continue;
+        };
+
+        let start = tokens[index].start;
+        let original = &text[start..end];
+        let replacement = match_case(original, &term.destination_value);
+        output.push_str(&text[cursor..start]);
+        output.push_str(&replacement);
+        if original != replacement {
+            applied.push(AppliedReplacement {
+                term_id: term.id.clone(),
+                original: original.to_string(),
+                replacement,
+                match_mode: term.match_mode,
+            });
+        }
+        cursor = end;
+        index += count;
+    }
+
+    output.push_str(&text[cursor..]);
+    output
+}
+
+/// Matches `source` word by word at the start of `tokens`, returning the
+/// number of tokens consumed and the byte offset where the match ends.
+fn match_exact(text: &str, tokens: &[Token], source: &[String]) -> Option<(usize, usize)> {
+    if tokens.len() < source.len() {
+        return None;
+    }
+
+    let mut end = tokens[0].start;
+    for (position, (token, expected)) in tokens.iter().zip(source).enumerate() {
+        if position > 0 && !joins_phrase(&text[tokens[position - 1].end..token.start]) {
+            return None;
+        }
+        let word = normalize(&text[token.start..token.end]);
+        if position + 1 == source.len() {
+            let matched = strip_particle(&word, expected)?;
+            end = token.start + original_offset(&text[token.start..token.end], matched);
+        } else if &word != expected {
+            return None;
+        }
+    }
+    Some((source.len(), end))
+}
+
+/// Maps a byte length in the normalized form of `original` back onto
+/// `original`, walking past the apostrophes that normalizing dropped.
+fn original_offset(original: &str, normalized_len: usize) -> usize {
+    let mut consumed = 0;
+    for (offset, ch) in original.char_indices() {
+        if consumed >= normalized_len {
+            return offset;
+        }
+        if !is_apostrophe(ch) {
+            consumed += ch.to_lowercase().map(char::len_utf8).sum::<usize>();
+        }
+    }
+    original.len()
+}
+
+/// Tries windows one word shorter and longer than the source so split or
+/// merged words ("kube control", "open ai") still match.
+fn match_fuzzy(
+    text: &str,
+    tokens: &[Token],
+    source_words: usize,
+    source_key: &[char],
+) -> Option<(usize, usize)> {
+    let mut best: Option<(f32, usize)> = None;
+
+    for count in source_words.saturating_sub(1).max(1)..=source_words + 1 {
+        if count > tokens.len() {
+            break;
+        }
+        let window = &tokens[..count];
+        let joined = window
+            .windows(2)
+            .all(|pair| joins_phrase(&text[pair[0].end..pair[1].start]));
+        if !joined {
+            break;
+        }
+        let words: String = window
+            .iter()
+            .map(|token| normalize(&text[token.start..token.end]))
+            .collect();
+        let key = phonetic_key(&words);
+        if key.first() != source_key.first() {
+            continue;
+        }
+        let score = similarity(&key, source_key);
+        if score >= FUZZY_THRESHOLD && best.is_none_or(|(current, _)| score > current) {
+            best = Some((score, count));
+        }
+    }
+
+    best.map(|(_, count)| (count, tokens[count - 1].end))
+}
+
+/// A rough sound-alike key: Hangul syllables decompose into jamo, common
+/// English spellings of the same sound collapse, and doubled letters merge.
+fn phonetic_key(word: &str) -> Vec<char> {
+    let mut key: Vec<char> = Vec::with_capacity(word.len());
+    let chars: Vec<char> = word.chars().collect();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let ch = chars[index];
+        let next = chars.get(index + 1).copied();
+        let mapped: &[char] = match (ch, next) {
+            ('p', Some('h')) => {
+                index += 1;
+                &['f']
+            }
+            ('c', Some('k')) => {
+                index += 1;
+                &['k']
+            }
+            ('c', Some('e' | 'i' | 'y')) => &['s'],
+            ('c' | 'q', _) => &['k'],
+            ('z', _) => &['s'],
+            ('x', _) => &['k', 's'],
+            _ => &[],
+        };
+
+        if !mapped.is_empty() {
+            key.extend_from_slice(mapped);
+        } else if is_hangul(ch) {
+            key.extend(decompose_hangul(ch));
+        } else if ch.is_alphanumeric() {
+            key.push(ch);
+        }
+        index += 1;
+    }
+
+    key.dedup();
+    key
+}
+
+/// Splits a precomposed Hangul syllable into its leading consonant, vowel and
+/// optional final consonant.
+fn decompose_hangul(ch: char) -> impl Iterator<Item = char> {
+    let index = ch as u32 - 0xAC00;
+    let lead = char::from_u32(0x1100 + index / 588);
+    let vowel = char::from_u32(0x1161 + (index % 588) / 28);
+    let tail = match index % 28 {
+        0 => None,
+        tail => char::from_u32(0x11A7 + tail),
+    };
+    [lead, vowel, tail].into_iter().flatten()
+}
+
+fn similarity(left: &[char], right: &[char]) -> f32 {
+    let longest = left.len().max(right.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(left, right) as f32 / longest as f32
+}
+
+fn levenshtein(left: &[char], right: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current = vec![0; right.len() + 1];
+
+    for (i, left_ch) in left.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, right_ch) in right.iter().enumerate() {
+            let cost = usize::from(left_ch != right_ch);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[right.len()]
+}
+
+/// Carries the matched text's capitalization onto a lowercase destination;
+/// destinations with their own casing ("OpenAI") are kept as written.
+fn match_case(original: &str, destination: &str) -> String {
+    if destination.chars().any(char::is_uppercase) {
+        return destination.to_string();
+    }
+
+    let letters: Vec<char> = original.chars().filter(|ch| ch.is_alphabetic()).collect();
+    if letters.len() > 1 && letters.iter().all(|ch| ch.is_uppercase()) {
+        return destination.to_uppercase();
+    }
+
+    let mut chars = destination.chars();
+    match (letters.first(), chars.next()) {
+        (Some(first), Some(head)) if first.is_uppercase() => {
+            head.to_uppercase().chain(chars).collect()
+        }
+        _ => destination.to_string(),
+    }
+}
+
+fn replace_regex(text: &str, term: &Term, applied: &mut Vec<AppliedReplacement>) -> String {
+    let regex = match RegexBuilder::new(&term.source_value)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+    {
+        Ok(regex) => regex,
+        Err(err) => {
+            eprintln!(
+                "[dictionary] Skipping invalid regex term {}: {err}",
+                term.id
+            );
+            return text.to_string();
+        }
+    };
+
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for captures in regex.captures_iter(text) {
+        let Some(whole) = captures.get(0).filter(|whole| !whole.is_empty()) else {
+            continue;
+        };
+        let mut replacement = String::new();
+        captures.expand(&term.destination_value, &mut replacement);
+
+        output.push_str(&text[cursor..whole.start()]);
+        output.push_str(&replacement);
+        if whole.as_str() != replacement {
+            applied.push(AppliedReplacement {
+                term_id: term.id.clone(),
+                original: whole.as_str().to_string(),
+                replacement,
+                match_mode: TermMatchMode::Regex,
+            });
+        }
+        cursor = whole.end();
+    }
+
+    output.push_str(&text[cursor..]);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn term(id: &str, source: &str, destination: &str, match_mode: TermMatchMode) -> Term {
+        Term {
+            id: id.to_string(),
+            created_at: 0,
+            created_by_user_id: "local-user-id".to_string(),
+            source_value: source.to_string(),
+            destination_value: destination.to_string(),
+            is_replacement: true,
+            is_deleted: false,
+            match_mode,
+        }
+    }
+
+    #[test]
+    fn exact_terms_respect_boundaries_case_and_punctuation() {
+        let terms = [
+            term("1", "vocally", "Vocally", TermMatchMode::Exact),
+            term("2", "open ai", "OpenAI", TermMatchMode::Exact),
+            term("3", "gonna", "going to", TermMatchMode::Exact),
+        ];
+
+        let (text, applied) = apply_dictionary(
+            "vocally and Open-AI. Gonna try vocallyx, open. ai GONNA",
+            &terms,
+        );
+
+        assert_eq!(
+            text,
+            "Vocally and OpenAI. Going to try vocallyx, open. ai GOING TO"
+        );
+        assert_eq!(applied.len(), 4);
+        assert_eq!(applied[0].term_id, "2");
+        assert_eq!(applied[0].original, "Open-AI");
+    }
+
+    #[test]
+    fn korean_terms_keep_trailing_particles() {
+        let terms = [term("1", "보컬리", "Vocally", TermMatchMode::Exact)];
+
+        let (text, applied) = apply_dictionary("보컬리가 좋아요. 보컬리스트", &terms);
+
+        assert_eq!(text, "Vocally가 좋아요. 보컬리스트");
+        assert_eq!(applied[0].original, "보컬리");
+    }
+
+    #[test]
+    fn exact_terms_replace_whole_contractions() {
+        let terms = [term("1", "can't", "cannot", TermMatchMode::Exact)];
+
+        let (text, applied) = apply_dictionary("I can't go", &terms);
+
+        assert_eq!(text, "I cannot go");
+        assert_eq!(applied[0].original, "can't");
+    }
+
+    #[test]
+    fn fuzzy_terms_match_sound_alikes_and_jamo() {
+        let terms = [
+            term("1", "kubectl", "kubectl", TermMatchMode::Fuzzy),
+            term("2", "Raphael", "Rafael", TermMatchMode::Fuzzy),
+            term("3", "보컬리", "Vocally", TermMatchMode::Fuzzy),
+        ];
+
+        let (text, applied) = apply_dictionary(
+            "run kube ctl for Rafael, then ask 보커리 and Michael",
+            &terms,
+        );
+
+        assert_eq!(text, "run kubectl for Rafael, then ask Vocally and Michael");
+        assert_eq!(applied.len(), 2);
+        assert!(applied
+            .iter()
+            .all(|replacement| replacement.match_mode == TermMatchMode::Fuzzy));
+    }
+
+    #[test]
+    fn regex_terms_expand_captures_and_skip_invalid_patterns() {
+        let terms = [
+            term("1", r"ticket (\d+)", "JIRA-$1", TermMatchMode::Regex),
+            term("2", r"(unclosed", "x", TermMatchMode::Regex),
+        ];
+
+        let (text, applied) = apply_dictionary("Close Ticket 42 and ticket 7", &terms);
+
+        assert_eq!(text, "Close JIRA-42 and JIRA-7");
+        assert_eq!(applied.len(), 2);
+    }
+
+    #[test]
+    fn ignores_deleted_and_non_replacement_terms() {
+        let mut deleted = term("1", "foo", "bar", TermMatchMode::Exact);
+        deleted.is_deleted = true;
+        let mut vocabulary = term("2", "foo", "baz", TermMatchMode::Exact);
+        vocabulary.is_replacement = false;
+
+        let (text, applied) = apply_dictionary("foo", &[deleted, vocabulary]);
+
+        assert_eq!(text, "foo");
+        assert!(applied.is_empty());
+    }
+}
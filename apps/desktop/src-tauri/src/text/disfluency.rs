@@ -0,0 +1,431 @@
+//! Local cleanup of spoken disfluencies: filler words, stutters, repeated
+//! words and false starts, and the spacing left around punctuation.
+
+/// Fillers removed when the user has not configured their own list.
+pub const DEFAULT_FILLER_WORDS: &[&str] = &[
+    "um", "umm", "uh", "uhh", "er", "erm", "hmm", "like", "you know", "i mean", "음", "으음", "어",
+    "어어", "그", "저", "뭐", "막",
+];
+
+/// Fillers that are also ordinary words, removed only when set off by a
+/// comma: "it was, like, huge" but not "I like it".
+const COMMA_BOUND_FILLERS: &[&str] = &[
+    "like", "you know", "i mean", "so", "well", "그", "저", "뭐", "막", "이제",
+];
+
+/// Words that are often doubled on purpose ("I know that that works").
+const REPEATABLE_WORDS: &[&str] = &["that", "had", "bye", "no", "ha", "very", "so"];
+
+/// Spoken digits keep their repeats: "five five five".
+const NUMBER_WORDS: &[&str] = &[
+    "zero", "oh", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "공",
+    "영", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구",
+];
+
+/// Longest false start collapsed: "to the, to the store".
+const MAX_REPEAT_SPAN: usize = 4;
+
+const SENTENCE_ENDINGS: [char; 3] = ['.', '?', '!'];
+const CLAUSE_PUNCTUATION: [char; 6] = [',', '.', '?', '!', ';', ':'];
+
+#[derive(Clone)]
+struct Word {
+    space: String,
+    text: String,
+}
+
+fn split_words(text: &str) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut rest = text;
+    loop {
+        let word_start = rest.len() - rest.trim_start().len();
+        if word_start == rest.len() {
+            return words;
+        }
+        let after_space = &rest[word_start..];
+        let word_len = after_space
+            .find(char::is_whitespace)
+            .unwrap_or(after_space.len());
+        words.push(Word {
+            space: rest[..word_start].to_string(),
+            text: after_space[..word_len].to_string(),
+        });
+        rest = &after_space[word_len..];
+    }
+}
+
+fn key(word: &str) -> String {
+    word.trim_matches(|ch: char| !ch.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn ends_sentence(word: &str) -> bool {
+    word.ends_with(SENTENCE_ENDINGS)
+}
+
+fn starts_upper(word: &str) -> bool {
+    word.chars().next().is_some_and(char::is_uppercase)
+}
+
+fn capitalize_first(word: &mut String) {
+    if let Some(first) = word.chars().next() {
+        let upper: String = first.to_uppercase().collect();
+        word.replace_range(..first.len_utf8(), &upper);
+    }
+}
+
+fn is_number(key: &str) -> bool {
+    key.chars().all(|ch| ch.is_ascii_digit()) || NUMBER_WORDS.contains(&key)
+}
+
+/// Removes the comma that set a filler off from `word`, if any.
+fn strip_comma(word: &mut String) {
+    if word.ends_with(',') {
+        word.pop();
+    }
+}
+
+/// How many words of `words` the filler `phrase` spans, if it starts there.
+fn match_filler(words: &[Word], phrase: &[String], previous: Option<&Word>) -> Option<usize> {
+    if words.len() < phrase.len() {
+        return None;
+    }
+    let spoken = &words[..phrase.len()];
+    if !spoken
+        .iter()
+        .zip(phrase)
+        .all(|(word, expected)| key(&word.text) == *expected)
+    {
+        return None;
+    }
+    // Punctuation inside the phrase means the words belong to different clauses.
+    if spoken[..phrase.len() - 1]
+        .iter()
+        .any(|word| word.text.ends_with(CLAUSE_PUNCTUATION))
+    {
+        return None;
+    }
+
+    if COMMA_BOUND_FILLERS.contains(&phrase.join(" ").as_str()) {
+        let last = &spoken[phrase.len() - 1].text;
+        let after_comma = previous.is_some_and(|word| word.text.ends_with(','));
+        let set_off = last.ends_with(',') || (after_comma && ends_sentence(last));
+        if !set_off {
+            return None;
+        }
+    }
+    Some(phrase.len())
+}
+
+fn remove_fillers(words: Vec<Word>, fillers: &[Vec<String>]) -> Vec<Word> {
+    let mut out: Vec<Word> = Vec::with_capacity(words.len());
+    let mut removed_space: Option<String> = None;
+    let mut capitalize = false;
+    let mut index = 0;
+
+    while index < words.len() {
+        let matched = fillers
+            .iter()
+            .filter_map(|phrase| match_filler(&words[index..], phrase, out.last()))
+            .max();
+        let Some(len) = matched else {
+            let mut word = words[index].clone();
+            if let Some(space) = removed_space.take() {
+                if out.is_empty() || space.contains('\n') {
+                    word.space = space;
+                }
+            }
+            if capitalize {
+                capitalize_first(&mut word.text);
+                capitalize = false;
+            }
+            out.push(word);
+            index += 1;
+            continue;
+        };
+
+        let first = &words[index];
+        let last = &words[index + len - 1].text;
+        let sentence_start =
+            first.space.contains('\n') || out.last().is_none_or(|word| ends_sentence(&word.text));
+        capitalize |= sentence_start && starts_upper(&first.text);
+
+        if let Some(previous) = out.last_mut() {
+            // "should, uh, ship" loses both commas; "works, you know." keeps the stop.
+            if let Some(end) = last
+                .chars()
+                .last()
+                .filter(|ch| SENTENCE_ENDINGS.contains(ch))
+            {
+                strip_comma(&mut previous.text);
+                if !previous.text.ends_with(CLAUSE_PUNCTUATION) {
+                    previous.text.push(end);
+                }
+            } else if last.ends_with(',') {
+                strip_comma(&mut previous.text);
+            }
+        }
+
+        removed_space.get_or_insert_with(|| first.space.clone());
+        index += len;
+    }
+
+    out
+}
+
+/// "th- the" becomes "the" and "w-we" becomes "we".
+fn collapse_stutters(words: Vec<Word>) -> Vec<Word> {
+    let mut out: Vec<Word> = Vec::with_capacity(words.len());
+    let mut pending: Option<Word> = None;
+
+    for mut word in words {
+        if let Some(fragment) = pending.take() {
+            let prefix = key(&fragment.text);
+            if key(&word.text).starts_with(&prefix) {
+                word.space = fragment.space;
+                if starts_upper(&fragment.text) {
+                    capitalize_first(&mut word.text);
+                }
+            } else {
+                out.push(fragment);
+            }
+        }
+
+        if word.text.ends_with('-') && word.text.len() > 1 && !key(&word.text).is_empty() {
+            pending = Some(word);
+            continue;
+        }
+
+        while let Some((left, right)) = word.text.split_once('-') {
+            let (left_key, right_key) = (left.to_lowercase(), key(right));
+            let is_stutter = !left_key.is_empty()
+                && left_key.chars().all(char::is_alphabetic)
+                && right_key.starts_with(&left_key)
+                && (left_key.chars().count() == 1 || left_key == right_key);
+            if !is_stutter {
+                break;
+            }
+            let upper = starts_upper(left);
+            word.text = right.to_string();
+            if upper {
+                capitalize_first(&mut word.text);
+            }
+        }
+        out.push(word);
+    }
+
+    out.extend(pending);
+    out
+}
+
+/// Drops the first copy of a word or short phrase said twice in a row.
+fn collapse_repeats(mut words: Vec<Word>) -> Vec<Word> {
+    let mut index = 0;
+    while index < words.len() {
+        let span = (1..=MAX_REPEAT_SPAN).rev().find(|&span| {
+            let Some(second) = words.get(index + span..index + 2 * span) else {
+                return false;
+            };
+            let first = &words[index..index + span];
+            let keys: Vec<String> = first.iter().map(|word| key(&word.text)).collect();
+            let repeated = keys
+                .iter()
+                .zip(second)
+                .all(|(first, word)| !first.is_empty() && *first == key(&word.text));
+            let deliberate = keys.iter().any(|key| is_number(key))
+                || (span == 1 && REPEATABLE_WORDS.contains(&keys[0].as_str()));
+            let inner_punctuation = first[..span - 1]
+                .iter()
+                .any(|word| word.text.ends_with(CLAUSE_PUNCTUATION));
+            repeated && !deliberate && !inner_punctuation && !ends_sentence(&first[span - 1].text)
+        });
+
+        let Some(span) = span else {
+            index += 1;
+            continue;
+        };
+        let space = words[index].space.clone();
+        let upper = starts_upper(&words[index].text);
+        words.drain(index..index + span);
+        words[index].space = space;
+        if upper {
+            capitalize_first(&mut words[index].text);
+        }
+    }
+    words
+}
+
+fn trim_trailing_spaces(text: &mut String) {
+    let trimmed = text.trim_end_matches([' ', '\t']).len();
+    text.truncate(trimmed);
+}
+
+/// Characters that mark a word as a URL, path, address or code rather than
+/// prose.
+const CODE_MARKERS: &[char] = &[
+    '/', '\\', '_', '=', '`', '(', ')', '[', ']', '{', '}', '<', '>', '@', '#', '$', '&', '|', '~',
+];
+
+/// `https://a.io/?q=a,b`, `src/a,b.rs` and `f(a,b)` keep their commas tight.
+fn is_code_like(word: &str) -> bool {
+    word.contains(CODE_MARKERS) || word.starts_with("www.")
+}
+
+/// Collapses runs of spaces, removes spaces before punctuation and adds one
+/// after a comma, semicolon, question or exclamation mark followed by a word,
+/// unless it sits inside a URL, path or code.
+fn fix_spacing(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.char_indices().peekable();
+    let mut word_start = 0;
+
+    while let Some((index, ch)) = chars.next() {
+        if ch.is_whitespace() {
+            word_start = index + ch.len_utf8();
+        }
+        let in_prose = || {
+            let word_end = text[index..]
+                .find(char::is_whitespace)
+                .map_or(text.len(), |end| index + end);
+            !is_code_like(&text[word_start..word_end])
+        };
+
+        match ch {
+            ' ' | '\t' => {
+                if !out.is_empty() && !out.ends_with([' ', '\t', '\n']) {
+                    out.push(' ');
+                }
+            }
+            '\n' => {
+                trim_trailing_spaces(&mut out);
+                out.push('\n');
+            }
+            _ if CLAUSE_PUNCTUATION.contains(&ch)
+                && chars.peek().is_none_or(|(_, next)| {
+                    next.is_whitespace() || CLAUSE_PUNCTUATION.contains(next)
+                }) =>
+            {
+                trim_trailing_spaces(&mut out);
+                if ch == ',' && out.ends_with(CLAUSE_PUNCTUATION) {
+                    continue;
+                }
+                if ch != ',' && out.ends_with(',') {
+                    out.pop();
+                }
+                out.push(ch);
+            }
+            ',' | ';' | '?' | '!'
+                if chars.peek().is_some_and(|(_, next)| next.is_alphabetic()) && in_prose() =>
+            {
+                trim_trailing_spaces(&mut out);
+                out.push(ch);
+                out.push(' ');
+            }
+            _ => out.push(ch),
+        }
+    }
+
+    trim_trailing_spaces(&mut out);
+    out
+}
+
+/// Removes `filler_words` (phrases are matched word by word, ignoring case),
+/// collapses stutters and repeated words, and tidies spacing around
+/// punctuation.
+pub fn clean_disfluencies(text: &str, filler_words: &[String]) -> String {
+    let fillers: Vec<Vec<String>> = filler_words
+        .iter()
+        .map(|phrase| {
+            phrase
+                .split_whitespace()
+                .map(key)
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|phrase| !phrase.is_empty())
+        .collect();
+
+    let words = remove_fillers(split_words(text), &fillers);
+    let words = collapse_repeats(collapse_stutters(words));
+    let joined: String = words
+        .iter()
+        .flat_map(|word| [word.space.as_str(), word.text.as_str()])
+        .collect();
+    fix_spacing(&joined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean(text: &str) -> String {
+        let fillers: Vec<String> = DEFAULT_FILLER_WORDS
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        clean_disfluencies(text, &fillers)
+    }
+
+    #[test]
+    fn removes_filler_words() {
+        assert_eq!(
+            clean("Um, I think we should, uh, ship it."),
+            "I think we should ship it."
+        );
+        assert_eq!(clean("It was, like, really good"), "It was really good");
+        assert_eq!(clean("I like it"), "I like it");
+        assert_eq!(clean("You know, it works, you know."), "It works.");
+        assert_eq!(
+            clean("음 그러니까 어 내일 회의는 취소야"),
+            "그러니까 내일 회의는 취소야"
+        );
+        assert_eq!(clean("그, 저, 내일 뵙겠습니다"), "내일 뵙겠습니다");
+        assert_eq!(clean("그 사람이 저 건물에"), "그 사람이 저 건물에");
+    }
+
+    #[test]
+    fn collapses_stutters_and_repeats() {
+        assert_eq!(
+            clean("I I I think th- the plan w-works"),
+            "I think the plan works"
+        );
+        assert_eq!(clean("Go to the, to the store"), "Go to the store");
+        assert_eq!(
+            clean("I know that that is true"),
+            "I know that that is true"
+        );
+        assert_eq!(clean("call five five five"), "call five five five");
+        assert_eq!(clean("Stop. Stop. re-read it"), "Stop. Stop. re-read it");
+        assert_eq!(clean("그 그 회의는"), "그 회의는");
+    }
+
+    #[test]
+    fn fixes_spacing_around_punctuation() {
+        assert_eq!(
+            clean("Hello ,world . How  are you ?"),
+            "Hello, world. How are you?"
+        );
+        assert_eq!(clean("It costs 3,500 won ."), "It costs 3,500 won.");
+        assert_eq!(clean("We use .NET\n  daily"), "We use .NET\ndaily");
+        assert_eq!(
+            clean("See https://example.com/?q=a,b&sort=asc or src/a,b.rs"),
+            "See https://example.com/?q=a,b&sort=asc or src/a,b.rs"
+        );
+        assert_eq!(clean("call f(a,b) now"), "call f(a,b) now");
+    }
+
+    #[test]
+    fn keeps_words_that_are_not_fillers() {
+        assert_eq!(clean("Mm, that tastes good"), "Mm, that tastes good");
+    }
+
+    #[test]
+    fn uses_the_configured_filler_list() {
+        let fillers = vec!["basically".to_string()];
+        assert_eq!(
+            clean_disfluencies("Basically, we ship um today", &fillers),
+            "We ship um today"
+        );
+        assert_eq!(clean_disfluencies("um hello", &[]), "um hello");
+    }
+}
@@ -0,0 +1,791 @@
+use super::{format_phone_number, group_thousands, tokenize};
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const UNITS: &[(&str, &str)] = &[
+    ("kilometer", "km"),
+    ("kilometers", "km"),
+    ("kilometre", "km"),
+    ("kilometres", "km"),
+    ("meter", "m"),
+    ("meters", "m"),
+    ("metre", "m"),
+    ("metres", "m"),
+    ("centimeter", "cm"),
+    ("centimeters", "cm"),
+    ("millimeter", "mm"),
+    ("millimeters", "mm"),
+    ("kilogram", "kg"),
+    ("kilograms", "kg"),
+    ("gram", "g"),
+    ("grams", "g"),
+    ("milligram", "mg"),
+    ("milligrams", "mg"),
+    ("pound", "lb"),
+    ("pounds", "lb"),
+    ("ounce", "oz"),
+    ("ounces", "oz"),
+    ("mile", "mi"),
+    ("miles", "mi"),
+    ("foot", "ft"),
+    ("feet", "ft"),
+    ("inch", "in"),
+    ("inches", "in"),
+    ("liter", "L"),
+    ("liters", "L"),
+    ("litre", "L"),
+    ("litres", "L"),
+    ("milliliter", "mL"),
+    ("milliliters", "mL"),
+    ("kilobyte", "KB"),
+    ("kilobytes", "KB"),
+    ("megabyte", "MB"),
+    ("megabytes", "MB"),
+    ("gigabyte", "GB"),
+    ("gigabytes", "GB"),
+    ("terabyte", "TB"),
+    ("terabytes", "TB"),
+    ("mph", "mph"),
+    ("kph", "km/h"),
+];
+
+struct Word<'a> {
+    space: &'a str,
+    lead: &'a str,
+    core: &'a str,
+    trail: &'a str,
+    key: String,
+}
+
+impl Word<'_> {
+    fn ends_clause(&self) -> bool {
+        !self.trail.is_empty()
+    }
+}
+
+fn words(text: &str) -> (Vec<Word<'_>>, &str) {
+    let (tokens, rest) = tokenize(text);
+    let mut words = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let is_edge = |ch: char| !ch.is_alphanumeric();
+        let core_start = token.word.len() - token.word.trim_start_matches(is_edge).len();
+        let core_end = token.word.trim_end_matches(is_edge).len().max(core_start);
+        let (lead, core, trail) = (
+            &token.word[..core_start],
+            &token.word[core_start..core_end],
+            &token.word[core_end..],
+        );
+
+        // "twenty-five" is read as two number words.
+        let parts: Vec<&str> = core.split('-').collect();
+        if parts.len() > 1 && parts.iter().all(|part| classify(&key(part)).is_some()) {
+            let last = parts.len() - 1;
+            for (index, part) in parts.iter().enumerate() {
+                words.push(Word {
+                    space: if index == 0 { token.space } else { "-" },
+                    lead: if index == 0 { lead } else { "" },
+                    core: part,
+                    trail: if index == last { trail } else { "" },
+                    key: key(part),
+                });
+            }
+            continue;
+        }
+
+        words.push(Word {
+            space: token.space,
+            lead,
+            core,
+            trail,
+            key: key(core),
+        });
+    }
+    (words, rest)
+}
+
+fn key(core: &str) -> String {
+    core.chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Class {
+    Ones(u64),
+    Teen(u64),
+    Tens(u64),
+    Hundred,
+    Scale(u64),
+    Ordinal(u64),
+}
+
+fn classify(key: &str) -> Option<Class> {
+    const ONES: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    const TEENS: [&str; 10] = [
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    const ORDINALS: [&str; 19] = [
+        "first",
+        "second",
+        "third",
+        "fourth",
+        "fifth",
+        "sixth",
+        "seventh",
+        "eighth",
+        "ninth",
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+    ];
+    const ORDINAL_TENS: [&str; 8] = [
+        "twentieth",
+        "thirtieth",
+        "fortieth",
+        "fiftieth",
+        "sixtieth",
+        "seventieth",
+        "eightieth",
+        "ninetieth",
+    ];
+
+    let position = |list: &[&str]| list.iter().position(|word| *word == key).map(|i| i as u64);
+    if let Some(index) = position(&ONES) {
+        return Some(Class::Ones(index + 1));
+    }
+    if let Some(index) = position(&TEENS) {
+        return Some(Class::Teen(index + 10));
+    }
+    if let Some(index) = position(&TENS) {
+        return Some(Class::Tens((index + 2) * 10));
+    }
+    if let Some(index) = position(&ORDINALS) {
+        return Some(Class::Ordinal(index + 1));
+    }
+    if let Some(index) = position(&ORDINAL_TENS) {
+        return Some(Class::Ordinal((index + 2) * 10));
+    }
+    match key {
+        "hundred" => Some(Class::Hundred),
+        "thousand" => Some(Class::Scale(1_000)),
+        "million" => Some(Class::Scale(1_000_000)),
+        "billion" => Some(Class::Scale(1_000_000_000)),
+        "hundredth" => Some(Class::Ordinal(100)),
+        "thousandth" => Some(Class::Ordinal(1_000)),
+        _ => None,
+    }
+}
+
+fn digit_word(key: &str) -> Option<char> {
+    match key {
+        "zero" | "oh" | "o" => Some('0'),
+        _ => match classify(key) {
+            Some(Class::Ones(value)) => char::from_digit(value as u32, 10),
+            _ => None,
+        },
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Number {
+    value: u64,
+    fraction: Option<String>,
+    /// "million" in "two point five million".
+    scale: Option<&'static str>,
+    ordinal: bool,
+    spoken: bool,
+    len: usize,
+}
+
+impl Number {
+    fn digits(&self) -> String {
+        let mut text = if self.value >= 10_000 {
+            group_thousands(self.value)
+        } else {
+            self.value.to_string()
+        };
+        if let Some(fraction) = &self.fraction {
+            text.push('.');
+            text.push_str(fraction);
+        }
+        if let Some(scale) = self.scale {
+            text.push(' ');
+            text.push_str(scale);
+        }
+        text
+    }
+
+    fn ordinal_suffix(&self) -> &'static str {
+        match (self.value % 10, self.value % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        }
+    }
+}
+
+/// Numbers Whisper already wrote with digits, such as "25", "3.5" or "3rd".
+fn parse_digits(word: &Word) -> Option<Number> {
+    let core = word.core;
+    let (body, ordinal) = match core.get(core.len().saturating_sub(2)..) {
+        Some("st" | "nd" | "rd" | "th") => (&core[..core.len() - 2], true),
+        _ => (core, false),
+    };
+    let (integer, fraction) = match body.split_once('.') {
+        Some((integer, fraction)) if !ordinal => (integer, Some(fraction)),
+        _ => (body, None),
+    };
+    let integer: String = integer.chars().filter(|ch| *ch != ',').collect();
+    if integer.is_empty() || !integer.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+    if fraction
+        .is_some_and(|value| value.is_empty() || !value.chars().all(|ch| ch.is_ascii_digit()))
+    {
+        return None;
+    }
+
+    Some(Number {
+        value: integer.parse().ok()?,
+        fraction: fraction.map(str::to_string),
+        scale: None,
+        ordinal,
+        spoken: false,
+        len: 1,
+    })
+}
+
+fn parse_number(words: &[Word]) -> Option<Number> {
+    let first = words.first()?;
+    if let Some(number) = parse_digits(first) {
+        return Some(number);
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Last {
+        Start,
+        Ones,
+        Teen,
+        Tens,
+        Hundred,
+        Scale(u64),
+    }
+
+    let mut total = 0u64;
+    let mut current = 0u64;
+    let mut last = Last::Start;
+    let mut number = Number {
+        spoken: true,
+        ..Default::default()
+    };
+    let mut index = 0;
+
+    while index < words.len() {
+        let word = &words[index];
+        let class = classify(&word.key);
+
+        if word.key == "and"
+            && matches!(last, Last::Hundred | Last::Scale(_))
+            && !word.ends_clause()
+        {
+            let next = words.get(index + 1).and_then(|next| classify(&next.key));
+            if matches!(
+                next,
+                Some(Class::Ones(_) | Class::Teen(_) | Class::Tens(_) | Class::Ordinal(_))
+            ) {
+                index += 1;
+                continue;
+            }
+            break;
+        }
+
+        if word.key == "point" && last != Last::Start && !word.ends_clause() {
+            let fraction: String = words[index + 1..]
+                .iter()
+                .scan(false, |ended, word| {
+                    if *ended {
+                        return None;
+                    }
+                    *ended = word.ends_clause();
+                    digit_word(&word.key)
+                })
+                .collect();
+            if fraction.is_empty() {
+                break;
+            }
+            number.fraction = Some(fraction.clone());
+            index += 1 + fraction.len();
+            number.len = index;
+            if !words[index - 1].ends_clause() {
+                if let Some(scale @ ("thousand" | "million" | "billion")) =
+                    words.get(index).map(|word| word.key.as_str())
+                {
+                    number.scale = Some(match scale {
+                        "thousand" => "thousand",
+                        "million" => "million",
+                        _ => "billion",
+                    });
+                    number.len += 1;
+                }
+            }
+            break;
+        }
+
+        let starts_section = matches!(last, Last::Start | Last::Hundred | Last::Scale(_));
+        match class {
+            Some(Class::Ones(value)) if starts_section || last == Last::Tens => {
+                current += value;
+                last = Last::Ones;
+            }
+            Some(Class::Teen(value)) if starts_section => {
+                current += value;
+                last = Last::Teen;
+            }
+            Some(Class::Tens(value)) if starts_section => {
+                current += value;
+                last = Last::Tens;
+            }
+            Some(Class::Hundred)
+                if matches!(last, Last::Ones | Last::Teen) && (1..=99).contains(&current) =>
+            {
+                current *= 100;
+                last = Last::Hundred;
+            }
+            Some(Class::Scale(scale))
+                if current > 0
+                    && !matches!(last, Last::Start)
+                    && !matches!(last, Last::Scale(previous) if previous <= scale) =>
+            {
+                total += current * scale;
+                current = 0;
+                last = Last::Scale(scale);
+            }
+            Some(Class::Ordinal(value))
+                if value < 100 && (starts_section || last == Last::Tens) =>
+            {
+                if value >= 10 && last == Last::Tens {
+                    break;
+                }
+                current += value;
+                number.ordinal = true;
+            }
+            Some(Class::Ordinal(value))
+                if value >= 100 && matches!(last, Last::Ones | Last::Teen | Last::Tens) =>
+            {
+                current *= value;
+                number.ordinal = true;
+            }
+            _ => break,
+        }
+
+        index += 1;
+        number.len = index;
+        if number.ordinal || word.ends_clause() {
+            break;
+        }
+    }
+
+    if number.len == 0 {
+        return None;
+    }
+    number.value = total + current;
+    Some(number)
+}
+
+/// Years read in pairs: "nineteen ninety nine", "twenty twenty four",
+/// "nineteen oh five".
+fn parse_paired_year(words: &[Word]) -> Option<(u64, usize)> {
+    let century = match classify(&words.first()?.key)? {
+        Class::Teen(value) if value >= 11 => value,
+        Class::Tens(20) => 20,
+        _ => return None,
+    };
+    if words[0].ends_clause() {
+        return None;
+    }
+
+    let second = words.get(1)?;
+    if second.key == "hundred" {
+        return Some((century * 100, 2));
+    }
+    if second.key == "oh" && !second.ends_clause() {
+        if let Some(Class::Ones(value)) = words.get(2).and_then(|word| classify(&word.key)) {
+            return Some((century * 100 + value, 3));
+        }
+        return None;
+    }
+
+    let rest = parse_number(&words[1..])?;
+    if !rest.spoken || rest.ordinal || rest.fraction.is_some() || !(10..=99).contains(&rest.value) {
+        return None;
+    }
+    Some((century * 100 + rest.value, 1 + rest.len))
+}
+
+fn parse_year(words: &[Word]) -> Option<(u64, usize)> {
+    if let Some(year) = parse_paired_year(words) {
+        return Some(year);
+    }
+    let number = parse_number(words)?;
+    (!number.ordinal && number.fraction.is_none() && (1000..=2999).contains(&number.value))
+        .then_some((number.value, number.len))
+}
+
+fn month_at(word: &Word) -> Option<usize> {
+    let index = MONTHS.iter().position(|month| *month == word.key)?;
+    // "may" is usually the verb unless capitalised.
+    if word.key == "may" && !word.core.starts_with('M') {
+        return None;
+    }
+    Some(index)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// "March third", "March 3rd, twenty twenty four", "the third of March".
+fn parse_date(words: &[Word]) -> Option<(String, usize)> {
+    let day_in_range =
+        |number: &Number| (1..=31).contains(&number.value) && number.fraction.is_none();
+
+    if let Some(month) = month_at(&words[0]) {
+        if words[0].ends_clause() {
+            return None;
+        }
+        let day = parse_number(&words[1..]).filter(day_in_range)?;
+        let mut len = 1 + day.len;
+        let mut text = format!("{} {}", capitalize(MONTHS[month]), day.value);
+
+        let day_trail = words[len - 1].trail;
+        if day_trail.is_empty() || day_trail == "," {
+            if let Some((year, year_len)) = parse_year(&words[len..]) {
+                text.push_str(&format!(", {year}"));
+                len += year_len;
+            }
+        }
+        return Some((text, len));
+    }
+
+    let skip = usize::from(words[0].key == "the" && !words[0].ends_clause());
+    let day = parse_number(&words[skip..]).filter(|day| day.ordinal && day_in_range(day))?;
+    let of = words
+        .get(skip + day.len)
+        .filter(|word| word.key == "of" && !word.ends_clause())?;
+    let _ = of;
+    let month = month_at(words.get(skip + day.len + 1)?)?;
+    Some((
+        format!("{} {}", capitalize(MONTHS[month]), day.value),
+        skip + day.len + 2,
+    ))
+}
+
+/// "three thirty p.m.", "seven oh five am", "five o'clock".
+fn parse_time(words: &[Word]) -> Option<(String, usize)> {
+    let hour = parse_number(words)?;
+    if hour.ordinal || hour.fraction.is_some() || !(1..=12).contains(&hour.value) {
+        return None;
+    }
+    let mut len = hour.len;
+    let mut minutes = None;
+
+    if !words[len - 1].ends_clause() {
+        let next = words.get(len)?;
+        if next.key == "oh" && !next.ends_clause() {
+            if let Some(Class::Ones(value)) =
+                words.get(len + 1).and_then(|word| classify(&word.key))
+            {
+                minutes = Some(value);
+                len += 2;
+            }
+        } else if let Some(number) = parse_number(&words[len..])
+            .filter(|number| number.spoken && !number.ordinal && (10..=59).contains(&number.value))
+        {
+            minutes = Some(number.value);
+            len += number.len;
+        }
+    }
+
+    if words[len - 1].ends_clause() {
+        return None;
+    }
+    let suffix = words.get(len)?;
+    match suffix.key.as_str() {
+        "am" | "pm" => {
+            let time = match minutes {
+                Some(minutes) => format!("{}:{minutes:02}", hour.value),
+                None => hour.value.to_string(),
+            };
+            Some((format!("{time} {}", suffix.core), len + 1))
+        }
+        "oclock" if minutes.is_none() => Some((format!("{} {}", hour.value, suffix.core), len + 1)),
+        _ => None,
+    }
+}
+
+fn parse_phone_number(words: &[Word]) -> Option<(String, usize)> {
+    let mut digits = String::new();
+    for word in words {
+        let Some(digit) = digit_word(&word.key) else {
+            break;
+        };
+        digits.push(digit);
+        if word.ends_clause() {
+            break;
+        }
+    }
+    (digits.len() >= 7).then(|| (format_phone_number(&digits), digits.len()))
+}
+
+/// Currency, percentages, units and temperatures after a number.
+fn parse_quantity(number: &Number, words: &[Word]) -> Option<(String, usize)> {
+    if number.ordinal || words[number.len - 1].ends_clause() {
+        return None;
+    }
+    let amount = number.digits();
+    let next = words.get(number.len)?;
+    let after = number.len + 1;
+
+    let currency = |symbol: &str| Some((format!("{symbol}{amount}"), after));
+    match next.key.as_str() {
+        "percent" => return Some((format!("{amount}%"), after)),
+        "per" if words.get(after).is_some_and(|word| word.key == "cent") && !next.ends_clause() => {
+            return Some((format!("{amount}%"), after + 1));
+        }
+        "dollar" | "dollars" | "bucks" => {
+            let cents = (!next.ends_clause())
+                .then(|| {
+                    words
+                        .get(after)
+                        .filter(|word| word.key == "and" && !word.ends_clause())
+                })
+                .flatten()
+                .and_then(|_| parse_number(&words[after + 1..]))
+                .filter(|cents| {
+                    cents.spoken && !cents.ordinal && cents.value < 100 && cents.fraction.is_none()
+                })
+                .filter(|cents| {
+                    words
+                        .get(after + 1 + cents.len)
+                        .is_some_and(|word| matches!(word.key.as_str(), "cent" | "cents"))
+                        && !words[after + cents.len].ends_clause()
+                });
+            if let (Some(cents), None, None) = (cents, &number.fraction, number.scale) {
+                return Some((
+                    format!("${amount}.{:02}", cents.value),
+                    after + cents.len + 2,
+                ));
+            }
+            return currency("$");
+        }
+        "cent" | "cents" if number.fraction.is_none() && number.scale.is_none() => {
+            return Some((format!("{amount}¢"), after));
+        }
+        "euro" | "euros" => return currency("€"),
+        "yen" => return currency("¥"),
+        "won" => return currency("₩"),
+        "degree" | "degrees" => {
+            let scale = (!next.ends_clause())
+                .then(|| words.get(after))
+                .flatten()
+                .and_then(|word| match word.key.as_str() {
+                    "celsius" | "centigrade" => Some("C"),
+                    "fahrenheit" => Some("F"),
+                    _ => None,
+                });
+            return Some(match scale {
+                Some(scale) => (format!("{amount}°{scale}"), after + 1),
+                None => (format!("{amount}°"), after),
+            });
+        }
+        _ => {}
+    }
+
+    let unit = UNITS
+        .iter()
+        .find(|(spoken, _)| *spoken == next.key)
+        .map(|(_, unit)| *unit)?;
+    Some((format!("{amount} {unit}"), after))
+}
+
+fn is_number_word(word: &Word) -> bool {
+    classify(&word.key).is_some()
+}
+
+pub(super) fn normalize(text: &str) -> String {
+    let (words, rest) = words(text);
+    let mut out = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while index < words.len() {
+        let window = &words[index..];
+        let previous_is_number = index > 0 && is_number_word(&words[index - 1]);
+
+        let rewrite = parse_phone_number(window)
+            .or_else(|| parse_time(window))
+            .or_else(|| parse_date(window))
+            .or_else(|| {
+                let number = parse_number(window)?;
+                if let Some(quantity) = parse_quantity(&number, window) {
+                    return Some(quantity);
+                }
+                if !number.spoken || previous_is_number {
+                    return None;
+                }
+                if let Some((year, len)) = parse_paired_year(window) {
+                    return Some((year.to_string(), len));
+                }
+                let followed_by_number = window.get(number.len).is_some_and(is_number_word)
+                    && !window[number.len - 1].ends_clause();
+                if followed_by_number {
+                    return None;
+                }
+                if number.ordinal {
+                    let compound = number.len > 1 || number.value >= 10;
+                    return compound.then(|| {
+                        (
+                            format!("{}{}", number.value, number.ordinal_suffix()),
+                            number.len,
+                        )
+                    });
+                }
+                let significant = number.value >= 10 || number.fraction.is_some();
+                significant.then(|| (number.digits(), number.len))
+            });
+
+        match rewrite {
+            Some((replacement, len)) => {
+                let first = &words[index];
+                let last = &words[index + len - 1];
+                out.push_str(first.space);
+                out.push_str(first.lead);
+                out.push_str(&replacement);
+                out.push_str(last.trail);
+                index += len;
+            }
+            None => {
+                let word = &words[index];
+                out.push_str(word.space);
+                out.push_str(word.lead);
+                out.push_str(word.core);
+                out.push_str(word.trail);
+                index += 1;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn cardinals_and_ordinals() {
+        assert_eq!(normalize("I have two cats"), "I have two cats");
+        assert_eq!(
+            normalize("about twenty five thousand three hundred and twelve people"),
+            "about 25,312 people"
+        );
+        assert_eq!(normalize("pi is three point one four"), "pi is 3.14");
+        assert_eq!(
+            normalize("the twenty-first century, for the first time"),
+            "the 21st century, for the first time"
+        );
+    }
+
+    #[test]
+    fn currency_and_percentages() {
+        assert_eq!(
+            normalize("it costs twenty five dollars and fifty cents."),
+            "it costs $25.50."
+        );
+        assert_eq!(normalize("ninety nine cents"), "99¢");
+        assert_eq!(normalize("two point five million dollars"), "$2.5 million");
+        assert_eq!(normalize("up fifteen percent"), "up 15%");
+        assert_eq!(normalize("five thousand won"), "₩5000");
+        assert_eq!(normalize("we won the game"), "we won the game");
+    }
+
+    #[test]
+    fn dates_and_years() {
+        assert_eq!(
+            normalize("twenty five dollars on march third"),
+            "$25 on March 3"
+        );
+        assert_eq!(
+            normalize("Due March 3rd, twenty twenty four."),
+            "Due March 3, 2024."
+        );
+        assert_eq!(normalize("on the fifth of May"), "on May 5");
+        assert_eq!(normalize("it may first rain"), "it may first rain");
+        assert_eq!(normalize("born in nineteen ninety nine"), "born in 1999");
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(normalize("meet at three thirty p.m."), "meet at 3:30 p.m.");
+        assert_eq!(normalize("wake at seven oh five am"), "wake at 7:05 am");
+        assert_eq!(normalize("five o'clock"), "5 o'clock");
+        assert_eq!(normalize("three thirty"), "three thirty");
+    }
+
+    #[test]
+    fn phone_numbers_and_units() {
+        assert_eq!(
+            normalize("call five five five one two three four"),
+            "call 555-1234"
+        );
+        assert_eq!(
+            normalize("ran five kilometers and lost two pounds"),
+            "ran 5 km and lost 2 lb"
+        );
+        assert_eq!(
+            normalize("it is twenty degrees celsius with 80 percent humidity"),
+            "it is 20°C with 80% humidity"
+        );
+    }
+}
@@ -0,0 +1,592 @@
+use super::{format_phone_number, group_thousands, tokenize, Token};
+
+/// Months whose spoken form drops a consonant: "유월" and "시월".
+const IRREGULAR_MONTHS: &[(&str, u64)] = &[("유월", 6), ("시월", 10)];
+
+/// Counters and units that may follow a Sino-Korean number. The second value
+/// replaces the spoken unit; `None` keeps it as written.
+const COUNTERS: &[(&str, Option<&str>)] = &[
+    ("퍼센트", Some("%")),
+    ("프로", Some("%")),
+    ("킬로미터", Some("km")),
+    ("센티미터", Some("cm")),
+    ("밀리미터", Some("mm")),
+    ("미터", Some("m")),
+    ("킬로그램", Some("kg")),
+    ("밀리그램", Some("mg")),
+    ("그램", Some("g")),
+    ("밀리리터", Some("mL")),
+    ("리터", Some("L")),
+    ("테라바이트", Some("TB")),
+    ("기가바이트", Some("GB")),
+    ("메가바이트", Some("MB")),
+    ("킬로바이트", Some("KB")),
+    ("센티", Some("cm")),
+    ("달러", None),
+    ("유로", None),
+    ("원", None),
+    ("엔", None),
+    ("년", None),
+    ("월", None),
+    ("일", None),
+    ("시", None),
+    ("분", None),
+    ("초", None),
+    ("개월", None),
+    ("주년", None),
+    ("층", None),
+    ("호", None),
+    ("번", None),
+    ("회", None),
+    ("명", None),
+    ("개", None),
+    ("권", None),
+    ("대", None),
+    ("배", None),
+    ("도", None),
+    ("세", None),
+];
+
+/// Counters that are safe after a bare one-syllable number. Others ("이번",
+/// "이분", "사명") are everyday words far more often than numbers.
+const SINGLE_SYLLABLE_COUNTERS: &[&str] = &[
+    "퍼센트",
+    "프로",
+    "킬로미터",
+    "센티미터",
+    "밀리미터",
+    "미터",
+    "킬로그램",
+    "밀리그램",
+    "그램",
+    "밀리리터",
+    "리터",
+    "테라바이트",
+    "기가바이트",
+    "메가바이트",
+    "킬로바이트",
+    "달러",
+    "유로",
+    "개월",
+    "월",
+];
+
+/// Endings allowed after a number or counter; anything else means the
+/// syllables belong to another word ("일시적", "사명감").
+const PARTICLES: &[&str] = &[
+    "",
+    "은",
+    "는",
+    "이",
+    "가",
+    "을",
+    "를",
+    "에",
+    "에서",
+    "에게",
+    "의",
+    "도",
+    "로",
+    "으로",
+    "와",
+    "과",
+    "까지",
+    "부터",
+    "만",
+    "쯤",
+    "정도",
+    "이나",
+    "나",
+    "이고",
+    "이에요",
+    "예요",
+    "입니다",
+    "이상",
+    "이하",
+    "씩",
+    "째",
+    "짜리",
+    "간",
+];
+
+/// Spellings that read as numbers but are mostly other words: "이만" (this
+/// much), "오만" (arrogance), "천만" (not at all).
+const AMBIGUOUS_NUMBERS: &[&str] = &["이만", "오만", "천만", "구만", "이천", "사천", "삼천"];
+
+const NATIVE_COUNTERS: &[&str] = &["시간", "번째", "시", "살"];
+
+fn sino_digit(ch: char) -> Option<u64> {
+    match ch {
+        '영' | '공' => Some(0),
+        '일' => Some(1),
+        '이' => Some(2),
+        '삼' => Some(3),
+        '사' => Some(4),
+        '오' => Some(5),
+        '육' | '륙' => Some(6),
+        '칠' => Some(7),
+        '팔' => Some(8),
+        '구' => Some(9),
+        _ => None,
+    }
+}
+
+fn small_unit(ch: char) -> Option<u64> {
+    match ch {
+        '십' => Some(10),
+        '백' => Some(100),
+        '천' => Some(1_000),
+        _ => None,
+    }
+}
+
+fn large_unit(ch: char) -> Option<u64> {
+    match ch {
+        '만' => Some(10_000),
+        '억' => Some(100_000_000),
+        '조' => Some(1_000_000_000_000),
+        _ => None,
+    }
+}
+
+struct SinoNumber {
+    value: u64,
+    fraction: Option<String>,
+    /// Byte length of the numeral at the start of the word.
+    len: usize,
+    syllables: usize,
+    has_unit: bool,
+}
+
+impl SinoNumber {
+    fn digits(&self, grouped: bool) -> String {
+        let mut text = if grouped && self.value >= 1_000 {
+            group_thousands(self.value)
+        } else {
+            self.value.to_string()
+        };
+        if let Some(fraction) = &self.fraction {
+            text.push('.');
+            text.push_str(fraction);
+        }
+        text
+    }
+}
+
+/// Reads the longest valid Sino-Korean numeral at the start of `word`, such as
+/// "삼만오천" or "삼점오".
+fn parse_sino(word: &str) -> Option<SinoNumber> {
+    let mut total = 0u64;
+    let mut section = 0u64;
+    let mut digit: Option<u64> = None;
+    let mut last_small = u64::MAX;
+    let mut last_large = u64::MAX;
+    let mut best: Option<SinoNumber> = None;
+    let mut has_unit = false;
+
+    for (offset, ch) in word.char_indices() {
+        if let Some(value) = sino_digit(ch) {
+            if digit.is_some() {
+                break;
+            }
+            digit = Some(value);
+        } else if let Some(unit) = small_unit(ch) {
+            if unit >= last_small || digit == Some(0) {
+                break;
+            }
+            section += digit.take().unwrap_or(1) * unit;
+            last_small = unit;
+            has_unit = true;
+        } else if let Some(unit) = large_unit(ch) {
+            let amount = section + digit.take().unwrap_or(0);
+            if unit >= last_large || (amount == 0 && total > 0) {
+                break;
+            }
+            total += amount.max(1) * unit;
+            section = 0;
+            last_small = u64::MAX;
+            last_large = unit;
+            has_unit = true;
+        } else {
+            break;
+        }
+
+        let len = offset + ch.len_utf8();
+        best = Some(SinoNumber {
+            value: total + section + digit.unwrap_or(0),
+            fraction: None,
+            len,
+            syllables: word[..len].chars().count(),
+            has_unit,
+        });
+    }
+
+    let mut number = best?;
+    if let Some(after_point) = word[number.len..].strip_prefix('점') {
+        let fraction: String = after_point
+            .chars()
+            .map_while(sino_digit)
+            .map(|value| char::from_digit(value as u32, 10).unwrap_or('0'))
+            .collect();
+        if !fraction.is_empty() {
+            number.len += '점'.len_utf8() + fraction.chars().count() * '영'.len_utf8();
+            number.syllables = word[..number.len].chars().count();
+            number.fraction = Some(fraction);
+        }
+    }
+    Some(number)
+}
+
+fn native_number(word: &str) -> Option<(u64, usize)> {
+    const TENS: &[(&str, u64)] = &[
+        ("아흔", 90),
+        ("여든", 80),
+        ("일흔", 70),
+        ("예순", 60),
+        ("쉰", 50),
+        ("마흔", 40),
+        ("서른", 30),
+        ("스물", 20),
+        ("스무", 20),
+        ("열", 10),
+    ];
+    const ONES: &[(&str, u64)] = &[
+        ("하나", 1),
+        ("한", 1),
+        ("둘", 2),
+        ("두", 2),
+        ("셋", 3),
+        ("세", 3),
+        ("석", 3),
+        ("넷", 4),
+        ("네", 4),
+        ("다섯", 5),
+        ("여섯", 6),
+        ("일곱", 7),
+        ("여덟", 8),
+        ("아홉", 9),
+    ];
+
+    let (tens, rest) = TENS
+        .iter()
+        .find_map(|(spoken, value)| word.strip_prefix(spoken).map(|rest| (*value, rest)))
+        .unwrap_or((0, word));
+    let (ones, rest) = ONES
+        .iter()
+        .find_map(|(spoken, value)| rest.strip_prefix(spoken).map(|rest| (*value, rest)))
+        .unwrap_or((0, rest));
+
+    let value = tens + ones;
+    (value > 0).then(|| (value, word.len() - rest.len()))
+}
+
+fn counter_at(text: &str) -> Option<(&'static str, Option<&'static str>)> {
+    COUNTERS
+        .iter()
+        .filter(|(counter, _)| text.starts_with(counter))
+        .max_by_key(|(counter, _)| counter.len())
+        .copied()
+}
+
+fn is_particle(text: &str) -> bool {
+    PARTICLES.contains(&text)
+}
+
+fn split_word(word: &str) -> (&str, &str, &str) {
+    let is_edge = |ch: char| !ch.is_alphanumeric();
+    let start = word.len() - word.trim_start_matches(is_edge).len();
+    let end = word.trim_end_matches(is_edge).len().max(start);
+    (&word[..start], &word[start..end], &word[end..])
+}
+
+#[derive(Clone, Copy, Default)]
+struct Context {
+    after_month: bool,
+    after_hour: bool,
+}
+
+struct Rewrite {
+    text: String,
+    len: usize,
+    context: Context,
+}
+
+/// What follows a number: a counter attached to it ("삼월") or written as
+/// the next word ("이십오 퍼센트"), the ending after the counter, and the
+/// punctuation of the last word consumed.
+struct Suffix<'a> {
+    counter: Option<(&'static str, Option<&'static str>)>,
+    ending: &'a str,
+    trail: &'a str,
+    len: usize,
+}
+
+fn suffix<'a>(tokens: &[Token<'a>], rest: &'a str, trail: &'a str) -> Suffix<'a> {
+    if rest.is_empty() && trail.is_empty() {
+        if let Some(next) = tokens.get(1) {
+            let (lead, next_core, next_trail) = split_word(next.word);
+            if let Some(counter) = counter_at(next_core).filter(|_| lead.is_empty()) {
+                return Suffix {
+                    counter: Some(counter),
+                    ending: &next_core[counter.0.len()..],
+                    trail: next_trail,
+                    len: 2,
+                };
+            }
+        }
+    }
+
+    match counter_at(rest) {
+        Some(counter) => Suffix {
+            counter: Some(counter),
+            ending: &rest[counter.0.len()..],
+            trail,
+            len: 1,
+        },
+        None => Suffix {
+            counter: None,
+            ending: rest,
+            trail,
+            len: 1,
+        },
+    }
+}
+
+/// A Sino-Korean number with an optional counter: "삼천오백 명", "삼월",
+/// "이십오 퍼센트", "삼점오프로".
+fn rewrite_sino(tokens: &[Token], context: Context) -> Option<Rewrite> {
+    let (lead, core, trail) = split_word(tokens[0].word);
+    let mut number = parse_sino(core)?;
+    // After a month, a trailing "일" is the day counter: "십일" is the 10th.
+    if context.after_month && number.len == core.len() && core.ends_with('일') {
+        number = parse_sino(&core[..core.len() - '일'.len_utf8()])
+            .filter(|shorter| shorter.len + '일'.len_utf8() == core.len())?;
+    }
+    let suffix = suffix(tokens, &core[number.len..], trail);
+    if !is_particle(suffix.ending) {
+        return None;
+    }
+
+    let single = number.syllables == 1 && number.fraction.is_none();
+    let mut next = Context::default();
+    let text = match suffix.counter {
+        Some((counter, replacement)) => {
+            let allowed = match counter {
+                "월" => number.fraction.is_none() && (1..=12).contains(&number.value),
+                "일" => context.after_month && (1..=31).contains(&number.value),
+                "년" => number.value >= 10,
+                "분" | "초" => !single || context.after_hour,
+                _ => !single || SINGLE_SYLLABLE_COUNTERS.contains(&counter),
+            };
+            if !allowed {
+                return None;
+            }
+            next.after_month = counter == "월";
+            let grouped = !matches!(
+                counter,
+                "년" | "월" | "일" | "시" | "분" | "초" | "층" | "호" | "번"
+            );
+            format!(
+                "{}{}",
+                number.digits(grouped),
+                replacement.unwrap_or(counter)
+            )
+        }
+        None => {
+            let spoken = &core[..number.len];
+            if single || (!number.has_unit && number.fraction.is_none()) {
+                return None;
+            }
+            if AMBIGUOUS_NUMBERS.contains(&spoken) {
+                return None;
+            }
+            number.digits(true)
+        }
+    };
+
+    Some(Rewrite {
+        text: format!("{lead}{text}{}{}", suffix.ending, suffix.trail),
+        len: suffix.len,
+        context: next,
+    })
+}
+
+/// "유월", "시월".
+fn rewrite_irregular_month(tokens: &[Token]) -> Option<Rewrite> {
+    let (lead, core, trail) = split_word(tokens[0].word);
+    let (month, value) = IRREGULAR_MONTHS
+        .iter()
+        .find(|(month, _)| core.starts_with(month))?;
+    let ending = &core[month.len()..];
+    is_particle(ending).then(|| Rewrite {
+        text: format!("{lead}{value}월{ending}{trail}"),
+        len: 1,
+        context: Context {
+            after_month: true,
+            after_hour: false,
+        },
+    })
+}
+
+/// Native numbers before hour, age and ordinal counters: "세 시" → "3시",
+/// "열두 번째" → "12번째".
+fn rewrite_native(tokens: &[Token]) -> Option<Rewrite> {
+    let (lead, core, trail) = split_word(tokens[0].word);
+    let (value, len) = native_number(core)?;
+    let rest = &core[len..];
+
+    let (counter_text, trail, consumed) = if rest.is_empty() && trail.is_empty() {
+        let (next_lead, next_core, next_trail) = split_word(tokens.get(1)?.word);
+        if !next_lead.is_empty() {
+            return None;
+        }
+        (next_core, next_trail, 2)
+    } else {
+        (rest, trail, 1)
+    };
+    let counter = NATIVE_COUNTERS
+        .iter()
+        .find(|counter| counter_text.starts_with(*counter))?;
+    let ending = &counter_text[counter.len()..];
+    if !is_particle(ending) || (*counter == "시" && value > 12) {
+        return None;
+    }
+
+    Some(Rewrite {
+        text: format!("{lead}{value}{counter}{ending}{trail}"),
+        len: consumed,
+        context: Context {
+            after_month: false,
+            after_hour: matches!(*counter, "시" | "시간"),
+        },
+    })
+}
+
+/// Digits read one syllable at a time: "공일공 일이삼사 오육칠팔".
+fn rewrite_phone_number(tokens: &[Token]) -> Option<Rewrite> {
+    let mut groups: Vec<String> = Vec::new();
+    let mut ending = "";
+    let mut trail = "";
+    for token in tokens {
+        let (lead, core, token_trail) = split_word(token.word);
+        if !lead.is_empty() && !groups.is_empty() {
+            break;
+        }
+        let digits: String = core
+            .chars()
+            .map_while(sino_digit)
+            .map(|value| char::from_digit(value as u32, 10).unwrap_or('0'))
+            .collect();
+        if digits.chars().count() < 2 {
+            break;
+        }
+        let rest = &core[digits.len() * '영'.len_utf8()..];
+        if !is_particle(rest) {
+            break;
+        }
+        groups.push(digits);
+        ending = rest;
+        trail = token_trail;
+        if !rest.is_empty() || !token_trail.is_empty() {
+            break;
+        }
+    }
+
+    let digits: String = groups.concat();
+    if digits.len() < 9 {
+        return None;
+    }
+    let (lead, _, _) = split_word(tokens[0].word);
+    let number = if groups.len() > 1 {
+        groups.join("-")
+    } else {
+        format_phone_number(&digits)
+    };
+    Some(Rewrite {
+        text: format!("{lead}{number}{ending}{trail}"),
+        len: groups.len(),
+        context: Context::default(),
+    })
+}
+
+pub(super) fn normalize(text: &str) -> String {
+    let (tokens, rest) = tokenize(text);
+    let mut out = String::with_capacity(text.len());
+    let mut context = Context::default();
+    let mut index = 0;
+
+    while index < tokens.len() {
+        let window = &tokens[index..];
+        let rewrite = rewrite_phone_number(window)
+            .or_else(|| rewrite_irregular_month(window))
+            .or_else(|| rewrite_native(window))
+            .or_else(|| rewrite_sino(window, context));
+
+        out.push_str(window[0].space);
+        match rewrite {
+            Some(rewrite) => {
+                out.push_str(&rewrite.text);
+                context = rewrite.context;
+                index += rewrite.len;
+            }
+            None => {
+                out.push_str(window[0].word);
+                context = Context::default();
+                index += 1;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn cardinals_and_ordinals() {
+        assert_eq!(
+            normalize("참가자는 삼천오백 명입니다"),
+            "참가자는 3,500명입니다"
+        );
+        assert_eq!(normalize("정답은 이십오입니다."), "정답은 25입니다.");
+        assert_eq!(normalize("세 번째 줄"), "3번째 줄");
+        assert_eq!(
+            normalize("이번에 이분이 오셨어요. 이만 가 볼게요"),
+            "이번에 이분이 오셨어요. 이만 가 볼게요"
+        );
+    }
+
+    #[test]
+    fn currency_and_percentages() {
+        assert_eq!(normalize("삼만오천 원이에요"), "35,000원이에요");
+        assert_eq!(normalize("오 달러"), "5달러");
+        assert_eq!(normalize("이십오 퍼센트"), "25%");
+        assert_eq!(normalize("금리는 삼점오프로"), "금리는 3.5%");
+    }
+
+    #[test]
+    fn dates_and_times() {
+        assert_eq!(normalize("이천이십사년 삼월 삼일"), "2024년 3월 3일");
+        assert_eq!(normalize("시월 십오일, 유월 십일"), "10월 15일, 6월 10일");
+        assert_eq!(
+            normalize("오후 세 시 삼십 분에 만나요"),
+            "오후 3시 30분에 만나요"
+        );
+        assert_eq!(normalize("두 시간 오 분"), "2시간 5분");
+        assert_eq!(normalize("오일을 샀어요"), "오일을 샀어요");
+    }
+
+    #[test]
+    fn phone_numbers_and_units() {
+        assert_eq!(
+            normalize("공일공 일이삼사 오육칠팔로 전화 주세요"),
+            "010-1234-5678로 전화 주세요"
+        );
+        assert_eq!(normalize("오 킬로미터를 뛰었어요"), "5km를 뛰었어요");
+        assert_eq!(normalize("기온은 이십 도"), "기온은 20도");
+        assert_eq!(normalize("일시적인 사명감"), "일시적인 사명감");
+    }
+}
@@ -0,0 +1,111 @@
+//! Rule-based inverse text normalization: spoken forms such as "twenty five
+//! dollars" or "이십오 퍼센트" become "$25" and "25%".
+
+mod en;
+mod ko;
+
+/// Normalizes `text` for `language` (`en`, `ko`, or a tag such as `ko-KR`).
+/// Without a language both rule sets run; they touch disjoint scripts.
+/// Other languages are returned unchanged.
+pub fn inverse_normalize(text: &str, language: Option<&str>) -> String {
+    let language = language.map(crate::domain::language_subtag);
+
+    match language.as_deref() {
+        Some("en") => en::normalize(text),
+        Some("ko") => ko::normalize(text),
+        None | Some("") => ko::normalize(&en::normalize(text)),
+        Some(_) => text.to_string(),
+    }
+}
+
+/// A word and the whitespace that preceded it, so rewritten spans keep the
+/// original line breaks.
+struct Token<'a> {
+    space: &'a str,
+    word: &'a str,
+}
+
+fn tokenize(text: &str) -> (Vec<Token<'_>>, &str) {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    loop {
+        let word_start = rest.len() - rest.trim_start().len();
+        if word_start == rest.len() {
+            return (tokens, rest);
+        }
+        let after_space = &rest[word_start..];
+        let word_len = after_space
+            .find(char::is_whitespace)
+            .unwrap_or(after_space.len());
+        tokens.push(Token {
+            space: &rest[..word_start],
+            word: &after_space[..word_len],
+        });
+        rest = &after_space[word_len..];
+    }
+}
+
+/// `1234567` becomes `1,234,567`.
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    grouped
+}
+
+/// Hyphenates a spoken digit sequence the way phone numbers are written.
+fn format_phone_number(digits: &str) -> String {
+    let split = |sizes: &[usize]| {
+        let mut parts = Vec::new();
+        let mut start = 0;
+        for size in sizes {
+            parts.push(&digits[start..start + size]);
+            start += size;
+        }
+        parts.join("-")
+    };
+
+    match digits.len() {
+        7 => split(&[3, 4]),
+        9 if digits.starts_with("02") => split(&[2, 3, 4]),
+        10 if digits.starts_with("02") => split(&[2, 4, 4]),
+        10 => split(&[3, 3, 4]),
+        11 => split(&[3, 4, 4]),
+        _ => digits.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_by_language() {
+        assert_eq!(
+            inverse_normalize("twenty five percent", Some("en-US")),
+            "25%"
+        );
+        assert_eq!(inverse_normalize("이십오 퍼센트", Some("ko")), "25%");
+        assert_eq!(
+            inverse_normalize("twenty five percent, 이십오 퍼센트", None),
+            "25%, 25%"
+        );
+        assert_eq!(
+            inverse_normalize("twenty five percent", Some("fr")),
+            "twenty five percent"
+        );
+    }
+
+    #[test]
+    fn keeps_surrounding_whitespace() {
+        assert_eq!(
+            inverse_normalize("Total:\ntwenty five dollars \n", Some("en")),
+            "Total:\n$25 \n"
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! Rule-based Korean spacing (띄어쓰기) and sentence-final punctuation fixes
+//! for dictated text.
+
+/// Which syllables a particle may follow.
+#[derive(Clone, Copy)]
+enum Attach {
+    /// After a final consonant (받침): 은, 을, 과.
+    Consonant,
+    /// After an open syllable: 는, 를, 와.
+    Vowel,
+    /// After an open syllable or ㄹ: 로.
+    VowelOrRieul,
+    /// Anywhere: 에, 의, 까지.
+    Any,
+}
+
+/// Particles Whisper sometimes splits off their noun ("회의 는"). Forms that
+/// are also common standalone words (이, 가, 와, 만) are left alone.
+const PARTICLES: &[(&str, Attach)] = &[
+    ("은", Attach::Consonant),
+    ("을", Attach::Consonant),
+    ("과", Attach::Consonant),
+    ("으로", Attach::Consonant),
+    ("이랑", Attach::Consonant),
+    ("는", Attach::Vowel),
+    ("를", Attach::Vowel),
+    ("랑", Attach::Vowel),
+    ("로", Attach::VowelOrRieul),
+    ("에", Attach::Any),
+    ("에는", Attach::Any),
+    ("에도", Attach::Any),
+    ("에서", Attach::Any),
+    ("에서는", Attach::Any),
+    ("에게", Attach::Any),
+    ("께서", Attach::Any),
+    ("한테", Attach::Any),
+    ("의", Attach::Any),
+    ("도", Attach::Any),
+    ("까지", Attach::Any),
+    ("부터", Attach::Any),
+    ("보다", Attach::Any),
+    ("처럼", Attach::Any),
+    ("만큼", Attach::Any),
+];
+
+/// Dependent nouns written apart from the adnominal before them and the
+/// verb after them: "할수있다" → "할 수 있다".
+const DEPENDENT_NOUNS: &[(char, &[&str])] = &[
+    ('수', &["있", "없"]),
+    ('것', &["같"]),
+    ('거', &["같"]),
+    ('줄', &["알", "몰"]),
+    ('적', &["있", "없", "이"]),
+];
+
+/// Auxiliary verbs written apart from the "-지" before them.
+const NEGATIONS: &[&str] = &["지않", "지못", "지말"];
+
+/// Endings that close a statement.
+const STATEMENT_ENDINGS: &[&str] = &[
+    "습니다",
+    "니다",
+    "었다",
+    "았다",
+    "였다",
+    "했다",
+    "겠다",
+    "한다",
+    "는다",
+    "된다",
+    "거야",
+    "이야",
+    "죠",
+];
+
+/// Nouns ending in an open syllable plus 요 that are not verb endings.
+const NOUNS_ENDING_IN_YO: &[&str] = &["주요", "수요", "소요", "요요"];
+
+const QUESTION_ENDINGS: &[&str] = &["까요", "나요", "습니까", "는지요", "을까"];
+
+/// Words that make a sentence ending in 요 or 어 a question: "어디 가요".
+const INTERROGATIVES: &[&str] = &[
+    "뭐",
+    "무엇",
+    "왜",
+    "어디",
+    "언제",
+    "누가",
+    "누구",
+    "어떻게",
+    "어때",
+    "몇",
+    "무슨",
+    "어느",
+];
+
+/// Quotative endings that continue the sentence after a quoted ending:
+/// "좋아요 라고 했다".
+const QUOTATIVES: &[&str] = &["라고", "라는", "라며", "하고", "고"];
+
+const FINAL_RIEUL: u32 = 8;
+const FINAL_NIEUN: u32 = 4;
+const FINAL_BIEUP: u32 = 17;
+const FINAL_BIEUP_SIOT: u32 = 18;
+const FINAL_SSANGSIOT: u32 = 20;
+
+fn is_hangul(ch: char) -> bool {
+    ('가'..='힣').contains(&ch)
+}
+
+/// The final consonant index of a Hangul syllable, `0` for none.
+fn final_consonant(ch: char) -> Option<u32> {
+    is_hangul(ch).then(|| (ch as u32 - '가' as u32) % 28)
+}
+
+fn split_trailing_punctuation(word: &str) -> (&str, &str) {
+    let core = word.trim_end_matches(|ch: char| !ch.is_alphanumeric());
+    (core, &word[core.len()..])
+}
+
+fn is_punctuation(word: &str) -> bool {
+    !word.is_empty() && word.chars().all(|ch| ch.is_ascii_punctuation())
+}
+
+/// Splits dependent nouns and negations off the syllables around them.
+fn split_word(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut index = 1;
+
+    while index < chars.len() {
+        let rest: String = chars[index..].iter().collect();
+        let after: String = chars[index + 1..].iter().collect();
+
+        let adnominal = matches!(
+            final_consonant(chars[index - 1]),
+            Some(FINAL_RIEUL | FINAL_NIEUN)
+        );
+        let dependent = adnominal
+            && DEPENDENT_NOUNS.iter().any(|(noun, verbs)| {
+                chars[index] == *noun && verbs.iter().any(|verb| after.starts_with(verb))
+            });
+        if dependent {
+            parts.push(chars[start..index].iter().collect());
+            parts.push(chars[index].to_string());
+            start = index + 1;
+            index += 2;
+            continue;
+        }
+
+        let negation = NEGATIONS.iter().any(|pattern| rest.starts_with(pattern));
+        if negation && index + 1 < chars.len() {
+            parts.push(chars[start..=index].iter().collect());
+            start = index + 1;
+            index += 2;
+            continue;
+        }
+
+        if rest.starts_with("때문") {
+            parts.push(chars[start..index].iter().collect());
+            start = index;
+        }
+        index += 1;
+    }
+
+    parts.push(chars[start..].iter().collect());
+    parts
+}
+
+fn particle_fits(previous: &str, particle: &str) -> bool {
+    let Some(final_consonant) = previous.chars().last().and_then(final_consonant) else {
+        return false;
+    };
+    PARTICLES
+        .iter()
+        .find(|(form, _)| *form == particle)
+        .is_some_and(|(_, attach)| match attach {
+            Attach::Consonant => final_consonant != 0,
+            Attach::Vowel => final_consonant == 0,
+            Attach::VowelOrRieul => final_consonant == 0 || final_consonant == FINAL_RIEUL,
+            Attach::Any => true,
+        })
+}
+
+fn ends_statement(core: &str) -> bool {
+    let chars: Vec<char> = core.chars().collect();
+    if chars.len() < 2 || !chars.iter().all(|ch| is_hangul(*ch)) {
+        return false;
+    }
+    let before_last = final_consonant(chars[chars.len() - 2]);
+    let last = chars[chars.len() - 1];
+
+    STATEMENT_ENDINGS
+        .iter()
+        .any(|ending| core.ends_with(ending))
+        || (last == '요' && before_last == Some(0) && !NOUNS_ENDING_IN_YO.contains(&core))
+        || (last == '어' && matches!(before_last, Some(FINAL_SSANGSIOT | FINAL_BIEUP_SIOT)))
+}
+
+fn ends_question(core: &str) -> bool {
+    let chars: Vec<char> = core.chars().collect();
+    if chars.len() < 2 {
+        return false;
+    }
+    let before_last = final_consonant(chars[chars.len() - 2]);
+    let last = chars[chars.len() - 1];
+    // "합니까" but not the connective "가니까".
+    let formal_question = core.ends_with("니까")
+        && chars.len() >= 3
+        && final_consonant(chars[chars.len() - 3]) == Some(FINAL_BIEUP);
+
+    QUESTION_ENDINGS.iter().any(|ending| core.ends_with(ending))
+        || (last == '까' && before_last == Some(FINAL_RIEUL))
+        || formal_question
+}
+
+fn is_interrogative(word: &str) -> bool {
+    let (core, _) = split_trailing_punctuation(word);
+    INTERROGATIVES.iter().any(|form| core.starts_with(form))
+        && !core.contains('든')
+        && !core.ends_with('나')
+        && !core.ends_with("라도")
+}
+
+/// Adds or fixes the punctuation that ends each sentence.
+fn punctuate(words: &mut [String]) {
+    let mut asked = false;
+    for index in 0..words.len() {
+        asked |= is_interrogative(&words[index]);
+
+        let word = &words[index];
+        let (core, trail) = split_trailing_punctuation(word);
+        let continues = words
+            .get(index + 1)
+            .is_some_and(|next| QUOTATIVES.contains(&split_trailing_punctuation(next).0));
+        if continues || !(trail.is_empty() || trail == ".") {
+            if trail.ends_with(['.', '?', '!']) {
+                asked = false;
+            }
+            continue;
+        }
+
+        let polite_question = asked
+            && ends_statement(core)
+            && !core.ends_with("주세요")
+            && (core.ends_with('요') || core.ends_with('어') || core.ends_with('야'));
+        let mark = if ends_question(core) || polite_question {
+            '?'
+        } else if ends_statement(core) {
+            '.'
+        } else {
+            if trail == "." {
+                asked = false;
+            }
+            continue;
+        };
+
+        words[index] = format!("{core}{mark}");
+        asked = false;
+    }
+}
+
+fn correct_line(line: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    for word in line.split_whitespace().flat_map(split_word) {
+        let (core, trail) = split_trailing_punctuation(&word);
+        let attaches = words.last().is_some_and(|previous| {
+            is_punctuation(&word)
+                || ((trail.is_empty() || is_punctuation(trail))
+                    && !previous.ends_with(|ch: char| ch.is_ascii_punctuation())
+                    && particle_fits(previous, core))
+        });
+        match words.last_mut() {
+            Some(previous) if attaches => previous.push_str(&word),
+            _ => words.push(word),
+        }
+    }
+
+    punctuate(&mut words);
+    words.join(" ")
+}
+
+/// Fixes word spacing around particles, dependent nouns and negations, and
+/// adds or corrects sentence-final punctuation. Lines are handled separately
+/// so dictated line breaks survive; words without Hangul are left as spoken.
+pub fn correct_korean_spacing(text: &str) -> String {
+    text.split('\n')
+        .map(correct_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attaches_split_particles() {
+        assert_eq!(
+            correct_korean_spacing("오늘 회의 는 취소 되었습니다"),
+            "오늘 회의는 취소 되었습니다."
+        );
+        assert_eq!(
+            correct_korean_spacing("사과 를 먹었어요 그리고 물 을 마셨어요"),
+            "사과를 먹었어요. 그리고 물을 마셨어요."
+        );
+        assert_eq!(
+            correct_korean_spacing("학교 는 집 에서 멀어요"),
+            "학교는 집에서 멀어요."
+        );
+        assert_eq!(correct_korean_spacing("사과 은 없다"), "사과 은 없다");
+    }
+
+    #[test]
+    fn separates_dependent_nouns_and_negations() {
+        assert_eq!(correct_korean_spacing("할수있어요"), "할 수 있어요.");
+        assert_eq!(
+            correct_korean_spacing("비가 올것같아요"),
+            "비가 올 것 같아요."
+        );
+        assert_eq!(
+            correct_korean_spacing("그것때문에 가지않았어"),
+            "그것 때문에 가지 않았어."
+        );
+        assert_eq!(correct_korean_spacing("갈수록 좋아져"), "갈수록 좋아져");
+    }
+
+    #[test]
+    fn fixes_terminal_punctuation() {
+        assert_eq!(correct_korean_spacing("내일 뭐 할까요."), "내일 뭐 할까요?");
+        assert_eq!(correct_korean_spacing("어디 가요"), "어디 가요?");
+        assert_eq!(correct_korean_spacing("이것 좀 주세요"), "이것 좀 주세요.");
+        assert_eq!(
+            correct_korean_spacing("필요 없어요 언제든지 연락 주세요"),
+            "필요 없어요. 언제든지 연락 주세요."
+        );
+        assert_eq!(
+            correct_korean_spacing("좋아요 라고 했다\n주요 뉴스"),
+            "좋아요 라고 했다.\n주요 뉴스"
+        );
+    }
+
+    #[test]
+    fn leaves_other_scripts_alone() {
+        assert_eq!(
+            correct_korean_spacing("Ship it on Monday"),
+            "Ship it on Monday"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::ops::Range;
+
+use pulldown_cmark::{html, Event, Options, Parser, Tag};
+
+fn parser(text: &str) -> Parser<'_> {
+    Parser::new_ext(text, Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES)
+}
+
+fn is_formatting(event: &Event) -> bool {
+    match event {
+        Event::Start(Tag::Paragraph) => false,
+        Event::Start(_) | Event::Code(_) | Event::Rule | Event::TaskListMarker(_) => true,
+        _ => false,
+    }
+}
+
+/// Markers that dictated prose produces by accident: underscores around an
+/// identifier such as `__init__`, and a sentence starting with a number such
+/// as "2024. That was…", which would otherwise become bold text and a list.
+fn accidental_markers(text: &str) -> Vec<usize> {
+    let mut markers = Vec::new();
+    for (event, Range { start, end }) in parser(text).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Emphasis | Tag::Strong) if text[start..end].starts_with('_') => {
+                let span = &text[start..end];
+                let open = span.len() - span.trim_start_matches('_').len();
+                let close = span.len() - span.trim_end_matches('_').len();
+                markers.extend(start..start + open);
+                markers.extend(end - close..end);
+            }
+            // Lists the model writes start at 1.
+            Event::Start(Tag::List(Some(number))) if number != 1 => {
+                if let Some(offset) = text[start..].find(['.', ')']) {
+                    markers.push(start + offset);
+                }
+            }
+            _ => {}
+        }
+    }
+    markers.sort_unstable();
+    markers.dedup();
+    markers
+}
+
+/// Backslash-escapes the accidental markers in `text` so they stay text.
+fn escape_accidental_markers(text: &str) -> String {
+    let mut escaped = text.to_string();
+    // Escaping one marker can expose another, such as the next numbered line.
+    for _ in 0..4 {
+        let markers = accidental_markers(&escaped);
+        if markers.is_empty() {
+            break;
+        }
+        for index in markers.into_iter().rev() {
+            escaped.insert(index, '\\');
+        }
+    }
+    escaped
+}
+
+/// Renders `text` from a tone that writes Markdown as HTML when it uses
+/// formatting such as lists, bold or headings. Plain prose returns `None` and
+/// is pasted as it is.
+pub fn markdown_to_html(text: &str) -> Option<String> {
+    let text = escape_accidental_markers(text);
+    if !parser(&text).any(|event| is_formatting(&event)) {
+        return None;
+    }
+
+    let mut html = String::new();
+    html::push_html(&mut html, parser(&text));
+    Some(html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_markdown_as_html() {
+        let html = markdown_to_html(
+            "## Agenda\n\n- **Budget** review\n- Hiring\n  1. Interviews\n  2. Offers\n\nSee `notes.md` for *details*.",
+        )
+        .expect("formatted transcript");
+
+        assert!(html.contains("<h2>Agenda</h2>"));
+        assert!(html.contains("<li><strong>Budget</strong> review</li>"));
+        assert!(html.contains("<ol>\n<li>Interviews</li>"));
+        assert!(html.contains("<code>notes.md</code>"));
+    }
+
+    #[test]
+    fn leaves_plain_prose_alone() {
+        assert_eq!(markdown_to_html("See you at 5. Bring 2 * 3 copies."), None);
+        assert_eq!(markdown_to_html("#hashtag and a line\nbreak"), None);
+        assert_eq!(markdown_to_html("2024. That was a good year."), None);
+        assert_eq!(markdown_to_html("Call __init__ first"), None);
+        assert_eq!(markdown_to_html(""), None);
+    }
+
+    #[test]
+    fn keeps_accidental_markers_as_text_next_to_formatting() {
+        let html = markdown_to_html("**Recap**\n\n2024. That was __init__ again.")
+            .expect("formatted transcript");
+
+        assert!(html.contains("<strong>Recap</strong>"));
+        assert!(html.contains("<p>2024. That was __init__ again.</p>"));
+    }
+}
@@ -0,0 +1,25 @@
+pub mod code_mode;
+pub mod corrections;
+pub mod deletion;
+pub mod dictionary;
+pub mod disfluency;
+pub mod itn;
+pub mod korean_spacing;
+pub mod markdown;
+pub mod redaction;
+pub mod snippets;
+pub mod vocabulary;
+pub mod voice_commands;
+
+pub use code_mode::format_code_dictation;
+pub use corrections::{correction_substitutions, WordSubstitution};
+pub use deletion::{backspaces_to_delete, backspaces_to_remove_suffix};
+pub use dictionary::apply_dictionary;
+pub use disfluency::{clean_disfluencies, DEFAULT_FILLER_WORDS};
+pub use itn::inverse_normalize;
+pub use korean_spacing::correct_korean_spacing;
+pub use markdown::markdown_to_html;
+pub use redaction::{redact_pii, redact_transcription};
+pub use snippets::match_snippet;
+pub use vocabulary::{build_vocabulary_prompt, VocabularyPromptInput, WHISPER_PROMPT_TOKEN_BUDGET};
+pub use voice_commands::apply_voice_commands;
@@ -0,0 +1,343 @@
+use regex::{Regex, RegexBuilder};
+use std::sync::OnceLock;
+
+use crate::domain::{redaction_warning, PiiKind, Redaction, RedactionStage, Transcription};
+
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+const RESIDENT_REGISTRATION_PATTERN: &str = r"\d{6}[ -]?[1-8]\d{6}";
+/// Amex-style 4-6-5 groups, four groups of four, or an unbroken run.
+const CARD_PATTERN: &str = r"\d{4}[ -]\d{6}[ -]\d{5}|\d{4}(?:[ -]\d{4}){3}|\d{13,19}";
+const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}";
+/// International numbers, North American numbers and Korean numbers with a
+/// leading 0, in that order.
+const PHONE_PATTERN: &str = concat!(
+    r"\+\d{1,3}(?:[ .-]?\(?\d{1,4}\)?){2,5}",
+    r"|(?:1[ .-])?\(?[2-9]\d{2}\)?[ .-]?[2-9]\d{2}[ .-]?\d{4}",
+    r"|0\d{1,2}[ .-]?\d{3,4}[ .-]?\d{4}",
+);
+
+const PHONE_DIGITS: std::ops::RangeInclusive<usize> = 9..=15;
+const CARD_DIGITS: std::ops::RangeInclusive<usize> = 13..=19;
+
+#[derive(Clone, Copy)]
+struct Span {
+    start: usize,
+    end: usize,
+    kind: PiiKind,
+}
+
+fn builtin_regex(kind: PiiKind) -> Option<&'static Regex> {
+    static RESIDENT_REGISTRATION: OnceLock<Regex> = OnceLock::new();
+    static CARD: OnceLock<Regex> = OnceLock::new();
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    static PHONE: OnceLock<Regex> = OnceLock::new();
+
+    let (cell, pattern) = match kind {
+        PiiKind::ResidentRegistrationNumber => {
+            (&RESIDENT_REGISTRATION, RESIDENT_REGISTRATION_PATTERN)
+        }
+        PiiKind::CardNumber => (&CARD, CARD_PATTERN),
+        PiiKind::Email => (&EMAIL, EMAIL_PATTERN),
+        PiiKind::PhoneNumber => (&PHONE, PHONE_PATTERN),
+        PiiKind::Custom => return None,
+    };
+    Some(cell.get_or_init(|| Regex::new(pattern).expect("built-in redaction pattern")))
+}
+
+fn digits(value: &str) -> Vec<u32> {
+    value.chars().filter_map(|ch| ch.to_digit(10)).collect()
+}
+
+/// Numbers glued to more digits or letters are part of something else, such
+/// as an order ID; Hangul particles after them are fine ("010-1234-5678로").
+fn is_standalone(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '+')
+        && !after.is_some_and(|ch| ch.is_ascii_alphanumeric())
+}
+
+fn passes_luhn(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                *digit
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// The checksum digit stopped being meaningful for numbers issued after
+/// October 2020, so only the birth date and the gender digit are checked.
+fn is_resident_registration_number(digits: &[u32]) -> bool {
+    let month = digits[2] * 10 + digits[3];
+    let day = digits[4] * 10 + digits[5];
+    (1..=12).contains(&month) && (1..=31).contains(&day)
+}
+
+fn validate(kind: PiiKind, value: &str) -> bool {
+    let digits = digits(value);
+    match kind {
+        PiiKind::ResidentRegistrationNumber => is_resident_registration_number(&digits),
+        PiiKind::CardNumber => CARD_DIGITS.contains(&digits.len()) && passes_luhn(&digits),
+        PiiKind::PhoneNumber => PHONE_DIGITS.contains(&digits.len()),
+        PiiKind::Email | PiiKind::Custom => true,
+    }
+}
+
+fn overlaps(spans: &[Span], start: usize, end: usize) -> bool {
+    spans
+        .iter()
+        .any(|span| start < span.end && span.start < end)
+}
+
+fn collect_spans(text: &str, regex: &Regex, kind: PiiKind, spans: &mut Vec<Span>) {
+    for found in regex.find_iter(text) {
+        let (start, end) = (found.start(), found.end());
+        let numeric = !matches!(kind, PiiKind::Email | PiiKind::Custom);
+        if found.is_empty()
+            || overlaps(spans, start, end)
+            || (numeric && !is_standalone(text, start, end))
+            || !validate(kind, found.as_str())
+        {
+            continue;
+        }
+        spans.push(Span { start, end, kind });
+    }
+}
+
+/// Replaces personal data in `text` with numbered placeholders such as
+/// `[PHONE_1]`. Built-in detectors run in the order given, then each custom
+/// pattern; a value repeated in the text reuses its placeholder.
+pub fn redact_pii(
+    text: &str,
+    kinds: &[PiiKind],
+    custom_patterns: &[String],
+) -> (String, Vec<Redaction>) {
+    let mut spans = Vec::new();
+    for kind in kinds {
+        if let Some(regex) = builtin_regex(*kind) {
+            collect_spans(text, regex, *kind, &mut spans);
+        }
+    }
+    for pattern in custom_patterns.iter().filter(|p| !p.trim().is_empty()) {
+        match RegexBuilder::new(pattern)
+            .size_limit(REGEX_SIZE_LIMIT)
+            .build()
+        {
+            Ok(regex) => collect_spans(text, &regex, PiiKind::Custom, &mut spans),
+            Err(err) => eprintln!("[redaction] Skipping invalid pattern {pattern:?}: {err}"),
+        }
+    }
+    spans.sort_by_key(|span| span.start);
+
+    let mut output = String::with_capacity(text.len());
+    let mut redactions: Vec<Redaction> = Vec::new();
+    let mut cursor = 0;
+    for span in spans {
+        let original = &text[span.start..span.end];
+        let placeholder = match redactions
+            .iter()
+            .find(|redaction| redaction.kind == span.kind && redaction.original == original)
+        {
+            Some(existing) => existing.placeholder.clone(),
+            None => {
+                let number = redactions
+                    .iter()
+                    .filter(|redaction| redaction.kind == span.kind)
+                    .count()
+                    + 1;
+                let placeholder = format!("[{}_{number}]", span.kind.placeholder_label());
+                redactions.push(Redaction {
+                    kind: span.kind,
+                    placeholder: placeholder.clone(),
+                    original: original.to_string(),
+                });
+                placeholder
+            }
+        };
+        output.push_str(&text[cursor..span.start]);
+        output.push_str(&placeholder);
+        cursor = span.end;
+    }
+    output.push_str(&text[cursor..]);
+
+    (output, redactions)
+}
+
+/// Masks personal data in every text a transcription stores. The original
+/// values are dropped; only the counts summary is added to `warnings`.
+pub fn redact_transcription(
+    transcription: &mut Transcription,
+    kinds: &[PiiKind],
+    custom_patterns: &[String],
+) {
+    let mut found: Vec<Redaction> = Vec::new();
+    let mut redact = |text: &mut String| {
+        let (redacted, redactions) = redact_pii(text, kinds, custom_patterns);
+        *text = redacted;
+        for redaction in redactions {
+            let seen = found.iter().any(|existing| {
+                existing.kind == redaction.kind && existing.original == redaction.original
+            });
+            if !seen {
+                found.push(redaction);
+            }
+        }
+    };
+
+    redact(&mut transcription.transcript);
+    for text in [
+        transcription.raw_transcript.as_mut(),
+        transcription.sanitized_transcript.as_mut(),
+        transcription.transcription_prompt.as_mut(),
+        transcription.post_process_prompt.as_mut(),
+        transcription.translated_transcript.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        redact(text);
+    }
+    for replacement in transcription.applied_replacements.iter_mut().flatten() {
+        redact(&mut replacement.original);
+        redact(&mut replacement.replacement);
+    }
+    let mut warnings = transcription.warnings.take().unwrap_or_default();
+    for warning in &mut warnings {
+        redact(warning);
+    }
+
+    if let Some(summary) = redaction_warning(RedactionStage::Storage, &found) {
+        if !warnings.contains(&summary) {
+            warnings.push(summary);
+        }
+    }
+    transcription.warnings = (!warnings.is_empty()).then_some(warnings);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::DEFAULT_PII_KINDS;
+
+    fn redact(text: &str) -> String {
+        redact_pii(text, DEFAULT_PII_KINDS, &[]).0
+    }
+
+    #[test]
+    fn redacts_contact_details() {
+        assert_eq!(
+            redact("Call me at 010-1234-5678 or mail jane.doe@example.co.kr"),
+            "Call me at [PHONE_1] or mail [EMAIL_1]"
+        );
+        assert_eq!(
+            redact("제 번호는 01012345678이고 사무실은 02-345-6789입니다"),
+            "제 번호는 [PHONE_1]이고 사무실은 [PHONE_2]입니다"
+        );
+        assert_eq!(
+            redact("Reach me on +44 20 7946 0958 or (415) 555-0132"),
+            "Reach me on [PHONE_1] or [PHONE_2]"
+        );
+        assert_eq!(
+            redact("Order 1234 shipped in 2024, tracking AB01012345678"),
+            "Order 1234 shipped in 2024, tracking AB01012345678"
+        );
+    }
+
+    #[test]
+    fn checks_card_and_registration_numbers() {
+        assert_eq!(
+            redact("Card 4111 1111 1111 1111 expires soon"),
+            "Card [CARD_1] expires soon"
+        );
+        assert_eq!(
+            redact("Card 4111 1111 1111 1112 expires soon"),
+            "Card 4111 1111 1111 1112 expires soon"
+        );
+        assert_eq!(
+            redact("주민번호는 900101-1234567 입니다"),
+            "주민번호는 [RRN_1] 입니다"
+        );
+        assert_eq!(redact("코드 901301-1234567"), "코드 901301-1234567");
+    }
+
+    #[test]
+    fn reuses_placeholders_and_honours_detector_choice() {
+        let (text, redactions) = redact_pii(
+            "a@b.io, then a@b.io and 010-1234-5678",
+            &[PiiKind::Email],
+            &["ticket-\\d+".to_string(), "(".to_string()],
+        );
+        assert_eq!(text, "[EMAIL_1], then [EMAIL_1] and 010-1234-5678");
+        assert_eq!(redactions.len(), 1);
+        assert_eq!(redactions[0].original, "a@b.io");
+
+        let (text, _) = redact_pii("see ticket-42", &[], &["ticket-\\d+".to_string()]);
+        assert_eq!(text, "see [REDACTED_1]");
+    }
+
+    #[test]
+    fn summarises_redactions_for_warnings() {
+        let (_, redactions) = redact_pii(
+            "010-1234-5678, 010-9876-5432, jane@example.com",
+            DEFAULT_PII_KINDS,
+            &[],
+        );
+        assert_eq!(
+            redaction_warning(RedactionStage::PostProcessing, &redactions).as_deref(),
+            Some("Redacted 2 phone numbers and 1 email address before post-processing")
+        );
+        assert_eq!(redaction_warning(RedactionStage::Storage, &[]), None);
+    }
+
+    #[test]
+    fn stored_transcriptions_keep_only_the_summary() {
+        let mut transcription: Transcription = serde_json::from_value(serde_json::json!({
+            "id": "t1",
+            "transcript": "Mail jane@example.com or call 010-1234-5678.",
+            "timestamp": 0,
+            "rawTranscript": "mail jane@example.com or call 010 1234 5678",
+            "postProcessPrompt": "Clean up: mail [EMAIL_1] or call [PHONE_1]",
+            "translatedTranscript": "Mail jane@example.com",
+            "warnings": ["Redacted 1 email address and 1 phone number before post-processing"],
+            "appliedReplacements": [{
+                "termId": "term",
+                "original": "jane at example dot com",
+                "replacement": "jane@example.com",
+                "matchMode": "exact",
+            }],
+        }))
+        .unwrap();
+
+        redact_transcription(&mut transcription, DEFAULT_PII_KINDS, &[]);
+
+        let stored = serde_json::to_string(&transcription).unwrap();
+        for raw in ["jane@example.com", "010-1234-5678", "010 1234 5678"] {
+            assert!(!stored.contains(raw), "{raw} was stored: {stored}");
+        }
+        assert_eq!(
+            transcription.transcript,
+            "Mail [EMAIL_1] or call [PHONE_1]."
+        );
+        assert_eq!(
+            transcription.warnings.unwrap(),
+            vec![
+                "Redacted 1 email address and 1 phone number before post-processing",
+                "Redacted 1 email address and 2 phone numbers before saving",
+            ]
+        );
+    }
+}
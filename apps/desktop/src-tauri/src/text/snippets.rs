@@ -0,0 +1,92 @@
+use crate::domain::Snippet;
+
+/// Spoken forms that introduce a trigger: "insert snippet signature".
+const SNIPPET_PREFIXES: &[&str] = &["insert snippet", "paste snippet", "snippet"];
+
+/// Korean puts the command after the trigger: "서명 스니펫 삽입".
+const SNIPPET_SUFFIXES: &[&str] = &["스니펫 삽입", "스니펫 넣어줘", "스니펫"];
+
+/// Lower-cased letters and digits only, so punctuation, spacing and case
+/// added by the transcriber do not matter.
+fn key(text: &str) -> String {
+    text.chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Finds the snippet a whole dictation asks for, either its exact trigger or
+/// the trigger wrapped in a snippet command. Snippets are never expanded from
+/// the middle of a sentence.
+pub fn match_snippet<'a>(transcript: &str, snippets: &'a [Snippet]) -> Option<&'a Snippet> {
+    let spoken = key(transcript);
+    if spoken.is_empty() {
+        return None;
+    }
+
+    let mut candidates = vec![spoken.as_str()];
+    for prefix in SNIPPET_PREFIXES {
+        if let Some(rest) = spoken.strip_prefix(&key(prefix)) {
+            candidates.push(rest);
+        }
+    }
+    for suffix in SNIPPET_SUFFIXES {
+        if let Some(rest) = spoken.strip_suffix(&key(suffix)) {
+            candidates.push(rest);
+        }
+    }
+
+    candidates
+        .into_iter()
+        .filter(|candidate| !candidate.is_empty())
+        .find_map(|candidate| {
+            snippets
+                .iter()
+                .find(|snippet| key(&snippet.trigger) == candidate)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippet(trigger: &str) -> Snippet {
+        Snippet {
+            id: trigger.to_string(),
+            created_at: 0,
+            created_by_user_id: "user".to_string(),
+            trigger: trigger.to_string(),
+            expansion: format!("<{trigger}>"),
+        }
+    }
+
+    #[test]
+    fn matches_exact_triggers_and_commands() {
+        let snippets = vec![
+            snippet("signature"),
+            snippet("home address"),
+            snippet("서명"),
+        ];
+        let trigger = |text: &str| match_snippet(text, &snippets).map(|s| s.trigger.as_str());
+
+        assert_eq!(trigger("Signature."), Some("signature"));
+        assert_eq!(
+            trigger("Insert snippet, home address."),
+            Some("home address")
+        );
+        assert_eq!(trigger("snippet signature"), Some("signature"));
+        assert_eq!(trigger("서명 스니펫 삽입"), Some("서명"));
+    }
+
+    #[test]
+    fn ignores_triggers_inside_sentences() {
+        let snippets = vec![snippet("signature")];
+
+        assert_eq!(
+            match_snippet("Please add my signature below", &snippets),
+            None
+        );
+        assert_eq!(match_snippet("Insert snippet", &snippets), None);
+        assert_eq!(match_snippet("", &snippets), None);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::domain::{RecentDictation, Term};
+use crate::text::dictionary::contains_phrase;
+
+/// Whisper conditions on at most `n_text_ctx / 2` prompt tokens and silently
+/// drops the oldest ones beyond that.
+pub const WHISPER_PROMPT_TOKEN_BUDGET: usize = 224;
+
+/// Dictations into the app being dictated into weigh this much more than
+/// dictations elsewhere when ranking terms.
+const SAME_APP_WEIGHT: f32 = 3.0;
+const GLOSSARY_PREFIX: &str = "Glossary: ";
+/// Always leads the glossary so the app's own name is spelled right.
+const BUILT_IN_TERMS: &[&str] = &["Vocally"];
+
+pub struct VocabularyPromptInput<'a> {
+    /// Caller text kept at the start of the prompt, such as a script hint.
+    pub preamble: Option<&'a str>,
+    pub terms: &'a [Term],
+    /// Recent dictations, newest first.
+    pub recent: &'a [RecentDictation],
+    pub app_target_id: Option<&'a str>,
+    /// Text already in the focused field; only its tail is used.
+    pub app_context: Option<&'a str>,
+    pub token_budget: usize,
+}
+
+/// Builds the Whisper prompt: the preamble, then as much of the focused
+/// field's recent text as fits, then a glossary of the highest ranked terms.
+/// The glossary is sized first so app text never crowds out vocabulary.
+pub fn build_vocabulary_prompt(input: &VocabularyPromptInput) -> Option<String> {
+    let mut remaining = input.token_budget;
+
+    let preamble = input
+        .preamble
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .filter(|value| estimate_tokens(value) <= remaining);
+    if let Some(preamble) = preamble {
+        remaining -= estimate_tokens(preamble);
+    }
+
+    let mut glossary: Vec<String> = Vec::new();
+    let mut glossary_tokens = estimate_tokens(GLOSSARY_PREFIX);
+    for term in rank_terms(input.terms, input.recent, input.app_target_id) {
+        let cost = estimate_tokens(&term) + 1;
+        if glossary_tokens + cost > remaining {
+            continue;
+        }
+        glossary_tokens += cost;
+        glossary.push(term);
+    }
+    if !glossary.is_empty() {
+        remaining -= glossary_tokens;
+    }
+
+    let context = input
+        .app_context
+        .and_then(|text| tail_within_budget(text, remaining));
+
+    let mut sections: Vec<String> = Vec::new();
+    sections.extend(preamble.map(str::to_string));
+    sections.extend(context);
+    if !glossary.is_empty() {
+        sections.push(format!("{GLOSSARY_PREFIX}{}.", glossary.join(", ")));
+    }
+
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+/// Vocabulary (non-replacement) terms, most relevant first: terms that came up
+/// in recent dictations rank by how often and how recently, with extra weight
+/// for the current app; the rest keep their stored (newest first) order.
+/// Built-in terms always come first.
+fn rank_terms(
+    terms: &[Term],
+    recent: &[RecentDictation],
+    app_target_id: Option<&str>,
+) -> Vec<String> {
+    let mut seen: Vec<String> = BUILT_IN_TERMS
+        .iter()
+        .map(|value| value.to_lowercase())
+        .collect();
+
+    let mut scored: Vec<(f32, String)> = Vec::new();
+    for term in terms
+        .iter()
+        .filter(|term| !term.is_replacement && !term.is_deleted)
+    {
+        let value = sanitize_term(&term.source_value);
+        let key = value.to_lowercase();
+        if value.is_empty() || seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+
+        let score = recent
+            .iter()
+            .enumerate()
+            .filter(|(_, dictation)| contains_phrase(&dictation.transcript, &value))
+            .map(|(age, dictation)| {
+                let recency = 1.0 / (1.0 + age as f32 * 0.1);
+                let same_app =
+                    app_target_id.is_some() && dictation.app_target_id.as_deref() == app_target_id;
+                if same_app {
+                    recency * SAME_APP_WEIGHT
+                } else {
+                    recency
+                }
+            })
+            .sum();
+        scored.push((score, value));
+    }
+
+    scored.sort_by(|left, right| right.0.total_cmp(&left.0));
+    BUILT_IN_TERMS
+        .iter()
+        .map(|value| value.to_string())
+        .chain(scored.into_iter().map(|(_, value)| value))
+        .collect()
+}
+
+/// Drops NUL bytes and collapses whitespace so a stored term cannot break
+/// the prompt's layout.
+fn sanitize_term(value: &str) -> String {
+    let without_nul: String = value.chars().filter(|ch| *ch != '\0').collect();
+    without_nul.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Rough Whisper token count. Latin words average about four characters per
+/// token; other scripts are counted at two tokens per character so Hangul
+/// and CJK text stays under budget.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.split_whitespace()
+        .map(|word| {
+            let ascii = word.chars().filter(char::is_ascii).count();
+            let other = word.chars().count() - ascii;
+            ascii.div_ceil(4) + other * 2
+        })
+        .sum()
+}
+
+/// The longest run of whole words from the end of `text` that fits `budget`.
+fn tail_within_budget(text: &str, budget: usize) -> Option<String> {
+    let mut used = 0;
+    let mut words: Vec<&str> = Vec::new();
+    for word in text.split_whitespace().rev() {
+        let cost = estimate_tokens(word);
+        if used + cost > budget {
+            break;
+        }
+        used += cost;
+        words.push(word);
+    }
+
+    if words.is_empty() {
+        return None;
+    }
+    words.reverse();
+    Some(words.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TermMatchMode;
+
+    fn vocabulary(id: &str, value: &str) -> Term {
+        Term {
+            id: id.to_string(),
+            created_at: 0,
+            created_by_user_id: "local-user-id".to_string(),
+            source_value: value.to_string(),
+            destination_value: value.to_string(),
+            is_replacement: false,
+            is_deleted: false,
+            match_mode: TermMatchMode::Exact,
+        }
+    }
+
+    fn dictation(transcript: &str, app_target_id: Option<&str>) -> RecentDictation {
+        RecentDictation {
+            transcript: transcript.to_string(),
+            app_target_id: app_target_id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn ranks_terms_by_recent_use_in_the_current_app() {
+        let terms = [
+            vocabulary("1", "Kubernetes"),
+            vocabulary("2", "Figma"),
+            vocabulary("3", "Supabase"),
+        ];
+        let recent = [
+            dictation("deploy the Kubernetes cluster", Some("terminal")),
+            dictation("share the figma file", Some("slack")),
+            dictation("update figma frames", Some("slack")),
+        ];
+
+        let prompt = build_vocabulary_prompt(&VocabularyPromptInput {
+            preamble: None,
+            terms: &terms,
+            recent: &recent,
+            app_target_id: Some("slack"),
+            app_context: None,
+            token_budget: WHISPER_PROMPT_TOKEN_BUDGET,
+        });
+
+        assert_eq!(
+            prompt.as_deref(),
+            Some("Glossary: Vocally, Figma, Kubernetes, Supabase.")
+        );
+    }
+
+    #[test]
+    fn ranks_only_whole_word_uses_and_sanitizes_terms() {
+        let terms = [vocabulary("1", "Ant"), vocabulary("2", " Supa\0base \n")];
+        let recent = [
+            dictation("an important antenna", None),
+            dictation("ship supabase today", None),
+        ];
+
+        let prompt = build_vocabulary_prompt(&VocabularyPromptInput {
+            preamble: None,
+            terms: &terms,
+            recent: &recent,
+            app_target_id: None,
+            app_context: None,
+            token_budget: WHISPER_PROMPT_TOKEN_BUDGET,
+        });
+
+        assert_eq!(prompt.as_deref(), Some("Glossary: Vocally, Supabase, Ant."));
+    }
+
+    #[test]
+    fn glossary_wins_over_app_context_within_budget() {
+        let terms = [vocabulary("1", "Vocally"), vocabulary("2", "보컬리")];
+
+        let prompt = build_vocabulary_prompt(&VocabularyPromptInput {
+            preamble: Some("以下是普通话的句子。"),
+            terms: &terms,
+            recent: &[],
+            app_target_id: None,
+            app_context: Some("earlier paragraph text. latest note"),
+            token_budget: 40,
+        })
+        .unwrap();
+
+        assert_eq!(
+            prompt,
+            "以下是普通话的句子。\n\ntext. latest note\n\nGlossary: Vocally, 보컬리."
+        );
+        assert!(estimate_tokens(&prompt) <= 40);
+    }
+
+    #[test]
+    fn keeps_only_built_in_terms_without_vocabulary() {
+        let mut replacement = vocabulary("1", "gonna");
+        replacement.is_replacement = true;
+
+        assert_eq!(
+            build_vocabulary_prompt(&VocabularyPromptInput {
+                preamble: Some("  "),
+                terms: &[replacement],
+                recent: &[],
+                app_target_id: None,
+                app_context: None,
+                token_budget: WHISPER_PROMPT_TOKEN_BUDGET,
+            }),
+            Some("Glossary: Vocally.".to_string())
+        );
+    }
+}
@@ -0,0 +1,458 @@
+use crate::domain::{ExecutedVoiceCommand, VoiceCommand, VoiceCommandAction};
+
+const SENTENCE_ENDINGS: [char; 3] = ['.', '?', '!'];
+/// Punctuation the model writes where the speaker paused.
+const PAUSE_MARKS: &[char] = &[',', '.', '?', '!', ';', ':', '…', '。', '、', '？', '！'];
+/// Words that introduce a command anywhere in a sentence ("command new line").
+const COMMAND_PREFIXES: &[&str] = &["command", "명령"];
+
+struct Token<'a> {
+    text: &'a str,
+    key: String,
+}
+
+/// Commands ready to match: each phrase reduced to lowercase letters with the
+/// spacing removed, so "줄바꿈" still matches "줄 바꿈".
+struct Grammar<'a> {
+    commands: Vec<(&'a VoiceCommand, String, usize)>,
+    max_words: usize,
+}
+
+impl<'a> Grammar<'a> {
+    fn new(commands: &'a [VoiceCommand]) -> Self {
+        let commands: Vec<(&VoiceCommand, String, usize)> = commands
+            .iter()
+            .filter(|command| command.is_enabled)
+            .filter(|command| {
+                command.action != VoiceCommandAction::Insert || command.value.is_some()
+            })
+            .map(|command| {
+                let key = command
+                    .phrase
+                    .split_whitespace()
+                    .map(normalize)
+                    .collect::<String>();
+                (command, key, command.phrase.split_whitespace().count())
+            })
+            .filter(|(_, key, _)| !key.is_empty())
+            .collect();
+        let max_words = commands
+            .iter()
+            .map(|(_, _, words)| *words)
+            .max()
+            .unwrap_or(0);
+        Self {
+            commands,
+            max_words,
+        }
+    }
+
+    /// The longest command starting at `tokens[0]` and how many tokens it spans.
+    fn match_at(&self, tokens: &[Token]) -> Option<(&'a VoiceCommand, usize)> {
+        for span in (1..=self.max_words.min(tokens.len())).rev() {
+            let spoken: String = tokens[..span]
+                .iter()
+                .map(|token| token.key.as_str())
+                .collect();
+            if let Some((command, _, _)) = self.commands.iter().find(|(_, key, _)| *key == spoken) {
+                return Some((command, span));
+            }
+        }
+        None
+    }
+}
+
+fn normalize(word: &str) -> String {
+    word.trim_matches(|ch: char| !ch.is_alphanumeric())
+        .to_lowercase()
+}
+
+#[derive(Default)]
+struct Output {
+    text: String,
+    all_caps: bool,
+    capitalize_next: bool,
+}
+
+impl Output {
+    fn push_word(&mut self, word: &str) {
+        if !self.text.is_empty() && !self.text.ends_with(char::is_whitespace) {
+            self.text.push(' ');
+        }
+        if self.all_caps {
+            self.text.push_str(&word.to_uppercase());
+        } else if self.capitalize_next {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                self.text.extend(first.to_uppercase());
+                self.text.push_str(chars.as_str());
+            }
+        } else {
+            self.text.push_str(word);
+        }
+        self.capitalize_next = false;
+    }
+
+    fn insert(&mut self, value: &str) {
+        self.trim_trailing_spaces();
+        // Spoken punctuation replaces the mark the model wrote at the pause.
+        if value.starts_with(PAUSE_MARKS) {
+            let trimmed = self.text.trim_end_matches(PAUSE_MARKS).len();
+            self.text.truncate(trimmed);
+        }
+        self.text.push_str(value);
+        self.capitalize_next = value.ends_with('\n') || value.ends_with(SENTENCE_ENDINGS);
+    }
+
+    /// Removes the last line break, or else the last sentence.
+    fn delete_last(&mut self) {
+        self.trim_trailing_spaces();
+        if self.text.ends_with('\n') {
+            self.text.pop();
+        } else {
+            let body = self
+                .text
+                .trim_end_matches(|ch: char| ch.is_ascii_punctuation() || ch == ' ');
+            let start = body
+                .rfind(|ch: char| ch == '\n' || SENTENCE_ENDINGS.contains(&ch))
+                .map(|index| index + 1)
+                .unwrap_or(0);
+            self.text.truncate(start);
+            self.trim_trailing_spaces();
+        }
+        self.capitalize_next = self.text.is_empty()
+            || self.text.ends_with('\n')
+            || self.text.ends_with(SENTENCE_ENDINGS);
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let trimmed = self.text.trim_end_matches([' ', '\t']).len();
+        self.text.truncate(trimmed);
+    }
+}
+
+fn ends_at_pause(token: &Token) -> bool {
+    token.text.ends_with(PAUSE_MARKS)
+}
+
+fn is_prefix(token: &Token) -> bool {
+    COMMAND_PREFIXES.contains(&token.key.as_str())
+}
+
+/// The command starting at `tokens[index]`, with the number of tokens it
+/// spans including any prefix. A command runs only after a prefix word or
+/// when it stands alone between pauses, so the same words inside a sentence
+/// ("the trial period ends") stay text.
+fn command_at<'a>(
+    grammar: &Grammar<'a>,
+    tokens: &[Token],
+    index: usize,
+) -> Option<(&'a VoiceCommand, usize)> {
+    if is_prefix(&tokens[index]) {
+        if let Some((command, span)) = grammar.match_at(&tokens[index + 1..]) {
+            return Some((command, span + 1));
+        }
+    }
+
+    let starts_at_pause = index == 0 || ends_at_pause(&tokens[index - 1]);
+    if !starts_at_pause {
+        return None;
+    }
+    // An escape phrase is followed by the command it escapes, not a pause.
+    grammar
+        .match_at(&tokens[index..])
+        .filter(|(command, span)| {
+            command.action == VoiceCommandAction::Escape
+                || index + span == tokens.len()
+                || ends_at_pause(&tokens[index + span - 1])
+        })
+}
+
+/// Runs the spoken commands in `text` against `commands` and returns the
+/// remaining dictation with the commands applied, plus the commands that ran.
+/// A command must follow a prefix word or stand alone between pauses. An
+/// escape phrase followed by a command types that command literally; on its
+/// own it is ordinary text.
+pub fn apply_voice_commands(
+    text: &str,
+    commands: &[VoiceCommand],
+) -> (String, Vec<ExecutedVoiceCommand>) {
+    let grammar = Grammar::new(commands);
+    if grammar.commands.is_empty() {
+        return (text.to_string(), Vec::new());
+    }
+
+    let tokens: Vec<Token> = text
+        .split_whitespace()
+        .map(|word| Token {
+            text: word,
+            key: normalize(word),
+        })
+        .collect();
+
+    let mut output = Output::default();
+    let mut executed = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        let Some((command, span)) = command_at(&grammar, &tokens, index) else {
+            output.push_word(tokens[index].text);
+            index += 1;
+            continue;
+        };
+
+        if command.action == VoiceCommandAction::Escape {
+            if let Some((_, literal_span)) = grammar.match_at(&tokens[index + span..]) {
+                let literal = &tokens[index + span..index + span + literal_span];
+                for token in literal {
+                    output.push_word(token.text);
+                }
+                index += span + literal_span;
+                continue;
+            }
+            for token in &tokens[index..index + span] {
+                output.push_word(token.text);
+            }
+            index += span;
+            continue;
+        }
+
+        output.all_caps = false;
+        match command.action {
+            VoiceCommandAction::Insert => output.insert(command.value.as_deref().unwrap_or("")),
+            VoiceCommandAction::DeleteLast => output.delete_last(),
+            VoiceCommandAction::AllCaps => output.all_caps = true,
+            VoiceCommandAction::Escape => {}
+        }
+        executed.push(ExecutedVoiceCommand {
+            phrase: command.phrase.clone(),
+            action: command.action,
+        });
+        index += span;
+    }
+
+    output.trim_trailing_spaces();
+    (output.text, executed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::default_voice_commands;
+
+    fn run(text: &str) -> String {
+        apply_voice_commands(text, &default_voice_commands()).0
+    }
+
+    #[test]
+    fn inserts_line_breaks() {
+        assert_eq!(run("Dear team. New line. Thanks"), "Dear team.\nThanks");
+        assert_eq!(
+            run("First point. New paragraph. second point"),
+            "First point.\n\nSecond point"
+        );
+        assert_eq!(run("첫 줄 명령 줄바꿈 둘째 줄"), "첫 줄\n둘째 줄");
+        assert_eq!(run("인사. 새 문단. 본문"), "인사.\n\n본문");
+    }
+
+    #[test]
+    fn inserts_punctuation() {
+        assert_eq!(
+            run("hi command comma are you there command question mark yes command full stop"),
+            "hi, are you there? Yes."
+        );
+        assert_eq!(
+            run("Wait, exclamation point. note command semicolon pears"),
+            "Wait! Note; pears"
+        );
+        assert_eq!(
+            run("안녕하세요 명령 쉼표 잘 지내요 명령 물음표 네 명령 마침표 좋아요 명령 느낌표"),
+            "안녕하세요, 잘 지내요? 네. 좋아요!"
+        );
+    }
+
+    #[test]
+    fn leaves_command_words_inside_sentences() {
+        assert_eq!(
+            run("the trial period ends with a full stop comma or colon"),
+            "the trial period ends with a full stop comma or colon"
+        );
+        assert_eq!(
+            run("I made a new line item. Delete that file later."),
+            "I made a new line item. Delete that file later."
+        );
+    }
+
+    #[test]
+    fn deletes_the_last_sentence() {
+        assert_eq!(
+            run("Ship it today. Actually wait. Delete that. Ship it friday."),
+            "Ship it today. Ship it friday."
+        );
+        assert_eq!(
+            run("line one command new line command scratch that more"),
+            "line one more"
+        );
+        assert_eq!(
+            run("회의는 내일. 방금 거 지워. 회의는 금요일"),
+            "회의는 금요일"
+        );
+    }
+
+    #[test]
+    fn upper_cases_until_the_next_command() {
+        assert_eq!(
+            run("this is command all caps very important command full stop ok"),
+            "this is VERY IMPORTANT. Ok"
+        );
+        assert_eq!(run("모두 대문자. api 키"), "API 키");
+    }
+
+    #[test]
+    fn escape_phrases_type_commands_literally() {
+        assert_eq!(
+            run("type command literal new line here"),
+            "type new line here"
+        );
+        assert_eq!(run("그대로 쉼표 라고 쓰세요"), "쉼표 라고 쓰세요");
+        assert_eq!(run("a literal translation"), "a literal translation");
+    }
+
+    /// Every built-in phrase, spoken after its language's prefix word and on
+    /// its own between pauses.
+    #[test]
+    fn runs_every_default_command() {
+        let commands = default_voice_commands();
+
+        for command in &commands {
+            let phrase = command.phrase.as_str();
+            let prefix = if command.language == "ko" {
+                "명령"
+            } else {
+                "command"
+            };
+            let cases: [(String, String, &str); 2] = match command.action {
+                VoiceCommandAction::Insert => {
+                    let value = command.value.as_deref().unwrap();
+                    let next = if value.ends_with('\n') {
+                        "Beta"
+                    } else if value.ends_with(SENTENCE_ENDINGS) {
+                        " Beta"
+                    } else {
+                        " beta"
+                    };
+                    // Spoken punctuation replaces the mark at the pause; a line
+                    // break keeps it.
+                    let paused = if value.starts_with(PAUSE_MARKS) {
+                        ""
+                    } else {
+                        "."
+                    };
+                    [
+                        (
+                            format!("Alpha {prefix} {phrase} beta"),
+                            format!("Alpha{value}{next}"),
+                            phrase,
+                        ),
+                        (
+                            format!("Alpha. {phrase}. beta"),
+                            format!("Alpha{paused}{value}{next}"),
+                            phrase,
+                        ),
+                    ]
+                }
+                VoiceCommandAction::DeleteLast => [
+                    (
+                        format!("Keep this. Drop this. {prefix} {phrase} done"),
+                        "Keep this. Done".to_string(),
+                        phrase,
+                    ),
+                    (
+                        format!("Keep this. Drop this. {phrase}. done"),
+                        "Keep this. Done".to_string(),
+                        phrase,
+                    ),
+                ],
+                VoiceCommandAction::AllCaps => [
+                    (
+                        format!("note {prefix} {phrase} api key"),
+                        "note API KEY".to_string(),
+                        phrase,
+                    ),
+                    (
+                        format!("note. {phrase}. api key"),
+                        "note. API KEY".to_string(),
+                        phrase,
+                    ),
+                ],
+                VoiceCommandAction::Escape => {
+                    let escaped = &commands
+                        .iter()
+                        .find(|other| {
+                            other.language == command.language
+                                && other.action == VoiceCommandAction::Insert
+                        })
+                        .unwrap()
+                        .phrase;
+                    [
+                        (
+                            format!("type {prefix} {phrase} {escaped} here"),
+                            format!("type {escaped} here"),
+                            "",
+                        ),
+                        (
+                            format!("Type. {phrase} {escaped} here"),
+                            format!("Type. {escaped} here"),
+                            "",
+                        ),
+                    ]
+                }
+            };
+
+            for (input, expected, executed_phrase) in cases {
+                let (text, executed) = apply_voice_commands(&input, &commands);
+                assert_eq!(text, expected, "{input:?}");
+                let executed: Vec<&str> = executed
+                    .iter()
+                    .map(|executed| executed.phrase.as_str())
+                    .collect();
+                let expected_executed: Vec<&str> = if executed_phrase.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![executed_phrase]
+                };
+                assert_eq!(executed, expected_executed, "{input:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn respects_disabled_and_custom_commands() {
+        let mut commands = default_voice_commands();
+        for command in commands
+            .iter_mut()
+            .filter(|command| command.phrase == "full stop")
+        {
+            command.is_enabled = false;
+        }
+        commands.push(VoiceCommand {
+            id: "custom".to_string(),
+            language: "en".to_string(),
+            phrase: "smiley".to_string(),
+            action: VoiceCommandAction::Insert,
+            value: Some(" :)".to_string()),
+            is_enabled: true,
+            is_default: false,
+        });
+
+        let (text, executed) =
+            apply_voice_commands("great command full stop command smiley", &commands);
+        assert_eq!(text, "great command full stop :)");
+        assert_eq!(
+            executed,
+            vec![ExecutedVoiceCommand {
+                phrase: "smiley".to_string(),
+                action: VoiceCommandAction::Insert,
+            }]
+        );
+    }
+}
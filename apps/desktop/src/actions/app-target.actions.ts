@@ -1,4 +1,4 @@
-import { AppTarget, Nullable } from "@repo/types";
+import { AppTarget, InjectionStrategy, Nullable } from "@repo/types";
 import { getRec } from "@repo/utilities";
 import { invoke } from "@tauri-apps/api/core";
 import { getAppTargetRepo, getStorageRepo } from "../repos";
@@ -46,6 +46,11 @@ export const setAppTargetTone = async (
       toneId,
       iconPath: existing.iconPath ?? null,
       pasteKeybind: existing.pasteKeybind ?? null,
+      inverseTextNormalization: existing.inverseTextNormalization ?? null,
+      codeModeEnabled: existing.codeModeEnabled ?? false,
+      injectionStrategy: existing.injectionStrategy ?? "clipboard",
+      typingDelayMs: existing.typingDelayMs ?? null,
+      richTextPaste: existing.richTextPaste ?? false,
     });
   } catch (error) {
     console.error("Failed to update app target tone", error);
@@ -74,6 +79,11 @@ export const setAppTargetPasteKeybind = async (
       toneId: existing.toneId ?? null,
       iconPath: existing.iconPath ?? null,
       pasteKeybind,
+      inverseTextNormalization: existing.inverseTextNormalization ?? null,
+      codeModeEnabled: existing.codeModeEnabled ?? false,
+      injectionStrategy: existing.injectionStrategy ?? "clipboard",
+      typingDelayMs: existing.typingDelayMs ?? null,
+      richTextPaste: existing.richTextPaste ?? false,
     });
   } catch (error) {
     console.error("Failed to update app target paste keybind", error);
@@ -85,6 +95,139 @@ export const setAppTargetPasteKeybind = async (
   }
 };
 
+export const setAppTargetInverseTextNormalization = async (
+  id: string,
+  enabled: boolean,
+): Promise<void> => {
+  const existing = getAppState().appTargetById[id];
+  if (!existing) {
+    showErrorSnackbar("App target is not registered.");
+    return;
+  }
+
+  try {
+    await upsertAppTarget({
+      id,
+      name: existing.name,
+      toneId: existing.toneId ?? null,
+      iconPath: existing.iconPath ?? null,
+      pasteKeybind: existing.pasteKeybind ?? null,
+      inverseTextNormalization: enabled,
+      codeModeEnabled: existing.codeModeEnabled ?? false,
+      injectionStrategy: existing.injectionStrategy ?? "clipboard",
+      typingDelayMs: existing.typingDelayMs ?? null,
+      richTextPaste: existing.richTextPaste ?? false,
+    });
+  } catch (error) {
+    console.error("Failed to update app target number formatting", error);
+    showErrorSnackbar(
+      error instanceof Error
+        ? error.message
+        : "Failed to update app target number formatting.",
+    );
+  }
+};
+
+export const setAppTargetCodeMode = async (
+  id: string,
+  enabled: boolean,
+): Promise<void> => {
+  const existing = getAppState().appTargetById[id];
+  if (!existing) {
+    showErrorSnackbar("App target is not registered.");
+    return;
+  }
+
+  try {
+    await upsertAppTarget({
+      id,
+      name: existing.name,
+      toneId: existing.toneId ?? null,
+      iconPath: existing.iconPath ?? null,
+      pasteKeybind: existing.pasteKeybind ?? null,
+      inverseTextNormalization: existing.inverseTextNormalization ?? null,
+      codeModeEnabled: enabled,
+      injectionStrategy: existing.injectionStrategy ?? "clipboard",
+      typingDelayMs: existing.typingDelayMs ?? null,
+      richTextPaste: existing.richTextPaste ?? false,
+    });
+  } catch (error) {
+    console.error("Failed to update app target code mode", error);
+    showErrorSnackbar(
+      error instanceof Error
+        ? error.message
+        : "Failed to update app target code mode.",
+    );
+  }
+};
+
+export const setAppTargetRichTextPaste = async (
+  id: string,
+  enabled: boolean,
+): Promise<void> => {
+  const existing = getAppState().appTargetById[id];
+  if (!existing) {
+    showErrorSnackbar("App target is not registered.");
+    return;
+  }
+
+  try {
+    await upsertAppTarget({
+      id,
+      name: existing.name,
+      toneId: existing.toneId ?? null,
+      iconPath: existing.iconPath ?? null,
+      pasteKeybind: existing.pasteKeybind ?? null,
+      inverseTextNormalization: existing.inverseTextNormalization ?? null,
+      codeModeEnabled: existing.codeModeEnabled ?? false,
+      injectionStrategy: existing.injectionStrategy ?? "clipboard",
+      typingDelayMs: existing.typingDelayMs ?? null,
+      richTextPaste: enabled,
+    });
+  } catch (error) {
+    console.error("Failed to update app target rich text paste", error);
+    showErrorSnackbar(
+      error instanceof Error
+        ? error.message
+        : "Failed to update app target rich text paste.",
+    );
+  }
+};
+
+export const setAppTargetInjection = async (
+  id: string,
+  injectionStrategy: InjectionStrategy,
+  typingDelayMs: number | null,
+): Promise<void> => {
+  const existing = getAppState().appTargetById[id];
+  if (!existing) {
+    showErrorSnackbar("App target is not registered.");
+    return;
+  }
+
+  try {
+    await upsertAppTarget({
+      id,
+      name: existing.name,
+      toneId: existing.toneId ?? null,
+      iconPath: existing.iconPath ?? null,
+      pasteKeybind: existing.pasteKeybind ?? null,
+      inverseTextNormalization: existing.inverseTextNormalization ?? null,
+      codeModeEnabled: existing.codeModeEnabled ?? false,
+      injectionStrategy,
+      typingDelayMs,
+      richTextPaste: existing.richTextPaste ?? false,
+    });
+  } catch (error) {
+    console.error("Failed to update app target text insertion", error);
+    showErrorSnackbar(
+      error instanceof Error
+        ? error.message
+        : "Failed to update app target text insertion.",
+    );
+  }
+};
+
 type CurrentAppInfoResponse = {
   appName: string;
   iconBase64: string;
@@ -121,6 +264,12 @@ export const tryRegisterCurrentAppTarget = async (): Promise<
         toneId: existingApp?.toneId ?? null,
         iconPath: iconPath ?? existingApp?.iconPath ?? null,
         pasteKeybind: existingApp?.pasteKeybind ?? null,
+        inverseTextNormalization:
+          existingApp?.inverseTextNormalization ?? null,
+        codeModeEnabled: existingApp?.codeModeEnabled ?? false,
+        injectionStrategy: existingApp?.injectionStrategy ?? "clipboard",
+        typingDelayMs: existingApp?.typingDelayMs ?? null,
+        richTextPaste: existingApp?.richTextPaste ?? false,
       };
       await upsertAppTarget(params);
     } catch (error) {
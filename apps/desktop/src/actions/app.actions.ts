@@ -1,5 +1,8 @@
-import { produceAppState } from "../store";
+import { invoke } from "@tauri-apps/api/core";
+import { getIntl } from "../i18n";
+import { getAppState, produceAppState } from "../store";
 import { setSnackbar, type ShowSnackbarOpts } from "../utils/app.utils";
+import { showToast } from "./toast.actions";
 
 export const showSnackbar = (
   message: string,
@@ -20,3 +23,44 @@ export const showConfetti = (): void => {
     state.confettiCounter += 1;
   });
 };
+
+export const toggleCodeMode = async (): Promise<void> => {
+  const enabled = !getAppState().codeModeActive;
+  produceAppState((state) => {
+    state.codeModeActive = enabled;
+  });
+
+  await showToast({
+    title: enabled
+      ? getIntl().formatMessage({ defaultMessage: "Code mode on" })
+      : getIntl().formatMessage({ defaultMessage: "Code mode off" }),
+    message: enabled
+      ? getIntl().formatMessage({
+          defaultMessage:
+            "Dictation is formatted as code in every app until you turn it off.",
+        })
+      : getIntl().formatMessage({
+          defaultMessage: "Dictation is formatted as regular text again.",
+        }),
+    duration: 3_000,
+  });
+};
+
+/**
+ * Removes the text the last dictation pasted, as long as its app still has
+ * focus. The desktop backend explains why when it refuses.
+ */
+export const undoLastPaste = async (): Promise<void> => {
+  try {
+    await invoke<void>("undo_last_paste");
+  } catch (error) {
+    await showToast({
+      title: getIntl().formatMessage({
+        defaultMessage: "Unable to undo dictation",
+      }),
+      message: String(error),
+      toastType: "error",
+      duration: 4_000,
+    });
+  }
+};
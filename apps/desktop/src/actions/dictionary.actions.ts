@@ -1,7 +1,9 @@
+import { Term, TermSuggestion } from "@repo/types";
 import dayjs from "dayjs";
 import { getTermRepo } from "../repos";
 import { produceAppState } from "../store";
 import { registerTerms } from "../utils/app.utils";
+import { createId } from "../utils/id.utils";
 
 export const loadDictionary = async (): Promise<void> => {
   const terms = await getTermRepo().listTerms();
@@ -15,3 +17,46 @@ export const loadDictionary = async (): Promise<void> => {
     draft.dictionary.termIds = activeTerms.map((term) => term.id);
   });
 };
+
+export const loadTermSuggestions = async (): Promise<void> => {
+  const suggestions = await getTermRepo().listTermSuggestions();
+  produceAppState((draft) => {
+    draft.dictionary.suggestions = suggestions;
+  });
+};
+
+const isSameSuggestion = (a: TermSuggestion, b: TermSuggestion): boolean =>
+  a.sourceValue === b.sourceValue && a.destinationValue === b.destinationValue;
+
+export const acceptTermSuggestion = async (
+  suggestion: TermSuggestion,
+): Promise<Term> => {
+  const term: Term = {
+    id: createId(),
+    createdAt: dayjs().toISOString(),
+    sourceValue: suggestion.sourceValue,
+    destinationValue: suggestion.destinationValue,
+    isReplacement: true,
+  };
+
+  const created = await getTermRepo().acceptTermSuggestion(suggestion, term);
+  produceAppState((draft) => {
+    registerTerms(draft, [created]);
+    draft.dictionary.termIds = [created.id, ...draft.dictionary.termIds];
+    draft.dictionary.suggestions = draft.dictionary.suggestions.filter(
+      (existing) => !isSameSuggestion(existing, suggestion),
+    );
+  });
+  return created;
+};
+
+export const rejectTermSuggestion = async (
+  suggestion: TermSuggestion,
+): Promise<void> => {
+  await getTermRepo().rejectTermSuggestion(suggestion);
+  produceAppState((draft) => {
+    draft.dictionary.suggestions = draft.dictionary.suggestions.filter(
+      (existing) => !isSameSuggestion(existing, suggestion),
+    );
+  });
+};
@@ -1,6 +1,10 @@
 import {
+  AppliedReplacement,
+  LanguageProbability,
   Nullable,
+  Redaction,
   Transcription,
+  TranslationMode,
   TranscriptionAudioSnapshot,
 } from "@repo/types";
 import { countWords, dedup, getRec } from "@repo/utilities";
@@ -8,6 +12,7 @@ import { invoke } from "@tauri-apps/api/core";
 import dayjs from "dayjs";
 import {
   getGenerateTextRepo,
+  getToneRepo,
   getTranscribeAudioRepo,
   getTranscriptionRepo,
 } from "../repos";
@@ -25,13 +30,20 @@ import { mapLocaleToWhisperLanguage } from "../utils/language.utils";
 import {
   buildLocalizedPostProcessingPrompt,
   buildLocalizedTranscriptionPrompt,
+  buildSelectionRewritePrompt,
   buildSystemPostProcessingTonePrompt,
+  buildSystemSelectionRewritePrompt,
   collectDictionaryEntries,
   PROCESSED_TRANSCRIPTION_JSON_SCHEMA,
   PROCESSED_TRANSCRIPTION_SCHEMA,
 } from "../utils/prompt.utils";
+import {
+  redactTexts,
+  restoreRedactions,
+} from "../utils/redaction.utils";
 import {
   getMyDictationLanguage,
+  getMyDictationLanguageCandidates,
   getMyEffectiveUserId,
 } from "../utils/user.utils";
 import { showErrorSnackbar } from "./app.actions";
@@ -40,6 +52,10 @@ import { addWordsToCurrentUser } from "./user.actions";
 export type TranscribeAudioInput = {
   samples: AudioSamples;
   sampleRate: number;
+  appTargetId?: Nullable<string>;
+  appContext?: Nullable<string>;
+  toneId?: Nullable<string>;
+  hotkeyAction?: Nullable<string>;
 };
 
 export type TranscribeAudioMetadata = {
@@ -49,6 +65,11 @@ export type TranscribeAudioMetadata = {
   transcriptionApiKeyId?: string | null;
   transcriptionMode?: TranscriptionMode | null;
   transcriptionDurationMs?: number | null;
+  detectedLanguage?: string | null;
+  languageProbabilities?: LanguageProbability[] | null;
+  translatedTranscript?: string | null;
+  translationMode?: TranslationMode | null;
+  appliedReplacements?: AppliedReplacement[] | null;
 };
 
 export type TranscribeAudioResult = {
@@ -61,6 +82,14 @@ export type PostProcessInput = {
   rawTranscript: string;
   toneId: Nullable<string>;
   a11yInfo: Nullable<TextFieldInfo>;
+  appName?: Nullable<string>;
+  requestId?: string;
+};
+
+export type RewriteSelectionInput = {
+  selectedText: string;
+  instruction: string;
+  requestId?: string;
 };
 
 export type PostProcessMetadata = {
@@ -75,6 +104,8 @@ export type PostProcessResult = {
   transcript: string;
   warnings: string[];
   metadata: PostProcessMetadata;
+  /** Tone that rewrote the transcript; unset when it was left as dictated. */
+  toneId?: Nullable<string>;
 };
 
 // Combined metadata type for storage compatibility
@@ -90,6 +121,10 @@ export type TranscriptionMetadata = TranscribeAudioMetadata &
 export const transcribeAudio = async ({
   samples,
   sampleRate,
+  appTargetId,
+  appContext,
+  toneId,
+  hotkeyAction,
 }: TranscribeAudioInput): Promise<TranscribeAudioResult> => {
   const state = getAppState();
 
@@ -105,31 +140,46 @@ export const transcribeAudio = async ({
 
   const dictationLanguage = getMyDictationLanguage(state);
   const whisperLanguage = mapLocaleToWhisperLanguage(dictationLanguage);
+  const candidateLanguages = getMyDictationLanguageCandidates(state)
+    .map(mapLocaleToWhisperLanguage)
+    .filter((language) => language.length > 0);
 
   const dictionaryEntries = collectDictionaryEntries(state);
   const baseTranscriptionPrompt =
     buildLocalizedTranscriptionPrompt(dictionaryEntries);
-  const transcriptionPrompt = (() => {
+  const promptPreamble = (() => {
     // Adding a patch to generate text precisely when dealing with different
     //   variants of Chinese.
     // See reference: https://github.com/openai/whisper/discussions/277
     if (dictationLanguage === "zh-CN") {
-      return `以下是普通话的句子。\n\n${baseTranscriptionPrompt}`.trim();
+      return "以下是普通话的句子。";
     }
 
     if (dictationLanguage === "zh-TW" || dictationLanguage === "zh-HK") {
-      return `以下是普通話的句子。\n\n${baseTranscriptionPrompt}`.trim();
+      return "以下是普通話的句子。";
     }
 
-    return baseTranscriptionPrompt;
+    return null;
   })();
+  const transcriptionPrompt = promptPreamble
+    ? `${promptPreamble}\n\n${baseTranscriptionPrompt}`.trim()
+    : baseTranscriptionPrompt;
 
   const transcribeStart = performance.now();
   const transcribeOutput = await transcribeRepo.transcribeAudio({
     samples,
     sampleRate,
     prompt: transcriptionPrompt,
-    language: whisperLanguage,
+    // With language switching on, detect among the user's languages rather
+    // than forcing the active one.
+    language: candidateLanguages.length >= 2 ? undefined : whisperLanguage,
+    candidateLanguages,
+    promptPreamble,
+    appTargetId,
+    appContext,
+    // The app's tone, else the active one, may ask for a translation.
+    toneId: toneId ?? state.userPrefs?.activeToneId ?? null,
+    hotkeyAction,
   });
   const transcribeDuration = performance.now() - transcribeStart;
   const rawTranscript = transcribeOutput.text.trim();
@@ -137,10 +187,23 @@ export const transcribeAudio = async ({
   metadata.modelSize = state.settings.aiTranscription.modelSize || null;
   metadata.inferenceDevice = transcribeOutput.metadata?.inferenceDevice || null;
   metadata.transcriptionDurationMs = Math.round(transcribeDuration);
-  metadata.transcriptionPrompt = transcriptionPrompt;
-  metadata.transcriptionApiKeyId = transcriptionApiKeyId;
+  metadata.transcriptionPrompt =
+    transcribeOutput.metadata?.transcriptionPrompt ?? transcriptionPrompt;
+  metadata.transcriptionApiKeyId =
+    transcribeOutput.metadata?.transcriptionApiKeyId ?? transcriptionApiKeyId;
   metadata.transcriptionMode =
     transcribeOutput.metadata?.transcriptionMode || null;
+  metadata.detectedLanguage =
+    transcribeOutput.metadata?.detectedLanguage || null;
+  metadata.languageProbabilities =
+    transcribeOutput.metadata?.languageProbabilities || null;
+  metadata.translatedTranscript =
+    transcribeOutput.metadata?.translatedTranscript || null;
+  metadata.translationMode =
+    transcribeOutput.metadata?.translationMode || null;
+  metadata.appliedReplacements =
+    transcribeOutput.metadata?.appliedReplacements ?? null;
+  warnings.push(...(transcribeOutput.metadata?.warnings ?? []));
 
   return {
     rawTranscript,
@@ -157,6 +220,8 @@ export const postProcessTranscript = async ({
   rawTranscript,
   toneId,
   a11yInfo,
+  appName,
+  requestId,
 }: PostProcessInput): Promise<PostProcessResult> => {
   const state = getAppState();
 
@@ -171,8 +236,49 @@ export const postProcessTranscript = async ({
   warnings.push(...genWarnings);
 
   let processedTranscript = rawTranscript;
+  let promptTranscript = rawTranscript;
+  let textFieldContext = extractTextFieldContext(a11yInfo);
+  let redactions: Redaction[] = [];
+  let redactionFailed = false;
+  let appliedToneId: Nullable<string> = null;
+
+  if (genRepo && state.userPrefs?.redactBeforeCloud) {
+    try {
+      // The text around the cursor goes into the prompt too.
+      const redacted = await redactTexts(
+        [
+          rawTranscript,
+          textFieldContext?.precedingText ?? "",
+          textFieldContext?.selectedText ?? "",
+          textFieldContext?.followingText ?? "",
+        ],
+        "post_processing",
+        state.userPrefs,
+      );
+      const [transcriptText, precedingText, selectedText, followingText] =
+        redacted.texts;
+      promptTranscript = transcriptText;
+      if (textFieldContext) {
+        textFieldContext = {
+          precedingText: textFieldContext.precedingText && precedingText,
+          selectedText: textFieldContext.selectedText && selectedText,
+          followingText: textFieldContext.followingText && followingText,
+        };
+      }
+      redactions = redacted.redactions;
+      if (redacted.warning) {
+        warnings.push(redacted.warning);
+      }
+    } catch (e) {
+      // Never send the unmasked transcript when masking was requested.
+      redactionFailed = true;
+      warnings.push(
+        `Skipped post-processing because redaction failed: ${String(e)}`,
+      );
+    }
+  }
 
-  if (genRepo) {
+  if (genRepo && !redactionFailed) {
     const dictationLanguage = getMyDictationLanguage(state);
     const myPrefs = state.userPrefs;
     const tone =
@@ -180,11 +286,25 @@ export const postProcessTranscript = async ({
       getRec(state.toneById, myPrefs?.activeToneId) ??
       null;
 
-    const textFieldContext = extractTextFieldContext(a11yInfo);
+    let toneTemplate = tone?.promptTemplate ?? null;
+    if (toneTemplate) {
+      try {
+        toneTemplate = await getToneRepo().renderTone({
+          promptTemplate: toneTemplate,
+          transcript: promptTranscript,
+          appName: appName ?? null,
+          selectedText: textFieldContext?.selectedText ?? null,
+          language: dictationLanguage,
+        });
+      } catch (e) {
+        warnings.push(`Failed to render style template: ${String(e)}`);
+      }
+    }
+
     const ppPrompt = buildLocalizedPostProcessingPrompt({
-      transcript: rawTranscript,
+      transcript: promptTranscript,
       dictationLanguage,
-      toneTemplate: tone?.promptTemplate ?? null,
+      toneTemplate,
       textFieldContext: textFieldContext ?? null,
     });
 
@@ -192,6 +312,7 @@ export const postProcessTranscript = async ({
 
     const postprocessStart = performance.now();
     const genOutput = await genRepo.generateText({
+      requestId,
       system: ppSystem,
       prompt: ppPrompt,
       jsonResponse: {
@@ -212,8 +333,11 @@ export const postProcessTranscript = async ({
           `Post-processing response validation failed: ${validationResult.error.message}`,
         );
       } else {
-        processedTranscript =
-          validationResult.data.processedTranscription.trim();
+        processedTranscript = restoreRedactions(
+          validationResult.data.processedTranscription.trim(),
+          redactions,
+        );
+        appliedToneId = tone?.id ?? null;
       }
     } catch (e) {
       warnings.push(
@@ -242,6 +366,110 @@ export const postProcessTranscript = async ({
     transcript: processedTranscript,
     warnings: dedup(warnings),
     metadata,
+    toneId: appliedToneId,
+  };
+};
+
+/**
+ * Stops a post-processing or rewrite request started with `requestId`, e.g.
+ * when its recording is abandoned.
+ */
+export const cancelPostProcessing = async (requestId: string) => {
+  try {
+    await getGenerateTextRepo().repo?.cancelGenerateText(requestId);
+  } catch (error) {
+    console.error("Failed to cancel post-processing", error);
+  }
+};
+
+/**
+ * Rewrites the user's selected text by following a spoken instruction.
+ * Throws when post-processing is unavailable or fails so the caller can leave
+ * the selection untouched.
+ */
+export const rewriteSelectedText = async ({
+  selectedText,
+  instruction,
+  requestId,
+}: RewriteSelectionInput): Promise<PostProcessResult> => {
+  const state = getAppState();
+
+  const metadata: PostProcessMetadata = {};
+  const warnings: string[] = [];
+
+  const {
+    repo: genRepo,
+    apiKeyId: genApiKeyId,
+    warnings: genWarnings,
+  } = getGenerateTextRepo();
+  warnings.push(...genWarnings);
+  if (!genRepo) {
+    throw new Error(
+      "Rewriting a selection needs post-processing to be set up.",
+    );
+  }
+
+  let promptSelection = selectedText;
+  let promptInstruction = instruction;
+  let redactions: Redaction[] = [];
+  if (state.userPrefs?.redactBeforeCloud) {
+    const redacted = await redactTexts(
+      [selectedText, instruction],
+      "post_processing",
+      state.userPrefs,
+    );
+    [promptSelection, promptInstruction] = redacted.texts;
+    redactions = redacted.redactions;
+    if (redacted.warning) {
+      warnings.push(redacted.warning);
+    }
+  }
+
+  const prompt = buildSelectionRewritePrompt({
+    selectedText: promptSelection,
+    instruction: promptInstruction,
+    dictationLanguage: getMyDictationLanguage(state),
+  });
+
+  const rewriteStart = performance.now();
+  const genOutput = await genRepo.generateText({
+    requestId,
+    system: buildSystemSelectionRewritePrompt(),
+    prompt,
+    jsonResponse: {
+      name: "selection_rewrite",
+      description: "JSON response with the rewritten selection",
+      schema: PROCESSED_TRANSCRIPTION_JSON_SCHEMA,
+    },
+  });
+  metadata.postprocessDurationMs = Math.round(performance.now() - rewriteStart);
+
+  const validationResult = PROCESSED_TRANSCRIPTION_SCHEMA.safeParse(
+    JSON.parse(genOutput.text),
+  );
+  if (!validationResult.success) {
+    throw new Error(
+      `Rewrite response validation failed: ${validationResult.error.message}`,
+    );
+  }
+
+  const rewritten = restoreRedactions(
+    validationResult.data.processedTranscription.trim(),
+    redactions,
+  );
+  if (!rewritten) {
+    throw new Error("The rewrite came back empty.");
+  }
+
+  metadata.postProcessPrompt = prompt;
+  metadata.postProcessApiKeyId = genApiKeyId;
+  metadata.postProcessMode = genOutput.metadata?.postProcessingMode || null;
+  metadata.postProcessDevice = genOutput.metadata?.inferenceDevice || null;
+
+  return {
+    transcript: rewritten,
+    warnings: dedup(warnings),
+    metadata,
   };
 };
 
@@ -253,6 +481,7 @@ export type StoreTranscriptionInput = {
   transcriptionMetadata: TranscribeAudioMetadata;
   postProcessMetadata: PostProcessMetadata;
   warnings: string[];
+  appTargetId?: Nullable<string>;
 };
 
 export type StoreTranscriptionOutput = {
@@ -361,6 +590,15 @@ export const storeTranscription = async (
     postprocessDurationMs:
       input.postProcessMetadata.postprocessDurationMs ?? null,
     warnings: input.warnings.length > 0 ? input.warnings : null,
+    detectedLanguage: input.transcriptionMetadata.detectedLanguage ?? null,
+    languageProbabilities:
+      input.transcriptionMetadata.languageProbabilities ?? null,
+    translatedTranscript:
+      input.transcriptionMetadata.translatedTranscript ?? null,
+    translationMode: input.transcriptionMetadata.translationMode ?? null,
+    appliedReplacements:
+      input.transcriptionMetadata.appliedReplacements ?? null,
+    appTargetId: input.appTargetId ?? null,
   };
 
   let storedTranscription: Transcription;
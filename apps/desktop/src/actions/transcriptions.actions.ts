@@ -2,6 +2,7 @@ import { Transcription } from "@repo/types";
 import { getRec } from "@repo/utilities";
 import { getTranscriptionRepo } from "../repos";
 import { getAppState, produceAppState } from "../store";
+import { loadTermSuggestions } from "./dictionary.actions";
 import { postProcessTranscript, transcribeAudio } from "./transcribe.actions";
 
 export const openTranscriptionDetailsDialog = (transcriptionId: string) => {
@@ -76,6 +77,11 @@ export const retranscribeTranscription = async ({
     postProcessMode: metadata?.postProcessMode ?? null,
     postProcessDevice: metadata?.postProcessDevice ?? null,
     warnings: warnings.length > 0 ? warnings : null,
+    detectedLanguage: metadata?.detectedLanguage ?? null,
+    languageProbabilities: metadata?.languageProbabilities ?? null,
+    translatedTranscript: metadata?.translatedTranscript ?? null,
+    translationMode: metadata?.translationMode ?? null,
+    appliedReplacements: metadata?.appliedReplacements ?? null,
   };
 
   const updated = await repo.updateTranscription(updatedPayload);
@@ -84,3 +90,19 @@ export const retranscribeTranscription = async ({
     draft.transcriptionById[transcriptionId] = updated;
   });
 };
+
+export const editTranscriptionText = async (
+  transcriptionId: string,
+  transcript: string,
+): Promise<void> => {
+  const updated = await getTranscriptionRepo().editTranscript(
+    transcriptionId,
+    transcript,
+  );
+
+  produceAppState((draft) => {
+    draft.transcriptionById[transcriptionId] = updated;
+  });
+
+  await loadTermSuggestions();
+};
@@ -1,6 +1,9 @@
 import {
   DictationPillVisibility,
+  LocalTranscriptionEngine,
   Nullable,
+  PiiKind,
+  TranscriptionFallbackStep,
   User,
   UserPreferences,
 } from "@repo/types";
@@ -14,6 +17,7 @@ import {
   type TranscriptionMode,
 } from "../types/ai.types";
 import {
+  DEFAULT_SPACING_CORRECTION_LANGUAGES,
   getMyEffectiveUserId,
   getMyUser,
   getMyUserPreferences,
@@ -315,6 +319,22 @@ export const setPreferredTranscriptionModelSize = async (
   await persistAiPreferences();
 };
 
+export const setLocalTranscriptionEngine = async (
+  engine: LocalTranscriptionEngine,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.localTranscriptionEngine = engine;
+  }, "Failed to save transcription engine. Please try again.");
+};
+
+export const setTranscriptionFallbackChain = async (
+  chain: TranscriptionFallbackStep[],
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.transcriptionFallbackChain = chain.length > 0 ? chain : null;
+  }, "Failed to save transcription fallback order. Please try again.");
+};
+
 export const setGpuEnumerationEnabled = async (
   enabled: boolean,
 ): Promise<void> => {
@@ -497,6 +517,84 @@ export const setIgnoreUpdateDialog = async (ignore: boolean): Promise<void> => {
   }, "Failed to save update dialog preference. Please try again.");
 };
 
+export const setDisfluencyCleanupEnabled = async (
+  enabled: boolean,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.disfluencyCleanupEnabled = enabled;
+  }, "Failed to save filler word preference. Please try again.");
+};
+
+export const setVoiceCommandsEnabled = async (
+  enabled: boolean,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.voiceCommandsEnabled = enabled;
+  }, "Failed to save voice command preference. Please try again.");
+};
+
+export const setFillerWords = async (words: Nullable<string[]>) => {
+  const normalized =
+    words?.map((word) => word.trim()).filter((word) => word.length > 0) ??
+    null;
+
+  await updateUserPreferences((preferences) => {
+    preferences.fillerWords = normalized;
+  }, "Failed to save filler words. Please try again.");
+};
+
+export const setSpacingCorrectionEnabled = async (
+  language: string,
+  enabled: boolean,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    const current =
+      preferences.spacingCorrectionLanguages ??
+      DEFAULT_SPACING_CORRECTION_LANGUAGES;
+    const others = current.filter((value) => value !== language);
+    preferences.spacingCorrectionLanguages = enabled
+      ? [...others, language]
+      : others;
+  }, "Failed to save spacing correction preference. Please try again.");
+};
+
+export const setRedactBeforeCloud = async (
+  enabled: boolean,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.redactBeforeCloud = enabled;
+  }, "Failed to save redaction preference. Please try again.");
+};
+
+export const setRedactBeforeStorage = async (
+  enabled: boolean,
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.redactBeforeStorage = enabled;
+  }, "Failed to save redaction preference. Please try again.");
+};
+
+export const setRedactionDetectors = async (
+  detectors: PiiKind[],
+): Promise<void> => {
+  await updateUserPreferences((preferences) => {
+    preferences.redactionDetectors = detectors;
+  }, "Failed to save redaction detectors. Please try again.");
+};
+
+export const setRedactionPatterns = async (
+  patterns: Nullable<string[]>,
+): Promise<void> => {
+  const normalized =
+    patterns
+      ?.map((pattern) => pattern.trim())
+      .filter((pattern) => pattern.length > 0) ?? null;
+
+  await updateUserPreferences((preferences) => {
+    preferences.redactionPatterns = normalized;
+  }, "Failed to save redaction patterns. Please try again.");
+};
+
 export const setIncognitoModeEnabled = async (
   enabled: boolean,
 ): Promise<void> => {
@@ -3,7 +3,8 @@ import Typography from "@mui/material/Typography";
 import { keyframes } from "@mui/material/styles";
 import { useEffect, useMemo, useRef, useState } from "react";
 import { useIntl } from "react-intl";
-import { produceAppState, useAppStore } from "../../store";
+import { useRecordingKeysHeld } from "../../hooks/hotkey.hooks";
+import { produceAppState } from "../../store";
 import { getPrettyKeyName } from "../../utils/keyboard.utils";
 
 type HotKeyProps = {
@@ -25,7 +26,7 @@ export const HotKey = ({ value, onChange }: HotKeyProps) => {
   const boxRef = useRef<HTMLDivElement | null>(null);
   const [focused, setFocused] = useState(false);
   const [hasInteracted, setHasInteracted] = useState(false);
-  const keysHeld = useAppStore((s) => s.keysHeld);
+  const keysHeld = useRecordingKeysHeld(focused);
 
   const lastEmittedRef = useRef<string[]>(value);
   useEffect(() => {
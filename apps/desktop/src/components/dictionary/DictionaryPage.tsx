@@ -1,9 +1,9 @@
 import { FindReplaceOutlined, SpellcheckOutlined } from "@mui/icons-material";
 import AddRoundedIcon from "@mui/icons-material/AddRounded";
 import { Button } from "@mui/material";
-import { Term } from "@repo/types";
+import { Term, TermSuggestion } from "@repo/types";
 import dayjs from "dayjs";
-import { useCallback } from "react";
+import { useCallback, useMemo } from "react";
 import { FormattedMessage } from "react-intl";
 import { showErrorSnackbar } from "../../actions/app.actions";
 import { getTermRepo } from "../../repos";
@@ -12,9 +12,26 @@ import { createId } from "../../utils/id.utils";
 import { MenuPopoverBuilder } from "../common/MenuPopover";
 import { VirtualizedListPage } from "../common/VirtualizedListPage";
 import { DictionaryRow } from "./DictionaryRow";
+import { TermSuggestionRow } from "./TermSuggestionRow";
+
+type DictionaryItem =
+  | { kind: "suggestion"; suggestion: TermSuggestion }
+  | { kind: "term"; id: string };
 
 export default function DictionaryPage() {
   const termIds = useAppStore((state) => state.dictionary.termIds);
+  const suggestions = useAppStore((state) => state.dictionary.suggestions);
+
+  const items = useMemo<DictionaryItem[]>(
+    () => [
+      ...suggestions.map((suggestion) => ({
+        kind: "suggestion" as const,
+        suggestion,
+      })),
+      ...termIds.map((id) => ({ kind: "term" as const, id })),
+    ],
+    [suggestions, termIds],
+  );
 
   const handleAddTerm = useCallback(async (replacement: boolean) => {
     const newTerm: Term = {
@@ -91,10 +108,20 @@ export default function DictionaryPage() {
         <FormattedMessage defaultMessage="Vocally may misunderstand you on occasion. If you see certain words being missed frequently, you can define a replacement rule here to fix the spelling automatically." />
       }
       action={addButton}
-      items={termIds}
-      computeItemKey={(id) => id}
+      items={items}
+      computeItemKey={(item) =>
+        item.kind === "term"
+          ? item.id
+          : `suggestion:${item.suggestion.sourceValue}→${item.suggestion.destinationValue}`
+      }
       heightMult={10}
-      renderItem={(id) => <DictionaryRow key={id} id={id} />}
+      renderItem={(item) =>
+        item.kind === "term" ? (
+          <DictionaryRow id={item.id} />
+        ) : (
+          <TermSuggestionRow suggestion={item.suggestion} />
+        )
+      }
     />
   );
 }
@@ -0,0 +1,98 @@
+import ArrowForwardRoundedIcon from "@mui/icons-material/ArrowForwardRounded";
+import CheckRoundedIcon from "@mui/icons-material/CheckRounded";
+import CloseRoundedIcon from "@mui/icons-material/CloseRounded";
+import { IconButton, Stack, Tooltip, Typography } from "@mui/material";
+import { TermSuggestion } from "@repo/types";
+import { useCallback, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import {
+  acceptTermSuggestion,
+  rejectTermSuggestion,
+} from "../../actions/dictionary.actions";
+
+export type TermSuggestionRowProps = {
+  suggestion: TermSuggestion;
+};
+
+export const TermSuggestionRow = ({ suggestion }: TermSuggestionRowProps) => {
+  const intl = useIntl();
+  const [isPending, setIsPending] = useState(false);
+
+  const handleDecision = useCallback(
+    async (accept: boolean) => {
+      setIsPending(true);
+      try {
+        if (accept) {
+          await acceptTermSuggestion(suggestion);
+        } else {
+          await rejectTermSuggestion(suggestion);
+        }
+      } catch (error) {
+        showErrorSnackbar(error);
+        setIsPending(false);
+      }
+    },
+    [suggestion],
+  );
+
+  return (
+    <Stack
+      direction="row"
+      spacing={2}
+      alignItems="center"
+      py={1}
+      px={1.5}
+      sx={{ borderRadius: 1, bgcolor: "action.hover" }}
+    >
+      <Stack sx={{ flex: 1, minWidth: 0 }}>
+        <Stack direction="row" spacing={1} alignItems="center">
+          <Typography variant="body2" noWrap>
+            {suggestion.sourceValue}
+          </Typography>
+          <ArrowForwardRoundedIcon color="action" fontSize="small" />
+          <Typography variant="body2" fontWeight={600} noWrap>
+            {suggestion.destinationValue}
+          </Typography>
+        </Stack>
+        <Typography variant="caption" color="text.secondary">
+          <FormattedMessage
+            defaultMessage="Suggested replacement · you corrected this {count, plural, one {# time} other {# times}}"
+            values={{ count: suggestion.occurrences }}
+          />
+        </Typography>
+      </Stack>
+      <Tooltip title={<FormattedMessage defaultMessage="Add to dictionary" />}>
+        <span>
+          <IconButton
+            aria-label={intl.formatMessage(
+              { defaultMessage: "Accept suggestion {term}" },
+              { term: suggestion.sourceValue },
+            )}
+            onClick={() => handleDecision(true)}
+            disabled={isPending}
+            size="small"
+            color="primary"
+          >
+            <CheckRoundedIcon fontSize="small" />
+          </IconButton>
+        </span>
+      </Tooltip>
+      <Tooltip title={<FormattedMessage defaultMessage="Dismiss" />}>
+        <span>
+          <IconButton
+            aria-label={intl.formatMessage(
+              { defaultMessage: "Reject suggestion {term}" },
+              { term: suggestion.sourceValue },
+            )}
+            onClick={() => handleDecision(false)}
+            disabled={isPending}
+            size="small"
+          >
+            <CloseRoundedIcon fontSize="small" />
+          </IconButton>
+        </span>
+      </Tooltip>
+    </Stack>
+  );
+};
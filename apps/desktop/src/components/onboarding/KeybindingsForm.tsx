@@ -4,6 +4,7 @@ import { useEffect, useRef, useState } from "react";
 import { FormattedMessage } from "react-intl";
 import { showErrorSnackbar } from "../../actions/app.actions";
 import { goToOnboardingPage } from "../../actions/onboarding.actions";
+import { useRecordingKeysHeld } from "../../hooks/hotkey.hooks";
 import { getHotkeyRepo } from "../../repos";
 import { produceAppState, useAppStore } from "../../store";
 import { trackButtonClick } from "../../utils/analytics.utils";
@@ -25,7 +26,7 @@ export const KeybindingsForm = () => {
   const [isListening, setIsListening] = useState(false);
   const boxRef = useRef<HTMLDivElement | null>(null);
 
-  const keysHeld = useAppStore((s) => s.keysHeld);
+  const keysHeld = useRecordingKeysHeld(isListening);
   const hotkeys = useAppStore((state) =>
     state.settings.hotkeyIds
       .map((id) => state.hotkeyById[id])
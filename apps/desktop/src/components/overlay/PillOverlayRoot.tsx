@@ -2,7 +2,7 @@ import { Box, LinearProgress, Typography } from "@mui/material";
 import { alpha, useTheme } from "@mui/material/styles";
 import { invoke } from "@tauri-apps/api/core";
 import { emitTo } from "@tauri-apps/api/event";
-import { useEffect, useState } from "react";
+import { useEffect, useRef, useState } from "react";
 import { FormattedMessage } from "react-intl";
 import { useTauriListen } from "../../hooks/tauri.hooks";
 import { produceAppState, useAppStore } from "../../store";
@@ -42,6 +42,56 @@ type RecordingLevelPayload = {
   levels?: number[];
 };
 
+type PostProcessDeltaPayload = {
+  requestId: string;
+  delta: string;
+  done: boolean;
+};
+
+const STREAM_PREVIEW_CHARS = 20;
+const STREAMED_TRANSCRIPT_FIELD = /"processedTranscription"\s*:\s*"/;
+
+/**
+ * Post-processing streams its raw JSON answer; this decodes the transcript
+ * field as far as it has arrived, on one line for the pill.
+ */
+const getStreamedTranscript = (raw: string): string => {
+  const match = STREAMED_TRANSCRIPT_FIELD.exec(raw);
+  if (!match) {
+    return "";
+  }
+
+  let text = "";
+  for (let i = match.index + match[0].length; i < raw.length; i++) {
+    const char = raw[i];
+    if (char === '"') {
+      break;
+    }
+    if (char !== "\\") {
+      text += char;
+      continue;
+    }
+
+    const next = raw[i + 1];
+    if (next === undefined) {
+      break;
+    }
+    if (next === "u") {
+      const hex = raw.slice(i + 2, i + 6);
+      if (hex.length < 4) {
+        break;
+      }
+      text += String.fromCharCode(parseInt(hex, 16));
+      i += 5;
+      continue;
+    }
+    text += "nrtbf".includes(next) ? " " : next;
+    i++;
+  }
+
+  return text;
+};
+
 export const PillOverlayRoot = () => {
   const [isExpanded, setIsExpanded] = useState(false);
   const [isHovered, setIsHovered] = useState(false);
@@ -52,6 +102,8 @@ export const PillOverlayRoot = () => {
   const hotkeyKeys = combos.length > 0 ? combos[0] : ["?"];
   const phase = useAppStore((state) => state.overlayPhase);
   const levels = useAppStore((state) => state.audioLevels);
+  const [streamedText, setStreamedText] = useState("");
+  const streamRequestIdRef = useRef<string | null>(null);
 
   const isIdle = phase === "idle";
   const isListening = phase === "recording";
@@ -76,6 +128,21 @@ export const PillOverlayRoot = () => {
         draft.audioLevels = [];
       }
     });
+    if (payload.phase !== "loading") {
+      streamRequestIdRef.current = null;
+      setStreamedText("");
+    }
+  });
+
+  useTauriListen<PostProcessDeltaPayload>("post_process_delta", (payload) => {
+    if (payload.done) {
+      return;
+    }
+    const isNewRequest = streamRequestIdRef.current !== payload.requestId;
+    streamRequestIdRef.current = payload.requestId;
+    setStreamedText((prev) =>
+      isNewRequest ? payload.delta : prev + payload.delta,
+    );
   });
 
   useTauriListen<RecordingLevelPayload>("recording_level", (payload) => {
@@ -105,6 +172,10 @@ export const PillOverlayRoot = () => {
   );
   const isDictationUnlocked = useAppStore(getIsDictationUnlocked);
 
+  const streamPreview = isProcessing
+    ? getStreamedTranscript(streamedText).slice(-STREAM_PREVIEW_CHARS)
+    : "";
+
   const isOverlayActive = !isIdle;
   const isVisible =
     isDictationUnlocked &&
@@ -261,7 +332,20 @@ export const PillOverlayRoot = () => {
                 transition: "opacity 150ms ease-out",
               }}
             >
-              <LinearProgress sx={{ width: "100%", height: "2px" }} />
+              {streamPreview ? (
+                <Typography
+                  sx={{
+                    color: alpha(theme.palette.common.white, 0.7),
+                    fontSize: "11px",
+                    fontWeight: 500,
+                    whiteSpace: "nowrap",
+                  }}
+                >
+                  {streamPreview}
+                </Typography>
+              ) : (
+                <LinearProgress sx={{ width: "100%", height: "2px" }} />
+              )}
             </Box>
 
             {/* Audio waveform */}
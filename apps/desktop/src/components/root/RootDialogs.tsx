@@ -8,6 +8,8 @@ import { MicrophoneDialog } from "../settings/MicrophoneDialog";
 import { MoreSettingsDialog } from "../settings/MoreSettingsDialog";
 import { ProfileDialog } from "../settings/ProfileDialog";
 import { ShortcutsDialog } from "../settings/ShortcutsDialog";
+import { SnippetsDialog } from "../settings/SnippetsDialog";
+import { VoiceCommandsDialog } from "../settings/VoiceCommandsDialog";
 import { UpgradePlanDialog } from "../pricing/UpgradePlanDialog";
 import { UpdateDialog } from "./UpdateDialog";
 import { DeleteAccountDialog } from "../settings/DeleteAccountDialog";
@@ -30,6 +32,8 @@ export const RootDialogs = () => {
       <PaymentDialog />
       <DeleteAccountDialog />
       <MoreSettingsDialog />
+      <VoiceCommandsDialog />
+      <SnippetsDialog />
     </>
   );
 };
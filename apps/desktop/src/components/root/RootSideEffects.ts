@@ -8,8 +8,15 @@ import {
   loadAppTargets,
   tryRegisterCurrentAppTarget,
 } from "../../actions/app-target.actions";
-import { showErrorSnackbar } from "../../actions/app.actions";
-import { loadDictionary } from "../../actions/dictionary.actions";
+import {
+  showErrorSnackbar,
+  toggleCodeMode,
+  undoLastPaste,
+} from "../../actions/app.actions";
+import {
+  loadDictionary,
+  loadTermSuggestions,
+} from "../../actions/dictionary.actions";
 import { loadHotkeys } from "../../actions/hotkey.actions";
 import { handleGoogleAuthPayload } from "../../actions/login.actions";
 import { refreshMember } from "../../actions/member.actions";
@@ -38,10 +45,12 @@ import { getAppState, produceAppState, useAppStore } from "../../store";
 import { AgentStrategy } from "../../strategies/agent.strategy";
 import { BaseStrategy } from "../../strategies/base.strategy";
 import { DictationStrategy } from "../../strategies/dictation.strategy";
+import { RewriteStrategy } from "../../strategies/rewrite.strategy";
 import type { TextFieldInfo } from "../../types/accessibility.types";
 import { REGISTER_CURRENT_APP_EVENT } from "../../types/app-target.types";
 import type { GoogleAuthPayload } from "../../types/google-auth.types";
 import { GOOGLE_AUTH_EVENT } from "../../types/google-auth.types";
+import type { KeyListenerSource } from "../../types/keyboard.types";
 import type { OverlayPhase } from "../../types/overlay.types";
 import type { StrategyContext } from "../../types/strategy.types";
 import {
@@ -65,8 +74,14 @@ import {
 } from "../../utils/audio.utils";
 import {
   AGENT_DICTATE_HOTKEY,
+  CODE_MODE_HOTKEY,
+  DICTATE_BILINGUAL_HOTKEY,
   DICTATE_HOTKEY,
+  DICTATE_TRANSLATE_HOTKEY,
+  getListenerHotkeys,
   LANGUAGE_SWITCH_HOTKEY,
+  REWRITE_SELECTION_HOTKEY,
+  UNDO_PASTE_HOTKEY,
 } from "../../utils/keyboard.utils";
 import { isPermissionAuthorized } from "../../utils/permission.utils";
 import {
@@ -123,18 +138,31 @@ export const RootSideEffects = () => {
   const overlayLoadingTokenRef = useRef<symbol | null>(null);
   const sessionRef = useRef<TranscriptionSession | null>(null);
   const strategyRef = useRef<BaseStrategy | null>(null);
+  const listenerStartedHotkeysJsonRef = useRef<string | null>(null);
   const recordingWarningTimerRef = useRef<NodeJS.Timeout | null>(null);
   const recordingAutoStopTimerRef = useRef<NodeJS.Timeout | null>(null);
+  // The dictate hotkey that started the recording; the translate variants
+  // switch on translation for that dictation.
+  const dictationHotkeyRef = useRef<string | null>(null);
   const userId = useAppStore((state) => state.auth?.id);
   const keyPermAuthorized = useAppStore((state) =>
     isPermissionAuthorized(getRec(state.permissions, "accessibility")?.state),
   );
+  // Serialized so the listener only restarts when the hotkeys really change.
+  const listenerHotkeysJson = useAppStore((state) =>
+    JSON.stringify(getListenerHotkeys(state)),
+  );
+  const keyListenerSource = useAppStore((state) => state.keyListenerSource);
   const intl = useIntl();
 
-  const startDictationRef = useRef<(() => void) | null>(null);
+  const startDictationRef = useRef<((hotkeyAction?: string) => void) | null>(
+    null,
+  );
   const stopDictationRef = useRef<(() => void) | null>(null);
   const startAgentRef = useRef<(() => void) | null>(null);
   const stopAgentRef = useRef<(() => void) | null>(null);
+  const startRewriteRef = useRef<(() => void) | null>(null);
+  const stopRewriteRef = useRef<(() => void) | null>(null);
   const stopRecordingRef = useRef<(() => void) | null>(null);
 
   const dictationController = useMemo(
@@ -147,6 +175,26 @@ export const RootSideEffects = () => {
     [],
   );
 
+  const translateController = useMemo(
+    () =>
+      getOrCreateController(
+        "dictation-translate",
+        () => startDictationRef.current?.(DICTATE_TRANSLATE_HOTKEY),
+        () => stopDictationRef.current?.(),
+      ),
+    [],
+  );
+
+  const bilingualController = useMemo(
+    () =>
+      getOrCreateController(
+        "dictation-bilingual",
+        () => startDictationRef.current?.(DICTATE_BILINGUAL_HOTKEY),
+        () => stopDictationRef.current?.(),
+      ),
+    [],
+  );
+
   const agentController = useMemo(
     () =>
       getOrCreateController(
@@ -157,6 +205,16 @@ export const RootSideEffects = () => {
     [],
   );
 
+  const rewriteController = useMemo(
+    () =>
+      getOrCreateController(
+        "rewrite",
+        () => startRewriteRef.current?.(),
+        () => stopRewriteRef.current?.(),
+      ),
+    [],
+  );
+
   const strategyContext: StrategyContext = useMemo(
     () => ({
       overlayLoadingTokenRef,
@@ -188,14 +246,39 @@ export const RootSideEffects = () => {
     sessionRef.current = null;
   }, [clearRecordingTimers]);
 
+  const startKeyListener = useCallback(async () => {
+    const hotkeys = getListenerHotkeys(getAppState());
+    listenerStartedHotkeysJsonRef.current = JSON.stringify(hotkeys);
+    const source = await invoke<KeyListenerSource>("start_key_listener", {
+      hotkeys,
+    });
+    produceAppState((draft) => {
+      draft.keyListenerSource = source;
+    });
+  }, []);
+
   useAsyncEffect(async () => {
     if (keyPermAuthorized) {
-      await invoke("start_key_listener");
+      await startKeyListener();
     } else {
       await invoke("stop_key_listener");
+      listenerStartedHotkeysJsonRef.current = null;
+      produceAppState((draft) => {
+        draft.keyListenerSource = null;
+      });
     }
   }, [keyPermAuthorized]);
 
+  // Only the portal binds hotkeys up front; other sources report every key.
+  useAsyncEffect(async () => {
+    if (
+      keyListenerSource === "portal" &&
+      listenerHotkeysJson !== listenerStartedHotkeysJsonRef.current
+    ) {
+      await startKeyListener();
+    }
+  }, [keyListenerSource, listenerHotkeysJson]);
+
   useAsyncEffect(async () => {
     await Promise.allSettled([refreshMember(), refreshCurrentUser()]);
 
@@ -203,6 +286,7 @@ export const RootSideEffects = () => {
       loadHotkeys(),
       loadApiKeys(),
       loadDictionary(),
+      loadTermSuggestions(),
       loadTones(),
       loadAppTargets(),
       migratePreferredMicrophoneToPreferences(),
@@ -256,12 +340,16 @@ export const RootSideEffects = () => {
     // Create or reuse strategy based on mode
     const currentMode = getAppState().activeRecordingMode;
     let strategy = strategyRef.current;
+    const isNewStrategy = !strategy;
     if (!strategy) {
       const mode: RecordingMode = currentMode ?? "dictate";
-      strategy =
-        mode === "agent"
-          ? new AgentStrategy(strategyContext)
-          : new DictationStrategy(strategyContext);
+      if (mode === "agent") {
+        strategy = new AgentStrategy(strategyContext);
+      } else if (mode === "rewrite") {
+        strategy = new RewriteStrategy(strategyContext);
+      } else {
+        strategy = new DictationStrategy(strategyContext);
+      }
       strategyRef.current = strategy;
     }
 
@@ -275,6 +363,14 @@ export const RootSideEffects = () => {
         action: validationError.action ?? undefined,
         duration: 8_000,
       });
+      // Don't let a rejected mode, e.g. a rewrite with nothing selected,
+      // handle the next recording.
+      if (isNewStrategy) {
+        strategyRef.current = null;
+        produceAppState((draft) => {
+          draft.activeRecordingMode = null;
+        });
+      }
       return;
     }
 
@@ -347,7 +443,10 @@ export const RootSideEffects = () => {
           });
 
           dictationController.reset();
+          translateController.reset();
+          bilingualController.reset();
           agentController.reset();
+          rewriteController.reset();
           void stopRecordingRef.current?.();
         }, RECORDING_AUTO_STOP_DURATION_MS);
       } catch (error) {
@@ -355,7 +454,10 @@ export const RootSideEffects = () => {
 
         clearRecordingTimers();
         dictationController.reset();
+        translateController.reset();
+        bilingualController.reset();
         agentController.reset();
+        rewriteController.reset();
 
         await strategy.setPhase("idle");
         showErrorSnackbar("Unable to start recording. Please try again.");
@@ -459,9 +561,14 @@ export const RootSideEffects = () => {
           return;
         }
 
+        const currentAppPromise = tryRegisterCurrentAppTarget();
         const [currentApp, transcribeResult] = await Promise.all([
-          tryRegisterCurrentAppTarget(),
-          session.finalize(audio),
+          currentAppPromise,
+          session.finalize(audio, {
+            appTarget: currentAppPromise,
+            appContext: a11yInfo?.textContent ?? null,
+            hotkeyAction: dictationHotkeyRef.current,
+          }),
         ]);
         const toneId = currentApp?.toneId ?? null;
         const rawTranscript = transcribeResult.rawTranscript;
@@ -521,6 +628,7 @@ export const RootSideEffects = () => {
             transcriptionMetadata: transcribeResult.metadata,
             postProcessMetadata,
             warnings: [...transcribeResult.warnings, ...postProcessWarnings],
+            appTargetId: currentApp?.id ?? null,
           });
         }
       }
@@ -530,37 +638,59 @@ export const RootSideEffects = () => {
     }
   }, [clearRecordingTimers, resetRecordingState]);
 
-  const startDictationRecording = useCallback(async () => {
+  const startDictationRecording = useCallback(
+    async (hotkeyAction: string = DICTATE_HOTKEY) => {
+      const state = getAppState();
+      if (!getIsDictationUnlocked(state)) {
+        return;
+      }
+
+      trackDictationStart();
+      dictationHotkeyRef.current = hotkeyAction;
+      produceAppState((draft) => {
+        draft.activeRecordingMode = "dictate";
+      });
+      await startRecording();
+    },
+    [startRecording],
+  );
+
+  const stopDictationRecording = useCallback(async () => {
+    await stopRecording();
+  }, [stopRecording]);
+
+  const startAgentRecording = useCallback(async () => {
     const state = getAppState();
     if (!getIsDictationUnlocked(state)) {
       return;
     }
 
-    trackDictationStart();
+    trackAgentStart();
+    dictationHotkeyRef.current = null;
     produceAppState((draft) => {
-      draft.activeRecordingMode = "dictate";
+      draft.activeRecordingMode = "agent";
     });
     await startRecording();
-  }, [startRecording]);
+  }, [intl, startRecording]);
 
-  const stopDictationRecording = useCallback(async () => {
+  const stopAgentRecording = useCallback(async () => {
     await stopRecording();
   }, [stopRecording]);
 
-  const startAgentRecording = useCallback(async () => {
+  const startRewriteRecording = useCallback(async () => {
     const state = getAppState();
     if (!getIsDictationUnlocked(state)) {
       return;
     }
 
-    trackAgentStart();
+    dictationHotkeyRef.current = null;
     produceAppState((draft) => {
-      draft.activeRecordingMode = "agent";
+      draft.activeRecordingMode = "rewrite";
     });
     await startRecording();
-  }, [intl, startRecording]);
+  }, [startRecording]);
 
-  const stopAgentRecording = useCallback(async () => {
+  const stopRewriteRecording = useCallback(async () => {
     await stopRecording();
   }, [stopRecording]);
 
@@ -568,6 +698,8 @@ export const RootSideEffects = () => {
   stopDictationRef.current = stopDictationRecording;
   startAgentRef.current = startAgentRecording;
   stopAgentRef.current = stopAgentRecording;
+  startRewriteRef.current = startRewriteRecording;
+  stopRewriteRef.current = stopRewriteRecording;
   stopRecordingRef.current = stopRecording;
 
   useHotkeyHold({
@@ -575,11 +707,26 @@ export const RootSideEffects = () => {
     controller: dictationController,
   });
 
+  useHotkeyHold({
+    actionName: DICTATE_TRANSLATE_HOTKEY,
+    controller: translateController,
+  });
+
+  useHotkeyHold({
+    actionName: DICTATE_BILINGUAL_HOTKEY,
+    controller: bilingualController,
+  });
+
   useHotkeyHold({
     actionName: AGENT_DICTATE_HOTKEY,
     controller: agentController,
   });
 
+  useHotkeyHold({
+    actionName: REWRITE_SELECTION_HOTKEY,
+    controller: rewriteController,
+  });
+
   const languageSwitchEnabled = useAppStore(
     (state) => state.settings.languageSwitch.enabled,
   );
@@ -593,6 +740,24 @@ export const RootSideEffects = () => {
     onFire: handleLanguageSwitch,
   });
 
+  const handleCodeModeToggle = useCallback(() => {
+    void toggleCodeMode();
+  }, []);
+
+  useHotkeyFire({
+    actionName: CODE_MODE_HOTKEY,
+    onFire: handleCodeModeToggle,
+  });
+
+  const handleUndoPaste = useCallback(() => {
+    void undoLastPaste();
+  }, []);
+
+  useHotkeyFire({
+    actionName: UNDO_PASTE_HOTKEY,
+    onFire: handleUndoPaste,
+  });
+
   useTauriListen<void>(REGISTER_CURRENT_APP_EVENT, async () => {
     await tryRegisterCurrentAppTarget();
   });
@@ -9,11 +9,13 @@ import {
   Stack,
   Typography,
 } from "@mui/material";
+import { LocalTranscriptionEngine } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { useCallback, useMemo, useState } from "react";
 import { FormattedMessage } from "react-intl";
 import {
   setGpuEnumerationEnabled,
+  setLocalTranscriptionEngine,
   setPreferredTranscriptionApiKeyId,
   setPreferredTranscriptionDevice,
   setPreferredTranscriptionMode,
@@ -30,6 +32,7 @@ import {
 } from "../common/SegmentedControl";
 import { maybeArrayElements } from "./AIPostProcessingConfiguration";
 import { ApiKeyList } from "./ApiKeyList";
+import { TranscriptionFallbackChainSetting } from "./TranscriptionFallbackChainSetting";
 import { VoquillCloudSetting } from "./VoquillCloudSetting";
 
 type ModelOption = {
@@ -57,6 +60,19 @@ const MODEL_OPTIONS: ModelOption[] = [
   },
 ];
 
+const ENGINE_OPTIONS: ModelOption[] = [
+  {
+    value: "whisper_cpp",
+    label: "whisper.cpp",
+    helper: "Supports hardware acceleration",
+  },
+  {
+    value: "candle",
+    label: "Candle",
+    helper: "Pure Rust, runs on the CPU",
+  },
+];
+
 export type AITranscriptionConfigurationProps = {
   hideCloudOption?: boolean;
 };
@@ -65,6 +81,13 @@ export const AITranscriptionConfiguration = ({
   hideCloudOption,
 }: AITranscriptionConfigurationProps) => {
   const transcription = useAppStore((state) => state.settings.aiTranscription);
+  const engine = useAppStore(
+    (state) => state.userPrefs?.localTranscriptionEngine ?? "whisper_cpp",
+  );
+  // Candle runs on the CPU only.
+  const usesCandle = engine === "candle";
+  const showGpuPrompt =
+    !usesCandle && !transcription.gpuEnumerationEnabled && isGPUBuild();
   const [gpuEnumerationError, setGpuEnumerationError] = useState<string | null>(
     null,
   );
@@ -128,6 +151,10 @@ export const AITranscriptionConfiguration = ({
     void setPreferredTranscriptionDevice(device);
   }, []);
 
+  const handleEngineChange = useCallback((value: LocalTranscriptionEngine) => {
+    void setLocalTranscriptionEngine(value);
+  }, []);
+
   const handleModelSizeChange = useCallback((modelSize: string) => {
     void setPreferredTranscriptionModelSize(modelSize);
   }, []);
@@ -159,7 +186,40 @@ export const AITranscriptionConfiguration = ({
 
       {transcription.mode === "local" && (
         <Stack spacing={3} sx={{ width: "100%" }}>
-          {!transcription.gpuEnumerationEnabled && isGPUBuild() && (
+          <FormControl fullWidth size="small">
+            <InputLabel id="transcription-engine-label">
+              <FormattedMessage defaultMessage="Engine" />
+            </InputLabel>
+            <Select
+              labelId="transcription-engine-label"
+              label={<FormattedMessage defaultMessage="Engine" />}
+              value={engine}
+              onChange={(event) =>
+                handleEngineChange(
+                  event.target.value as LocalTranscriptionEngine,
+                )
+              }
+            >
+              {ENGINE_OPTIONS.map(({ value, label, helper }) => (
+                <MenuItem key={value} value={value}>
+                  <Box>
+                    <Typography variant="body2" fontWeight={600}>
+                      {label}
+                    </Typography>
+                    <Typography
+                      variant="caption"
+                      color="text.secondary"
+                      display="block"
+                    >
+                      {helper}
+                    </Typography>
+                  </Box>
+                </MenuItem>
+              ))}
+            </Select>
+          </FormControl>
+
+          {showGpuPrompt && (
             <Alert
               severity="info"
               sx={{
@@ -200,7 +260,7 @@ export const AITranscriptionConfiguration = ({
             <Alert severity="warning">{gpuEnumerationError}</Alert>
           )}
 
-          {transcription.gpuEnumerationEnabled && (
+          {!usesCandle && transcription.gpuEnumerationEnabled && (
             <FormControl fullWidth size="small">
               <InputLabel id="processing-device-label">
                 <FormattedMessage defaultMessage="Processing device" />
@@ -261,6 +321,8 @@ export const AITranscriptionConfiguration = ({
       )}
 
       {transcription.mode === "cloud" && <VoquillCloudSetting />}
+
+      {transcription.mode !== "cloud" && <TranscriptionFallbackChainSetting />}
     </Stack>
   );
 };
@@ -9,19 +9,31 @@ import {
   Select,
   Stack,
   Switch,
+  TextField,
 } from "@mui/material";
-import type { DictationPillVisibility } from "@repo/types";
-import { ChangeEvent } from "react";
+import type { DictationPillVisibility, PiiKind } from "@repo/types";
+import { ChangeEvent, FocusEvent } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
 import {
   setDictationPillVisibility,
+  setDisfluencyCleanupEnabled,
+  setFillerWords,
   setIgnoreUpdateDialog,
   setIncognitoModeEnabled,
   setIncognitoModeIncludeInStats,
+  setRedactBeforeCloud,
+  setRedactBeforeStorage,
+  setRedactionDetectors,
+  setRedactionPatterns,
+  setSpacingCorrectionEnabled,
+  setVoiceCommandsEnabled,
 } from "../../actions/user.actions";
 import { produceAppState, useAppStore } from "../../store";
 import {
+  DEFAULT_PII_KINDS,
   getEffectivePillVisibility,
+  getMyRedactionDetectors,
+  getMySpacingCorrectionLanguages,
   getMyUserPreferences,
 } from "../../utils/user.utils";
 import { SettingSection } from "../common/SettingSection";
@@ -34,6 +46,14 @@ export const MoreSettingsDialog = () => {
     incognitoModeEnabled,
     incognitoIncludeInStats,
     dictationPillVisibility,
+    disfluencyCleanupEnabled,
+    fillerWords,
+    koreanSpacingEnabled,
+    redactBeforeCloud,
+    redactBeforeStorage,
+    redactionDetectors,
+    redactionPatterns,
+    voiceCommandsEnabled,
   ] = useAppStore((state) => {
     const prefs = getMyUserPreferences(state);
     return [
@@ -42,6 +62,14 @@ export const MoreSettingsDialog = () => {
       prefs?.incognitoModeEnabled ?? false,
       prefs?.incognitoModeIncludeInStats ?? false,
       getEffectivePillVisibility(prefs?.dictationPillVisibility),
+      prefs?.disfluencyCleanupEnabled ?? false,
+      prefs?.fillerWords ?? null,
+      getMySpacingCorrectionLanguages(state).includes("ko"),
+      prefs?.redactBeforeCloud ?? false,
+      prefs?.redactBeforeStorage ?? false,
+      getMyRedactionDetectors(state),
+      prefs?.redactionPatterns ?? null,
+      prefs?.voiceCommandsEnabled ?? false,
     ] as const;
   });
 
@@ -68,6 +96,84 @@ export const MoreSettingsDialog = () => {
     void setIncognitoModeIncludeInStats(enabled);
   };
 
+  const handleToggleDisfluencyCleanup = (
+    event: ChangeEvent<HTMLInputElement>,
+  ) => {
+    void setDisfluencyCleanupEnabled(event.target.checked);
+  };
+
+  const handleToggleVoiceCommands = (event: ChangeEvent<HTMLInputElement>) => {
+    void setVoiceCommandsEnabled(event.target.checked);
+  };
+
+  const handleOpenVoiceCommands = () => {
+    produceAppState((draft) => {
+      draft.settings.voiceCommandsDialogOpen = true;
+    });
+  };
+
+  const handleOpenSnippets = () => {
+    produceAppState((draft) => {
+      draft.settings.snippetsDialogOpen = true;
+    });
+  };
+
+  const handleFillerWordsBlur = (event: FocusEvent<HTMLInputElement>) => {
+    const words = event.target.value.split(",");
+    const hasWords = words.some((word) => word.trim().length > 0);
+    void setFillerWords(hasWords ? words : null);
+  };
+
+  const handleToggleKoreanSpacing = (event: ChangeEvent<HTMLInputElement>) => {
+    void setSpacingCorrectionEnabled("ko", event.target.checked);
+  };
+
+  const handleToggleRedactBeforeCloud = (
+    event: ChangeEvent<HTMLInputElement>,
+  ) => {
+    void setRedactBeforeCloud(event.target.checked);
+  };
+
+  const handleToggleRedactBeforeStorage = (
+    event: ChangeEvent<HTMLInputElement>,
+  ) => {
+    void setRedactBeforeStorage(event.target.checked);
+  };
+
+  const handleRedactionDetectorsChange = (
+    event: SelectChangeEvent<PiiKind[]>,
+  ) => {
+    const value = event.target.value;
+    const selected = typeof value === "string" ? value.split(",") : value;
+    // Keep the built-in order, which decides which detector wins on overlap.
+    void setRedactionDetectors(
+      DEFAULT_PII_KINDS.filter((kind) => selected.includes(kind)),
+    );
+  };
+
+  const handleRedactionPatternsBlur = (event: FocusEvent<HTMLInputElement>) => {
+    const patterns = event.target.value.split("\n");
+    const hasPatterns = patterns.some((pattern) => pattern.trim().length > 0);
+    void setRedactionPatterns(hasPatterns ? patterns : null);
+  };
+
+  const getDetectorLabel = (kind: PiiKind): string => {
+    switch (kind) {
+      case "email":
+        return intl.formatMessage({ defaultMessage: "Email addresses" });
+      case "phone_number":
+        return intl.formatMessage({ defaultMessage: "Phone numbers" });
+      case "card_number":
+        return intl.formatMessage({ defaultMessage: "Card numbers" });
+      case "resident_registration_number":
+        return intl.formatMessage({
+          defaultMessage: "Resident registration numbers",
+        });
+      case "custom":
+        return intl.formatMessage({ defaultMessage: "Custom patterns" });
+    }
+  };
+
   const handleDictationPillVisibilityChange = (
     event: SelectChangeEvent<DictationPillVisibility>,
   ) => {
@@ -114,6 +220,152 @@ export const MoreSettingsDialog = () => {
             />
           )}
 
+          <SettingSection
+            title={
+              <FormattedMessage defaultMessage="Mask personal data before post-processing" />
+            }
+            description={
+              <FormattedMessage defaultMessage="Replace phone numbers, emails, card and registration numbers with placeholders before your transcript is sent to a post-processing provider." />
+            }
+            action={
+              <Switch
+                edge="end"
+                checked={redactBeforeCloud}
+                onChange={handleToggleRedactBeforeCloud}
+              />
+            }
+          />
+
+          <SettingSection
+            title={
+              <FormattedMessage defaultMessage="Mask personal data in history" />
+            }
+            description={
+              <FormattedMessage defaultMessage="Save transcripts with personal data replaced by placeholders. Pasted text is not affected." />
+            }
+            action={
+              <Switch
+                edge="end"
+                checked={redactBeforeStorage}
+                onChange={handleToggleRedactBeforeStorage}
+              />
+            }
+          />
+
+          {(redactBeforeCloud || redactBeforeStorage) && (
+            <Stack spacing={2}>
+              <Select<PiiKind[]>
+                multiple
+                size="small"
+                value={redactionDetectors}
+                onChange={handleRedactionDetectorsChange}
+                renderValue={(selected) =>
+                  selected.map(getDetectorLabel).join(", ")
+                }
+              >
+                {DEFAULT_PII_KINDS.map((kind) => (
+                  <MenuItem key={kind} value={kind}>
+                    {getDetectorLabel(kind)}
+                  </MenuItem>
+                ))}
+              </Select>
+              <TextField
+                size="small"
+                multiline
+                minRows={2}
+                label={getDetectorLabel("custom")}
+                placeholder={intl.formatMessage({
+                  defaultMessage: "One regular expression per line",
+                })}
+                defaultValue={redactionPatterns?.join("\n") ?? ""}
+                onBlur={handleRedactionPatternsBlur}
+              />
+            </Stack>
+          )}
+
+          <SettingSection
+            title={<FormattedMessage defaultMessage="Remove filler words" />}
+            description={
+              <FormattedMessage defaultMessage="Strip fillers such as um and uh, stutters and repeated words before your text is pasted." />
+            }
+            action={
+              <Switch
+                edge="end"
+                checked={disfluencyCleanupEnabled}
+                onChange={handleToggleDisfluencyCleanup}
+              />
+            }
+          />
+
+          {disfluencyCleanupEnabled && (
+            <TextField
+              size="small"
+              label={intl.formatMessage({ defaultMessage: "Filler words" })}
+              placeholder={intl.formatMessage({
+                defaultMessage: "Leave empty to use the built-in list",
+              })}
+              defaultValue={fillerWords?.join(", ") ?? ""}
+              onBlur={handleFillerWordsBlur}
+            />
+          )}
+
+          <SettingSection
+            title={<FormattedMessage defaultMessage="Voice commands" />}
+            description={
+              <FormattedMessage defaultMessage="Say “new line”, “comma” or “delete that” on its own, or after the word “command”, to edit as you dictate." />
+            }
+            action={
+              <Switch
+                edge="end"
+                checked={voiceCommandsEnabled}
+                onChange={handleToggleVoiceCommands}
+              />
+            }
+          />
+
+          {voiceCommandsEnabled && (
+            <Button
+              variant="outlined"
+              size="small"
+              onClick={handleOpenVoiceCommands}
+              sx={{ alignSelf: "flex-start" }}
+            >
+              <FormattedMessage defaultMessage="Manage voice commands" />
+            </Button>
+          )}
+
+          <SettingSection
+            title={<FormattedMessage defaultMessage="Snippets" />}
+            description={
+              <FormattedMessage defaultMessage="Paste saved text, such as a signature or an address, by saying its trigger." />
+            }
+            action={
+              <Button
+                variant="outlined"
+                size="small"
+                onClick={handleOpenSnippets}
+              >
+                <FormattedMessage defaultMessage="Manage" />
+              </Button>
+            }
+          />
+
+          <SettingSection
+            title={
+              <FormattedMessage defaultMessage="Korean spacing and punctuation" />
+            }
+            description={
+              <FormattedMessage defaultMessage="Fix word spacing, particles and sentence-final punctuation in Korean dictation." />
+            }
+            action={
+              <Switch
+                edge="end"
+                checked={koreanSpacingEnabled}
+                onChange={handleToggleKoreanSpacing}
+              />
+            }
+          />
+
           <SettingSection
             title={
               <FormattedMessage defaultMessage="Automatically show updates" />
@@ -1,18 +1,40 @@
 import ErrorOutlineIcon from "@mui/icons-material/ErrorOutline";
 import {
   Box,
+  Button,
   CircularProgress,
   FormControl,
   InputLabel,
+  LinearProgress,
   MenuItem,
   Select,
+  Stack,
+  TextField,
   Typography,
 } from "@mui/material";
+import { invoke } from "@tauri-apps/api/core";
 import { useCallback, useEffect, useState } from "react";
-import { FormattedMessage } from "react-intl";
-import { OllamaRepo } from "../../repos/ollama.repo";
+import { FormattedMessage, useIntl } from "react-intl";
+import { useTauriListen } from "../../hooks/tauri.hooks";
 import { OLLAMA_DEFAULT_URL } from "../../utils/ollama.utils";
 
+type OllamaStatus = {
+  url: string;
+  version: string;
+};
+
+type OllamaModel = {
+  name: string;
+};
+
+type OllamaPullProgressPayload = {
+  model: string;
+  status: string;
+  digest?: string | null;
+  total?: number | null;
+  completed?: number | null;
+};
+
 type OllamaModelPickerProps = {
   baseUrl: string | null;
   apiKey?: string | null;
@@ -30,31 +52,35 @@ export const OllamaModelPicker = ({
 }: OllamaModelPickerProps) => {
   const [models, setModels] = useState<string[]>([]);
   const [isAvailable, setIsAvailable] = useState<boolean | null>(null);
+  const [connectionError, setConnectionError] = useState<string | null>(null);
   const [isLoading, setIsLoading] = useState(false);
+  const [pullName, setPullName] = useState("");
+  const [pullingModel, setPullingModel] = useState<string | null>(null);
+  const [pullProgress, setPullProgress] =
+    useState<OllamaPullProgressPayload | null>(null);
+  const [pullError, setPullError] = useState<string | null>(null);
+  const intl = useIntl();
 
   const effectiveUrl = baseUrl || OLLAMA_DEFAULT_URL;
+  const effectiveApiKey = apiKey || null;
 
   const fetchModels = useCallback(async () => {
     setIsLoading(true);
+    const args = { baseUrl: effectiveUrl, apiKey: effectiveApiKey };
     try {
-      const repo = new OllamaRepo(effectiveUrl, apiKey || undefined);
-      const available = await repo.checkAvailability();
-      setIsAvailable(available);
-
-      if (available) {
-        const fetchedModels = await repo.getAvailableModels();
-        setModels(fetchedModels);
-      } else {
-        setModels([]);
-      }
+      await invoke<OllamaStatus>("ollama_probe", args);
+      const installed = await invoke<OllamaModel[]>("ollama_list_models", args);
+      setModels(installed.map((model) => model.name));
+      setIsAvailable(true);
+      setConnectionError(null);
     } catch (error) {
-      console.error("Failed to fetch Ollama models", error);
       setIsAvailable(false);
+      setConnectionError(String(error));
       setModels([]);
     } finally {
       setIsLoading(false);
     }
-  }, [effectiveUrl, apiKey]);
+  }, [effectiveUrl, effectiveApiKey]);
 
   useEffect(() => {
     void fetchModels();
@@ -69,6 +95,62 @@ export const OllamaModelPicker = ({
     return () => clearInterval(interval);
   }, [fetchModels]);
 
+  useTauriListen<OllamaPullProgressPayload>(
+    "ollama_pull_progress",
+    (payload) => {
+      if (payload.model === pullingModel) {
+        setPullProgress(payload);
+      }
+    },
+  );
+
+  const handleModelSelect = useCallback(
+    (model: string | null) => {
+      onModelSelect(model);
+      if (model) {
+        invoke<void>("ollama_warm_up", {
+          baseUrl: effectiveUrl,
+          apiKey: effectiveApiKey,
+          model,
+        }).catch((error) =>
+          console.error("Failed to warm up Ollama model", error),
+        );
+      }
+    },
+    [effectiveApiKey, effectiveUrl, onModelSelect],
+  );
+
+  const handlePull = useCallback(async () => {
+    const model = pullName.trim();
+    if (!model) {
+      return;
+    }
+
+    setPullingModel(model);
+    setPullProgress(null);
+    setPullError(null);
+    try {
+      await invoke<void>("ollama_pull_model", {
+        baseUrl: effectiveUrl,
+        apiKey: effectiveApiKey,
+        model,
+      });
+      setPullName("");
+      await fetchModels();
+      handleModelSelect(model);
+    } catch (error) {
+      setPullError(String(error));
+    } finally {
+      setPullingModel(null);
+      setPullProgress(null);
+    }
+  }, [effectiveApiKey, effectiveUrl, fetchModels, handleModelSelect, pullName]);
+
+  const pullPercent =
+    pullProgress?.total && pullProgress.completed != null
+      ? (pullProgress.completed / pullProgress.total) * 100
+      : null;
+
   if (isLoading && isAvailable === null) {
     return (
       <Box sx={{ display: "flex", alignItems: "center", gap: 1, py: 1 }}>
@@ -85,39 +167,92 @@ export const OllamaModelPicker = ({
       <Box sx={{ display: "flex", alignItems: "center", gap: 1, py: 1 }}>
         <ErrorOutlineIcon color="error" fontSize="small" />
         <Typography variant="body2" color="error">
-          <FormattedMessage defaultMessage="Unable to connect to Ollama at the specified URL." />
+          {connectionError ?? (
+            <FormattedMessage defaultMessage="Unable to connect to Ollama at the specified URL." />
+          )}
         </Typography>
       </Box>
     );
   }
 
   return (
-    <FormControl fullWidth size="small">
-      <InputLabel id="ollama-model-label" shrink>
-        <FormattedMessage defaultMessage="Model" />
-      </InputLabel>
-      <Select
-        labelId="ollama-model-label"
-        label={<FormattedMessage defaultMessage="Model" />}
-        value={selectedModel ?? ""}
-        onChange={(event) =>
-          onModelSelect(event.target.value ? String(event.target.value) : null)
-        }
-        displayEmpty
-        notched
-        disabled={disabled || !isAvailable}
-      >
-        <MenuItem value="">
-          <em>
-            <FormattedMessage defaultMessage="Select a model" />
-          </em>
-        </MenuItem>
-        {models.map((model) => (
-          <MenuItem key={model} value={model}>
-            {model}
+    <Stack spacing={1.5}>
+      <FormControl fullWidth size="small">
+        <InputLabel id="ollama-model-label" shrink>
+          <FormattedMessage defaultMessage="Model" />
+        </InputLabel>
+        <Select
+          labelId="ollama-model-label"
+          label={<FormattedMessage defaultMessage="Model" />}
+          value={selectedModel ?? ""}
+          onChange={(event) =>
+            handleModelSelect(
+              event.target.value ? String(event.target.value) : null,
+            )
+          }
+          displayEmpty
+          notched
+          disabled={disabled || !isAvailable}
+        >
+          <MenuItem value="">
+            <em>
+              <FormattedMessage defaultMessage="Select a model" />
+            </em>
           </MenuItem>
-        ))}
-      </Select>
-    </FormControl>
+          {models.map((model) => (
+            <MenuItem key={model} value={model}>
+              {model}
+            </MenuItem>
+          ))}
+        </Select>
+      </FormControl>
+
+      <Stack direction="row" spacing={1} alignItems="center">
+        <TextField
+          size="small"
+          fullWidth
+          value={pullName}
+          onChange={(event) => setPullName(event.target.value)}
+          placeholder={intl.formatMessage({
+            defaultMessage: "Download a model, e.g. llama3.2",
+          })}
+          disabled={disabled || !isAvailable || pullingModel !== null}
+        />
+        <Button
+          variant="outlined"
+          size="small"
+          onClick={() => void handlePull()}
+          disabled={
+            disabled ||
+            !isAvailable ||
+            pullingModel !== null ||
+            !pullName.trim()
+          }
+          sx={{ flexShrink: 0 }}
+        >
+          <FormattedMessage defaultMessage="Download" />
+        </Button>
+      </Stack>
+
+      {pullingModel && (
+        <Box>
+          <LinearProgress
+            variant={pullPercent === null ? "indeterminate" : "determinate"}
+            value={pullPercent ?? undefined}
+          />
+          <Typography variant="caption" color="text.secondary">
+            {pullProgress?.status ?? (
+              <FormattedMessage defaultMessage="Starting download..." />
+            )}
+          </Typography>
+        </Box>
+      )}
+
+      {pullError && (
+        <Typography variant="caption" color="error">
+          {pullError}
+        </Typography>
+      )}
+    </Stack>
   );
 };
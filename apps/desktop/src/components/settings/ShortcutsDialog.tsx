@@ -13,19 +13,24 @@ import { setLanguageSwitchEnabled } from "../../actions/user.actions";
 import { produceAppState, useAppStore } from "../../store";
 import {
     AGENT_DICTATE_HOTKEY,
+    CODE_MODE_HOTKEY,
+    DICTATE_BILINGUAL_HOTKEY,
     DICTATE_HOTKEY,
+    DICTATE_TRANSLATE_HOTKEY,
     LANGUAGE_SWITCH_HOTKEY,
+    REWRITE_SELECTION_HOTKEY,
+    UNDO_PASTE_HOTKEY,
 } from "../../utils/keyboard.utils";
 import { HotkeySetting } from "./HotkeySetting";
 
 export const ShortcutsDialog = () => {
-  const { open, hotkeysStatus, languageSwitchEnabled } = useAppStore(
-    (state) => ({
+  const { open, hotkeysStatus, languageSwitchEnabled, keyListenerSource } =
+    useAppStore((state) => ({
       open: state.settings.shortcutsDialogOpen,
       hotkeysStatus: state.settings.hotkeysStatus,
       languageSwitchEnabled: state.settings.languageSwitch.enabled,
-    }),
-  );
+      keyListenerSource: state.keyListenerSource,
+    }));
 
   const handleClose = () => {
     produceAppState((draft) => {
@@ -37,6 +42,26 @@ export const ShortcutsDialog = () => {
     void setLanguageSwitchEnabled(enabled);
   };
 
+  const renderSourceNote = () => {
+    if (keyListenerSource === "portal") {
+      return (
+        <Typography variant="caption" color="textSecondary">
+          <FormattedMessage defaultMessage="Shortcuts are registered with your desktop. If one doesn't trigger, assign it in your system's keyboard settings." />
+        </Typography>
+      );
+    }
+
+    if (keyListenerSource === "evdev") {
+      return (
+        <Typography variant="caption" color="textSecondary">
+          <FormattedMessage defaultMessage="Shortcuts are read directly from your keyboard devices." />
+        </Typography>
+      );
+    }
+
+    return null;
+  };
+
   const renderContent = () => {
     if (hotkeysStatus === "loading") {
       return (
@@ -60,6 +85,20 @@ export const ShortcutsDialog = () => {
           }
           actionName={DICTATE_HOTKEY}
         />
+        <HotkeySetting
+          title={<FormattedMessage defaultMessage="Dictate in English" />}
+          description={
+            <FormattedMessage defaultMessage="Dictate in any language and paste the English translation." />
+          }
+          actionName={DICTATE_TRANSLATE_HOTKEY}
+        />
+        <HotkeySetting
+          title={<FormattedMessage defaultMessage="Dictate bilingually" />}
+          description={
+            <FormattedMessage defaultMessage="Paste what you said followed by its English translation." />
+          }
+          actionName={DICTATE_BILINGUAL_HOTKEY}
+        />
         <HotkeySetting
           title={<FormattedMessage defaultMessage="Agent mode" />}
           description={
@@ -67,6 +106,13 @@ export const ShortcutsDialog = () => {
           }
           actionName={AGENT_DICTATE_HOTKEY}
         />
+        <HotkeySetting
+          title={<FormattedMessage defaultMessage="Rewrite selection" />}
+          description={
+            <FormattedMessage defaultMessage="Select text, hold the shortcut and say how to change it. The rewrite replaces the selection." />
+          }
+          actionName={REWRITE_SELECTION_HOTKEY}
+        />
         <HotkeySetting
           title={
             <FormattedMessage defaultMessage="Switch dictation language" />
@@ -78,6 +124,20 @@ export const ShortcutsDialog = () => {
           enabled={languageSwitchEnabled}
           onEnabledChange={handleLanguageSwitchEnabledChange}
         />
+        <HotkeySetting
+          title={<FormattedMessage defaultMessage="Toggle code mode" />}
+          description={
+            <FormattedMessage defaultMessage="Format dictation as code in any app: casing commands like camel case, spoken symbols and keywords, without sentence punctuation." />
+          }
+          actionName={CODE_MODE_HOTKEY}
+        />
+        <HotkeySetting
+          title={<FormattedMessage defaultMessage="Undo last dictation" />}
+          description={
+            <FormattedMessage defaultMessage="Remove the text the last dictation pasted, if you are still in the same app and it was under two minutes ago." />
+          }
+          actionName={UNDO_PASTE_HOTKEY}
+        />
       </Stack>
     );
   };
@@ -92,6 +152,7 @@ export const ShortcutsDialog = () => {
           <Typography variant="body2" color="textSecondary">
             <FormattedMessage defaultMessage="Customize your keyboard shortcuts. Keyboard shortcuts can be triggered from within any app." />
           </Typography>
+          {renderSourceNote()}
         </Stack>
       </DialogTitle>
       <DialogContent dividers>{renderContent()}</DialogContent>
@@ -0,0 +1,234 @@
+import DeleteOutlineRoundedIcon from "@mui/icons-material/DeleteOutlineRounded";
+import EditRoundedIcon from "@mui/icons-material/EditRounded";
+import {
+  Button,
+  CircularProgress,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  IconButton,
+  Stack,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { Nullable, Snippet } from "@repo/types";
+import dayjs from "dayjs";
+import { useCallback, useEffect, useMemo, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import { getSnippetRepo } from "../../repos";
+import { produceAppState, useAppStore } from "../../store";
+import { createId } from "../../utils/id.utils";
+
+const SNIPPET_VARIABLES_HINT =
+  "{{date}}, {{time}}, {{clipboard}}, {{selected_text}}";
+
+export const SnippetsDialog = () => {
+  const intl = useIntl();
+  const open = useAppStore((state) => state.settings.snippetsDialogOpen);
+  const [snippets, setSnippets] = useState<Snippet[] | null>(null);
+  const [editing, setEditing] = useState<Nullable<Snippet>>(null);
+  const [trigger, setTrigger] = useState("");
+  const [expansion, setExpansion] = useState("");
+  const [isSaving, setIsSaving] = useState(false);
+
+  const loadSnippets = useCallback(async () => {
+    try {
+      setSnippets(await getSnippetRepo().listSnippets());
+    } catch (error) {
+      showErrorSnackbar(error);
+      setSnippets([]);
+    }
+  }, []);
+
+  const resetForm = () => {
+    setEditing(null);
+    setTrigger("");
+    setExpansion("");
+  };
+
+  useEffect(() => {
+    if (open) {
+      setEditing(null);
+      setTrigger("");
+      setExpansion("");
+      void loadSnippets();
+    }
+  }, [open, loadSnippets]);
+
+  const sortedSnippets = useMemo(
+    () =>
+      [...(snippets ?? [])].sort((left, right) =>
+        left.trigger.localeCompare(right.trigger),
+      ),
+    [snippets],
+  );
+
+  const handleClose = () => {
+    produceAppState((draft) => {
+      draft.settings.snippetsDialogOpen = false;
+    });
+  };
+
+  const handleEdit = (snippet: Snippet) => {
+    setEditing(snippet);
+    setTrigger(snippet.trigger);
+    setExpansion(snippet.expansion);
+  };
+
+  const handleDelete = async (snippet: Snippet) => {
+    try {
+      await getSnippetRepo().deleteSnippet(snippet.id);
+      if (editing?.id === snippet.id) {
+        resetForm();
+      }
+      await loadSnippets();
+    } catch (error) {
+      showErrorSnackbar(error);
+    }
+  };
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    try {
+      if (editing) {
+        await getSnippetRepo().updateSnippet({
+          ...editing,
+          trigger,
+          expansion,
+        });
+      } else {
+        await getSnippetRepo().createSnippet({
+          id: createId(),
+          createdAt: dayjs().toISOString(),
+          trigger,
+          expansion,
+        });
+      }
+      resetForm();
+      await loadSnippets();
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const renderSnippet = (snippet: Snippet) => (
+    <Stack key={snippet.id} direction="row" spacing={1.5} alignItems="center">
+      <Stack sx={{ flex: 1, minWidth: 0 }}>
+        <Typography variant="body2" fontWeight={600} noWrap>
+          {snippet.trigger}
+        </Typography>
+        <Typography variant="caption" color="text.secondary" noWrap>
+          {snippet.expansion}
+        </Typography>
+      </Stack>
+      <IconButton
+        size="small"
+        aria-label={intl.formatMessage(
+          { defaultMessage: "Edit snippet {trigger}" },
+          { trigger: snippet.trigger },
+        )}
+        onClick={() => handleEdit(snippet)}
+      >
+        <EditRoundedIcon fontSize="small" />
+      </IconButton>
+      <IconButton
+        size="small"
+        aria-label={intl.formatMessage(
+          { defaultMessage: "Delete snippet {trigger}" },
+          { trigger: snippet.trigger },
+        )}
+        onClick={() => void handleDelete(snippet)}
+      >
+        <DeleteOutlineRoundedIcon fontSize="small" />
+      </IconButton>
+    </Stack>
+  );
+
+  const canSave =
+    !isSaving && trigger.trim().length > 0 && expansion.trim().length > 0;
+
+  return (
+    <Dialog open={open} onClose={handleClose} fullWidth maxWidth="sm">
+      <DialogTitle>
+        <Stack spacing={1}>
+          <Typography variant="h6">
+            <FormattedMessage defaultMessage="Snippets" />
+          </Typography>
+          <Typography variant="body2" color="textSecondary">
+            <FormattedMessage defaultMessage="Say a trigger on its own, or “insert snippet” and the trigger, to paste its text." />
+          </Typography>
+        </Stack>
+      </DialogTitle>
+      <DialogContent dividers>
+        {snippets === null ? (
+          <Stack alignItems="center" sx={{ py: 4 }}>
+            <CircularProgress size={24} />
+          </Stack>
+        ) : sortedSnippets.length === 0 ? (
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="You have no snippets yet." />
+          </Typography>
+        ) : (
+          <Stack spacing={1}>{sortedSnippets.map(renderSnippet)}</Stack>
+        )}
+
+        <Stack spacing={1.5} sx={{ mt: 3 }}>
+          <Typography variant="subtitle2">
+            {editing ? (
+              <FormattedMessage defaultMessage="Edit snippet" />
+            ) : (
+              <FormattedMessage defaultMessage="Add a snippet" />
+            )}
+          </Typography>
+          <TextField
+            size="small"
+            label={<FormattedMessage defaultMessage="Trigger" />}
+            value={trigger}
+            onChange={(event) => setTrigger(event.target.value)}
+          />
+          <TextField
+            size="small"
+            multiline
+            minRows={3}
+            label={<FormattedMessage defaultMessage="Text to paste" />}
+            helperText={
+              <FormattedMessage
+                defaultMessage="Placeholders such as {variables} are filled in when the snippet is pasted."
+                values={{ variables: SNIPPET_VARIABLES_HINT }}
+              />
+            }
+            value={expansion}
+            onChange={(event) => setExpansion(event.target.value)}
+          />
+          <Stack direction="row" spacing={1} justifyContent="flex-end">
+            {editing && (
+              <Button onClick={resetForm} disabled={isSaving}>
+                <FormattedMessage defaultMessage="Cancel" />
+              </Button>
+            )}
+            <Button
+              variant="contained"
+              onClick={() => void handleSave()}
+              disabled={!canSave}
+            >
+              {editing ? (
+                <FormattedMessage defaultMessage="Save" />
+              ) : (
+                <FormattedMessage defaultMessage="Add" />
+              )}
+            </Button>
+          </Stack>
+        </Stack>
+      </DialogContent>
+      <DialogActions>
+        <Button onClick={handleClose}>
+          <FormattedMessage defaultMessage="Close" />
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+};
@@ -0,0 +1,208 @@
+import AddIcon from "@mui/icons-material/Add";
+import ArrowDownwardIcon from "@mui/icons-material/ArrowDownward";
+import ArrowUpwardIcon from "@mui/icons-material/ArrowUpward";
+import CloseIcon from "@mui/icons-material/Close";
+import {
+  Box,
+  FormControl,
+  IconButton,
+  InputLabel,
+  MenuItem,
+  Paper,
+  Select,
+  Stack,
+  Typography,
+} from "@mui/material";
+import { TranscriptionFallbackStep } from "@repo/types";
+import { useCallback, useEffect, useMemo } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { loadApiKeys } from "../../actions/api-key.actions";
+import { setTranscriptionFallbackChain } from "../../actions/user.actions";
+import { SettingsApiKey } from "../../state/settings.state";
+import { useAppStore } from "../../store";
+
+const LOCAL_STEP_VALUE = "local";
+
+// Providers the backend can transcribe with when it walks the chain.
+const canServeFallback = (key: SettingsApiKey): boolean => {
+  if (key.provider === "azure") {
+    return !!key.azureRegion;
+  }
+
+  return (
+    key.provider === "openai" ||
+    key.provider === "groq" ||
+    key.provider === "deepgram"
+  );
+};
+
+const getStepValue = (step: TranscriptionFallbackStep): string =>
+  step.mode === "api" ? step.apiKeyId : LOCAL_STEP_VALUE;
+
+export const TranscriptionFallbackChainSetting = () => {
+  const intl = useIntl();
+  const chain = useAppStore(
+    (state) => state.userPrefs?.transcriptionFallbackChain ?? [],
+  );
+  const apiKeys = useAppStore((state) => state.settings.apiKeys);
+  const apiKeysStatus = useAppStore((state) => state.settings.apiKeysStatus);
+
+  useEffect(() => {
+    if (apiKeysStatus === "idle") {
+      void loadApiKeys();
+    }
+  }, [apiKeysStatus]);
+
+  const eligibleKeys = useMemo(
+    () => apiKeys.filter(canServeFallback),
+    [apiKeys],
+  );
+
+  const getStepLabel = useCallback(
+    (step: TranscriptionFallbackStep) => {
+      if (step.mode === "local") {
+        return intl.formatMessage({ defaultMessage: "Local model" });
+      }
+
+      const key = apiKeys.find((k) => k.id === step.apiKeyId);
+      return key
+        ? `${key.name} (${key.provider})`
+        : intl.formatMessage({ defaultMessage: "Deleted API key" });
+    },
+    [apiKeys, intl],
+  );
+
+  const availableToAdd = useMemo(() => {
+    const used = new Set(chain.map(getStepValue));
+    const options = eligibleKeys
+      .filter((key) => !used.has(key.id))
+      .map((key) => ({
+        value: key.id,
+        label: `${key.name} (${key.provider})`,
+      }));
+    if (!used.has(LOCAL_STEP_VALUE)) {
+      options.push({
+        value: LOCAL_STEP_VALUE,
+        label: intl.formatMessage({ defaultMessage: "Local model" }),
+      });
+    }
+    return options;
+  }, [chain, eligibleKeys, intl]);
+
+  const handleAddStep = useCallback(
+    (value: string) => {
+      const step: TranscriptionFallbackStep =
+        value === LOCAL_STEP_VALUE
+          ? { mode: "local" }
+          : { mode: "api", apiKeyId: value };
+      void setTranscriptionFallbackChain([...chain, step]);
+    },
+    [chain],
+  );
+
+  const handleRemoveStep = useCallback(
+    (index: number) => {
+      void setTranscriptionFallbackChain(chain.filter((_, i) => i !== index));
+    },
+    [chain],
+  );
+
+  const handleMoveStep = useCallback(
+    (index: number, direction: "up" | "down") => {
+      const newChain = [...chain];
+      const newIndex = direction === "up" ? index - 1 : index + 1;
+      if (newIndex < 0 || newIndex >= newChain.length) {
+        return;
+      }
+      [newChain[index], newChain[newIndex]] = [
+        newChain[newIndex],
+        newChain[index],
+      ];
+      void setTranscriptionFallbackChain(newChain);
+    },
+    [chain],
+  );
+
+  return (
+    <Box sx={{ width: "100%" }}>
+      <Typography variant="body2" fontWeight={600} sx={{ mb: 0.5 }}>
+        <FormattedMessage defaultMessage="Fallback order" />
+      </Typography>
+      <Typography
+        variant="caption"
+        color="text.secondary"
+        sx={{ mb: 1.5, display: "block" }}
+      >
+        <FormattedMessage defaultMessage="Providers are tried from the top. When one fails or is rate limited, the next one transcribes instead. Leave empty to use only the selection above." />
+      </Typography>
+
+      {chain.length > 0 && (
+        <Stack spacing={0.5} sx={{ mb: 1.5 }}>
+          {chain.map((step, index) => (
+            <Paper
+              key={getStepValue(step)}
+              variant="outlined"
+              sx={{
+                px: 1.5,
+                py: 0.75,
+                display: "flex",
+                alignItems: "center",
+                gap: 1,
+              }}
+            >
+              <Stack direction="row" spacing={0.25}>
+                <IconButton
+                  size="small"
+                  disabled={index === 0}
+                  onClick={() => handleMoveStep(index, "up")}
+                  sx={{ p: 0.25 }}
+                >
+                  <ArrowUpwardIcon fontSize="small" />
+                </IconButton>
+                <IconButton
+                  size="small"
+                  disabled={index === chain.length - 1}
+                  onClick={() => handleMoveStep(index, "down")}
+                  sx={{ p: 0.25 }}
+                >
+                  <ArrowDownwardIcon fontSize="small" />
+                </IconButton>
+              </Stack>
+              <Typography variant="body2" sx={{ flex: 1 }}>
+                {getStepLabel(step)}
+              </Typography>
+              <IconButton
+                size="small"
+                onClick={() => handleRemoveStep(index)}
+                sx={{ p: 0.25 }}
+              >
+                <CloseIcon fontSize="small" />
+              </IconButton>
+            </Paper>
+          ))}
+        </Stack>
+      )}
+
+      {availableToAdd.length > 0 && (
+        <FormControl size="small" sx={{ minWidth: 200 }}>
+          <InputLabel id="add-fallback-step-label">
+            <FormattedMessage defaultMessage="Add provider" />
+          </InputLabel>
+          <Select
+            labelId="add-fallback-step-label"
+            value=""
+            label={<FormattedMessage defaultMessage="Add provider" />}
+            onChange={(e) => handleAddStep(e.target.value)}
+            startAdornment={<AddIcon fontSize="small" sx={{ mr: 0.5 }} />}
+          >
+            {availableToAdd.map((option) => (
+              <MenuItem key={option.value} value={option.value}>
+                {option.label}
+              </MenuItem>
+            ))}
+          </Select>
+        </FormControl>
+      )}
+    </Box>
+  );
+};
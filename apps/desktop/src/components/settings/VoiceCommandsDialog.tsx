@@ -0,0 +1,289 @@
+import DeleteOutlineRoundedIcon from "@mui/icons-material/DeleteOutlineRounded";
+import RestartAltRoundedIcon from "@mui/icons-material/RestartAltRounded";
+import {
+  Button,
+  CircularProgress,
+  Dialog,
+  DialogActions,
+  DialogContent,
+  DialogTitle,
+  IconButton,
+  MenuItem,
+  Stack,
+  Switch,
+  TextField,
+  Typography,
+} from "@mui/material";
+import { VoiceCommand, VoiceCommandAction } from "@repo/types";
+import { useCallback, useEffect, useMemo, useState } from "react";
+import { FormattedMessage, useIntl } from "react-intl";
+import { showErrorSnackbar } from "../../actions/app.actions";
+import { getVoiceCommandRepo } from "../../repos";
+import { produceAppState, useAppStore } from "../../store";
+import { createId } from "../../utils/id.utils";
+import { getMyDictationLanguage } from "../../utils/user.utils";
+
+const DEFAULT_ID_PREFIX = "default:";
+
+const describeValue = (value: string): string =>
+  value.replace(/\n/g, "↵").replace(/ /g, "·");
+
+export const VoiceCommandsDialog = () => {
+  const intl = useIntl();
+  const open = useAppStore((state) => state.settings.voiceCommandsDialogOpen);
+  const dictationLanguage = useAppStore((state) =>
+    getMyDictationLanguage(state).split("-")[0].toLowerCase(),
+  );
+  const [commands, setCommands] = useState<VoiceCommand[] | null>(null);
+  const [language, setLanguage] = useState(dictationLanguage);
+  const [phrase, setPhrase] = useState("");
+  const [action, setAction] = useState<VoiceCommandAction>("insert");
+  const [value, setValue] = useState("");
+  const [isSaving, setIsSaving] = useState(false);
+
+  const loadCommands = useCallback(async () => {
+    try {
+      setCommands(await getVoiceCommandRepo().listVoiceCommands());
+    } catch (error) {
+      showErrorSnackbar(error);
+      setCommands([]);
+    }
+  }, []);
+
+  useEffect(() => {
+    if (open) {
+      setLanguage(dictationLanguage);
+      void loadCommands();
+    }
+  }, [open, dictationLanguage, loadCommands]);
+
+  const sortedCommands = useMemo(
+    () =>
+      [...(commands ?? [])].sort(
+        (left, right) =>
+          left.language.localeCompare(right.language) ||
+          left.phrase.localeCompare(right.phrase),
+      ),
+    [commands],
+  );
+
+  const getActionLabel = useCallback(
+    (command: VoiceCommand) => {
+      switch (command.action) {
+        case "insert":
+          return intl.formatMessage(
+            { defaultMessage: "Inserts {value}" },
+            { value: describeValue(command.value ?? "") },
+          );
+        case "delete_last":
+          return intl.formatMessage({
+            defaultMessage: "Deletes the last sentence",
+          });
+        case "all_caps":
+          return intl.formatMessage({
+            defaultMessage: "Upper-cases the following words",
+          });
+        case "escape":
+          return intl.formatMessage({
+            defaultMessage: "Types the next command as text",
+          });
+      }
+    },
+    [intl],
+  );
+
+  const handleClose = () => {
+    produceAppState((draft) => {
+      draft.settings.voiceCommandsDialogOpen = false;
+    });
+  };
+
+  const handleToggle = async (command: VoiceCommand, isEnabled: boolean) => {
+    try {
+      await getVoiceCommandRepo().saveVoiceCommand({ ...command, isEnabled });
+      await loadCommands();
+    } catch (error) {
+      showErrorSnackbar(error);
+    }
+  };
+
+  const handleDelete = async (command: VoiceCommand) => {
+    try {
+      await getVoiceCommandRepo().deleteVoiceCommand(command.id);
+      await loadCommands();
+    } catch (error) {
+      showErrorSnackbar(error);
+    }
+  };
+
+  const handleAdd = async () => {
+    setIsSaving(true);
+    try {
+      await getVoiceCommandRepo().saveVoiceCommand({
+        id: createId(),
+        language,
+        phrase,
+        action,
+        value: action === "insert" ? value.replace(/\\n/g, "\n") : null,
+        isEnabled: true,
+      });
+      setPhrase("");
+      setValue("");
+      await loadCommands();
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const renderCommand = (command: VoiceCommand) => {
+    const isOverride =
+      !command.isDefault && command.id.startsWith(DEFAULT_ID_PREFIX);
+
+    return (
+      <Stack key={command.id} direction="row" spacing={1.5} alignItems="center">
+        <Switch
+          size="small"
+          checked={command.isEnabled}
+          onChange={(event) =>
+            void handleToggle(command, event.target.checked)
+          }
+        />
+        <Typography
+          variant="caption"
+          color="text.secondary"
+          sx={{ width: 24 }}
+        >
+          {command.language}
+        </Typography>
+        <Stack sx={{ flex: 1, minWidth: 0 }}>
+          <Typography variant="body2" fontWeight={600} noWrap>
+            {command.phrase}
+          </Typography>
+          <Typography variant="caption" color="text.secondary" noWrap>
+            {getActionLabel(command)}
+          </Typography>
+        </Stack>
+        {!command.isDefault && (
+          <IconButton
+            size="small"
+            aria-label={
+              isOverride
+                ? intl.formatMessage({ defaultMessage: "Reset to default" })
+                : intl.formatMessage({ defaultMessage: "Delete command" })
+            }
+            onClick={() => void handleDelete(command)}
+          >
+            {isOverride ? (
+              <RestartAltRoundedIcon fontSize="small" />
+            ) : (
+              <DeleteOutlineRoundedIcon fontSize="small" />
+            )}
+          </IconButton>
+        )}
+      </Stack>
+    );
+  };
+
+  const canAdd =
+    !isSaving &&
+    phrase.trim().length > 0 &&
+    language.trim().length > 0 &&
+    (action !== "insert" || value.length > 0);
+
+  return (
+    <Dialog open={open} onClose={handleClose} fullWidth maxWidth="sm">
+      <DialogTitle>
+        <Stack spacing={1}>
+          <Typography variant="h6">
+            <FormattedMessage defaultMessage="Voice commands" />
+          </Typography>
+          <Typography variant="body2" color="textSecondary">
+            <FormattedMessage defaultMessage="Say a command on its own between pauses, or after the word “command”, for example “command new line”." />
+          </Typography>
+        </Stack>
+      </DialogTitle>
+      <DialogContent dividers>
+        {commands === null ? (
+          <Stack alignItems="center" sx={{ py: 4 }}>
+            <CircularProgress size={24} />
+          </Stack>
+        ) : (
+          <Stack spacing={1}>{sortedCommands.map(renderCommand)}</Stack>
+        )}
+
+        <Stack spacing={1.5} sx={{ mt: 3 }}>
+          <Typography variant="subtitle2">
+            <FormattedMessage defaultMessage="Add a command" />
+          </Typography>
+          <Stack direction="row" spacing={1}>
+            <TextField
+              size="small"
+              label={<FormattedMessage defaultMessage="Language" />}
+              value={language}
+              onChange={(event) => setLanguage(event.target.value)}
+              sx={{ width: 96 }}
+            />
+            <TextField
+              size="small"
+              label={<FormattedMessage defaultMessage="Phrase" />}
+              value={phrase}
+              onChange={(event) => setPhrase(event.target.value)}
+              sx={{ flex: 1 }}
+            />
+          </Stack>
+          <Stack direction="row" spacing={1}>
+            <TextField
+              select
+              size="small"
+              label={<FormattedMessage defaultMessage="Action" />}
+              value={action}
+              onChange={(event) =>
+                setAction(event.target.value as VoiceCommandAction)
+              }
+              sx={{ width: 200 }}
+            >
+              <MenuItem value="insert">
+                <FormattedMessage defaultMessage="Insert text" />
+              </MenuItem>
+              <MenuItem value="delete_last">
+                <FormattedMessage defaultMessage="Delete last sentence" />
+              </MenuItem>
+              <MenuItem value="all_caps">
+                <FormattedMessage defaultMessage="All caps" />
+              </MenuItem>
+              <MenuItem value="escape">
+                <FormattedMessage defaultMessage="Type next command" />
+              </MenuItem>
+            </TextField>
+            {action === "insert" && (
+              <TextField
+                size="small"
+                label={<FormattedMessage defaultMessage="Text to insert" />}
+                placeholder={intl.formatMessage({
+                  defaultMessage: "Use \\n for a line break",
+                })}
+                value={value}
+                onChange={(event) => setValue(event.target.value)}
+                sx={{ flex: 1 }}
+              />
+            )}
+            <Button
+              variant="contained"
+              onClick={() => void handleAdd()}
+              disabled={!canAdd}
+            >
+              <FormattedMessage defaultMessage="Add" />
+            </Button>
+          </Stack>
+        </Stack>
+      </DialogContent>
+      <DialogActions>
+        <Button onClick={handleClose}>
+          <FormattedMessage defaultMessage="Close" />
+        </Button>
+      </DialogActions>
+    </Dialog>
+  );
+};
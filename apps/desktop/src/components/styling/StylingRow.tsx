@@ -1,14 +1,19 @@
 import { Check, MoreVert } from "@mui/icons-material";
 import { Box, IconButton, Stack, Typography } from "@mui/material";
+import type { InjectionStrategy } from "@repo/types";
 import { getRec } from "@repo/utilities";
 import { useCallback } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
 import {
+  setAppTargetCodeMode,
+  setAppTargetInjection,
+  setAppTargetInverseTextNormalization,
   setAppTargetPasteKeybind,
+  setAppTargetRichTextPaste,
   setAppTargetTone,
 } from "../../actions/app-target.actions";
 import { useAppStore } from "../../store";
-import { isMacOS } from "../../utils/env.utils";
+import { isLinux, isMacOS } from "../../utils/env.utils";
 import { ListTile } from "../common/ListTile";
 import {
   MenuPopoverBuilder,
@@ -18,6 +23,13 @@ import { StorageImage } from "../common/StorageImage";
 import { ToneSelect } from "../tones/ToneSelect";
 import { PostProcessingDisabledTooltip } from "./PostProcessingDisabledTooltip";
 
+// Slower typing for remote desktops and apps that drop fast keystrokes:
+// milliseconds between characters, or between chunks for chunked typing.
+const SLOW_TYPING_DELAY_MS: Record<string, number> = {
+  typing: 25,
+  chunked_typing: 250,
+};
+
 export type StylingRowProps = {
   id: string;
 };
@@ -57,10 +69,82 @@ export const StylingRow = ({ id }: StylingRowProps) => {
     [target],
   );
 
+  const handleInverseTextNormalizationToggle = useCallback(() => {
+    if (!target) {
+      return;
+    }
+
+    void setAppTargetInverseTextNormalization(
+      target.id,
+      !(target.inverseTextNormalization ?? true),
+    );
+  }, [target]);
+
+  const handleCodeModeToggle = useCallback(() => {
+    if (!target) {
+      return;
+    }
+
+    void setAppTargetCodeMode(target.id, !(target.codeModeEnabled ?? false));
+  }, [target]);
+
+  const handleRichTextPasteToggle = useCallback(() => {
+    if (!target) {
+      return;
+    }
+
+    void setAppTargetRichTextPaste(target.id, !(target.richTextPaste ?? false));
+  }, [target]);
+
+  const handleInjectionChange = useCallback(
+    (strategy: InjectionStrategy, typingDelayMs: number | null) => {
+      if (!target) {
+        return;
+      }
+
+      void setAppTargetInjection(target.id, strategy, typingDelayMs);
+    },
+    [target],
+  );
+
   const toneValue = target?.toneId ?? null;
   const pasteKeybindValue = target?.pasteKeybind ?? "ctrl+v";
+  const injectionValue = target?.injectionStrategy ?? "clipboard";
+  const slowTypingDelayMs = SLOW_TYPING_DELAY_MS[injectionValue];
+
+  const formattingMenuItems: MenuPopoverItem[] = [
+    {
+      kind: "listItem",
+      title: <FormattedMessage defaultMessage="Format numbers and dates" />,
+      trailing:
+        (target?.inverseTextNormalization ?? true) ? <Check /> : undefined,
+      onClick: ({ close }) => {
+        handleInverseTextNormalizationToggle();
+        close();
+      },
+    },
+    {
+      kind: "listItem",
+      title: <FormattedMessage defaultMessage="Code mode" />,
+      trailing: target?.codeModeEnabled ? <Check /> : undefined,
+      onClick: ({ close }) => {
+        handleCodeModeToggle();
+        close();
+      },
+    },
+    {
+      kind: "listItem",
+      title: <FormattedMessage defaultMessage="Paste formatted text" />,
+      trailing: target?.richTextPaste ? <Check /> : undefined,
+      onClick: ({ close }) => {
+        handleRichTextPasteToggle();
+        close();
+      },
+    },
+  ];
 
   const pasteKeybindMenuItems: MenuPopoverItem[] = [
+    { kind: "divider" },
     {
       kind: "genericItem",
       builder: () => (
@@ -95,6 +179,85 @@ export const StylingRow = ({ id }: StylingRowProps) => {
     },
   ];
 
+  const injectionOptions: {
+    strategy: InjectionStrategy;
+    title: React.ReactNode;
+  }[] = [
+    {
+      strategy: "clipboard",
+      title: <FormattedMessage defaultMessage="Paste (default)" />,
+    },
+    {
+      strategy: "typing",
+      title: <FormattedMessage defaultMessage="Type the text" />,
+    },
+    {
+      strategy: "chunked_typing",
+      title: <FormattedMessage defaultMessage="Type in chunks" />,
+    },
+    {
+      strategy: "clipboard_only",
+      title: <FormattedMessage defaultMessage="Copy to clipboard only" />,
+    },
+  ];
+  if (isLinux()) {
+    injectionOptions.splice(3, 0, {
+      strategy: "primary_selection",
+      title: <FormattedMessage defaultMessage="Middle-click paste" />,
+    });
+  }
+
+  const injectionSubMenuItems: MenuPopoverItem[] = [
+    {
+      kind: "genericItem",
+      builder: () => (
+        <Box sx={{ px: 2, py: 1.5, maxWidth: 280 }}>
+          <Typography variant="body2" color="text.secondary">
+            <FormattedMessage defaultMessage="Try typing if pasting does not work in this app, such as in remote desktops or games." />
+          </Typography>
+        </Box>
+      ),
+    },
+    { kind: "divider" },
+    ...injectionOptions.map(
+      ({ strategy, title }): MenuPopoverItem => ({
+        kind: "listItem",
+        title,
+        trailing: injectionValue === strategy ? <Check /> : undefined,
+        onClick: ({ close }) => {
+          handleInjectionChange(strategy, null);
+          close();
+        },
+      }),
+    ),
+  ];
+  if (slowTypingDelayMs !== undefined) {
+    injectionSubMenuItems.push(
+      { kind: "divider" },
+      {
+        kind: "listItem",
+        title: <FormattedMessage defaultMessage="Type slowly" />,
+        trailing: target?.typingDelayMs ? <Check /> : undefined,
+        onClick: ({ close }) => {
+          handleInjectionChange(
+            injectionValue,
+            target?.typingDelayMs ? null : slowTypingDelayMs,
+          );
+          close();
+        },
+      },
+    );
+  }
+
+  const injectionMenuItems: MenuPopoverItem[] = [
+    { kind: "divider" },
+    {
+      kind: "subMenu",
+      title: <FormattedMessage defaultMessage="Text insertion" />,
+      children: injectionSubMenuItems,
+    },
+  ];
+
   const leading = (
     <Box
       sx={{
@@ -131,21 +294,29 @@ export const StylingRow = ({ id }: StylingRowProps) => {
           formControlSx={{ minWidth: 140 }}
         />
       </PostProcessingDisabledTooltip>
-      {!isMacOS() && (
-        <MenuPopoverBuilder items={pasteKeybindMenuItems}>
-          {({ ref, open }) => (
-            <IconButton
-              ref={ref}
-              onClick={open}
-              disabled={!target}
-              size="small"
-              sx={{ width: 32, height: 32, p: 0 }}
-            >
-              <MoreVert fontSize="small" />
-            </IconButton>
-          )}
-        </MenuPopoverBuilder>
-      )}
+      <MenuPopoverBuilder
+        items={
+          isMacOS()
+            ? [...formattingMenuItems, ...injectionMenuItems]
+            : [
+                ...formattingMenuItems,
+                ...injectionMenuItems,
+                ...pasteKeybindMenuItems,
+              ]
+        }
+      >
+        {({ ref, open }) => (
+          <IconButton
+            ref={ref}
+            onClick={open}
+            disabled={!target}
+            size="small"
+            sx={{ width: 32, height: 32, p: 0 }}
+          >
+            <MoreVert fontSize="small" />
+          </IconButton>
+        )}
+      </MenuPopoverBuilder>
     </Stack>
   );
 
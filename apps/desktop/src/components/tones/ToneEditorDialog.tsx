@@ -1,17 +1,19 @@
 import { DeleteForeverOutlined } from "@mui/icons-material";
 import SaveIcon from "@mui/icons-material/Save";
 import {
+  Alert,
   Box,
   Button,
   Dialog,
   DialogActions,
   DialogContent,
   DialogTitle,
+  MenuItem,
   Stack,
   TextField,
   Typography,
 } from "@mui/material";
-import { Tone } from "@repo/types";
+import { Tone, TranslationMode } from "@repo/types";
 import { useCallback, useEffect, useMemo, useState } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
 import { setAppTargetTone } from "../../actions/app-target.actions";
@@ -20,6 +22,8 @@ import {
   deleteTone,
   upsertTone,
 } from "../../actions/tone.actions";
+import { getToneRepo } from "../../repos";
+import { TonePreview } from "../../repos/tone.repo";
 import { useAppStore } from "../../store";
 import { createId } from "../../utils/id.utils";
 import { ConfirmDialog } from "../common/ConfirmDialog";
@@ -30,6 +34,12 @@ export const ToneEditorDialog = () => {
   const intl = useIntl();
   const toneEditor = useAppStore((state) => state.toneEditor);
   const toneById = useAppStore((state) => state.toneById);
+  const latestTranscriptionId = useAppStore((state) => {
+    const [latest] = Object.values(state.transcriptionById).sort((a, b) =>
+      b.createdAt.localeCompare(a.createdAt),
+    );
+    return latest?.id ?? null;
+  });
 
   const tones = useMemo(
     () =>
@@ -48,7 +58,11 @@ export const ToneEditorDialog = () => {
   }, []);
 
   const handleCreate = useCallback(
-    async (name: string, promptTemplate: string) => {
+    async (
+      name: string,
+      promptTemplate: string,
+      translationMode: TranslationMode,
+    ) => {
       const nextSortOrder =
         tones.length > 0 ? tones[tones.length - 1].sortOrder + 1 : 0;
 
@@ -59,6 +73,7 @@ export const ToneEditorDialog = () => {
         isSystem: false,
         createdAt: Date.now(),
         sortOrder: nextSortOrder,
+        translationMode: translationMode === "off" ? null : translationMode,
       };
 
       await upsertTone(newTone);
@@ -82,17 +97,24 @@ export const ToneEditorDialog = () => {
   const tone = isEditMode && editingTone ? editingTone : null;
   const [name, setName] = useState("");
   const [promptTemplate, setPromptTemplate] = useState("");
+  const [translationMode, setTranslationMode] =
+    useState<TranslationMode>("off");
   const [isSaving, setIsSaving] = useState(false);
   const [isDeleting, setIsDeleting] = useState(false);
   const [isConfirmOpen, setIsConfirmOpen] = useState(false);
+  const [preview, setPreview] = useState<TonePreview | null>(null);
+  const [previewError, setPreviewError] = useState<string | null>(null);
+  const [isPreviewing, setIsPreviewing] = useState(false);
 
   useEffect(() => {
     if (isEditMode && tone) {
       setName(tone.name);
       setPromptTemplate(tone.promptTemplate);
+      setTranslationMode(tone.translationMode ?? "off");
     } else if (toneEditor.mode === "create") {
       setName("");
       setPromptTemplate("");
+      setTranslationMode("off");
     }
   }, [isEditMode, tone, toneEditor.mode, toneEditor.open]);
 
@@ -100,12 +122,37 @@ export const ToneEditorDialog = () => {
     if (!toneEditor.open) {
       setIsConfirmOpen(false);
     }
+    setPreview(null);
+    setPreviewError(null);
   }, [toneEditor.open]);
 
+  const handlePreview = useCallback(async () => {
+    if (!latestTranscriptionId || !promptTemplate.trim()) {
+      return;
+    }
+
+    setIsPreviewing(true);
+    setPreviewError(null);
+    try {
+      const result = await getToneRepo().previewTone({
+        transcriptionId: latestTranscriptionId,
+        promptTemplate: promptTemplate.trim(),
+      });
+      setPreview(result);
+    } catch (error) {
+      setPreview(null);
+      setPreviewError(String(error));
+    } finally {
+      setIsPreviewing(false);
+    }
+  }, [latestTranscriptionId, promptTemplate]);
+
   const hasChanges =
     isEditMode &&
     tone &&
-    (name !== tone.name || promptTemplate !== tone.promptTemplate);
+    (name !== tone.name ||
+      promptTemplate !== tone.promptTemplate ||
+      translationMode !== (tone.translationMode ?? "off"));
 
   const handleSave = useCallback(async () => {
     const trimmedName = name.trim();
@@ -122,9 +169,10 @@ export const ToneEditorDialog = () => {
           ...tone,
           name: trimmedName,
           promptTemplate: trimmedPrompt,
+          translationMode: translationMode === "off" ? null : translationMode,
         });
       } else {
-        await handleCreate(trimmedName, trimmedPrompt);
+        await handleCreate(trimmedName, trimmedPrompt, translationMode);
       }
       handleClose();
     } finally {
@@ -133,6 +181,7 @@ export const ToneEditorDialog = () => {
   }, [
     name,
     promptTemplate,
+    translationMode,
     isEditMode,
     tone,
     handleEditSave,
@@ -171,6 +220,7 @@ export const ToneEditorDialog = () => {
     if (isEditMode && tone) {
       setName(tone.name);
       setPromptTemplate(tone.promptTemplate);
+      setTranslationMode(tone.translationMode ?? "off");
     }
     handleClose();
   }, [isEditMode, tone, handleClose]);
@@ -220,7 +270,11 @@ export const ToneEditorDialog = () => {
             <TextField
               label={<FormattedMessage defaultMessage="Prompt" />}
               value={promptTemplate}
-              onChange={(event) => setPromptTemplate(event.target.value)}
+              onChange={(event) => {
+                setPromptTemplate(event.target.value);
+                setPreview(null);
+                setPreviewError(null);
+              }}
               multiline
               rows={12}
               fullWidth
@@ -238,6 +292,81 @@ export const ToneEditorDialog = () => {
                 </Typography>
               }
             />
+
+            <Stack spacing={1}>
+              <Box sx={{ display: "flex", alignItems: "center", gap: 1 }}>
+                <Button
+                  variant="outlined"
+                  size="small"
+                  onClick={handlePreview}
+                  disabled={
+                    isPreviewing ||
+                    !latestTranscriptionId ||
+                    !promptTemplate.trim()
+                  }
+                >
+                  <FormattedMessage defaultMessage="Preview" />
+                </Button>
+                <Typography variant="caption" color="text.secondary">
+                  {latestTranscriptionId ? (
+                    <FormattedMessage defaultMessage="Fills in the variables using your latest transcription." />
+                  ) : (
+                    <FormattedMessage defaultMessage="Dictate something first to preview this style." />
+                  )}
+                </Typography>
+              </Box>
+              {previewError && <Alert severity="error">{previewError}</Alert>}
+              {preview?.warning && (
+                <Alert severity="warning">{preview.warning}</Alert>
+              )}
+              {preview && (
+                <Box
+                  sx={{
+                    p: 1.5,
+                    borderRadius: 1,
+                    bgcolor: "action.hover",
+                    whiteSpace: "pre-wrap",
+                  }}
+                >
+                  <Typography variant="body2">{preview.rendered}</Typography>
+                  {preview.variables.length > 0 && (
+                    <Typography
+                      variant="caption"
+                      color="text.secondary"
+                      sx={{ display: "block", mt: 1 }}
+                    >
+                      <FormattedMessage
+                        defaultMessage="Variables: {variables}"
+                        values={{ variables: preview.variables.join(", ") }}
+                      />
+                    </Typography>
+                  )}
+                </Box>
+              )}
+            </Stack>
+
+            <TextField
+              select
+              label={<FormattedMessage defaultMessage="Translation" />}
+              value={translationMode}
+              onChange={(event) =>
+                setTranslationMode(event.target.value as TranslationMode)
+              }
+              fullWidth
+              helperText={
+                <FormattedMessage defaultMessage="Dictations in this style can be translated to English before pasting." />
+              }
+            >
+              <MenuItem value="off">
+                <FormattedMessage defaultMessage="Off" />
+              </MenuItem>
+              <MenuItem value="english">
+                <FormattedMessage defaultMessage="English only" />
+              </MenuItem>
+              <MenuItem value="bilingual">
+                <FormattedMessage defaultMessage="Original and English" />
+              </MenuItem>
+            </TextField>
           </Stack>
         </DialogContent>
 
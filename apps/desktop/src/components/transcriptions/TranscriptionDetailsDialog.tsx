@@ -1,3 +1,4 @@
+import EditOutlinedIcon from "@mui/icons-material/EditOutlined";
 import ReplayRoundedIcon from "@mui/icons-material/ReplayRounded";
 import {
   Box,
@@ -8,14 +9,16 @@ import {
   DialogTitle,
   Divider,
   Stack,
+  TextField,
   Typography,
 } from "@mui/material";
 import { getRec } from "@repo/utilities";
-import { useCallback, useMemo, useState } from "react";
+import { useCallback, useEffect, useMemo, useState } from "react";
 import { FormattedMessage, useIntl } from "react-intl";
 import { showErrorSnackbar } from "../../actions/app.actions";
 import {
   closeTranscriptionDetailsDialog,
+  editTranscriptionText,
   retranscribeTranscription,
 } from "../../actions/transcriptions.actions";
 import { AppState } from "../../state/app.state";
@@ -115,6 +118,14 @@ export const TranscriptionDetailsDialog = () => {
   const apiKeysById = useAppStore((state) => state.apiKeyById);
   const intl = useIntl();
   const [isRetranscribing, setIsRetranscribing] = useState(false);
+  const [editedTranscript, setEditedTranscript] = useState<string | null>(
+    null,
+  );
+  const [isSavingEdit, setIsSavingEdit] = useState(false);
+
+  useEffect(() => {
+    setEditedTranscript(null);
+  }, [open, transcription?.id]);
 
   const handleClose = useCallback(() => {
     closeTranscriptionDetailsDialog();
@@ -151,6 +162,28 @@ export const TranscriptionDetailsDialog = () => {
     [intl, transcription?.id],
   );
 
+  const handleSaveEdit = useCallback(async () => {
+    if (!transcription?.id || editedTranscript === null) {
+      return;
+    }
+
+    const trimmed = editedTranscript.trim();
+    if (!trimmed || trimmed === transcription.transcript) {
+      setEditedTranscript(null);
+      return;
+    }
+
+    try {
+      setIsSavingEdit(true);
+      await editTranscriptionText(transcription.id, trimmed);
+      setEditedTranscript(null);
+    } catch (error) {
+      showErrorSnackbar(error);
+    } finally {
+      setIsSavingEdit(false);
+    }
+  }, [editedTranscript, transcription?.id, transcription?.transcript]);
+
   const transcriptionModeLabel = useMemo(() => {
     if (transcription?.transcriptionMode === "api") {
       return <FormattedMessage defaultMessage="API" />;
@@ -303,15 +336,64 @@ export const TranscriptionDetailsDialog = () => {
                       monospace: true,
                     },
                   )}
-                {renderTextBlock(
-                  <FormattedMessage defaultMessage="Final transcription" />,
-                  finalTranscriptText,
-                  {
-                    placeholder: (
-                      <FormattedMessage defaultMessage="Final transcript unavailable." />
-                    ),
-                    monospace: true,
-                  },
+                {editedTranscript === null ? (
+                  <Box>
+                    {renderTextBlock(
+                      <FormattedMessage defaultMessage="Final transcription" />,
+                      finalTranscriptText,
+                      {
+                        placeholder: (
+                          <FormattedMessage defaultMessage="Final transcript unavailable." />
+                        ),
+                        monospace: true,
+                      },
+                    )}
+                    <Button
+                      size="small"
+                      startIcon={<EditOutlinedIcon />}
+                      onClick={() => setEditedTranscript(finalTranscriptText)}
+                      disabled={isRetranscribing}
+                      sx={{ mt: 0.5 }}
+                    >
+                      <FormattedMessage defaultMessage="Correct transcript" />
+                    </Button>
+                  </Box>
+                ) : (
+                  <Box>
+                    <TextField
+                      label={
+                        <FormattedMessage defaultMessage="Final transcription" />
+                      }
+                      value={editedTranscript}
+                      onChange={(event) =>
+                        setEditedTranscript(event.target.value)
+                      }
+                      multiline
+                      minRows={3}
+                      fullWidth
+                      autoFocus
+                      helperText={
+                        <FormattedMessage defaultMessage="Words you correct here repeatedly are suggested as replacement rules in your dictionary." />
+                      }
+                    />
+                    <Stack direction="row" spacing={1} sx={{ mt: 1 }}>
+                      <Button
+                        size="small"
+                        variant="contained"
+                        onClick={handleSaveEdit}
+                        disabled={isSavingEdit || !editedTranscript.trim()}
+                      >
+                        <FormattedMessage defaultMessage="Save" />
+                      </Button>
+                      <Button
+                        size="small"
+                        onClick={() => setEditedTranscript(null)}
+                        disabled={isSavingEdit}
+                      >
+                        <FormattedMessage defaultMessage="Cancel" />
+                      </Button>
+                    </Stack>
+                  </Box>
                 )}
               </Stack>
             </Box>
@@ -1,7 +1,10 @@
-import { useEffect, useRef } from "react";
+import { useEffect, useRef, useState } from "react";
 import { useAppStore } from "../store";
 import type { ActivationController } from "../utils/activation.utils";
-import { getHotkeyCombosForAction } from "../utils/keyboard.utils";
+import {
+  getHotkeyCombosForAction,
+  getKeyLabelForCode,
+} from "../utils/keyboard.utils";
 
 export const useHotkeyHold = (args: {
   actionName: string;
@@ -126,3 +129,52 @@ export const useHotkeyFire = (args: {
     previousKeysHeldRef.current = keysHeld;
   }, [keysHeld, availableCombos, args]);
 };
+
+/**
+ * Keys held while a hotkey is being recorded. The desktop portal only reports
+ * shortcuts that are already bound, so under it keys are read from this
+ * window instead of the key listener.
+ */
+export const useRecordingKeysHeld = (isRecording: boolean): string[] => {
+  const listenerKeysHeld = useAppStore((state) => state.keysHeld);
+  const readFromWindow = useAppStore(
+    (state) => state.keyListenerSource === "portal",
+  );
+  const [windowKeysHeld, setWindowKeysHeld] = useState<string[]>([]);
+
+  useEffect(() => {
+    setWindowKeysHeld([]);
+    if (!isRecording || !readFromWindow) {
+      return;
+    }
+
+    const handleKey = (event: KeyboardEvent, isPressed: boolean) => {
+      const label = getKeyLabelForCode(event.code);
+      if (!label) {
+        return;
+      }
+
+      event.preventDefault();
+      setWindowKeysHeld((held) => {
+        if (!isPressed) {
+          return held.filter((key) => key !== label);
+        }
+        return held.includes(label) ? held : [...held, label];
+      });
+    };
+    const handleKeyDown = (event: KeyboardEvent) => handleKey(event, true);
+    const handleKeyUp = (event: KeyboardEvent) => handleKey(event, false);
+    const handleBlur = () => setWindowKeysHeld([]);
+
+    window.addEventListener("keydown", handleKeyDown, true);
+    window.addEventListener("keyup", handleKeyUp, true);
+    window.addEventListener("blur", handleBlur);
+    return () => {
+      window.removeEventListener("keydown", handleKeyDown, true);
+      window.removeEventListener("keyup", handleKeyUp, true);
+      window.removeEventListener("blur", handleBlur);
+    };
+  }, [isRecording, readFromWindow]);
+
+  return readFromWindow ? windowKeysHeld : listenerKeysHeld;
+};
@@ -1,4 +1,4 @@
-import { AppTarget } from "@repo/types";
+import { AppTarget, InjectionStrategy } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { BaseRepo } from "./base.repo";
 
@@ -8,6 +8,11 @@ export type AppTargetUpsertParams = {
   toneId: string | null;
   iconPath: string | null;
   pasteKeybind: string | null;
+  inverseTextNormalization?: boolean | null;
+  codeModeEnabled?: boolean;
+  injectionStrategy?: InjectionStrategy;
+  typingDelayMs?: number | null;
+  richTextPaste?: boolean;
 };
 
 export abstract class BaseAppTargetRepo extends BaseRepo {
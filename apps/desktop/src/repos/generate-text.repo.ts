@@ -14,9 +14,11 @@ import {
   OPENROUTER_DEFAULT_MODEL,
   openrouterGenerateTextResponse,
 } from "@repo/voice-ai";
+import { invoke } from "@tauri-apps/api/core";
 import { fetch as tauriFetch } from "@tauri-apps/plugin-http";
 import { supabase } from "../supabase";
 import { PostProcessingMode } from "../types/ai.types";
+import { createId } from "../utils/id.utils";
 import { BaseRepo } from "./base.repo";
 
 export type CloudModel = "small" | "medium" | "large";
@@ -25,6 +27,8 @@ export type GenerateTextInput = {
   system?: Nullable<string>;
   prompt: string;
   jsonResponse?: JsonResponse;
+  /** Identifies the request to `cancelGenerateText`. */
+  requestId?: string;
 };
 
 export type GenerateTextMetadata = {
@@ -39,6 +43,57 @@ export type GenerateTextOutput = {
 
 export abstract class BaseGenerateTextRepo extends BaseRepo {
   abstract generateText(input: GenerateTextInput): Promise<GenerateTextOutput>;
+
+  /** Stops a running request. Only requests streamed by the backend can be. */
+  async cancelGenerateText(_requestId: string): Promise<void> {}
+}
+
+type PostProcessTextResponse = {
+  text: string;
+  model: string;
+  apiKeyId: string;
+  inferenceDevice: string;
+  durationMs: number;
+};
+
+/**
+ * Generates text in the desktop backend with the provider behind an API key.
+ * Tokens are streamed to the overlay as `post_process_delta` events.
+ */
+export class DesktopGenerateTextRepo extends BaseGenerateTextRepo {
+  private apiKeyId: string;
+
+  constructor(apiKeyId: string) {
+    super();
+    this.apiKeyId = apiKeyId;
+  }
+
+  async generateText(input: GenerateTextInput): Promise<GenerateTextOutput> {
+    const response = await invoke<PostProcessTextResponse>(
+      "post_process_text",
+      {
+        args: {
+          requestId: input.requestId ?? createId(),
+          system: input.system ?? null,
+          prompt: input.prompt,
+          jsonResponse: input.jsonResponse ?? null,
+          apiKeyId: this.apiKeyId,
+        },
+      },
+    );
+
+    return {
+      text: response.text,
+      metadata: {
+        postProcessingMode: "api",
+        inferenceDevice: response.inferenceDevice,
+      },
+    };
+  }
+
+  async cancelGenerateText(requestId: string): Promise<void> {
+    await invoke<boolean>("cancel_post_processing", { requestId });
+  }
 }
 
 export class CloudGenerateTextRepo extends BaseGenerateTextRepo {
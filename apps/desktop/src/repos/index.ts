@@ -19,6 +19,7 @@ import {
   ClaudeGenerateTextRepo,
   CloudGenerateTextRepo,
   DeepseekGenerateTextRepo,
+  DesktopGenerateTextRepo,
   GeminiGenerateTextRepo,
   GroqGenerateTextRepo,
   OllamaGenerateTextRepo,
@@ -31,6 +32,7 @@ import {
   BaseUserPreferencesRepo,
   LocalUserPreferencesRepo,
 } from "./preferences.repo";
+import { BaseSnippetRepo, LocalSnippetRepo } from "./snippet.repo";
 import { BaseStorageRepo, LocalStorageRepo } from "./storage.repo";
 import { BaseTermRepo, LocalTermRepo } from "./term.repo";
 import { BaseToneRepo, LocalToneRepo } from "./tone.repo";
@@ -39,16 +41,25 @@ import {
   AzureTranscribeAudioRepo,
   BaseTranscribeAudioRepo,
   CloudTranscribeAudioRepo,
+  FallbackTranscribeAudioRepo,
   GeminiTranscribeAudioRepo,
   GroqTranscribeAudioRepo,
   LocalTranscribeAudioRepo,
   OpenAITranscribeAudioRepo,
 } from "./transcribe-audio.repo";
+import {
+  BaseTranscriptTextRepo,
+  LocalTranscriptTextRepo,
+} from "./transcript-text.repo";
 import {
   BaseTranscriptionRepo,
   LocalTranscriptionRepo,
 } from "./transcription.repo";
 import { BaseUserRepo, CloudUserRepo, LocalUserRepo } from "./user.repo";
+import {
+  BaseVoiceCommandRepo,
+  LocalVoiceCommandRepo,
+} from "./voice-command.repo";
 
 const shouldUseCloud = () => getHasCloudAccess(getAppState());
 
@@ -88,6 +99,18 @@ export const getToneRepo = (): BaseToneRepo => {
   return new LocalToneRepo();
 };
 
+export const getVoiceCommandRepo = (): BaseVoiceCommandRepo => {
+  return new LocalVoiceCommandRepo();
+};
+
+export const getTranscriptTextRepo = (): BaseTranscriptTextRepo => {
+  return new LocalTranscriptTextRepo();
+};
+
+export const getSnippetRepo = (): BaseSnippetRepo => {
+  return new LocalSnippetRepo();
+};
+
 export const getStorageRepo = (): BaseStorageRepo => {
   return new LocalStorageRepo();
 };
@@ -196,6 +219,16 @@ const getGenTextRepoInternal = ({
 export const getGenerateTextRepo = (): GenerateTextRepoOutput => {
   const state = getAppState();
   const prefs = getGenerativePrefs(state);
+  if (prefs.mode === "api") {
+    // Post-processing runs in the desktop backend so it can stream to the
+    // overlay and be cancelled.
+    return {
+      repo: new DesktopGenerateTextRepo(prefs.apiKeyId),
+      apiKeyId: prefs.apiKeyId,
+      warnings: prefs.warnings,
+    };
+  }
+
   return getGenTextRepoInternal({ prefs, cloudModel: "medium" });
 };
 
@@ -212,7 +245,18 @@ export type TranscribeAudioRepoOutput = {
 };
 
 export const getTranscribeAudioRepo = (): TranscribeAudioRepoOutput => {
-  const prefs = getTranscriptionPrefs(getAppState());
+  const state = getAppState();
+  const prefs = getTranscriptionPrefs(state);
+  const fallbackChain = state.userPrefs?.transcriptionFallbackChain ?? [];
+  if (fallbackChain.length > 0) {
+    // The serving provider's key is reported per dictation in the metadata.
+    return {
+      repo: new FallbackTranscribeAudioRepo(),
+      apiKeyId: null,
+      warnings: [],
+    };
+  }
+
   if (prefs.mode === "cloud") {
     return {
       repo: new CloudTranscribeAudioRepo(),
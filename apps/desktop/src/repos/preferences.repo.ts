@@ -1,8 +1,11 @@
 import {
   AgentMode,
   DictationPillVisibility,
+  LocalTranscriptionEngine,
   Nullable,
+  PiiKind,
   PostProcessingMode,
+  TranscriptionFallbackStep,
   TranscriptionMode,
   UserPreferences,
 } from "@repo/types";
@@ -16,6 +19,8 @@ type LocalUserPreferences = {
   transcriptionApiKeyId: Nullable<string>;
   transcriptionDevice: Nullable<string>;
   transcriptionModelSize: Nullable<string>;
+  localTranscriptionEngine?: Nullable<string>;
+  transcriptionFallbackChain?: Nullable<TranscriptionFallbackStep[]>;
   postProcessingMode: Nullable<string>;
   postProcessingApiKeyId: Nullable<string>;
   postProcessingOllamaUrl: Nullable<string>;
@@ -35,6 +40,14 @@ type LocalUserPreferences = {
   incognitoModeEnabled: boolean;
   incognitoModeIncludeInStats: boolean;
   dictationPillVisibility: DictationPillVisibility;
+  disfluencyCleanupEnabled?: boolean;
+  fillerWords?: Nullable<string[]>;
+  spacingCorrectionLanguages?: Nullable<string[]>;
+  redactBeforeCloud?: boolean;
+  redactBeforeStorage?: boolean;
+  redactionDetectors?: Nullable<PiiKind[]>;
+  redactionPatterns?: Nullable<string[]>;
+  voiceCommandsEnabled?: boolean;
 };
 
 // Normalize post-processing mode for backwards compatibility
@@ -50,6 +63,15 @@ const normalizePostProcessingMode = (
   return "none";
 };
 
+const normalizeLocalTranscriptionEngine = (
+  engine: Nullable<string> | undefined,
+): Nullable<LocalTranscriptionEngine> => {
+  if (engine === "whisper_cpp" || engine === "candle") {
+    return engine;
+  }
+  return null;
+};
+
 const fromLocalPreferences = (
   preferences: LocalUserPreferences,
 ): UserPreferences => ({
@@ -58,6 +80,10 @@ const fromLocalPreferences = (
   transcriptionApiKeyId: preferences.transcriptionApiKeyId,
   transcriptionDevice: preferences.transcriptionDevice,
   transcriptionModelSize: preferences.transcriptionModelSize,
+  localTranscriptionEngine: normalizeLocalTranscriptionEngine(
+    preferences.localTranscriptionEngine,
+  ),
+  transcriptionFallbackChain: preferences.transcriptionFallbackChain ?? null,
   postProcessingMode: normalizePostProcessingMode(
     preferences.postProcessingMode,
   ),
@@ -83,6 +109,14 @@ const fromLocalPreferences = (
   dictationPillVisibility: getEffectivePillVisibility(
     preferences.dictationPillVisibility,
   ),
+  disfluencyCleanupEnabled: preferences.disfluencyCleanupEnabled ?? false,
+  fillerWords: preferences.fillerWords ?? null,
+  spacingCorrectionLanguages: preferences.spacingCorrectionLanguages ?? null,
+  redactBeforeCloud: preferences.redactBeforeCloud ?? false,
+  redactBeforeStorage: preferences.redactBeforeStorage ?? false,
+  redactionDetectors: preferences.redactionDetectors ?? null,
+  redactionPatterns: preferences.redactionPatterns ?? null,
+  voiceCommandsEnabled: preferences.voiceCommandsEnabled ?? false,
 });
 
 const toLocalPreferences = (
@@ -93,6 +127,8 @@ const toLocalPreferences = (
   transcriptionApiKeyId: preferences.transcriptionApiKeyId ?? null,
   transcriptionDevice: preferences.transcriptionDevice ?? null,
   transcriptionModelSize: preferences.transcriptionModelSize ?? null,
+  localTranscriptionEngine: preferences.localTranscriptionEngine ?? null,
+  transcriptionFallbackChain: preferences.transcriptionFallbackChain ?? null,
   postProcessingMode: preferences.postProcessingMode ?? null,
   postProcessingApiKeyId: preferences.postProcessingApiKeyId ?? null,
   postProcessingOllamaUrl: preferences.postProcessingOllamaUrl ?? null,
@@ -114,6 +150,14 @@ const toLocalPreferences = (
   dictationPillVisibility: getEffectivePillVisibility(
     preferences.dictationPillVisibility,
   ),
+  disfluencyCleanupEnabled: preferences.disfluencyCleanupEnabled ?? false,
+  fillerWords: preferences.fillerWords ?? null,
+  spacingCorrectionLanguages: preferences.spacingCorrectionLanguages ?? null,
+  redactBeforeCloud: preferences.redactBeforeCloud ?? false,
+  redactBeforeStorage: preferences.redactBeforeStorage ?? false,
+  redactionDetectors: preferences.redactionDetectors ?? null,
+  redactionPatterns: preferences.redactionPatterns ?? null,
+  voiceCommandsEnabled: preferences.voiceCommandsEnabled ?? false,
 });
 
 export abstract class BaseUserPreferencesRepo extends BaseRepo {
@@ -0,0 +1,86 @@
+import { Nullable, Snippet, SnippetExpansion } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import dayjs from "dayjs";
+import { getAppState } from "../store";
+import { getMyEffectiveUserId } from "../utils/user.utils";
+import { BaseRepo } from "./base.repo";
+
+type LocalSnippet = {
+  id: string;
+  createdAt: number;
+  createdByUserId: string;
+  trigger: string;
+  expansion: string;
+};
+
+const toLocalSnippet = (snippet: Snippet): LocalSnippet => ({
+  id: snippet.id,
+  createdAt: dayjs(snippet.createdAt).valueOf(),
+  createdByUserId: getMyEffectiveUserId(getAppState()),
+  trigger: snippet.trigger,
+  expansion: snippet.expansion,
+});
+
+const fromLocalSnippet = (snippet: LocalSnippet): Snippet => ({
+  id: snippet.id,
+  createdAt: dayjs(snippet.createdAt).toISOString(),
+  trigger: snippet.trigger,
+  expansion: snippet.expansion,
+});
+
+export abstract class BaseSnippetRepo extends BaseRepo {
+  abstract listSnippets(): Promise<Snippet[]>;
+  abstract createSnippet(snippet: Snippet): Promise<Snippet>;
+  abstract updateSnippet(snippet: Snippet): Promise<Snippet>;
+  abstract deleteSnippet(snippetId: string): Promise<void>;
+  /**
+   * Pastes the matching snippet and returns it, or returns null without
+   * pasting when the transcript is not a snippet trigger.
+   */
+  abstract expandSnippet(
+    text: string,
+    keybind: Nullable<string>,
+    appTargetId: Nullable<string>,
+  ): Promise<Nullable<SnippetExpansion>>;
+}
+
+export class LocalSnippetRepo extends BaseSnippetRepo {
+  async listSnippets(): Promise<Snippet[]> {
+    const userId = getMyEffectiveUserId(getAppState());
+    const snippets = await invoke<LocalSnippet[]>("snippet_list", { userId });
+    return snippets.map(fromLocalSnippet);
+  }
+
+  async createSnippet(snippet: Snippet): Promise<Snippet> {
+    const created = await invoke<LocalSnippet>("snippet_create", {
+      snippet: toLocalSnippet(snippet),
+    });
+    return fromLocalSnippet(created);
+  }
+
+  async updateSnippet(snippet: Snippet): Promise<Snippet> {
+    const updated = await invoke<LocalSnippet>("snippet_update", {
+      snippet: toLocalSnippet(snippet),
+    });
+    return fromLocalSnippet(updated);
+  }
+
+  async deleteSnippet(snippetId: string): Promise<void> {
+    const userId = getMyEffectiveUserId(getAppState());
+    await invoke<void>("snippet_delete", { id: snippetId, userId });
+  }
+
+  async expandSnippet(
+    text: string,
+    keybind: Nullable<string>,
+    appTargetId: Nullable<string>,
+  ): Promise<Nullable<SnippetExpansion>> {
+    const userId = getMyEffectiveUserId(getAppState());
+    return invoke<Nullable<SnippetExpansion>>("snippet_expand", {
+      text,
+      userId,
+      keybind,
+      appTargetId,
+    });
+  }
+}
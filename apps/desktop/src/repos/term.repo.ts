@@ -1,4 +1,4 @@
-import { Term } from "@repo/types";
+import { Term, TermMatchMode, TermSuggestion } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import dayjs from "dayjs";
 import { getAppState } from "../store";
@@ -13,6 +13,7 @@ type LocalTerm = {
   destinationValue: string;
   isReplacement: boolean;
   isDeleted: boolean;
+  matchMode: TermMatchMode;
 };
 
 const toLocalTerm = (term: Term): LocalTerm => ({
@@ -23,6 +24,7 @@ const toLocalTerm = (term: Term): LocalTerm => ({
   destinationValue: term.destinationValue,
   isReplacement: term.isReplacement,
   isDeleted: false,
+  matchMode: term.matchMode ?? "exact",
 });
 
 const fromLocalTerm = (term: LocalTerm): Term => ({
@@ -31,6 +33,7 @@ const fromLocalTerm = (term: LocalTerm): Term => ({
   sourceValue: term.sourceValue,
   destinationValue: term.destinationValue,
   isReplacement: term.isReplacement,
+  matchMode: term.matchMode,
 });
 
 export abstract class BaseTermRepo extends BaseRepo {
@@ -38,6 +41,12 @@ export abstract class BaseTermRepo extends BaseRepo {
   abstract createTerm(term: Term): Promise<Term>;
   abstract updateTerm(term: Term): Promise<Term>;
   abstract deleteTerm(termId: string): Promise<void>;
+  abstract listTermSuggestions(): Promise<TermSuggestion[]>;
+  abstract acceptTermSuggestion(
+    suggestion: TermSuggestion,
+    term: Term,
+  ): Promise<Term>;
+  abstract rejectTermSuggestion(suggestion: TermSuggestion): Promise<void>;
 }
 
 export class LocalTermRepo extends BaseTermRepo {
@@ -64,4 +73,25 @@ export class LocalTermRepo extends BaseTermRepo {
   async deleteTerm(termId: string): Promise<void> {
     await invoke<void>("term_delete", { id: termId });
   }
+
+  async listTermSuggestions(): Promise<TermSuggestion[]> {
+    const userId = getMyEffectiveUserId(getAppState());
+    return invoke<TermSuggestion[]>("term_suggestions", { userId });
+  }
+
+  async acceptTermSuggestion(
+    suggestion: TermSuggestion,
+    term: Term,
+  ): Promise<Term> {
+    const created = await invoke<LocalTerm>("term_suggestion_accept", {
+      suggestion,
+      term: toLocalTerm(term),
+    });
+    return fromLocalTerm(created);
+  }
+
+  async rejectTermSuggestion(suggestion: TermSuggestion): Promise<void> {
+    const userId = getMyEffectiveUserId(getAppState());
+    await invoke<void>("term_suggestion_reject", { userId, suggestion });
+  }
 }
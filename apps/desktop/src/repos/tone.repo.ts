@@ -1,4 +1,4 @@
-import { Tone } from "@repo/types";
+import { Nullable, Tone, TranslationMode } from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { getAppState } from "../store";
 import { getMyEffectiveUserId } from "../utils/user.utils";
@@ -11,6 +11,8 @@ type LocalTone = {
   promptTemplate: string;
   createdAt: number;
   sortOrder: number;
+  translationMode?: TranslationMode | null;
+  inverseTextNormalization?: boolean | null;
 };
 
 const fromLocalTone = (tone: LocalTone): Tone => ({
@@ -20,6 +22,8 @@ const fromLocalTone = (tone: LocalTone): Tone => ({
   isSystem: false,
   createdAt: tone.createdAt,
   sortOrder: tone.sortOrder,
+  translationMode: tone.translationMode ?? null,
+  inverseTextNormalization: tone.inverseTextNormalization ?? null,
 });
 
 const toLocalTone = (tone: Tone): LocalTone => ({
@@ -28,8 +32,32 @@ const toLocalTone = (tone: Tone): LocalTone => ({
   promptTemplate: tone.promptTemplate,
   createdAt: tone.createdAt,
   sortOrder: tone.sortOrder,
+  translationMode: tone.translationMode ?? null,
+  inverseTextNormalization: tone.inverseTextNormalization ?? null,
 });
 
+export type TonePreviewInput = {
+  transcriptionId: string;
+  toneId?: string;
+  promptTemplate?: string;
+  appName?: string;
+  selectedText?: string;
+};
+
+export type TonePreview = {
+  rendered: string;
+  variables: string[];
+  warning: Nullable<string>;
+};
+
+export type ToneRenderInput = {
+  promptTemplate: string;
+  transcript: string;
+  appName?: Nullable<string>;
+  selectedText?: Nullable<string>;
+  language?: Nullable<string>;
+};
+
 const getSystemToneById = (id: string): Tone | undefined =>
   getDefaultSystemTones().find((tone) => tone.id === id);
 
@@ -44,6 +72,8 @@ export abstract class BaseToneRepo extends BaseRepo {
   abstract getTone(id: string): Promise<Tone | null>;
   abstract upsertTone(tone: Tone): Promise<Tone>;
   abstract deleteTone(id: string): Promise<void>;
+  abstract previewTone(input: TonePreviewInput): Promise<TonePreview>;
+  abstract renderTone(input: ToneRenderInput): Promise<string>;
 }
 
 export class LocalToneRepo extends BaseToneRepo {
@@ -84,4 +114,18 @@ export class LocalToneRepo extends BaseToneRepo {
 
     await invoke("tone_delete", { id });
   }
+
+  async previewTone(input: TonePreviewInput): Promise<TonePreview> {
+    const systemTone = input.toneId ? getSystemToneById(input.toneId) : null;
+    return invoke<TonePreview>("tone_preview", {
+      args: {
+        ...input,
+        promptTemplate: input.promptTemplate ?? systemTone?.promptTemplate,
+      },
+    });
+  }
+
+  async renderTone(input: ToneRenderInput): Promise<string> {
+    return invoke<string>("tone_render", { args: input });
+  }
 }
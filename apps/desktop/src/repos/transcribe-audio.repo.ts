@@ -1,5 +1,10 @@
-import { Nullable } from "@repo/types";
-import { batchAsync } from "@repo/utilities";
+import {
+  AppliedReplacement,
+  LanguageProbability,
+  Nullable,
+  TranslationMode,
+} from "@repo/types";
+import { batchAsync, dedup } from "@repo/utilities";
 import {
   aldeaTranscribeAudio,
   azureTranscribeAudio,
@@ -25,6 +30,7 @@ import {
   normalizeSamples,
 } from "../utils/audio.utils";
 import { loadDiscreteGpus } from "../utils/gpu.utils";
+import { getMyEffectiveUserId } from "../utils/user.utils";
 import {
   mergeTranscriptions,
   splitAudioTranscription,
@@ -47,6 +53,18 @@ export type TranscribeAudioMetadata = {
   inferenceDevice?: Nullable<string>;
   modelSize?: Nullable<string>;
   transcriptionMode?: Nullable<TranscriptionMode>;
+  detectedLanguage?: Nullable<string>;
+  languageProbabilities?: Nullable<LanguageProbability[]>;
+  translatedTranscript?: Nullable<string>;
+  translationMode?: Nullable<TranslationMode>;
+  transcriptionApiKeyId?: Nullable<string>;
+  warnings?: string[];
+  /**
+   * Dictionary replacements already made by the desktop backend; absent when
+   * the provider returned the transcript untouched.
+   */
+  appliedReplacements?: AppliedReplacement[];
+  transcriptionPrompt?: Nullable<string>;
 };
 
 export type TranscribeAudioInput = {
@@ -54,6 +72,28 @@ export type TranscribeAudioInput = {
   sampleRate: number;
   prompt?: Nullable<string>;
   language?: string;
+  candidateLanguages?: string[];
+} & VocabularyContext &
+  TranslationContext;
+
+/**
+ * Inputs for the desktop backend, which builds its own vocabulary prompt
+ * instead of using `prompt`.
+ */
+export type VocabularyContext = {
+  promptPreamble?: Nullable<string>;
+  appTargetId?: Nullable<string>;
+  appContext?: Nullable<string>;
+};
+
+/**
+ * Inputs the desktop backend uses to pick a translation mode: an explicit
+ * mode wins, then a translate hotkey, then the tone's own setting.
+ */
+export type TranslationContext = {
+  translationMode?: Nullable<TranslationMode>;
+  hotkeyAction?: Nullable<string>;
+  toneId?: Nullable<string>;
 };
 
 export type TranscribeAudioOutput = {
@@ -66,6 +106,60 @@ export type TranscribeSegmentInput = {
   sampleRate: number;
   prompt?: Nullable<string>;
   language?: string;
+  candidateLanguages?: string[];
+} & VocabularyContext &
+  TranslationContext;
+
+type LocalTranscribeAudioResponse = {
+  text: string;
+  detectedLanguage: Nullable<string>;
+  languageProbabilities: LanguageProbability[];
+  translationMode: TranslationMode;
+  originalText: Nullable<string>;
+  translatedText: Nullable<string>;
+  appliedReplacements: AppliedReplacement[];
+  transcriptionPrompt: Nullable<string>;
+};
+
+type FallbackTranscribeAudioResponse = LocalTranscribeAudioResponse & {
+  transcriptionMode: TranscriptionMode;
+  transcriptionApiKeyId: Nullable<string>;
+  modelSize: Nullable<string>;
+  warnings: string[];
+};
+
+/**
+ * Settings come from the first segment; warnings, replacements and
+ * translations are collected from every segment, since a fallback chain may
+ * serve each one through a different provider.
+ */
+const mergeSegmentMetadata = (
+  segments: Nullable<TranscribeAudioMetadata>[],
+): Nullable<TranscribeAudioMetadata> => {
+  const present = segments.filter(
+    (metadata): metadata is TranscribeAudioMetadata => metadata != null,
+  );
+  const first = present[0];
+  if (!first) {
+    return null;
+  }
+
+  const translations = present
+    .map((metadata) => metadata.translatedTranscript)
+    .filter((text): text is string => !!text);
+  const warnings = dedup(
+    present.flatMap((metadata) => metadata.warnings ?? []),
+  );
+
+  return {
+    ...first,
+    translatedTranscript:
+      translations.length > 0 ? mergeTranscriptions(translations) : null,
+    warnings: warnings.length > 0 ? warnings : undefined,
+    appliedReplacements: first.appliedReplacements
+      ? present.flatMap((metadata) => metadata.appliedReplacements ?? [])
+      : undefined,
+  };
 };
 
 export abstract class BaseTranscribeAudioRepo extends BaseRepo {
@@ -121,6 +215,13 @@ export abstract class BaseTranscribeAudioRepo extends BaseRepo {
         sampleRate: input.sampleRate,
         prompt: input.prompt,
         language: input.language,
+        candidateLanguages: input.candidateLanguages,
+        promptPreamble: input.promptPreamble,
+        appTargetId: input.appTargetId,
+        appContext: input.appContext,
+        translationMode: input.translationMode,
+        hotkeyAction: input.hotkeyAction,
+        toneId: input.toneId,
       });
     }
 
@@ -140,6 +241,13 @@ export abstract class BaseTranscribeAudioRepo extends BaseRepo {
           sampleRate: input.sampleRate,
           prompt: input.prompt,
           language: input.language,
+          candidateLanguages: input.candidateLanguages,
+          promptPreamble: input.promptPreamble,
+          appTargetId: input.appTargetId,
+          appContext: input.appContext,
+          translationMode: input.translationMode,
+          hotkeyAction: input.hotkeyAction,
+          toneId: input.toneId,
         }),
     );
 
@@ -153,12 +261,9 @@ export abstract class BaseTranscribeAudioRepo extends BaseRepo {
     const transcriptionTexts = results.map((r) => r.text);
     const mergedText = mergeTranscriptions(transcriptionTexts);
 
-    // Use metadata from first result (all segments use same provider/device)
-    const metadata = results[0]?.metadata ?? null;
-
     return {
       text: mergedText,
-      metadata,
+      metadata: mergeSegmentMetadata(results.map((r) => r.metadata ?? null)),
     };
   }
 }
@@ -178,7 +283,7 @@ export class LocalTranscribeAudioRepo extends BaseTranscribeAudioRepo {
     return 1;
   }
 
-  private async resolveTranscriptionOptions(): Promise<TranscriptionOptionsPayload> {
+  protected async resolveTranscriptionOptions(): Promise<TranscriptionOptionsPayload> {
     const state = getAppState();
     const { device, modelSize } = state.settings.aiTranscription;
 
@@ -230,23 +335,103 @@ export class LocalTranscribeAudioRepo extends BaseTranscribeAudioRepo {
     input: TranscribeSegmentInput,
   ): Promise<TranscribeAudioOutput> {
     const options = await this.resolveTranscriptionOptions();
-    const transcript = await invoke<string>("transcribe_audio", {
-      samples: Array.from(input.samples),
-      sampleRate: input.sampleRate,
-      options: {
-        modelSize: options.modelSize,
-        device: options.device,
-        initialPrompt: input.prompt,
-        language: input.language,
+    const response = await invoke<LocalTranscribeAudioResponse>(
+      "transcribe_audio",
+      {
+        samples: Array.from(input.samples),
+        sampleRate: input.sampleRate,
+        options: {
+          modelSize: options.modelSize,
+          device: options.device,
+          initialPrompt: input.promptPreamble,
+          language: input.language,
+          candidateLanguages: input.candidateLanguages ?? [],
+          userId: getMyEffectiveUserId(getAppState()),
+          appTargetId: input.appTargetId,
+          appContext: input.appContext,
+          translationMode: input.translationMode,
+          hotkeyAction: input.hotkeyAction,
+          toneId: input.toneId,
+        },
       },
-    });
+    );
 
     return {
-      text: transcript,
+      text: response.text,
       metadata: {
         inferenceDevice: options.deviceLabel,
         modelSize: options.modelSize,
         transcriptionMode: "local",
+        detectedLanguage: response.detectedLanguage,
+        languageProbabilities:
+          response.languageProbabilities.length > 0
+            ? response.languageProbabilities
+            : null,
+        translatedTranscript: response.translatedText,
+        translationMode:
+          response.translationMode === "off" ? null : response.translationMode,
+        appliedReplacements: response.appliedReplacements,
+        transcriptionPrompt: response.transcriptionPrompt,
+      },
+    };
+  }
+}
+
+/**
+ * Walks the user's ordered fallback chain (e.g. Groq → OpenAI → local
+ * Whisper) in the desktop backend, which reports the provider that served
+ * the dictation and every attempt made along the way.
+ */
+export class FallbackTranscribeAudioRepo extends LocalTranscribeAudioRepo {
+  // Cloud providers in the chain cap request length at about a minute
+  protected getSegmentDurationSec(): number {
+    return 60;
+  }
+
+  protected async transcribeSegment(
+    input: TranscribeSegmentInput,
+  ): Promise<TranscribeAudioOutput> {
+    const options = await this.resolveTranscriptionOptions();
+    const response = await invoke<FallbackTranscribeAudioResponse>(
+      "transcribe_audio_with_fallback",
+      {
+        samples: Array.from(input.samples),
+        sampleRate: input.sampleRate,
+        options: {
+          modelSize: options.modelSize,
+          device: options.device,
+          initialPrompt: input.promptPreamble,
+          language: input.language,
+          candidateLanguages: input.candidateLanguages ?? [],
+          userId: getMyEffectiveUserId(getAppState()),
+          appTargetId: input.appTargetId,
+          appContext: input.appContext,
+          translationMode: input.translationMode,
+          hotkeyAction: input.hotkeyAction,
+          toneId: input.toneId,
+        },
+      },
+    );
+
+    const servedLocally = response.transcriptionMode === "local";
+    return {
+      text: response.text,
+      metadata: {
+        inferenceDevice: servedLocally ? options.deviceLabel : "API",
+        modelSize: response.modelSize,
+        transcriptionMode: response.transcriptionMode,
+        transcriptionApiKeyId: response.transcriptionApiKeyId,
+        warnings: response.warnings,
+        detectedLanguage: response.detectedLanguage,
+        languageProbabilities:
+          response.languageProbabilities.length > 0
+            ? response.languageProbabilities
+            : null,
+        translatedTranscript: response.translatedText,
+        translationMode:
+          response.translationMode === "off" ? null : response.translationMode,
+        appliedReplacements: response.appliedReplacements,
+        transcriptionPrompt: response.transcriptionPrompt,
       },
     };
   }
@@ -0,0 +1,51 @@
+import { Nullable } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { BaseRepo } from "./base.repo";
+
+export type InverseNormalizeInput = {
+  text: string;
+  language: Nullable<string>;
+  toneId: Nullable<string>;
+  appTargetId: Nullable<string>;
+};
+
+export type CorrectSpacingInput = {
+  text: string;
+  language: Nullable<string>;
+  enabledLanguages: string[];
+};
+
+/**
+ * Local rewrites applied to a transcript before it is post-processed and
+ * pasted.
+ */
+export abstract class BaseTranscriptTextRepo extends BaseRepo {
+  abstract cleanTranscript(
+    text: string,
+    fillerWords: Nullable<string[]>,
+  ): Promise<string>;
+  abstract inverseNormalize(input: InverseNormalizeInput): Promise<string>;
+  abstract correctSpacing(input: CorrectSpacingInput): Promise<string>;
+  abstract formatCode(text: string): Promise<string>;
+}
+
+export class LocalTranscriptTextRepo extends BaseTranscriptTextRepo {
+  async cleanTranscript(
+    text: string,
+    fillerWords: Nullable<string[]>,
+  ): Promise<string> {
+    return invoke<string>("clean_transcript", { text, fillerWords });
+  }
+
+  async inverseNormalize(input: InverseNormalizeInput): Promise<string> {
+    return invoke<string>("inverse_normalize_transcript", input);
+  }
+
+  async correctSpacing(input: CorrectSpacingInput): Promise<string> {
+    return invoke<string>("correct_transcript_spacing", input);
+  }
+
+  async formatCode(text: string): Promise<string> {
+    return invoke<string>("format_code_transcript", { text });
+  }
+}
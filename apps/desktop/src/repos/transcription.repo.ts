@@ -1,5 +1,8 @@
 import {
+  AppliedReplacement,
+  LanguageProbability,
   PostProcessingMode,
+  TranslationMode,
   Transcription,
   TranscriptionAudioSnapshot,
   TranscriptionMode,
@@ -31,6 +34,12 @@ type LocalTranscription = {
   transcriptionDurationMs?: number | null;
   postprocessDurationMs?: number | null;
   warnings?: string[] | null;
+  detectedLanguage?: string | null;
+  languageProbabilities?: LanguageProbability[] | null;
+  translatedTranscript?: string | null;
+  translationMode?: TranslationMode | null;
+  appliedReplacements?: AppliedReplacement[] | null;
+  appTargetId?: string | null;
 };
 
 export type TranscriptionAudioData = {
@@ -69,6 +78,12 @@ const toLocalTranscription = (
   transcriptionDurationMs: transcription.transcriptionDurationMs ?? null,
   postprocessDurationMs: transcription.postprocessDurationMs ?? null,
   warnings: transcription.warnings ?? null,
+  detectedLanguage: transcription.detectedLanguage ?? null,
+  languageProbabilities: transcription.languageProbabilities ?? null,
+  translatedTranscript: transcription.translatedTranscript ?? null,
+  translationMode: transcription.translationMode ?? null,
+  appliedReplacements: transcription.appliedReplacements ?? null,
+  appTargetId: transcription.appTargetId ?? null,
 });
 
 const fromLocalTranscription = (
@@ -99,6 +114,12 @@ const fromLocalTranscription = (
   transcriptionDurationMs: transcription.transcriptionDurationMs ?? undefined,
   postprocessDurationMs: transcription.postprocessDurationMs ?? undefined,
   warnings: transcription.warnings ?? undefined,
+  detectedLanguage: transcription.detectedLanguage ?? undefined,
+  languageProbabilities: transcription.languageProbabilities ?? undefined,
+  translatedTranscript: transcription.translatedTranscript ?? undefined,
+  translationMode: transcription.translationMode ?? undefined,
+  appliedReplacements: transcription.appliedReplacements ?? undefined,
+  appTargetId: transcription.appTargetId ?? undefined,
 });
 
 export abstract class BaseTranscriptionRepo extends BaseRepo {
@@ -112,6 +133,11 @@ export abstract class BaseTranscriptionRepo extends BaseRepo {
   abstract updateTranscription(
     transcription: Transcription,
   ): Promise<Transcription>;
+  /** Saves a transcript the user corrected by hand. */
+  abstract editTranscript(
+    id: string,
+    transcript: string,
+  ): Promise<Transcription>;
   abstract loadTranscriptionAudio(id: string): Promise<TranscriptionAudioData>;
   abstract purgeStaleAudio(): Promise<string[]>;
 }
@@ -157,6 +183,14 @@ export class LocalTranscriptionRepo extends BaseTranscriptionRepo {
     return fromLocalTranscription(stored);
   }
 
+  async editTranscript(id: string, transcript: string): Promise<Transcription> {
+    const stored = await invoke<LocalTranscription>(
+      "transcription_edit_transcript",
+      { id, transcript },
+    );
+    return fromLocalTranscription(stored);
+  }
+
   async loadTranscriptionAudio(id: string): Promise<TranscriptionAudioData> {
     return invoke<TranscriptionAudioData>("transcription_audio_load", { id });
   }
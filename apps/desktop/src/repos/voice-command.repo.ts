@@ -0,0 +1,49 @@
+import { ExecutedVoiceCommand, Nullable, VoiceCommand } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { getAppState } from "../store";
+import { getMyEffectiveUserId } from "../utils/user.utils";
+import { BaseRepo } from "./base.repo";
+
+export type ApplyVoiceCommandsOutput = {
+  text: string;
+  executed: ExecutedVoiceCommand[];
+};
+
+export abstract class BaseVoiceCommandRepo extends BaseRepo {
+  abstract listVoiceCommands(): Promise<VoiceCommand[]>;
+  abstract saveVoiceCommand(command: VoiceCommand): Promise<VoiceCommand>;
+  abstract deleteVoiceCommand(id: string): Promise<void>;
+  abstract applyVoiceCommands(
+    text: string,
+    language: Nullable<string>,
+  ): Promise<ApplyVoiceCommandsOutput>;
+}
+
+export class LocalVoiceCommandRepo extends BaseVoiceCommandRepo {
+  async listVoiceCommands(): Promise<VoiceCommand[]> {
+    const userId = getMyEffectiveUserId(getAppState());
+    return invoke<VoiceCommand[]>("voice_command_list", { userId });
+  }
+
+  async saveVoiceCommand(command: VoiceCommand): Promise<VoiceCommand> {
+    const userId = getMyEffectiveUserId(getAppState());
+    return invoke<VoiceCommand>("voice_command_upsert", { command, userId });
+  }
+
+  async deleteVoiceCommand(id: string): Promise<void> {
+    const userId = getMyEffectiveUserId(getAppState());
+    await invoke<void>("voice_command_delete", { id, userId });
+  }
+
+  async applyVoiceCommands(
+    text: string,
+    language: Nullable<string>,
+  ): Promise<ApplyVoiceCommandsOutput> {
+    const userId = getMyEffectiveUserId(getAppState());
+    return invoke<ApplyVoiceCommandsOutput>("apply_voice_commands", {
+      text,
+      language,
+      userId,
+    });
+  }
+}
@@ -2,6 +2,7 @@ import { transcribeAudio } from "../actions/transcribe.actions";
 import {
   StopRecordingResponse,
   TranscriptionSession,
+  TranscriptionSessionContext,
   TranscriptionSessionResult,
 } from "../types/transcription-session.types";
 import { showErrorSnackbar } from "../actions/app.actions";
@@ -18,6 +19,7 @@ export class BatchTranscriptionSession implements TranscriptionSession {
 
   async finalize(
     audio: StopRecordingResponse,
+    context?: TranscriptionSessionContext,
   ): Promise<TranscriptionSessionResult> {
     const payloadSamples = Array.isArray(audio.samples)
       ? audio.samples
@@ -35,9 +37,14 @@ export class BatchTranscriptionSession implements TranscriptionSession {
     const warnings: string[] = [];
 
     try {
+      const appTarget = await context?.appTarget?.catch(() => null);
       const result = await transcribeAudio({
         samples: payloadSamples,
         sampleRate: rate,
+        appTargetId: appTarget?.id ?? null,
+        appContext: context?.appContext ?? null,
+        toneId: appTarget?.toneId ?? null,
+        hotkeyAction: context?.hotkeyAction ?? null,
       });
 
       return {
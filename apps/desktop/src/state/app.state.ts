@@ -14,6 +14,7 @@ import {
   UserPreferences,
 } from "@repo/types";
 import { AuthUser } from "../types/auth.types";
+import { KeyListenerSource } from "../types/keyboard.types";
 import { OverlayPhase } from "../types/overlay.types";
 import { PermissionMap } from "../types/permission.types";
 import { Toast } from "../types/toast.types";
@@ -41,7 +42,7 @@ import { Vector2 } from "../types/math.types";
 
 export type SnackbarMode = "info" | "success" | "error";
 
-export type RecordingMode = "dictate" | "agent";
+export type RecordingMode = "dictate" | "agent" | "rewrite";
 
 export type PriceValue = Record<
   string,
@@ -56,8 +57,10 @@ export type AppState = {
   initialized: boolean;
   auth: Nullable<AuthUser>;
   keysHeld: string[];
+  keyListenerSource: Nullable<KeyListenerSource>;
   isRecordingHotkey: boolean;
   activeRecordingMode: Nullable<RecordingMode>;
+  codeModeActive: boolean;
   overlayPhase: OverlayPhase;
   audioLevels: number[];
   permissions: PermissionMap;
@@ -106,6 +109,7 @@ export const INITIAL_APP_STATE: AppState = {
   userPrefs: null,
   isRecordingHotkey: false,
   activeRecordingMode: null,
+  codeModeActive: false,
   memberById: {},
   userById: {},
   termById: {},
@@ -125,6 +129,7 @@ export const INITIAL_APP_STATE: AppState = {
   confettiCounter: 0,
   config: FULL_CONFIG,
   keysHeld: [],
+  keyListenerSource: null,
   initialized: false,
   snackbarCounter: 0,
   snackbarMode: "info",
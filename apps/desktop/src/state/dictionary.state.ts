@@ -1,11 +1,14 @@
+import { TermSuggestion } from "@repo/types";
 import { ActionStatus } from "../types/state.types";
 
 export type DictionaryState = {
   termIds: string[];
+  suggestions: TermSuggestion[];
   status: ActionStatus;
 };
 
 export const INITIAL_DICTIONARY_STATE: DictionaryState = {
   termIds: [],
+  suggestions: [],
   status: "idle",
 };
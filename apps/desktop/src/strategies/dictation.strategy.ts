@@ -1,12 +1,23 @@
-import type { Nullable } from "@repo/types";
+import type {
+  InjectionReport,
+  Nullable,
+  SnippetExpansion,
+  UserPreferences,
+} from "@repo/types";
 import { invoke } from "@tauri-apps/api/core";
 import { showErrorSnackbar } from "../actions/app.actions";
 import { showToast } from "../actions/toast.actions";
 import {
+  cancelPostProcessing,
   postProcessTranscript,
   type PostProcessMetadata,
 } from "../actions/transcribe.actions";
 import { getIntl } from "../i18n";
+import {
+  getSnippetRepo,
+  getTranscriptTextRepo,
+  getVoiceCommandRepo,
+} from "../repos";
 import { getAppState } from "../store";
 import type { OverlayPhase } from "../types/overlay.types";
 import type {
@@ -14,14 +25,174 @@ import type {
   HandleTranscriptResult,
   StrategyValidationError,
 } from "../types/strategy.types";
+import { createId } from "../utils/id.utils";
 import { getMemberExceedsLimitByState } from "../utils/member.utils";
+import {
+  getMyDictationLanguage,
+  getMySpacingCorrectionLanguages,
+  getMyUserPreferences,
+} from "../utils/user.utils";
 import {
   applyReplacements,
   applySymbolConversions,
 } from "../utils/string.utils";
 import { BaseStrategy } from "./base.strategy";
 
+/**
+ * Tells the user when dictated text did not reach the focused app, or was
+ * only copied because the app is set up that way.
+ */
+const showInjectionResult = async (report: InjectionReport): Promise<void> => {
+  if (report.outcome === "failed") {
+    await showToast({
+      title: getIntl().formatMessage({
+        defaultMessage: "Text may not have been inserted",
+      }),
+      message:
+        report.message ??
+        getIntl().formatMessage({
+          defaultMessage: "The dictation is saved in your history.",
+        }),
+      toastType: "error",
+    });
+  } else if (report.outcome === "copied") {
+    await showToast({
+      title: getIntl().formatMessage({
+        defaultMessage: "Copied to clipboard",
+      }),
+      message: getIntl().formatMessage({
+        defaultMessage: "Paste the dictation where you need it.",
+      }),
+      toastType: "info",
+    });
+  }
+};
+
+/**
+ * Runs one of the local transcript rewrites below. A failing step is logged
+ * and skipped so the dictation is still pasted.
+ */
+const keepTextOnError = async (
+  text: string,
+  step: string,
+  rewrite: () => Promise<string>,
+): Promise<string> => {
+  try {
+    return await rewrite();
+  } catch (error) {
+    console.error(`Failed to ${step}`, error);
+    return text;
+  }
+};
+
+/**
+ * Runs spoken commands such as "new line" or "delete that" before the text
+ * is post-processed and pasted, once the user has turned them on.
+ */
+const applySpokenCommands = async (
+  text: string,
+  language: Nullable<string>,
+  preferences: Nullable<UserPreferences>,
+): Promise<string> => {
+  if (!preferences?.voiceCommandsEnabled) {
+    return text;
+  }
+
+  return keepTextOnError(text, "apply voice commands", async () => {
+    const result = await getVoiceCommandRepo().applyVoiceCommands(
+      text,
+      language,
+    );
+    return result.text;
+  });
+};
+
+/**
+ * Rewrites spoken numbers, dates and units ("twenty five dollars") as written
+ * forms unless the tone or app target turns it off.
+ */
+const applyInverseTextNormalization = async (
+  text: string,
+  language: Nullable<string>,
+  toneId: Nullable<string>,
+  appTargetId: Nullable<string>,
+): Promise<string> =>
+  keepTextOnError(text, "normalize transcript", () =>
+    getTranscriptTextRepo().inverseNormalize({
+      text,
+      language,
+      toneId,
+      appTargetId,
+    }),
+  );
+
+/**
+ * Removes filler words, stutters and repeated words locally once the user has
+ * turned the cleanup on.
+ */
+const applyDisfluencyCleanup = async (
+  text: string,
+  preferences: Nullable<UserPreferences>,
+): Promise<string> => {
+  if (!preferences?.disfluencyCleanupEnabled) {
+    return text;
+  }
+
+  return keepTextOnError(text, "clean transcript", () =>
+    getTranscriptTextRepo().cleanTranscript(
+      text,
+      preferences?.fillerWords ?? null,
+    ),
+  );
+};
+
+/**
+ * Fixes word spacing and sentence-final punctuation for languages the user
+ * enabled it for.
+ */
+const applySpacingCorrection = async (
+  text: string,
+  language: Nullable<string>,
+  enabledLanguages: string[],
+): Promise<string> =>
+  keepTextOnError(text, "correct transcript spacing", () =>
+    getTranscriptTextRepo().correctSpacing({
+      text,
+      language,
+      enabledLanguages,
+    }),
+  );
+
+/**
+ * Formats the transcript as code: casing commands such as "camel case user
+ * id" become identifiers and spoken symbols become characters.
+ */
+const applyCodeFormatting = async (text: string): Promise<string> =>
+  keepTextOnError(text, "format transcript as code", () =>
+    getTranscriptTextRepo().formatCode(text),
+  );
+
+/**
+ * Pastes the user's snippet when the dictation is its trigger, such as
+ * "insert snippet signature". Returns null when nothing matched or on error,
+ * so the dictation is handled as usual.
+ */
+const expandSnippet = async (
+  text: string,
+  keybind: Nullable<string>,
+  appTargetId: Nullable<string>,
+): Promise<Nullable<SnippetExpansion>> => {
+  try {
+    return await getSnippetRepo().expandSnippet(text, keybind, appTargetId);
+  } catch (error) {
+    console.error("Failed to expand snippet", error);
+    return null;
+  }
+};
+
 export class DictationStrategy extends BaseStrategy {
+  private postProcessRequestId: Nullable<string> = null;
+
   shouldStoreTranscript(): boolean {
     return true;
   }
@@ -63,6 +234,7 @@ export class DictationStrategy extends BaseStrategy {
     a11yInfo,
     currentApp,
     loadingToken,
+    transcriptionMetadata,
   }: HandleTranscriptParams): Promise<HandleTranscriptResult> {
     const resetPhase = async () => {
       if (
@@ -78,8 +250,30 @@ export class DictationStrategy extends BaseStrategy {
     let sanitizedTranscript: string | null = null;
     let postProcessMetadata: PostProcessMetadata = {};
     let postProcessWarnings: string[] = [];
+    let formattingToneId: Nullable<string> = null;
 
     try {
+      const snippet = await expandSnippet(
+        rawTranscript,
+        currentApp?.pasteKeybind ?? null,
+        currentApp?.id ?? null,
+      );
+      if (snippet) {
+        // The backend already pasted the expansion.
+        transcript = snippet.text;
+        sanitizedTranscript = snippet.text;
+        postProcessMetadata = { postProcessMode: "none" };
+        await resetPhase();
+        await showInjectionResult(snippet.report);
+        return {
+          shouldContinue: false,
+          transcript,
+          sanitizedTranscript,
+          postProcessMetadata,
+          postProcessWarnings,
+        };
+      }
+
       const state = getAppState();
       const replacementRules = Object.values(state.termById)
         .filter((term) => term.isReplacement)
@@ -88,21 +282,60 @@ export class DictationStrategy extends BaseStrategy {
           destinationValue: term.destinationValue,
         }));
 
-      const afterReplacements = applyReplacements(
-        rawTranscript,
-        replacementRules,
-      );
-      sanitizedTranscript = applySymbolConversions(afterReplacements);
+      // The desktop backend already applied the dictionary when it reports
+      // its replacements; running the rules again could double-apply them.
+      const afterReplacements = transcriptionMetadata.appliedReplacements
+        ? rawTranscript
+        : applyReplacements(rawTranscript, replacementRules);
+      const language =
+        transcriptionMetadata.detectedLanguage ?? getMyDictationLanguage(state);
+      const converted = applySymbolConversions(afterReplacements);
 
-      const result = await postProcessTranscript({
-        rawTranscript: sanitizedTranscript,
-        toneId,
-        a11yInfo,
-      });
+      if (state.codeModeActive || currentApp?.codeModeEnabled) {
+        // Code is pasted as formatted: filler cleanup, prose commands,
+        // spacing fixes and LLM post-processing would drop repeated tokens
+        // and add capitals and sentence punctuation.
+        sanitizedTranscript = await applyCodeFormatting(converted);
+        transcript = sanitizedTranscript;
+        postProcessMetadata = { postProcessMode: "none" };
+      } else {
+        const cleaned = await applyDisfluencyCleanup(
+          converted,
+          getMyUserPreferences(state),
+        );
+        const commanded = await applySpokenCommands(
+          cleaned,
+          language,
+          getMyUserPreferences(state),
+        );
+        const normalized = await applyInverseTextNormalization(
+          commanded,
+          language,
+          toneId,
+          currentApp?.id ?? null,
+        );
+        sanitizedTranscript = await applySpacingCorrection(
+          normalized,
+          language,
+          getMySpacingCorrectionLanguages(state),
+        );
 
-      transcript = result.transcript;
-      postProcessMetadata = result.metadata;
-      postProcessWarnings = result.warnings;
+        this.postProcessRequestId = createId();
+        const result = await postProcessTranscript({
+          rawTranscript: sanitizedTranscript,
+          toneId,
+          a11yInfo,
+          appName: currentApp?.name ?? null,
+          requestId: this.postProcessRequestId,
+        }).finally(() => {
+          this.postProcessRequestId = null;
+        });
+
+        transcript = result.transcript;
+        postProcessMetadata = result.metadata;
+        postProcessWarnings = result.warnings;
+        formattingToneId = result.toneId ?? null;
+      }
 
       await resetPhase();
 
@@ -110,7 +343,13 @@ export class DictationStrategy extends BaseStrategy {
         await new Promise<void>((resolve) => setTimeout(resolve, 20));
         try {
           const keybind = currentApp?.pasteKeybind ?? null;
-          await invoke<void>("paste", { text: transcript, keybind });
+          const report = await invoke<InjectionReport>("paste", {
+            text: transcript,
+            keybind,
+            appTargetId: currentApp?.id ?? null,
+            toneId: formattingToneId,
+          });
+          await showInjectionResult(report);
         } catch (error) {
           console.error("Failed to paste transcription", error);
           showErrorSnackbar("Unable to paste transcription.");
@@ -141,6 +380,11 @@ export class DictationStrategy extends BaseStrategy {
   }
 
   async cleanup(): Promise<void> {
-    // Nothing to clean up for dictation
+    // Stops post-processing that is still streaming when the recording is
+    // abandoned.
+    if (this.postProcessRequestId) {
+      await cancelPostProcessing(this.postProcessRequestId);
+      this.postProcessRequestId = null;
+    }
   }
 }
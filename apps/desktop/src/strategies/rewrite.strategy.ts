@@ -0,0 +1,196 @@
+import type { Nullable } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+import { showToast } from "../actions/toast.actions";
+import {
+  cancelPostProcessing,
+  rewriteSelectedText,
+} from "../actions/transcribe.actions";
+import { getIntl } from "../i18n";
+import { getGenerateTextRepo } from "../repos";
+import { getAppState } from "../store";
+import type { OverlayPhase } from "../types/overlay.types";
+import type {
+  HandleTranscriptParams,
+  HandleTranscriptResult,
+  StrategyValidationError,
+} from "../types/strategy.types";
+import { createId } from "../utils/id.utils";
+import { getMemberExceedsLimitByState } from "../utils/member.utils";
+import { applyReplacements } from "../utils/string.utils";
+import { BaseStrategy } from "./base.strategy";
+
+type SelectionRewriteStart = {
+  selectedText: string;
+  appName: Nullable<string>;
+};
+
+/**
+ * Replaces the text selected in the focused app with a version rewritten by
+ * a spoken instruction such as "make this more formal". The selection and
+ * clipboard are captured by the desktop backend before recording starts and
+ * left as they were if anything fails.
+ */
+export class RewriteStrategy extends BaseStrategy {
+  private selection: Nullable<SelectionRewriteStart> = null;
+  private rewriteRequestId: Nullable<string> = null;
+
+  shouldStoreTranscript(): boolean {
+    return false;
+  }
+
+  async validateAvailability(): Promise<Nullable<StrategyValidationError>> {
+    const state = getAppState();
+
+    if (getMemberExceedsLimitByState(state)) {
+      return {
+        title: getIntl().formatMessage({
+          defaultMessage: "Word limit reached",
+        }),
+        body: getIntl().formatMessage({
+          defaultMessage: "Upgrade to Pro for unlimited words.",
+        }),
+        action: "upgrade",
+      };
+    }
+
+    if (!getGenerateTextRepo().repo) {
+      return {
+        title: getIntl().formatMessage({
+          defaultMessage: "Post-processing is off",
+        }),
+        body: getIntl().formatMessage({
+          defaultMessage:
+            "Set up post-processing in settings to rewrite selected text.",
+        }),
+        action: null,
+      };
+    }
+
+    // Read before recording starts, while the selection still has focus.
+    this.selection = await invoke<Nullable<SelectionRewriteStart>>(
+      "selection_rewrite_begin",
+    );
+    if (!this.selection) {
+      return {
+        title: getIntl().formatMessage({
+          defaultMessage: "No text selected",
+        }),
+        body: getIntl().formatMessage({
+          defaultMessage:
+            "Select the text to rewrite, then hold the shortcut and say how to change it.",
+        }),
+        action: null,
+      };
+    }
+
+    return null;
+  }
+
+  async onBeforeStart(): Promise<void> {
+    // The selection was captured during validation
+  }
+
+  async setPhase(phase: OverlayPhase): Promise<void> {
+    await invoke<void>("set_phase", { phase });
+  }
+
+  async handleTranscript({
+    rawTranscript,
+    currentApp,
+    loadingToken,
+    transcriptionMetadata,
+  }: HandleTranscriptParams): Promise<HandleTranscriptResult> {
+    const resetPhase = async () => {
+      if (
+        loadingToken &&
+        this.context.overlayLoadingTokenRef.current === loadingToken
+      ) {
+        this.context.overlayLoadingTokenRef.current = null;
+        await invoke<void>("set_phase", { phase: "idle" });
+      }
+    };
+
+    const result: HandleTranscriptResult = {
+      shouldContinue: false,
+      transcript: null,
+      sanitizedTranscript: null,
+      postProcessMetadata: {},
+      postProcessWarnings: [],
+    };
+
+    const selection = this.selection;
+    if (!selection) {
+      await resetPhase();
+      return result;
+    }
+
+    try {
+      const replacementRules = Object.values(getAppState().termById)
+        .filter((term) => term.isReplacement)
+        .map((term) => ({
+          sourceValue: term.sourceValue,
+          destinationValue: term.destinationValue,
+        }));
+      const instruction = transcriptionMetadata.appliedReplacements
+        ? rawTranscript
+        : applyReplacements(rawTranscript, replacementRules);
+
+      this.rewriteRequestId = createId();
+      const rewrite = await rewriteSelectedText({
+        selectedText: selection.selectedText,
+        instruction,
+        requestId: this.rewriteRequestId,
+      }).finally(() => {
+        this.rewriteRequestId = null;
+      });
+      result.sanitizedTranscript = instruction;
+      result.postProcessMetadata = rewrite.metadata;
+      result.postProcessWarnings = rewrite.warnings;
+
+      await resetPhase();
+      await new Promise<void>((resolve) => setTimeout(resolve, 20));
+      await invoke<void>("selection_rewrite_apply", {
+        text: rewrite.transcript,
+        keybind: currentApp?.pasteKeybind ?? null,
+        appTargetId: currentApp?.id ?? null,
+      });
+      result.transcript = rewrite.transcript;
+    } catch (error) {
+      console.error("Failed to rewrite selection", error);
+
+      const errorMessage =
+        error instanceof Error ? error.message : String(error);
+      result.postProcessWarnings.push(errorMessage);
+
+      await this.cancelRewrite();
+      await showToast({
+        title: getIntl().formatMessage({
+          defaultMessage: "Rewrite failed",
+        }),
+        message: errorMessage,
+        toastType: "error",
+      });
+      await resetPhase();
+    }
+
+    return result;
+  }
+
+  private async cancelRewrite(): Promise<void> {
+    this.selection = null;
+    if (this.rewriteRequestId) {
+      await cancelPostProcessing(this.rewriteRequestId);
+      this.rewriteRequestId = null;
+    }
+    try {
+      await invoke<void>("selection_rewrite_cancel");
+    } catch (error) {
+      console.error("Failed to cancel selection rewrite", error);
+    }
+  }
+
+  async cleanup(): Promise<void> {
+    // No-op once the rewrite was applied; otherwise restores the clipboard.
+    await this.cancelRewrite();
+  }
+}
@@ -0,0 +1,2 @@
+/** Where the desktop app reads key presses from. */
+export type KeyListenerSource = "rdev" | "evdev" | "portal";
@@ -1,4 +1,5 @@
 import { TranscribeAudioMetadata } from "../actions/transcribe.actions";
+import { AppTarget, Nullable } from "@repo/types";
 
 export type StopRecordingResponse = {
   samples: number[] | Float32Array;
@@ -11,8 +12,21 @@ export type TranscriptionSessionResult = {
   warnings: string[];
 };
 
+/**
+ * Where the dictation is headed, used to bias transcription vocabulary.
+ */
+export type TranscriptionSessionContext = {
+  appTarget?: Promise<Nullable<AppTarget>>;
+  appContext?: Nullable<string>;
+  /** Hotkey that started a dictation, e.g. `dictate-translate`. */
+  hotkeyAction?: Nullable<string>;
+};
+
 export interface TranscriptionSession {
   onRecordingStart(sampleRate: number): Promise<void>;
-  finalize(audio: StopRecordingResponse): Promise<TranscriptionSessionResult>;
+  finalize(
+    audio: StopRecordingResponse,
+    context?: TranscriptionSessionContext,
+  ): Promise<TranscriptionSessionResult>;
   cleanup(): void;
 }
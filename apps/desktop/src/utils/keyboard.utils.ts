@@ -1,9 +1,15 @@
+import { Hotkey, Nullable } from "@repo/types";
 import { AppState } from "../state/app.state";
 import { getPlatform } from "./platform.utils";
 
 export const DICTATE_HOTKEY = "dictate";
 export const AGENT_DICTATE_HOTKEY = "agent-dictate";
+export const DICTATE_TRANSLATE_HOTKEY = "dictate-translate";
+export const DICTATE_BILINGUAL_HOTKEY = "dictate-bilingual";
 export const LANGUAGE_SWITCH_HOTKEY = "language-switch";
+export const CODE_MODE_HOTKEY = "code-mode";
+export const REWRITE_SELECTION_HOTKEY = "rewrite-selection";
+export const UNDO_PASTE_HOTKEY = "undo-paste";
 
 export const getPrettyKeyName = (key: string): string => {
   const lower = key.toLowerCase();
@@ -34,6 +40,68 @@ export const getPrettyKeyName = (key: string): string => {
   return key;
 };
 
+const KEY_LABEL_BY_CODE: Record<string, string> = {
+  AltLeft: "Alt",
+  AltRight: "AltGr",
+  OSLeft: "MetaLeft",
+  OSRight: "MetaRight",
+  Enter: "Return",
+  NumpadEnter: "KpReturn",
+  ArrowUp: "UpArrow",
+  ArrowDown: "DownArrow",
+  ArrowLeft: "LeftArrow",
+  ArrowRight: "RightArrow",
+  BracketLeft: "LeftBracket",
+  BracketRight: "RightBracket",
+  Semicolon: "SemiColon",
+  Backquote: "BackQuote",
+  Backslash: "BackSlash",
+  Period: "Dot",
+};
+
+const SAME_KEY_LABELS = new Set([
+  "ControlLeft",
+  "ControlRight",
+  "ShiftLeft",
+  "ShiftRight",
+  "MetaLeft",
+  "MetaRight",
+  "Space",
+  "Escape",
+  "Tab",
+  "Backspace",
+  "CapsLock",
+  "Delete",
+  "Insert",
+  "Home",
+  "End",
+  "PageUp",
+  "PageDown",
+  "Minus",
+  "Equal",
+  "Quote",
+  "Comma",
+  "Slash",
+  "IntlBackslash",
+]);
+
+/**
+ * The listener's name for a DOM `KeyboardEvent.code`, e.g. `Digit1` becomes
+ * `Num1`, so keys pressed in this window can be recorded as hotkeys.
+ */
+export const getKeyLabelForCode = (code: string): Nullable<string> => {
+  if (/^Key[A-Z]$/.test(code) || /^F([1-9]|1[0-2])$/.test(code)) {
+    return code;
+  }
+  if (/^Digit[0-9]$/.test(code)) {
+    return `Num${code.slice(5)}`;
+  }
+  if (SAME_KEY_LABELS.has(code)) {
+    return code;
+  }
+  return KEY_LABEL_BY_CODE[code] ?? null;
+};
+
 type PlatformHotkeyCombos = {
   macos: string[][];
   windows: string[][];
@@ -87,3 +155,24 @@ export const getHotkeyCombosForAction = (
 
   return getDefaultHotkeyCombosForAction(actionName);
 };
+
+/**
+ * Every hotkey in effect, including defaults for actions without saved ones.
+ * Portal shortcuts must be registered up front, so these go to the listener.
+ */
+export const getListenerHotkeys = (state: AppState): Hotkey[] => {
+  const saved = Object.values(state.hotkeyById).filter(
+    (hotkey) => hotkey.keys.length > 0,
+  );
+  const defaults = Object.keys(DEFAULT_HOTKEY_COMBOS)
+    .filter((actionName) => !saved.some((h) => h.actionName === actionName))
+    .flatMap((actionName) =>
+      getDefaultHotkeyCombosForAction(actionName).map((keys, index) => ({
+        id: `default-${actionName}-${index}`,
+        actionName,
+        keys,
+      })),
+    );
+
+  return [...saved, ...defaults];
+};
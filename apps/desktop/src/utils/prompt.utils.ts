@@ -225,6 +225,40 @@ Your response MUST be in ${languageName}.${ifNotEnglish(dictationLanguage, "Do n
   return base;
 };
 
+export const buildSystemSelectionRewritePrompt = (): string => {
+  return "You are a writing assistant. You edit the text the user selected by following their spoken instruction, and return only the edited text.";
+};
+
+export const buildSelectionRewritePrompt = ({
+  selectedText,
+  instruction,
+  dictationLanguage,
+}: {
+  selectedText: string;
+  instruction: string;
+  dictationLanguage: string;
+}): string => {
+  const languageName = getDisplayNameForLanguage(dictationLanguage);
+
+  return `The user selected some text and spoke an instruction for changing it.
+
+SELECTED TEXT:
+-------
+${selectedText}
+-------
+
+INSTRUCTION (transcribed speech):
+"${instruction}"
+
+RULES (must follow):
+1. Apply the instruction to the selected text, and only the instruction. Keep everything it does not ask you to change.
+2. The output replaces the selection as-is: no quotes, labels, explanations or commentary.
+3. Keep the line breaks, lists and formatting of the selected text unless the instruction asks otherwise.
+4. Write in the language of the selected text unless the instruction asks for another one. If the selection has no clear language, use ${languageName}.
+
+Return only the rewritten text.`;
+};
+
 export const PROCESSED_TRANSCRIPTION_SCHEMA = z.object({
   processedTranscription: z
     .string()
@@ -0,0 +1,23 @@
+import { describe, expect, it } from "vitest";
+import { restoreRedactions } from "./redaction.utils";
+
+describe("restoreRedactions", () => {
+  it("should restore every occurrence of each placeholder", () => {
+    expect(
+      restoreRedactions("Call [PHONE_1], or [PHONE_1] after [EMAIL_1].", [
+        {
+          kind: "phone_number",
+          placeholder: "[PHONE_1]",
+          original: "010-1234-5678",
+        },
+        { kind: "email", placeholder: "[EMAIL_1]", original: "a@b.io" },
+      ]),
+    ).toBe("Call 010-1234-5678, or 010-1234-5678 after a@b.io.");
+  });
+
+  it("should leave text without placeholders unchanged", () => {
+    expect(restoreRedactions("Nothing to restore", [])).toBe(
+      "Nothing to restore",
+    );
+  });
+});
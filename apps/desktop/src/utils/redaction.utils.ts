@@ -0,0 +1,77 @@
+import { Nullable, Redaction, RedactionStage, UserPreferences } from "@repo/types";
+import { invoke } from "@tauri-apps/api/core";
+
+export type RedactTranscriptResult = {
+  text: string;
+  redactions: Redaction[];
+  warning: Nullable<string>;
+};
+
+/**
+ * Masks phone numbers, emails, card numbers and the like with placeholders
+ * using the detectors the user picked. Throws if the redaction engine fails
+ * so callers can refuse to send or save the unmasked text.
+ */
+export const redactTranscript = async (
+  text: string,
+  stage: RedactionStage,
+  preferences: Nullable<UserPreferences>,
+): Promise<RedactTranscriptResult> => {
+  return await invoke<RedactTranscriptResult>("redact_transcript", {
+    text,
+    detectors: preferences?.redactionDetectors ?? null,
+    patterns: preferences?.redactionPatterns ?? null,
+    stage,
+  });
+};
+
+export type RedactTextsResult = {
+  texts: string[];
+  redactions: Redaction[];
+  warning: Nullable<string>;
+};
+
+// Joins texts so they are redacted in one pass and a value in several of them
+// gets the same placeholder.
+const REDACTION_SEPARATOR = "\n\u241e\n";
+
+/**
+ * Masks several texts bound for the same request, such as a transcript and
+ * the text around the cursor. Throws if they cannot be split apart again.
+ */
+export const redactTexts = async (
+  texts: string[],
+  stage: RedactionStage,
+  preferences: Nullable<UserPreferences>,
+): Promise<RedactTextsResult> => {
+  const redacted = await redactTranscript(
+    texts.join(REDACTION_SEPARATOR),
+    stage,
+    preferences,
+  );
+  const parts = redacted.text.split(REDACTION_SEPARATOR);
+  if (parts.length !== texts.length) {
+    throw new Error("Unable to split the redacted text.");
+  }
+
+  return {
+    texts: parts,
+    redactions: redacted.redactions,
+    warning: redacted.warning,
+  };
+};
+
+/**
+ * Puts the original values back into text that came back from a provider
+ * with placeholders such as `[PHONE_1]`.
+ */
+export const restoreRedactions = (
+  text: string,
+  redactions: Redaction[],
+): string => {
+  return redactions.reduce(
+    (restored, redaction) =>
+      restored.split(redaction.placeholder).join(redaction.original),
+    text,
+  );
+};
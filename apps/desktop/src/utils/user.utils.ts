@@ -2,6 +2,7 @@ import {
   ApiKeyProvider,
   DictationPillVisibility,
   Nullable,
+  PiiKind,
   User,
   UserPreferences,
 } from "@repo/types";
@@ -15,6 +16,15 @@ import { getEffectivePlan, getMemberExceedsLimitByState } from "./member.utils";
 
 export const LOCAL_USER_ID = "local-user-id";
 
+export const DEFAULT_SPACING_CORRECTION_LANGUAGES = ["ko"];
+
+export const DEFAULT_PII_KINDS: PiiKind[] = [
+  "resident_registration_number",
+  "card_number",
+  "email",
+  "phone_number",
+];
+
 export const getIsLoggedIn = (state: AppState): boolean => {
   return !!state.auth;
 };
@@ -90,6 +100,19 @@ export const getMyDictationLanguage = (state: AppState): string => {
   return getMyPrimaryDictationLanguage(state);
 };
 
+/**
+ * Languages the user dictates in when language switching is enabled. Local
+ * transcription restricts language detection to this set.
+ */
+export const getMyDictationLanguageCandidates = (state: AppState): string[] => {
+  const { enabled, secondaryLanguage } = state.settings.languageSwitch;
+  if (!enabled || !secondaryLanguage) {
+    return [];
+  }
+
+  return [getMyPrimaryDictationLanguage(state), secondaryLanguage];
+};
+
 export const getMyDictationLanguageCode = (state: AppState): string => {
   const language = getMyDictationLanguage(state);
   const baseCode = language.split("-")[0];
@@ -102,6 +125,13 @@ export const getMyUserPreferences = (
   return state.userPrefs;
 };
 
+export const getMySpacingCorrectionLanguages = (state: AppState): string[] =>
+  state.userPrefs?.spacingCorrectionLanguages ??
+  DEFAULT_SPACING_CORRECTION_LANGUAGES;
+
+export const getMyRedactionDetectors = (state: AppState): PiiKind[] =>
+  state.userPrefs?.redactionDetectors ?? DEFAULT_PII_KINDS;
+
 export const getMyPreferredMicrophone = (state: AppState): Nullable<string> => {
   return state.userPrefs?.preferredMicrophone ?? null;
 };
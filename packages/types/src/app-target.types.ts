@@ -1,5 +1,21 @@
 import type { Nullable } from "./common.types";
 
+export type InjectionStrategy =
+  | "clipboard"
+  | "typing"
+  | "chunked_typing"
+  | "primary_selection"
+  | "clipboard_only";
+
+export type InjectionOutcome = "verified" | "unverified" | "failed" | "copied";
+
+export type InjectionReport = {
+  strategy: InjectionStrategy;
+  outcome: InjectionOutcome;
+  appName: Nullable<string>;
+  message: Nullable<string>;
+};
+
 export type AppTarget = {
   id: string;
   name: string;
@@ -7,4 +23,9 @@ export type AppTarget = {
   toneId: Nullable<string>;
   iconPath: Nullable<string>;
   pasteKeybind: Nullable<string>;
+  inverseTextNormalization?: Nullable<boolean>;
+  codeModeEnabled?: boolean;
+  injectionStrategy?: InjectionStrategy;
+  typingDelayMs?: Nullable<number>;
+  richTextPaste?: boolean;
 };
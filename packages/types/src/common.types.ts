@@ -22,6 +22,16 @@ export type JsonResponse = {
 
 export type TranscriptionMode = "local" | "api" | "cloud";
 
+export type LocalTranscriptionEngine = "whisper_cpp" | "candle";
+
+export type TranscriptionFallbackStep =
+  | { mode: "api"; apiKeyId: string }
+  | {
+      mode: "local";
+      modelSize?: Nullable<string>;
+      engine?: Nullable<LocalTranscriptionEngine>;
+    };
+
 export type PostProcessingMode = "none" | "api" | "cloud";
 
 export type AgentMode = "none" | "api" | "cloud";
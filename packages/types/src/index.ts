@@ -11,3 +11,6 @@ export * from "./apiKey.types";
 export * from "./preferences.types";
 export * from "./tone.types";
 export * from "./openrouter.types";
+export * from "./voice-command.types";
+export * from "./redaction.types";
+export * from "./snippet.types";
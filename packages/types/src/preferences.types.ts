@@ -1,10 +1,13 @@
 import type {
   AgentMode,
   DictationPillVisibility,
+  LocalTranscriptionEngine,
   Nullable,
   PostProcessingMode,
+  TranscriptionFallbackStep,
   TranscriptionMode,
 } from "./common.types";
+import type { PiiKind } from "./redaction.types";
 
 export type UserPreferences = {
   userId: string;
@@ -12,6 +15,8 @@ export type UserPreferences = {
   transcriptionApiKeyId: Nullable<string>;
   transcriptionDevice: Nullable<string>;
   transcriptionModelSize: Nullable<string>;
+  localTranscriptionEngine?: Nullable<LocalTranscriptionEngine>;
+  transcriptionFallbackChain?: Nullable<TranscriptionFallbackStep[]>;
   postProcessingMode: Nullable<PostProcessingMode>;
   postProcessingApiKeyId: Nullable<string>;
   postProcessingOllamaUrl: Nullable<string>;
@@ -31,4 +36,12 @@ export type UserPreferences = {
   incognitoModeEnabled: boolean;
   incognitoModeIncludeInStats: boolean;
   dictationPillVisibility: DictationPillVisibility;
+  disfluencyCleanupEnabled?: boolean;
+  fillerWords?: Nullable<string[]>;
+  spacingCorrectionLanguages?: Nullable<string[]>;
+  redactBeforeCloud?: boolean;
+  redactBeforeStorage?: boolean;
+  redactionDetectors?: Nullable<PiiKind[]>;
+  redactionPatterns?: Nullable<string[]>;
+  voiceCommandsEnabled?: boolean;
 };
@@ -0,0 +1,14 @@
+export type PiiKind =
+  | "email"
+  | "phone_number"
+  | "card_number"
+  | "resident_registration_number"
+  | "custom";
+
+export type RedactionStage = "post_processing" | "storage";
+
+export type Redaction = {
+  kind: PiiKind;
+  placeholder: string;
+  original: string;
+};
@@ -0,0 +1,21 @@
+import type { InjectionReport } from "./app-target.types";
+
+/**
+ * Text pasted when a dictation is exactly `trigger`, or a snippet command such
+ * as "insert snippet signature". `expansion` may contain `{{date}}`,
+ * `{{time}}`, `{{clipboard}}` and `{{selected_text}}` placeholders.
+ */
+export type Snippet = {
+  id: string;
+  createdAt: string;
+  trigger: string;
+  expansion: string;
+};
+
+export type SnippetExpansion = {
+  snippetId: string;
+  trigger: string;
+  text: string;
+  /** How the expansion was inserted into the focused app. */
+  report: InjectionReport;
+};
@@ -2,16 +2,29 @@ import { FiremixTimestamp } from "@firemix/core";
 import { Replace } from "./common.types";
 import z from "zod";
 
+export type TermMatchMode = "exact" | "fuzzy" | "regex";
+
 export type DatabaseTerm = {
   id: string;
   createdAt: FiremixTimestamp;
   sourceValue: string;
   destinationValue: string;
   isReplacement: boolean;
+  matchMode?: TermMatchMode;
 };
 
 export type Term = Replace<DatabaseTerm, FiremixTimestamp, string>;
 
+/**
+ * A replacement learned from recurring corrections to transcripts.
+ */
+export type TermSuggestion = {
+  sourceValue: string;
+  destinationValue: string;
+  occurrences: number;
+  lastSeenAt: number;
+};
+
 export type TermDoc = {
   id: string;
   termIds: string[];
@@ -25,5 +38,6 @@ export const TermZod = z
     sourceValue: z.string(),
     destinationValue: z.string(),
     isReplacement: z.boolean(),
+    matchMode: z.enum(["exact", "fuzzy", "regex"]).optional(),
   })
   .strict() satisfies z.ZodType<Term>;
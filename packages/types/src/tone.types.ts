@@ -1,3 +1,5 @@
+import { TranslationMode } from "./transcription.types";
+
 export type Tone = {
   id: string;
   name: string;
@@ -5,4 +7,6 @@ export type Tone = {
   isSystem: boolean;
   createdAt: number;
   sortOrder: number;
+  translationMode?: TranslationMode | null;
+  inverseTextNormalization?: boolean | null;
 };
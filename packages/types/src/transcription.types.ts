@@ -1,4 +1,5 @@
 import { PostProcessingMode, TranscriptionMode } from "./common.types";
+import { TermMatchMode } from "./term.types";
 
 export type Transcription = {
   id: string;
@@ -21,6 +22,26 @@ export type Transcription = {
   transcriptionDurationMs?: number | null;
   postprocessDurationMs?: number | null;
   warnings?: string[] | null;
+  detectedLanguage?: string | null;
+  languageProbabilities?: LanguageProbability[] | null;
+  translatedTranscript?: string | null;
+  translationMode?: TranslationMode | null;
+  appliedReplacements?: AppliedReplacement[] | null;
+  appTargetId?: string | null;
+};
+
+export type AppliedReplacement = {
+  termId: string;
+  original: string;
+  replacement: string;
+  matchMode: TermMatchMode;
+};
+
+export type TranslationMode = "off" | "english" | "bilingual";
+
+export type LanguageProbability = {
+  language: string;
+  probability: number;
 };
 
 export type TranscriptionAudioSnapshot = {
@@ -0,0 +1,16 @@
+export type VoiceCommandAction = "insert" | "delete_last" | "all_caps" | "escape";
+
+export type VoiceCommand = {
+  id: string;
+  language: string;
+  phrase: string;
+  action: VoiceCommandAction;
+  value?: string | null;
+  isEnabled: boolean;
+  isDefault?: boolean;
+};
+
+export type ExecutedVoiceCommand = {
+  phrase: string;
+  action: VoiceCommandAction;
+};